@@ -0,0 +1,31 @@
+//! Feeds arbitrary bytes through the HTML entry points
+//!
+//! Any panic or stack overflow is a bug. Inputs are capped in size because some
+//! extractors are quadratic in nesting depth; run with `-timeout` to catch
+//! anything slower than that.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use meta_oxide::html_utils;
+
+/// Largest input worth extracting; bigger inputs only find slowness, not bugs
+const MAX_INPUT_LEN: usize = 16 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT_LEN {
+        return;
+    }
+
+    let _ = html_utils::parse_html_bytes(data);
+
+    let html = String::from_utf8_lossy(data);
+    for base_url in [None, Some("https://example.com/dir/page.html")] {
+        if let Ok(extracted) = meta_oxide::extract_all(&html, base_url) {
+            let _ = meta_oxide::analysis::resolve_author(&extracted);
+            let _ = meta_oxide::analysis::canonical_report(&extracted);
+            let _ = serde_json::to_string(&extracted);
+        }
+        let _ = meta_oxide::serialize::extract_ntriples(&html, base_url);
+    }
+});
@@ -73,11 +73,22 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Version of the combined output shape
+ *
+ * Emitted as `schema_version` by [`extract_all_json`] and the Python
+ * `extract_all` dict. It is bumped only when the output changes in a way
+ * that can break consumers (a field removed, renamed or retyped), not on
+ * every release; new fields do not bump it.
+ */
+#define SCHEMA_VERSION 1
+
 /**
  * Result structure containing all extracted metadata
  *
- * Each field is a JSON string or NULL if no data was found.
- * The caller must free this struct using `meta_oxide_result_free()`.
+ * Each field is a JSON string or NULL if no data was found; `meta`,
+ * `open_graph`, `twitter` and `dublin_core` are always set, as empty objects
+ * when there is nothing to report. The caller must free this struct using `meta_oxide_result_free()`.
  */
 typedef struct MetaOxideResult {
   /**
@@ -215,6 +226,18 @@ char *meta_oxide_extract_microformats(const char *html, const char *base_url);
  */
 char *meta_oxide_extract_rdfa(const char *html, const char *base_url);
 
+/**
+ * Extract RDFa as RDF triples
+ *
+ * # Returns
+ * JSON array string or NULL on error
+ *
+ * # Safety
+ * - `html` must be a valid null-terminated C string
+ * - `base_url` may be NULL or a valid null-terminated C string
+ */
+char *meta_oxide_extract_rdfa_triples(const char *html, const char *base_url);
+
 /**
  * Extract Dublin Core metadata
  *
@@ -255,6 +278,41 @@ char *meta_oxide_extract_oembed(const char *html, const char *base_url);
  */
 char *meta_oxide_extract_rel_links(const char *html, const char *base_url);
 
+/**
+ * Extract resource hints (preload, preconnect, dns-prefetch, prefetch, prerender)
+ *
+ * # Returns
+ * JSON object string or NULL on error
+ *
+ * # Safety
+ * - `html` must be a valid null-terminated C string
+ * - `base_url` may be NULL or a valid null-terminated C string
+ */
+char *meta_oxide_extract_resource_hints(const char *html, const char *base_url);
+
+/**
+ * Extract the breadcrumb trail (JSON-LD, microdata, or nav fallback)
+ *
+ * # Returns
+ * JSON array string or NULL on error
+ *
+ * # Safety
+ * - `html` must be a valid null-terminated C string
+ * - `base_url` may be NULL or a valid null-terminated C string
+ */
+char *meta_oxide_extract_breadcrumbs(const char *html, const char *base_url);
+
+/**
+ * Extract legacy geotagging meta tags (geo.position, ICBM, geo.region, geo.placename)
+ *
+ * # Returns
+ * JSON object string or NULL on error
+ *
+ * # Safety
+ * `html` must be a valid null-terminated C string
+ */
+char *meta_oxide_extract_geo(const char *html);
+
 /**
  * Get the last error code
  *
@@ -311,4 +369,11 @@ void meta_oxide_manifest_discovery_free(struct ManifestDiscovery *discovery);
  */
 const char *meta_oxide_version(void);
 
+/**
+ * Get the version of the JSON output shape
+ *
+ * Bumped only on breaking output changes, not on every release.
+ */
+uint32_t meta_oxide_schema_version(void);
+
 #endif /* META_OXIDE_H */
@@ -0,0 +1,274 @@
+//! Postal address aggregation
+//!
+//! Local business pages often repeat their address in JSON-LD, microdata and
+//! an h-card. This collects every `PostalAddress` into one shape and drops the
+//! repeats.
+
+use crate::types::address::{AddressSource, PostalAddress};
+use crate::types::extracted::ExtractedData;
+use crate::types::microdata::{MicrodataItem, PropertyValue as MicrodataValue};
+use crate::types::rdfa::{RdfaItem, RdfaValue};
+use crate::types::PropertyValue;
+use serde_json::Value;
+
+/// Schema.org property names, in [`PostalAddress`] field order
+const SCHEMA_FIELDS: [&str; 5] =
+    ["streetAddress", "addressLocality", "addressRegion", "postalCode", "addressCountry"];
+
+/// Microformats property names, in [`PostalAddress`] field order
+const MF_FIELDS: [&str; 5] =
+    ["street-address", "locality", "region", "postal-code", "country-name"];
+
+/// Collect the postal addresses declared by the page
+///
+/// Sources are visited in order: JSON-LD, microdata, RDFa, then microformats
+/// (h-adr and h-card). Nested addresses are found too, e.g. a LocalBusiness
+/// `address` or an Event's `location.address`. An address that matches an
+/// earlier one field by field, ignoring case and whitespace, is dropped.
+pub fn addresses(data: &ExtractedData) -> Vec<PostalAddress> {
+    let mut addresses = Vec::new();
+
+    for object in &data.jsonld {
+        if let Ok(value) = serde_json::to_value(object) {
+            collect_jsonld(&value, false, &mut addresses);
+        }
+    }
+
+    for item in &data.microdata {
+        collect_microdata(item, &mut addresses);
+    }
+
+    for item in &data.rdfa {
+        collect_rdfa(item, &mut addresses);
+    }
+
+    for root in ["h-adr", "h-card"] {
+        for item in data.microformats.get(root).into_iter().flatten() {
+            let fields = MF_FIELDS.map(|name| {
+                item.properties.get(name).into_iter().flatten().find_map(|value| match value {
+                    PropertyValue::Text(text) | PropertyValue::Url(text) => Some(text.as_str()),
+                    _ => None,
+                })
+            });
+            push_address(&mut addresses, fields, AddressSource::Microformats);
+        }
+    }
+
+    addresses
+}
+
+/// Find `PostalAddress` objects anywhere in a JSON-LD value
+///
+/// `in_address` marks the value of an `address` property, which is taken as a
+/// postal address even without an explicit `@type`.
+fn collect_jsonld(value: &Value, in_address: bool, addresses: &mut Vec<PostalAddress>) {
+    match value {
+        Value::Object(map) => {
+            if in_address || map.get("@type").is_some_and(is_postal_address_type) {
+                let fields = SCHEMA_FIELDS.map(|name| map.get(name).and_then(jsonld_text));
+                push_address(addresses, fields, AddressSource::JsonLd);
+            }
+            for (key, value) in map {
+                collect_jsonld(value, key == "address", addresses);
+            }
+        }
+        Value::Array(values) => {
+            values.iter().for_each(|value| collect_jsonld(value, in_address, addresses))
+        }
+        _ => {}
+    }
+}
+
+/// Text of a JSON-LD value; a `Country` object gives its `name`
+fn jsonld_text(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(text) => Some(text),
+        Value::Object(map) => map.get("name").and_then(Value::as_str),
+        Value::Array(values) => values.first().and_then(jsonld_text),
+        _ => None,
+    }
+}
+
+fn is_postal_address_type(type_: &Value) -> bool {
+    match type_ {
+        Value::String(t) => t.ends_with("PostalAddress"),
+        Value::Array(types) => types.iter().any(is_postal_address_type),
+        _ => false,
+    }
+}
+
+fn collect_microdata(item: &MicrodataItem, addresses: &mut Vec<PostalAddress>) {
+    let types = item.item_type.as_deref().unwrap_or_default();
+    if types.iter().any(|t| t.ends_with("PostalAddress")) {
+        let fields = SCHEMA_FIELDS.map(|name| {
+            item.properties.get(name).and_then(|values| values.first()).and_then(microdata_text)
+        });
+        push_address(addresses, fields, AddressSource::Microdata);
+    }
+
+    for value in item.properties.values().flatten() {
+        if let MicrodataValue::Item(nested) = value {
+            collect_microdata(nested, addresses);
+        }
+    }
+}
+
+/// Text of a microdata value; a nested item (e.g. a `Country`) gives its `name`
+fn microdata_text(value: &MicrodataValue) -> Option<&str> {
+    match value {
+        MicrodataValue::Text(text) => Some(text),
+        MicrodataValue::Item(nested) => {
+            nested.properties.get("name")?.first().and_then(microdata_text)
+        }
+    }
+}
+
+fn collect_rdfa(item: &RdfaItem, addresses: &mut Vec<PostalAddress>) {
+    let types = item.type_of.as_deref().unwrap_or_default();
+    if types.iter().any(|t| t.ends_with("PostalAddress")) {
+        let fields = SCHEMA_FIELDS.map(|name| {
+            item.properties.get(name).and_then(|values| values.first()).and_then(rdfa_text)
+        });
+        push_address(addresses, fields, AddressSource::Rdfa);
+    }
+
+    for value in item.properties.values().flatten() {
+        if let RdfaValue::Item(nested) = value {
+            collect_rdfa(nested, addresses);
+        }
+    }
+}
+
+/// Text of an RDFa value; a nested item (e.g. a `Country`) gives its `name`
+fn rdfa_text(value: &RdfaValue) -> Option<&str> {
+    match value {
+        RdfaValue::Literal(text) | RdfaValue::Resource(text) => Some(text),
+        RdfaValue::TypedLiteral { value, .. } | RdfaValue::LangLiteral { value, .. } => Some(value),
+        RdfaValue::Item(nested) => nested.properties.get("name")?.first().and_then(rdfa_text),
+    }
+}
+
+/// Add an address unless it is empty or repeats an earlier one
+fn push_address(
+    addresses: &mut Vec<PostalAddress>,
+    fields: [Option<&str>; 5],
+    source: AddressSource,
+) {
+    let fields = fields.map(|field| {
+        field
+            .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|text| !text.is_empty())
+    });
+    if fields.iter().all(Option::is_none) {
+        return;
+    }
+
+    let [street, locality, region, postal_code, country] = fields;
+    let address = PostalAddress { street, locality, region, postal_code, country, source };
+    if !addresses.iter().any(|existing| same_address(existing, &address)) {
+        addresses.push(address);
+    }
+}
+
+/// Whether two addresses have the same fields, ignoring case and source
+fn same_address(a: &PostalAddress, b: &PostalAddress) -> bool {
+    let eq = |x: &Option<String>, y: &Option<String>| match (x, y) {
+        (Some(x), Some(y)) => x.to_lowercase() == y.to_lowercase(),
+        (None, None) => true,
+        _ => false,
+    };
+    eq(&a.street, &b.street)
+        && eq(&a.locality, &b.locality)
+        && eq(&a.region, &b.region)
+        && eq(&a.postal_code, &b.postal_code)
+        && eq(&a.country, &b.country)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_jsonld_and_hadr_deduplicated() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "LocalBusiness",
+                "name": "Corner Cafe",
+                "address": {
+                    "@type": "PostalAddress",
+                    "streetAddress": "123 Main St",
+                    "addressLocality": "Springfield",
+                    "addressRegion": "IL",
+                    "postalCode": "62701",
+                    "addressCountry": {"@type": "Country", "name": "US"}
+                }
+            }
+            </script>
+            <div class="h-adr">
+                <span class="p-street-address">123  main st</span>,
+                <span class="p-locality">Springfield</span>,
+                <span class="p-region">IL</span>
+                <span class="p-postal-code">62701</span>
+                <span class="p-country-name">US</span>
+            </div>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let found = addresses(&data);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0],
+            PostalAddress {
+                street: Some("123 Main St".to_string()),
+                locality: Some("Springfield".to_string()),
+                region: Some("IL".to_string()),
+                postal_code: Some("62701".to_string()),
+                country: Some("US".to_string()),
+                source: AddressSource::JsonLd,
+            }
+        );
+    }
+
+    #[test]
+    fn test_distinct_addresses_kept_separate() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Event",
+                "location": {
+                    "@type": "Place",
+                    "address": {"streetAddress": "1 Harbour Rd", "addressLocality": "Portsmouth"}
+                }
+            }
+            </script>
+            <div itemscope itemtype="https://schema.org/Organization">
+                <div itemprop="address" itemscope itemtype="https://schema.org/PostalAddress">
+                    <span itemprop="streetAddress">1 Harbour Rd</span>
+                    <span itemprop="addressLocality">Southampton</span>
+                </div>
+            </div>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let found = addresses(&data);
+
+        let summary: Vec<(Option<&str>, AddressSource)> =
+            found.iter().map(|a| (a.locality.as_deref(), a.source)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("Portsmouth"), AddressSource::JsonLd),
+                (Some("Southampton"), AddressSource::Microdata),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_addresses() {
+        let data = extract_all("<title>Nothing here</title>", None).unwrap();
+        assert!(addresses(&data).is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+//! Authorship reconciliation
+//!
+//! Collects author candidates from JSON-LD, microformats, `<meta name="author">`
+//! and `rel="author"` links into one deduplicated list.
+
+use crate::types::authorship::{Author, AuthorSource};
+use crate::types::extracted::ExtractedData;
+use crate::types::jsonld::JsonLdObject;
+use crate::types::PropertyValue;
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Collect the document's authors from every format
+///
+/// Sources are visited from most to least structured (JSON-LD, h-card, meta
+/// tag, rel-author). A later candidate with the same name or URL as an earlier
+/// one is merged into it, filling in a missing name or URL, so a plain meta
+/// author naming the same person as a JSON-LD `Person` keeps the JSON-LD entry.
+pub fn resolve_author(data: &ExtractedData) -> Vec<Author> {
+    let mut authors = Vec::new();
+
+    // JSON-LD `author` (string, object, @id reference, or array of those)
+    let nodes = jsonld_nodes_by_id(&data.jsonld);
+    for object in &data.jsonld {
+        collect_jsonld_authors(object, &nodes, &mut authors);
+    }
+
+    // Microformats: h-entry p-author, enriched from a matching h-card
+    let hcards = data.microformats.get("h-card").map(Vec::as_slice).unwrap_or_default();
+    for entry in data.microformats.get("h-entry").into_iter().flatten() {
+        for value in entry.properties.get("author").into_iter().flatten() {
+            let candidate = match value {
+                PropertyValue::Text(name) => {
+                    let url = hcards
+                        .iter()
+                        .find(|card| first_text(&card.properties, "name").as_deref() == Some(name))
+                        .and_then(|card| first_text(&card.properties, "url"));
+                    Author { name: non_empty(name), url, source: AuthorSource::HCard }
+                }
+                PropertyValue::Url(url) => {
+                    Author { name: None, url: non_empty(url), source: AuthorSource::HCard }
+                }
+                PropertyValue::Nested(card) => Author {
+                    name: first_text(&card.properties, "name"),
+                    url: first_text(&card.properties, "url"),
+                    source: AuthorSource::HCard,
+                },
+            };
+            push_candidate(&mut authors, candidate);
+        }
+    }
+
+    // <meta name="author">
+    if let Some(ref name) = data.meta.author {
+        push_candidate(
+            &mut authors,
+            Author { name: non_empty(name), url: None, source: AuthorSource::Meta },
+        );
+    }
+
+    // rel="author" links only contribute a URL
+    for url in data.rel_links.get("author").into_iter().flatten() {
+        push_candidate(
+            &mut authors,
+            Author { name: None, url: non_empty(url), source: AuthorSource::RelAuthor },
+        );
+    }
+
+    authors
+}
+
+/// Add a candidate, merging it into an existing author describing the same person
+fn push_candidate(authors: &mut Vec<Author>, candidate: Author) {
+    if candidate.name.is_none() && candidate.url.is_none() {
+        return;
+    }
+
+    let candidate_name = candidate.name.as_deref().map(normalize_name);
+    let existing = authors.iter_mut().find(|author| {
+        let same_name = match (&candidate_name, &author.name) {
+            (Some(a), Some(b)) => *a == normalize_name(b),
+            _ => false,
+        };
+        let same_url = match (&candidate.url, &author.url) {
+            (Some(a), Some(b)) => a.trim_end_matches('/') == b.trim_end_matches('/'),
+            _ => false,
+        };
+        same_name || same_url
+    });
+
+    match existing {
+        Some(author) => {
+            if author.name.is_none() {
+                author.name = candidate.name;
+            }
+            if author.url.is_none() {
+                author.url = candidate.url;
+            }
+        }
+        None => authors.push(candidate),
+    }
+}
+
+/// Compare names case-insensitively with collapsed whitespace
+fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string())
+    }
+}
+
+fn first_text(properties: &IndexMap<String, Vec<PropertyValue>>, key: &str) -> Option<String> {
+    properties.get(key)?.iter().find_map(|value| match value {
+        PropertyValue::Text(s) | PropertyValue::Url(s) => non_empty(s),
+        PropertyValue::Nested(_) => None,
+    })
+}
+
+/// Index every JSON-LD node (including `@graph` members) by `@id`
+fn jsonld_nodes_by_id(objects: &[JsonLdObject]) -> HashMap<&str, &JsonLdObject> {
+    let mut nodes = HashMap::new();
+    for object in objects {
+        if let Some(ref id) = object.id {
+            nodes.insert(id.as_str(), object);
+        }
+        if let Some(ref graph) = object.graph {
+            nodes.extend(jsonld_nodes_by_id(graph));
+        }
+    }
+    nodes
+}
+
+fn collect_jsonld_authors(
+    object: &JsonLdObject,
+    nodes: &HashMap<&str, &JsonLdObject>,
+    authors: &mut Vec<Author>,
+) {
+    if let Some(author) = object.properties.get("author") {
+        collect_author_value(author, nodes, authors);
+    }
+    for child in object.graph.iter().flatten() {
+        collect_jsonld_authors(child, nodes, authors);
+    }
+}
+
+fn collect_author_value(
+    value: &Value,
+    nodes: &HashMap<&str, &JsonLdObject>,
+    authors: &mut Vec<Author>,
+) {
+    let candidate = match value {
+        Value::String(name) => {
+            Author { name: non_empty(name), url: None, source: AuthorSource::JsonLd }
+        }
+        Value::Object(map) => {
+            let id = map.get("@id").and_then(Value::as_str);
+            // A bare {"@id": ...} points at a Person node elsewhere in the document
+            let referenced = id.and_then(|id| nodes.get(id));
+            let name = map
+                .get("name")
+                .and_then(Value::as_str)
+                .or_else(|| referenced.and_then(|node| node.properties.get("name")?.as_str()));
+            let url = map
+                .get("url")
+                .and_then(Value::as_str)
+                .or_else(|| referenced.and_then(|node| node.properties.get("url")?.as_str()))
+                .or(id.filter(|id| id.starts_with("http")));
+            Author {
+                name: name.and_then(non_empty),
+                url: url.and_then(non_empty),
+                source: AuthorSource::JsonLd,
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_author_value(value, nodes, authors);
+            }
+            return;
+        }
+        _ => return,
+    };
+    push_candidate(authors, candidate);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_meta_author_merged_into_jsonld_person() {
+        let html = r#"
+            <meta name="author" content="jane  doe">
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Article",
+                "author": {"@type": "Person", "name": "Jane Doe", "url": "https://jane.example"}
+            }
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let authors = resolve_author(&data);
+        assert_eq!(
+            authors,
+            vec![Author {
+                name: Some("Jane Doe".to_string()),
+                url: Some("https://jane.example".to_string()),
+                source: AuthorSource::JsonLd,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_rel_author_contributes_url_only_author() {
+        let html = r#"
+            <meta name="author" content="Jane Doe">
+            <link rel="author" href="/about/sam">
+        "#;
+        let data = extract_all(html, Some("https://example.com")).unwrap();
+        let authors = resolve_author(&data);
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[0].name, Some("Jane Doe".to_string()));
+        assert_eq!(authors[0].source, AuthorSource::Meta);
+        assert_eq!(authors[1].name, None);
+        assert_eq!(authors[1].url, Some("https://example.com/about/sam".to_string()));
+        assert_eq!(authors[1].source, AuthorSource::RelAuthor);
+    }
+
+    #[test]
+    fn test_rel_author_matching_jsonld_url_is_merged() {
+        let html = r#"
+            <link rel="author" href="https://jane.example/">
+            <script type="application/ld+json">
+            {"@type": "BlogPosting", "author": [{"name": "Jane Doe", "url": "https://jane.example"}]}
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let authors = resolve_author(&data);
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].source, AuthorSource::JsonLd);
+    }
+
+    #[test]
+    fn test_jsonld_author_id_reference() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@graph": [
+                    {"@type": "Article", "author": {"@id": "https://example.com/#jane"}},
+                    {"@type": "Person", "@id": "https://example.com/#jane", "name": "Jane Doe"}
+                ]
+            }
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let authors = resolve_author(&data);
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].name, Some("Jane Doe".to_string()));
+    }
+
+    #[test]
+    fn test_hentry_author_with_hcard() {
+        let html = r#"
+            <article class="h-entry">
+                <div class="p-author h-card"><a class="p-name u-url" href="https://sam.example">Sam</a></div>
+            </article>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let authors = resolve_author(&data);
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].name, Some("Sam".to_string()));
+        assert_eq!(authors[0].url, Some("https://sam.example".to_string()));
+        assert_eq!(authors[0].source, AuthorSource::HCard);
+    }
+
+    #[test]
+    fn test_no_authors() {
+        let data = extract_all("<title>Anonymous</title>", None).unwrap();
+        assert!(resolve_author(&data).is_empty());
+    }
+}
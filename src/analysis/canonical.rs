@@ -0,0 +1,165 @@
+//! Canonical URL consistency
+//!
+//! Compares the page URL declared by `<link rel="canonical">`, `og:url` and
+//! JSON-LD so SEO audits can flag pages whose formats disagree.
+
+use crate::extractors::jsonld::hierarchy::type_value_is_subtype_of;
+use crate::types::canonical::CanonicalReport;
+use crate::types::extracted::ExtractedData;
+use crate::types::jsonld::JsonLdObject;
+use serde_json::Value;
+use url::Url;
+
+/// Report the canonical URL declared by each format and whether they conflict
+///
+/// JSON-LD `url` is only taken from the page itself (a `WebPage` type) or from
+/// the entity that declares `mainEntityOfPage`, since the `url` of an
+/// `Organization` or `WebSite` node usually points at the homepage.
+pub fn canonical_report(data: &ExtractedData) -> CanonicalReport {
+    let mut report = CanonicalReport {
+        link_canonical: data.meta.canonical.as_deref().and_then(normalize_url),
+        og_url: data.opengraph.url.as_deref().and_then(normalize_url),
+        ..Default::default()
+    };
+
+    for object in &data.jsonld {
+        let main_entity = object.properties.get("mainEntityOfPage").and_then(url_of);
+        if report.jsonld_main_entity_of_page.is_none() {
+            report.jsonld_main_entity_of_page = main_entity.as_deref().and_then(normalize_url);
+        }
+
+        if report.jsonld_url.is_none() && (main_entity.is_some() || is_web_page(object)) {
+            report.jsonld_url =
+                object.properties.get("url").and_then(url_of).as_deref().and_then(normalize_url);
+        }
+    }
+
+    let urls = report.urls();
+    report.conflicts = urls.iter().any(|url| *url != urls[0]);
+    report
+}
+
+/// Normalize a URL for comparison
+///
+/// `http` is upgraded to `https`, and the fragment and any trailing slash on the
+/// path are dropped. Relative or unparseable URLs are compared as trimmed text.
+fn normalize_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return None;
+    }
+
+    let Ok(mut parsed) = Url::parse(url) else {
+        return Some(url.trim_end_matches('/').to_string());
+    };
+    if parsed.scheme() == "http" {
+        let _ = parsed.set_scheme("https");
+    }
+    parsed.set_fragment(None);
+
+    let path = parsed.path().trim_end_matches('/').to_string();
+    parsed.set_path(&path);
+
+    let normalized = parsed.to_string();
+    // The root path always serializes as "/"
+    Some(match normalized.strip_suffix('/') {
+        Some(stripped) if parsed.query().is_none() => stripped.to_string(),
+        _ => normalized,
+    })
+}
+
+/// A URL given as a string or as a node with `@id` / `url`
+fn url_of(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(node) => {
+            node.get("@id").or_else(|| node.get("url")).and_then(Value::as_str).map(str::to_string)
+        }
+        Value::Array(values) => values.iter().find_map(url_of),
+        _ => None,
+    }
+}
+
+/// Whether the node describes the page itself (`WebPage` or a subtype such as `AboutPage`)
+fn is_web_page(object: &JsonLdObject) -> bool {
+    type_value_is_subtype_of(object.type_.as_ref(), "WebPage")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_agreeing_canonicals_do_not_conflict() {
+        let html = r#"
+            <link rel="canonical" href="https://example.com/post/">
+            <meta property="og:url" content="http://example.com/post">
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Article",
+                "url": "https://example.com/post#top",
+                "mainEntityOfPage": {"@type": "WebPage", "@id": "https://example.com/post"}
+            }
+            </script>
+        "#;
+        let report = canonical_report(&extract_all(html, None).unwrap());
+        assert_eq!(report.link_canonical, Some("https://example.com/post".to_string()));
+        assert_eq!(report.og_url, Some("https://example.com/post".to_string()));
+        assert_eq!(report.jsonld_url, Some("https://example.com/post".to_string()));
+        assert_eq!(report.jsonld_main_entity_of_page, Some("https://example.com/post".to_string()));
+        assert!(!report.conflicts);
+    }
+
+    #[test]
+    fn test_differing_og_url_conflicts() {
+        let html = r#"
+            <link rel="canonical" href="https://example.com/post">
+            <meta property="og:url" content="https://example.com/amp/post">
+        "#;
+        let report = canonical_report(&extract_all(html, None).unwrap());
+        assert_eq!(report.og_url, Some("https://example.com/amp/post".to_string()));
+        assert!(report.conflicts);
+    }
+
+    #[test]
+    fn test_only_og_url_present() {
+        let html = r#"<meta property="og:url" content="https://example.com/">"#;
+        let report = canonical_report(&extract_all(html, None).unwrap());
+        assert_eq!(report.link_canonical, None);
+        assert_eq!(report.og_url, Some("https://example.com".to_string()));
+        assert!(!report.conflicts);
+    }
+
+    #[test]
+    fn test_organization_url_is_ignored() {
+        let html = r#"
+            <link rel="canonical" href="https://example.com/about">
+            <script type="application/ld+json">
+            {"@type": "Organization", "url": "https://example.com/"}
+            </script>
+        "#;
+        let report = canonical_report(&extract_all(html, None).unwrap());
+        assert_eq!(report.jsonld_url, None);
+        assert!(!report.conflicts);
+    }
+
+    #[test]
+    fn test_web_page_subtypes_give_the_jsonld_url() {
+        let page = |type_: &str| {
+            let html = format!(
+                r#"<script type="application/ld+json">
+                {{"@type": "{}", "url": "https://example.com/listing"}}
+                </script>"#,
+                type_
+            );
+            canonical_report(&extract_all(&html, None).unwrap()).jsonld_url
+        };
+        let url = Some("https://example.com/listing".to_string());
+        assert_eq!(page("RealEstateListing"), url);
+        assert_eq!(page("https://schema.org/FAQPage"), url);
+        // Not a WebPage even though the name ends in "Page"
+        assert_eq!(page("LandingPage"), None);
+    }
+}
@@ -0,0 +1,262 @@
+//! Structured data diff
+//!
+//! Compares what two versions of a page declare, so monitoring tools can
+//! report "canonical changed" or "a JSON-LD Product was added" instead of a
+//! raw JSON diff. Single-record formats are compared field by field; item
+//! formats (JSON-LD, microdata, RDFa, microformats) are matched by identity.
+
+use crate::types::diff::{Change, ChangeKind, DataDiff, DataFormat};
+use crate::types::extracted::ExtractedData;
+use crate::types::PropertyValue;
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Report the entries added, removed or changed between `old` and `new`
+///
+/// Empty values (`null`, `""`, `[]`, `{}`, `false`) count as absent, so a
+/// field going from unset to set is an addition. Items are matched by their
+/// `@id` (JSON-LD), `itemid` (microdata), `about` (RDFa) or `uid`
+/// (microformats), falling back to their type and position among items of
+/// that type, so reordering unrelated items is not reported. Items repeating
+/// an identity are matched by occurrence.
+pub fn diff(old: &ExtractedData, new: &ExtractedData) -> DataDiff {
+    let mut diff = DataDiff::default();
+
+    diff_entries(&mut diff, DataFormat::Meta, fields(&old.meta), fields(&new.meta));
+    diff_entries(&mut diff, DataFormat::OpenGraph, fields(&old.opengraph), fields(&new.opengraph));
+    diff_entries(&mut diff, DataFormat::Twitter, fields(&old.twitter), fields(&new.twitter));
+    diff_entries(&mut diff, DataFormat::JsonLd, jsonld_items(old), jsonld_items(new));
+    diff_entries(&mut diff, DataFormat::Microdata, microdata_items(old), microdata_items(new));
+    diff_entries(
+        &mut diff,
+        DataFormat::Microformats,
+        microformat_items(old),
+        microformat_items(new),
+    );
+    diff_entries(
+        &mut diff,
+        DataFormat::DublinCore,
+        fields(&old.dublin_core),
+        fields(&new.dublin_core),
+    );
+    diff_entries(&mut diff, DataFormat::Rdfa, rdfa_items(old), rdfa_items(new));
+    diff_entries(&mut diff, DataFormat::OEmbed, fields(&old.oembed), fields(&new.oembed));
+    diff_entries(&mut diff, DataFormat::Manifest, fields(&old.manifest), fields(&new.manifest));
+    diff_entries(&mut diff, DataFormat::RelLinks, fields(&old.rel_links), fields(&new.rel_links));
+    diff_entries(
+        &mut diff,
+        DataFormat::ResourceHints,
+        fields(&old.resource_hints),
+        fields(&new.resource_hints),
+    );
+    diff_entries(
+        &mut diff,
+        DataFormat::DocumentBase,
+        fields(&old.document_base),
+        fields(&new.document_base),
+    );
+
+    diff
+}
+
+/// Record removals and changes in old order, then additions in new order
+fn diff_entries(
+    diff: &mut DataDiff,
+    format: DataFormat,
+    old: IndexMap<String, Value>,
+    mut new: IndexMap<String, Value>,
+) {
+    for (key, old_value) in old {
+        let (kind, new_value) = match new.shift_remove(&key) {
+            None => (ChangeKind::Removed, None),
+            Some(new_value) if new_value != old_value => (ChangeKind::Changed, Some(new_value)),
+            Some(_) => continue,
+        };
+        diff.changes.push(Change { format, key, kind, old: Some(old_value), new: new_value });
+    }
+    for (key, new_value) in new {
+        diff.changes.push(Change {
+            format,
+            key,
+            kind: ChangeKind::Added,
+            old: None,
+            new: Some(new_value),
+        });
+    }
+}
+
+/// Serialize a record and keep its non-empty fields
+fn fields<T: Serialize>(record: &T) -> IndexMap<String, Value> {
+    match serde_json::to_value(record) {
+        Ok(Value::Object(map)) => map.into_iter().filter(|(_, value)| !is_empty(value)).collect(),
+        _ => IndexMap::new(),
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(a) => a.is_empty(),
+        Value::Object(o) => o.is_empty(),
+        _ => false,
+    }
+}
+
+/// Key items by identity, or by type and position among items of that type
+///
+/// Items sharing an identity are told apart by occurrence: the first keeps
+/// the bare identity, later ones get ` #2`, ` #3`, ...
+fn keyed<T: Serialize>(
+    items: impl IntoIterator<Item = (Option<String>, String, T)>,
+) -> IndexMap<String, Value> {
+    let mut seen_types: IndexMap<String, usize> = IndexMap::new();
+    let mut seen_ids: IndexMap<String, usize> = IndexMap::new();
+    let mut keyed = IndexMap::new();
+    for (id, type_label, item) in items {
+        let key = match id {
+            Some(id) => {
+                let count = seen_ids.entry(id.clone()).or_default();
+                *count += 1;
+                if *count == 1 {
+                    id
+                } else {
+                    format!("{} #{}", id, count)
+                }
+            }
+            None => {
+                let count = seen_types.entry(type_label.clone()).or_default();
+                *count += 1;
+                format!("{} #{}", type_label, count)
+            }
+        };
+        keyed.insert(key, serde_json::to_value(item).unwrap_or(Value::Null));
+    }
+    keyed
+}
+
+fn jsonld_items(data: &ExtractedData) -> IndexMap<String, Value> {
+    keyed(data.jsonld.iter().map(|object| {
+        let type_label = match &object.type_ {
+            Some(Value::String(t)) => t.clone(),
+            Some(Value::Array(types)) => {
+                types.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(" ")
+            }
+            _ => "Thing".to_string(),
+        };
+        (object.id.clone(), type_label, object)
+    }))
+}
+
+fn microdata_items(data: &ExtractedData) -> IndexMap<String, Value> {
+    keyed(data.microdata.iter().map(|item| {
+        let type_label = item.item_type.as_ref().map_or("item".to_string(), |t| t.join(" "));
+        (item.id.clone(), type_label, item)
+    }))
+}
+
+fn rdfa_items(data: &ExtractedData) -> IndexMap<String, Value> {
+    keyed(data.rdfa.iter().map(|item| {
+        let type_label = item.type_of.as_ref().map_or("item".to_string(), |t| t.join(" "));
+        (item.about.clone(), type_label, item)
+    }))
+}
+
+fn microformat_items(data: &ExtractedData) -> IndexMap<String, Value> {
+    keyed(data.microformats.iter().flat_map(|(root, items)| {
+        items.iter().map(move |item| {
+            let uid = item.properties.get("uid").and_then(|values| match values.first() {
+                Some(PropertyValue::Text(uid) | PropertyValue::Url(uid)) => Some(uid.clone()),
+                _ => None,
+            });
+            (uid, root.clone(), item)
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_identical_pages_have_no_diff() {
+        let html = r#"<title>Same</title><link rel="canonical" href="https://example.com/a">"#;
+        let data = extract_all(html, None).unwrap();
+        assert!(diff(&data, &data).is_empty());
+    }
+
+    #[test]
+    fn test_changed_canonical() {
+        let old = extract_all(r#"<link rel="canonical" href="https://example.com/a">"#, None);
+        let new = extract_all(r#"<link rel="canonical" href="https://example.com/b">"#, None);
+        let diff = diff(&old.unwrap(), &new.unwrap());
+
+        let change = diff.get(DataFormat::Meta, "canonical").unwrap();
+        assert_eq!(change.kind, ChangeKind::Changed);
+        assert_eq!(change.old, Some(Value::from("https://example.com/a")));
+        assert_eq!(change.new, Some(Value::from("https://example.com/b")));
+        // The same link is also reported as a rel link
+        assert_eq!(diff.get(DataFormat::RelLinks, "canonical").unwrap().kind, ChangeKind::Changed);
+        assert_eq!(diff.changes.len(), 2);
+    }
+
+    #[test]
+    fn test_added_jsonld_product() {
+        let org = r#"<script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Organization", "name": "Acme"}
+        </script>"#;
+        let product = r#"<script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Product", "name": "Widget"}
+        </script>"#;
+        let old = extract_all(org, None).unwrap();
+        let new = extract_all(&format!("{}{}", product, org), None).unwrap();
+        let diff = diff(&old, &new);
+
+        // The Organization moved but is matched by type, so only the Product shows up
+        assert_eq!(diff.changes.len(), 1);
+        let change = diff.get(DataFormat::JsonLd, "Product #1").unwrap();
+        assert_eq!(change.kind, ChangeKind::Added);
+        assert_eq!(change.new.as_ref().unwrap()["name"], "Widget");
+    }
+
+    #[test]
+    fn test_items_sharing_an_id_are_kept_apart() {
+        let block = |name: &str| {
+            format!(
+                r#"<script type="application/ld+json">
+                    {{"@id": "https://example.com/#org", "@type": "Organization", "name": "{}"}}
+                </script>"#,
+                name
+            )
+        };
+        let old = extract_all(&format!("{}{}", block("Acme"), block("Acme Inc")), None).unwrap();
+        let new = extract_all(&format!("{}{}", block("Acme"), block("Acme Corp")), None).unwrap();
+        let diff = diff(&old, &new);
+
+        // The first occurrence is unchanged; only the second one differs
+        assert_eq!(diff.changes.len(), 1);
+        let change = diff.get(DataFormat::JsonLd, "https://example.com/#org #2").unwrap();
+        assert_eq!(change.kind, ChangeKind::Changed);
+        assert_eq!(change.old.as_ref().unwrap()["name"], "Acme Inc");
+        assert_eq!(change.new.as_ref().unwrap()["name"], "Acme Corp");
+    }
+
+    #[test]
+    fn test_removed_twitter_card() {
+        let old = extract_all(
+            r#"<meta name="twitter:card" content="summary_large_image">
+               <meta name="twitter:site" content="@acme">"#,
+            None,
+        )
+        .unwrap();
+        let new = extract_all("<title>No card</title>", None).unwrap();
+        let diff = diff(&old, &new);
+
+        let twitter: Vec<(&str, ChangeKind)> =
+            diff.for_format(DataFormat::Twitter).map(|c| (c.key.as_str(), c.kind)).collect();
+        assert_eq!(twitter, vec![("card", ChangeKind::Removed), ("site", ChangeKind::Removed)]);
+        assert_eq!(diff.get(DataFormat::Meta, "title").unwrap().kind, ChangeKind::Added);
+    }
+}
@@ -0,0 +1,155 @@
+//! Copyright and license reconciliation
+//!
+//! Rights statements are spread over `<meta name="copyright">`,
+//! `rel="license"` links, JSON-LD `license`/`copyrightHolder` and Dublin Core
+//! `rights`. This merges them into one [`LicenseInfo`].
+
+use crate::types::extracted::ExtractedData;
+use crate::types::license::LicenseInfo;
+use serde_json::Value;
+use url::Url;
+
+/// Gather the page's copyright and license statements
+///
+/// - `copyright_text`: `<meta name="copyright">`, then a non-URL Dublin Core
+///   `rights`, then JSON-LD `copyrightNotice`
+/// - `license_urls`: `rel="license"` links, then JSON-LD `license` (a URL or
+///   a `CreativeWork` with a `url`/`@id`), then a Dublin Core `rights` URL.
+///   URLs differing only by scheme, `www.`, case or trailing slash are listed once.
+/// - `copyright_holder`: JSON-LD `copyrightHolder`, a name or a Person/Organization
+///
+/// JSON-LD objects are visited in document order, including `@graph` members.
+pub fn license_info(data: &ExtractedData) -> LicenseInfo {
+    let mut info = LicenseInfo::default();
+    let objects = &data.jsonld;
+
+    let dc_rights = data.dublin_core.rights.as_deref().map(str::trim).filter(|r| !r.is_empty());
+    let dc_rights_is_url = dc_rights.is_some_and(|rights| license_key(rights).is_some());
+
+    info.copyright_text = non_empty(data.meta.copyright.as_deref())
+        .or_else(|| dc_rights.filter(|_| !dc_rights_is_url).map(String::from))
+        .or_else(|| {
+            objects.iter().find_map(|object| {
+                non_empty(object.properties.get("copyrightNotice").and_then(Value::as_str))
+            })
+        });
+
+    for url in data.rel_links.get("license").into_iter().flatten() {
+        push_license(&mut info.license_urls, url);
+    }
+    for object in objects {
+        if let Some(license) = object.properties.get("license") {
+            for url in license_urls(license) {
+                push_license(&mut info.license_urls, url);
+            }
+        }
+    }
+    if let Some(rights) = dc_rights.filter(|_| dc_rights_is_url) {
+        push_license(&mut info.license_urls, rights);
+    }
+
+    info.copyright_holder = objects
+        .iter()
+        .find_map(|object| object.properties.get("copyrightHolder").and_then(holder_name));
+
+    info
+}
+
+/// URLs in a JSON-LD `license` value: strings, objects with `url`/`@id`, or a list of those
+fn license_urls(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(url) => vec![url.as_str()],
+        Value::Object(map) => {
+            map.get("url").or_else(|| map.get("@id")).and_then(Value::as_str).into_iter().collect()
+        }
+        Value::Array(values) => values.iter().flat_map(license_urls).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Name of a JSON-LD `copyrightHolder`: a string, or the `name` of the first entity
+fn holder_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(name) => non_empty(Some(name)),
+        Value::Object(map) => non_empty(map.get("name").and_then(Value::as_str)),
+        Value::Array(values) => values.iter().find_map(holder_name),
+        _ => None,
+    }
+}
+
+fn push_license(urls: &mut Vec<String>, url: &str) {
+    let url = url.trim();
+    let Some(key) = license_key(url) else {
+        return;
+    };
+    if !urls.iter().any(|existing| license_key(existing).as_ref() == Some(&key)) {
+        urls.push(url.to_string());
+    }
+}
+
+/// Comparison key: host without `www.` plus path, lowercased, without trailing slash
+fn license_key(url: &str) -> Option<String> {
+    let parsed = Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = parsed.host_str()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    Some(format!("{}{}", host, parsed.path().trim_end_matches('/').to_lowercase()))
+}
+
+fn non_empty(s: Option<&str>) -> Option<String> {
+    s.map(str::trim).filter(|s| !s.is_empty()).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_meta_copyright_rel_license_and_jsonld_license() {
+        let html = r#"
+            <meta name="copyright" content="© 2024 Acme Corp">
+            <link rel="license" href="https://creativecommons.org/licenses/by/4.0/">
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Article",
+                "license": [
+                    "http://creativecommons.org/licenses/by/4.0",
+                    {"@type": "CreativeWork", "url": "https://example.com/terms"}
+                ],
+                "copyrightHolder": {"@type": "Organization", "name": "Acme Corp"}
+            }
+            </script>
+        "#;
+        let info = license_info(&extract_all(html, None).unwrap());
+
+        assert_eq!(info.copyright_text.as_deref(), Some("© 2024 Acme Corp"));
+        // The JSON-LD CC BY URL repeats the rel-license link
+        assert_eq!(
+            info.license_urls,
+            vec!["https://creativecommons.org/licenses/by/4.0/", "https://example.com/terms"]
+        );
+        assert_eq!(info.copyright_holder.as_deref(), Some("Acme Corp"));
+    }
+
+    #[test]
+    fn test_dublin_core_rights() {
+        let text = r#"<meta name="DC.rights" content="All rights reserved">"#;
+        let info = license_info(&extract_all(text, None).unwrap());
+        assert_eq!(info.copyright_text.as_deref(), Some("All rights reserved"));
+        assert!(info.license_urls.is_empty());
+
+        let url = r#"<meta name="DC.rights" content="https://opensource.org/licenses/MIT">"#;
+        let info = license_info(&extract_all(url, None).unwrap());
+        assert!(info.copyright_text.is_none());
+        assert_eq!(info.license_urls, vec!["https://opensource.org/licenses/MIT"]);
+    }
+
+    #[test]
+    fn test_no_rights_statements() {
+        assert!(license_info(&extract_all("<title>Plain</title>", None).unwrap()).is_empty());
+    }
+}
@@ -0,0 +1,296 @@
+//! Media gallery collection
+//!
+//! Gallery and product pages declare their images across several formats:
+//! a handful of `og:image` tags, a `twitter:image`, and JSON-LD
+//! `ImageObject`s. This merges them into one list with the dimensions and
+//! alt text each source knows about.
+
+use crate::extractors::common::url_utils;
+use crate::extractors::jsonld::hierarchy::type_value_is_subtype_of;
+use crate::types::extracted::ExtractedData;
+use crate::types::media::{MediaItem, MediaKind, MediaSource};
+use crate::types::social::ImageUrlKind;
+use serde_json::{Map, Value};
+
+/// Collect every image and video the page declares
+///
+/// Sources are visited in order: `og:image` (with its width, height and alt),
+/// `og:video`, `twitter:image` (with `twitter:image:alt`), then JSON-LD in
+/// document order: top-level `ImageObject`s and `VideoObject`s (including
+/// `@graph` members), those found under media properties (`image`, `photo`,
+/// `video`, `associatedMedia`, `primaryImageOfPage`), and plain `image` URLs.
+/// Other properties are not searched, so a publisher `logo` or an author's
+/// `image` is left out. A URL declared again keeps its first position and
+/// source; later declarations only fill in a missing width, height or alt.
+///
+/// Relative URLs are resolved against `<base href>`, else `og:url`, else the
+/// canonical link. URLs that are neither http(s) nor `data:` are skipped.
+pub fn media_gallery(data: &ExtractedData) -> Vec<MediaItem> {
+    let base = data
+        .document_base
+        .as_ref()
+        .and_then(|base| base.href.as_deref())
+        .or(data.opengraph.url.as_deref())
+        .or(data.meta.canonical.as_deref());
+    let mut gallery = Gallery { base, items: Vec::new() };
+
+    for image in &data.opengraph.images {
+        gallery.push(MediaItem {
+            url: image.url.clone(),
+            kind: MediaKind::Image,
+            width: image.width,
+            height: image.height,
+            alt: image.alt.clone(),
+            source: MediaSource::OpenGraph,
+        });
+    }
+    for video in &data.opengraph.videos {
+        gallery.push(MediaItem {
+            url: video.url.clone(),
+            kind: MediaKind::Video,
+            width: video.width,
+            height: video.height,
+            alt: None,
+            source: MediaSource::OpenGraph,
+        });
+    }
+    if let Some(ref url) = data.twitter.image {
+        gallery.push(MediaItem {
+            url: url.clone(),
+            kind: MediaKind::Image,
+            width: None,
+            height: None,
+            alt: data.twitter.image_alt.clone(),
+            source: MediaSource::Twitter,
+        });
+    }
+    for object in &data.jsonld {
+        if let Ok(value) = serde_json::to_value(object) {
+            gallery.walk_jsonld(&value, None);
+        }
+    }
+
+    gallery.items
+}
+
+/// JSON-LD properties whose values are the page's own media
+const MEDIA_PROPERTIES: &[&str] =
+    &["image", "photo", "video", "associatedMedia", "primaryImageOfPage"];
+
+struct Gallery<'a> {
+    base: Option<&'a str>,
+    items: Vec<MediaItem>,
+}
+
+impl Gallery<'_> {
+    /// Resolve and add an item, or fill the gaps of an earlier one with the same URL
+    fn push(&mut self, mut item: MediaItem) {
+        item.url = url_utils::resolve_or_keep(self.base, item.url.trim());
+        if ImageUrlKind::classify(&item.url) == ImageUrlKind::Invalid {
+            return;
+        }
+        match self.items.iter_mut().find(|existing| existing.url == item.url) {
+            Some(existing) => {
+                existing.width = existing.width.or(item.width);
+                existing.height = existing.height.or(item.height);
+                existing.alt = existing.alt.take().or(item.alt);
+            }
+            None => self.items.push(item),
+        }
+    }
+
+    /// Visit a JSON-LD value; `key` is the property it was found under
+    fn walk_jsonld(&mut self, value: &Value, key: Option<&str>) {
+        match value {
+            Value::String(url) if key == Some("image") => self.push(MediaItem {
+                url: url.clone(),
+                kind: MediaKind::Image,
+                width: None,
+                height: None,
+                alt: None,
+                source: MediaSource::JsonLd,
+            }),
+            Value::Array(values) => {
+                for value in values {
+                    self.walk_jsonld(value, key);
+                }
+            }
+            Value::Object(object) => {
+                if let Some(item) = media_object(object, key) {
+                    self.push(item);
+                }
+                for key in MEDIA_PROPERTIES {
+                    if let Some(value) = object.get(*key) {
+                        self.walk_jsonld(value, Some(key));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An `ImageObject` or `VideoObject`; an untyped object under `image` counts as an image
+fn media_object(object: &Map<String, Value>, key: Option<&str>) -> Option<MediaItem> {
+    let is_a = |ancestor| type_value_is_subtype_of(object.get("@type"), ancestor);
+
+    let (kind, url_keys): (_, &[&str]) =
+        if is_a("ImageObject") || (key == Some("image") && !object.contains_key("@type")) {
+            (MediaKind::Image, &["contentUrl", "url"])
+        } else if is_a("VideoObject") {
+            // A VideoObject's `url` is usually the page showing it, not the file
+            (MediaKind::Video, &["contentUrl", "embedUrl"])
+        } else {
+            return None;
+        };
+
+    let url = url_keys.iter().find_map(|key| first_str(object.get(*key)?))?;
+    let alt = ["caption", "name"].iter().find_map(|key| first_str(object.get(*key)?));
+    Some(MediaItem {
+        url: url.to_string(),
+        kind,
+        width: object.get("width").and_then(dimension),
+        height: object.get("height").and_then(dimension),
+        alt: alt.map(String::from),
+        source: MediaSource::JsonLd,
+    })
+}
+
+/// A non-empty string, or the first of a list
+fn first_str(value: &Value) -> Option<&str> {
+    let text = match value {
+        Value::Array(values) => values.first()?.as_str()?,
+        value => value.as_str()?,
+    };
+    Some(text.trim()).filter(|text| !text.is_empty())
+}
+
+/// Pixels from `1200`, `"1200"`, `"1200px"` or a `QuantitativeValue`
+fn dimension(value: &Value) -> Option<u32> {
+    match value {
+        Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+        Value::String(s) => {
+            let s = s.trim();
+            s.strip_suffix("px").unwrap_or(s).trim().parse().ok()
+        }
+        Value::Object(object) => dimension(object.get("value")?),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_og_and_jsonld_images_are_merged() {
+        let html = r#"
+            <meta property="og:url" content="https://example.com/gallery">
+            <meta property="og:image" content="https://example.com/a.jpg">
+            <meta property="og:image:width" content="1200">
+            <meta property="og:image" content="https://example.com/b.jpg">
+            <meta property="og:image:alt" content="Second photo">
+            <meta property="og:image" content="/c.jpg">
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "ImageGallery",
+                "associatedMedia": [
+                    {"@type": "ImageObject", "contentUrl": "https://example.com/a.jpg",
+                     "height": "800", "caption": "First photo"},
+                    {"@type": "ImageObject", "contentUrl": "/d.jpg",
+                     "width": {"@type": "QuantitativeValue", "value": 640}}
+                ]
+            }
+            </script>
+        "#;
+        let data = extract_all(html, Some("https://example.com/gallery")).unwrap();
+        let gallery = media_gallery(&data);
+
+        let urls: Vec<&str> = gallery.iter().map(|item| item.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/a.jpg",
+                "https://example.com/b.jpg",
+                "https://example.com/c.jpg",
+                "https://example.com/d.jpg",
+            ]
+        );
+
+        // The duplicate keeps its OG source and gains the JSON-LD height and caption
+        let first = &gallery[0];
+        assert_eq!(first.source, MediaSource::OpenGraph);
+        assert_eq!((first.width, first.height), (Some(1200), Some(800)));
+        assert_eq!(first.alt.as_deref(), Some("First photo"));
+        assert_eq!(gallery[1].alt.as_deref(), Some("Second photo"));
+
+        let last = &gallery[3];
+        assert_eq!(last.source, MediaSource::JsonLd);
+        assert_eq!(last.width, Some(640));
+        assert!(gallery.iter().all(|item| item.kind == MediaKind::Image));
+
+        let json = serde_json::to_value(first).unwrap();
+        assert_eq!(
+            (json["kind"].as_str(), json["source"].as_str()),
+            (Some("image"), Some("open_graph"))
+        );
+    }
+
+    #[test]
+    fn test_videos_and_plain_image_urls() {
+        let html = r#"
+            <link rel="canonical" href="https://example.com/watch">
+            <meta name="twitter:image" content="https://example.com/poster.jpg">
+            <script type="application/ld+json">
+            {
+                "@type": "VideoObject",
+                "url": "https://example.com/watch",
+                "contentUrl": "/media/clip.mp4",
+                "width": 1920,
+                "image": ["/poster.jpg", "/still.jpg"]
+            }
+            </script>
+        "#;
+        let gallery = media_gallery(&extract_all(html, None).unwrap());
+
+        let summary: Vec<(&str, MediaKind, MediaSource)> =
+            gallery.iter().map(|item| (item.url.as_str(), item.kind, item.source)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("https://example.com/poster.jpg", MediaKind::Image, MediaSource::Twitter),
+                ("https://example.com/media/clip.mp4", MediaKind::Video, MediaSource::JsonLd),
+                ("https://example.com/still.jpg", MediaKind::Image, MediaSource::JsonLd),
+            ]
+        );
+        assert_eq!(gallery[1].width, Some(1920));
+    }
+
+    #[test]
+    fn test_logos_and_author_images_are_skipped() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": "Article",
+                "image": {"@type": "ImageObject", "url": "https://example.com/hero.jpg"},
+                "author": {"@type": "Person", "image": "https://example.com/avatar.jpg"},
+                "publisher": {
+                    "@type": "Organization",
+                    "logo": {"@type": "ImageObject", "url": "https://example.com/logo.png"}
+                }
+            }
+            </script>
+        "#;
+        let gallery = media_gallery(&extract_all(html, None).unwrap());
+
+        let urls: Vec<&str> = gallery.iter().map(|item| item.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://example.com/hero.jpg"]);
+    }
+
+    #[test]
+    fn test_empty_page_has_no_media() {
+        assert!(media_gallery(&extract_all("<title>Empty</title>", None).unwrap()).is_empty());
+    }
+}
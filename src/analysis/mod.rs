@@ -0,0 +1,24 @@
+//! Cross-format analysis over [`ExtractedData`](crate::ExtractedData)
+//!
+//! Extractors report what each format says in isolation; the helpers here
+//! reconcile the formats into a single answer (e.g. "who wrote this page?").
+
+pub mod addresses;
+pub mod authorship;
+pub mod canonical;
+pub mod diff;
+pub mod license;
+pub mod media;
+pub mod social_preview;
+pub mod social_profiles;
+pub mod theme_color;
+
+pub use addresses::addresses;
+pub use authorship::resolve_author;
+pub use canonical::canonical_report;
+pub use diff::diff;
+pub use license::license_info;
+pub use media::media_gallery;
+pub use social_preview::social_preview;
+pub use social_profiles::social_profiles;
+pub use theme_color::theme_color_report;
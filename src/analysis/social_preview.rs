@@ -0,0 +1,167 @@
+//! Social preview resolution
+//!
+//! Link unfurlers combine Twitter Cards, Open Graph, plain meta tags and
+//! JSON-LD into one card. This applies that fallback chain once, so callers
+//! don't each reimplement it.
+
+use crate::types::extracted::ExtractedData;
+use crate::types::jsonld::JsonLdObject;
+use crate::types::social::ImageUrlKind;
+use crate::types::social_preview::SocialPreview;
+use serde_json::Value;
+
+/// Resolve the preview card for a page
+///
+/// Each field takes the first non-empty value in this order:
+///
+/// | Field | Fallback order |
+/// |-------|----------------|
+/// | `title` | `twitter:title` → `og:title` → `<title>` → JSON-LD `headline` → JSON-LD `name` |
+/// | `description` | `twitter:description` → `og:description` → `<meta name="description">` → JSON-LD `description` |
+/// | `image` | `twitter:image` → primary `og:image` (its `secure_url` first) → JSON-LD `image` |
+/// | `site_name` | `og:site_name` → `twitter:site` → JSON-LD `publisher.name` |
+/// | `url` | `og:url` → canonical link → JSON-LD `url` |
+/// | `card_type` | `twitter:card` → `summary` |
+///
+/// Images that are neither http(s) URLs nor `data:` URIs (e.g. relative paths
+/// left unresolved without a base URL) are skipped. JSON-LD objects, including
+/// `@graph` members, are searched in document order.
+pub fn social_preview(data: &ExtractedData) -> SocialPreview {
+    let twitter = &data.twitter;
+    let og = &data.opengraph;
+    let meta = &data.meta;
+    let jsonld = &data.jsonld;
+
+    let title = first_of([&twitter.title, &og.title, &meta.title])
+        .or_else(|| jsonld_text(jsonld, "headline"))
+        .or_else(|| jsonld_text(jsonld, "name"));
+    let description = first_of([&twitter.description, &og.description, &meta.description])
+        .or_else(|| jsonld_text(jsonld, "description"));
+
+    let og_image = og.primary_image().map(|image| image.secure_url.as_ref().unwrap_or(&image.url));
+    // `twitter:image` falls back to `og:image` during extraction; take the
+    // secure URL in that case too
+    let twitter_image = match (&twitter.image, og.primary_image()) {
+        (Some(url), Some(primary)) if *url == primary.url => og_image,
+        (url, _) => url.as_ref(),
+    };
+    let image = [twitter_image, og_image, og.image.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|url| url.trim())
+        .chain(jsonld.iter().filter_map(|object| jsonld_image(object)))
+        .find(|url| ImageUrlKind::classify(url) != ImageUrlKind::Invalid)
+        .map(String::from);
+
+    let site_name =
+        first_of([&og.site_name, &twitter.site]).or_else(|| jsonld_text(jsonld, "publisher.name"));
+    let url = first_of([&og.url, &meta.canonical]).or_else(|| jsonld_text(jsonld, "url"));
+
+    let card_type = match twitter.card.as_deref().map(str::trim) {
+        Some(card) if !card.is_empty() => card.to_string(),
+        _ => "summary".to_string(),
+    };
+
+    SocialPreview { title, description, image, site_name, url, card_type }
+}
+
+/// The first trimmed, non-empty value
+fn first_of<const N: usize>(candidates: [&Option<String>; N]) -> Option<String> {
+    candidates
+        .into_iter()
+        .flatten()
+        .map(|value| value.trim())
+        .find(|value| !value.is_empty())
+        .map(String::from)
+}
+
+/// The first non-empty string at `path` across the JSON-LD objects
+fn jsonld_text(objects: &[JsonLdObject], path: &str) -> Option<String> {
+    objects.iter().find_map(|object| {
+        let value = object.borrowed_path(path)?;
+        let text = value.as_str().or_else(|| value.get(0)?.as_str())?.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+/// URL of a JSON-LD `image`: a string, an `ImageObject`, or the first of a list
+fn jsonld_image(object: &JsonLdObject) -> Option<&str> {
+    match object.borrowed_path("image.0")? {
+        Value::String(url) => Some(url.trim()),
+        image => image.get("url").or_else(|| image.get("contentUrl"))?.as_str().map(str::trim),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    const BASE: Option<&str> = Some("https://example.com/post");
+
+    fn preview(html: &str) -> SocialPreview {
+        social_preview(&extract_all(html, BASE).unwrap())
+    }
+
+    #[test]
+    fn test_title_from_each_fallback_level() {
+        let jsonld = r#"<script type="application/ld+json">
+            {"@type": "Article", "headline": "JSON-LD headline", "name": "JSON-LD name"}
+        </script>"#;
+        let name_only = r#"<script type="application/ld+json">
+            {"@type": "Organization", "name": "JSON-LD name"}
+        </script>"#;
+        let meta = format!("<title>Meta title</title>{}", jsonld);
+        let og = format!(r#"<meta property="og:title" content="OG title">{}"#, meta);
+        let twitter = format!(r#"<meta name="twitter:title" content="Twitter title">{}"#, og);
+
+        assert_eq!(preview(&twitter).title.as_deref(), Some("Twitter title"));
+        assert_eq!(preview(&og).title.as_deref(), Some("OG title"));
+        assert_eq!(preview(&meta).title.as_deref(), Some("Meta title"));
+        assert_eq!(preview(jsonld).title.as_deref(), Some("JSON-LD headline"));
+        assert_eq!(preview(name_only).title.as_deref(), Some("JSON-LD name"));
+    }
+
+    #[test]
+    fn test_other_fields_fall_back() {
+        let html = r#"
+            <meta name="description" content="Meta description">
+            <meta name="twitter:site" content="@acme">
+            <meta property="og:image" content="/hero.jpg">
+            <meta property="og:image:secure_url" content="https://cdn.example.com/hero.jpg">
+            <link rel="canonical" href="https://example.com/canonical">
+        "#;
+        let preview = preview(html);
+        assert_eq!(preview.description.as_deref(), Some("Meta description"));
+        assert_eq!(preview.site_name.as_deref(), Some("@acme"));
+        assert_eq!(preview.image.as_deref(), Some("https://cdn.example.com/hero.jpg"));
+        assert_eq!(preview.url.as_deref(), Some("https://example.com/canonical"));
+        // Twitter renders a page without `twitter:card` as a summary card
+        assert_eq!(preview.card_type, "summary");
+    }
+
+    #[test]
+    fn test_jsonld_image_and_publisher() {
+        let html = r#"<script type="application/ld+json">
+            {"@graph": [{"@type": "NewsArticle",
+                         "image": [{"@type": "ImageObject", "url": "https://example.com/a.jpg"}],
+                         "publisher": {"@type": "Organization", "name": "Acme News"},
+                         "url": "https://example.com/article"}]}
+        </script>
+        <meta name="twitter:card" content="summary_large_image">"#;
+        let preview = preview(html);
+        assert_eq!(preview.image.as_deref(), Some("https://example.com/a.jpg"));
+        assert_eq!(preview.site_name.as_deref(), Some("Acme News"));
+        assert_eq!(preview.url.as_deref(), Some("https://example.com/article"));
+        assert_eq!(preview.card_type, "summary_large_image");
+    }
+
+    #[test]
+    fn test_everything_absent() {
+        let preview = social_preview(&extract_all("<p>No metadata</p>", None).unwrap());
+        assert_eq!(
+            preview,
+            SocialPreview { card_type: "summary".to_string(), ..SocialPreview::default() }
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Social profile aggregation
+//!
+//! Gathers the profile URLs a page declares through JSON-LD `sameAs`,
+//! `rel="me"` links and h-cards, and classifies each by platform.
+
+use crate::extractors::jsonld::hierarchy::type_value_is_subtype_of;
+use crate::types::extracted::ExtractedData;
+use crate::types::jsonld::JsonLdObject;
+use crate::types::social_profiles::{ProfileSource, SocialPlatform, SocialProfile};
+use crate::types::PropertyValue;
+use serde_json::Value;
+use url::Url;
+
+/// JSON-LD types whose `sameAs` lists the entity's own profiles; subtypes
+/// (`Restaurant`, `Corporation`, ...) count too
+const PROFILE_TYPES: &[&str] = &["Person", "Organization", "Brand"];
+
+/// Hosts (without `www.`) of each platform; subdomains match too
+const PLATFORM_HOSTS: &[(&str, SocialPlatform)] = &[
+    ("twitter.com", SocialPlatform::Twitter),
+    ("x.com", SocialPlatform::Twitter),
+    ("facebook.com", SocialPlatform::Facebook),
+    ("fb.com", SocialPlatform::Facebook),
+    ("instagram.com", SocialPlatform::Instagram),
+    ("linkedin.com", SocialPlatform::LinkedIn),
+    ("github.com", SocialPlatform::GitHub),
+    ("youtube.com", SocialPlatform::YouTube),
+    ("youtu.be", SocialPlatform::YouTube),
+    ("tiktok.com", SocialPlatform::TikTok),
+    ("pinterest.com", SocialPlatform::Pinterest),
+    ("reddit.com", SocialPlatform::Reddit),
+    ("bsky.app", SocialPlatform::Bluesky),
+    ("threads.net", SocialPlatform::Threads),
+    ("mastodon.social", SocialPlatform::Mastodon),
+    ("mastodon.online", SocialPlatform::Mastodon),
+    ("fosstodon.org", SocialPlatform::Mastodon),
+    ("hachyderm.io", SocialPlatform::Mastodon),
+    ("infosec.exchange", SocialPlatform::Mastodon),
+    ("mstdn.social", SocialPlatform::Mastodon),
+];
+
+/// Collect the social profiles declared by the page
+///
+/// Sources are visited in order: JSON-LD `sameAs` of Person/Organization
+/// objects (including nested ones such as an Article's `publisher`), then
+/// `rel="me"` links, then h-card `u-url`s. A URL that differs from an earlier
+/// one only by scheme, `www.`, case or trailing slash is dropped.
+pub fn social_profiles(data: &ExtractedData) -> Vec<SocialProfile> {
+    let mut profiles = Vec::new();
+
+    for object in &data.jsonld {
+        collect_jsonld_profiles(object, &mut profiles);
+    }
+
+    for url in data.rel_links.get("me").into_iter().flatten() {
+        push_profile(&mut profiles, url, ProfileSource::RelMe);
+    }
+
+    for card in data.microformats.get("h-card").into_iter().flatten() {
+        for value in card.properties.get("url").into_iter().flatten() {
+            if let PropertyValue::Url(url) | PropertyValue::Text(url) = value {
+                push_profile(&mut profiles, url, ProfileSource::HCard);
+            }
+        }
+    }
+
+    profiles
+}
+
+/// Classify a URL by platform from its host
+pub fn classify_platform(url: &str) -> SocialPlatform {
+    let Some(host) = Url::parse(url.trim()).ok().and_then(|u| u.host_str().map(str::to_lowercase))
+    else {
+        return SocialPlatform::Other;
+    };
+
+    let known = PLATFORM_HOSTS.iter().find(|(platform_host, _)| {
+        host == *platform_host || host.ends_with(&format!(".{}", platform_host))
+    });
+    match known {
+        Some((_, platform)) => *platform,
+        // Self-hosted Mastodon instances usually say so in their name
+        None if host.contains("mastodon") || host.starts_with("mstdn.") => SocialPlatform::Mastodon,
+        None => SocialPlatform::Other,
+    }
+}
+
+fn collect_jsonld_profiles(object: &JsonLdObject, profiles: &mut Vec<SocialProfile>) {
+    if is_profile_type(object.type_.as_ref()) {
+        if let Some(same_as) = object.properties.get("sameAs") {
+            push_same_as(same_as, profiles);
+        }
+    }
+    for value in object.properties.values() {
+        collect_nested_profiles(value, profiles);
+    }
+    for child in object.graph.iter().flatten() {
+        collect_jsonld_profiles(child, profiles);
+    }
+}
+
+/// Look for Person/Organization objects nested inside property values
+fn collect_nested_profiles(value: &Value, profiles: &mut Vec<SocialProfile>) {
+    match value {
+        Value::Object(map) => {
+            if is_profile_type(map.get("@type")) {
+                if let Some(same_as) = map.get("sameAs") {
+                    push_same_as(same_as, profiles);
+                }
+            }
+            for value in map.values() {
+                collect_nested_profiles(value, profiles);
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|v| collect_nested_profiles(v, profiles)),
+        _ => {}
+    }
+}
+
+fn push_same_as(same_as: &Value, profiles: &mut Vec<SocialProfile>) {
+    match same_as {
+        Value::String(url) => push_profile(profiles, url, ProfileSource::JsonLdSameAs),
+        Value::Array(values) => {
+            for url in values.iter().filter_map(Value::as_str) {
+                push_profile(profiles, url, ProfileSource::JsonLdSameAs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_profile_type(type_: Option<&Value>) -> bool {
+    PROFILE_TYPES.iter().any(|ancestor| type_value_is_subtype_of(type_, ancestor))
+}
+
+fn push_profile(profiles: &mut Vec<SocialProfile>, url: &str, source: ProfileSource) {
+    let url = url.trim();
+    let Some(key) = dedup_key(url) else {
+        return;
+    };
+    if profiles.iter().any(|profile| dedup_key(&profile.url).as_ref() == Some(&key)) {
+        return;
+    }
+    profiles.push(SocialProfile { url: url.to_string(), platform: classify_platform(url), source });
+}
+
+/// Comparison key: host without `www.` plus path, lowercased, without trailing slash
+fn dedup_key(url: &str) -> Option<String> {
+    let parsed = Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = parsed.host_str()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    Some(format!("{}{}", host, parsed.path().trim_end_matches('/').to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_all;
+
+    #[test]
+    fn test_jsonld_same_as_and_rel_me_merged() {
+        let html = r#"
+            <link rel="me" href="https://github.com/acme">
+            <a rel="me" href="https://mastodon.social/@acme">Mastodon</a>
+            <a rel="me" href="http://www.twitter.com/Acme/">Twitter</a>
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Organization",
+                "name": "Acme",
+                "sameAs": [
+                    "https://twitter.com/acme",
+                    "https://www.linkedin.com/company/acme",
+                    "https://en.wikipedia.org/wiki/Acme"
+                ]
+            }
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let profiles = social_profiles(&data);
+
+        let summary: Vec<(&str, SocialPlatform, ProfileSource)> =
+            profiles.iter().map(|p| (p.url.as_str(), p.platform, p.source)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("https://twitter.com/acme", SocialPlatform::Twitter, ProfileSource::JsonLdSameAs),
+                (
+                    "https://www.linkedin.com/company/acme",
+                    SocialPlatform::LinkedIn,
+                    ProfileSource::JsonLdSameAs
+                ),
+                (
+                    "https://en.wikipedia.org/wiki/Acme",
+                    SocialPlatform::Other,
+                    ProfileSource::JsonLdSameAs
+                ),
+                ("https://github.com/acme", SocialPlatform::GitHub, ProfileSource::RelMe),
+                ("https://mastodon.social/@acme", SocialPlatform::Mastodon, ProfileSource::RelMe),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_publisher_same_as() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": "Article",
+                "sameAs": "https://ignored.example/article",
+                "publisher": {"@type": "NewsMediaOrganization", "sameAs": "https://x.com/daily"}
+            }
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let profiles = social_profiles(&data);
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].url, "https://x.com/daily");
+        assert_eq!(profiles[0].platform, SocialPlatform::Twitter);
+    }
+
+    #[test]
+    fn test_local_business_subtype_same_as() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": ["Restaurant", "https://example.com/CustomType"],
+                "name": "Chez Jane",
+                "sameAs": ["https://twitter.com/jane", "https://www.facebook.com/chezjane"]
+            }
+            </script>
+            <script type="application/ld+json">
+            {"@type": "WebPage", "sameAs": "https://github.com/ignored"}
+            </script>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let platforms: Vec<SocialPlatform> =
+            social_profiles(&data).iter().map(|profile| profile.platform).collect();
+        assert_eq!(platforms, vec![SocialPlatform::Twitter, SocialPlatform::Facebook]);
+    }
+
+    #[test]
+    fn test_hcard_url_profiles() {
+        let html = r#"
+            <div class="h-card">
+                <a class="p-name u-url" href="https://www.instagram.com/sam">Sam</a>
+            </div>
+        "#;
+        let data = extract_all(html, None).unwrap();
+        let profiles = social_profiles(&data);
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].platform, SocialPlatform::Instagram);
+        assert_eq!(profiles[0].source, ProfileSource::HCard);
+    }
+
+    #[test]
+    fn test_classify_platform() {
+        assert_eq!(classify_platform("https://m.facebook.com/acme"), SocialPlatform::Facebook);
+        assert_eq!(classify_platform("https://mastodon.example.org/@me"), SocialPlatform::Mastodon);
+        assert_eq!(classify_platform("https://bsky.app/profile/a.b"), SocialPlatform::Bluesky);
+        assert_eq!(classify_platform("https://notx.com/acme"), SocialPlatform::Other);
+        assert_eq!(classify_platform("not a url"), SocialPlatform::Other);
+    }
+}
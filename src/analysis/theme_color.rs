@@ -0,0 +1,99 @@
+//! Theme color consistency
+//!
+//! Browsers take the toolbar color from `<meta name="theme-color">` in a tab
+//! and from the manifest's `theme_color` once installed, so PWA audits flag
+//! pages where the two disagree. The manifest is not fetched: pass one parsed
+//! with [`manifest::parse_manifest`](crate::extractors::manifest::parse_manifest).
+
+use crate::types::manifest::WebAppManifest;
+use crate::types::meta::{MetaTags, ThemeColor};
+use crate::types::theme_color::ThemeColorReport;
+
+/// Compare the page's theme colors with the manifest's `theme_color`
+///
+/// A page may declare one color per media query (light and dark variants);
+/// the manifest matches if any of them is the same color. Colors are compared
+/// case-insensitively, with `#abc` shorthand equal to `#aabbcc` and
+/// whitespace inside functional notation ignored.
+pub fn theme_color_report(meta: &MetaTags, manifest: &WebAppManifest) -> ThemeColorReport {
+    let meta_colors = if meta.theme_colors.is_empty() {
+        // Tags built by hand may only set the single `theme_color`
+        meta.theme_color
+            .iter()
+            .map(|color| ThemeColor { color: color.clone(), media: None })
+            .collect()
+    } else {
+        meta.theme_colors.clone()
+    };
+    let manifest_color = manifest.theme_color.clone().filter(|color| !color.trim().is_empty());
+
+    let mismatch = match manifest_color.as_deref().map(normalize_color) {
+        Some(manifest) if !meta_colors.is_empty() => {
+            !meta_colors.iter().any(|meta| normalize_color(&meta.color) == manifest)
+        }
+        _ => false,
+    };
+
+    ThemeColorReport { meta_colors, manifest_color, mismatch }
+}
+
+/// Lowercase, strip whitespace and expand 3/4-digit hex colors
+fn normalize_color(color: &str) -> String {
+    let color: String =
+        color.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_lowercase();
+    match color.strip_prefix('#') {
+        Some(hex) if matches!(hex.len(), 3 | 4) && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            let expanded: String = hex.chars().flat_map(|c| [c, c]).collect();
+            format!("#{}", expanded)
+        }
+        _ => color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::meta;
+
+    fn manifest(theme_color: Option<&str>) -> WebAppManifest {
+        WebAppManifest { theme_color: theme_color.map(String::from), ..Default::default() }
+    }
+
+    #[test]
+    fn test_matching_colors() {
+        let meta = meta::extract(r##"<meta name="theme-color" content="#FFF">"##, None).unwrap();
+        let report = theme_color_report(&meta, &manifest(Some("#ffffff")));
+        assert!(!report.mismatch);
+        assert_eq!(report.meta_colors.len(), 1);
+
+        // Either variant of a light/dark pair may match
+        let html = r##"
+            <meta name="theme-color" media="(prefers-color-scheme: light)" content="#ffffff">
+            <meta name="theme-color" media="(prefers-color-scheme: dark)" content="#000000">
+        "##;
+        let meta = meta::extract(html, None).unwrap();
+        assert!(!theme_color_report(&meta, &manifest(Some("#000000"))).mismatch);
+    }
+
+    #[test]
+    fn test_mismatched_colors() {
+        let html = r#"<meta name="theme-color" content="rgb(0, 0, 255)">"#;
+        let meta = meta::extract(html, None).unwrap();
+        let report = theme_color_report(&meta, &manifest(Some("rgb(255,0,0)")));
+        assert!(report.mismatch);
+        assert_eq!(report.manifest_color.as_deref(), Some("rgb(255,0,0)"));
+    }
+
+    #[test]
+    fn test_one_side_missing_is_not_compared() {
+        let no_meta = meta::extract("<title>No color</title>", None).unwrap();
+        let report = theme_color_report(&no_meta, &manifest(Some("#123456")));
+        assert!(!report.mismatch);
+        assert!(report.meta_colors.is_empty());
+
+        let meta = meta::extract(r##"<meta name="theme-color" content="#123">"##, None).unwrap();
+        let report = theme_color_report(&meta, &manifest(None));
+        assert!(!report.mismatch);
+        assert!(report.manifest_color.is_none());
+    }
+}
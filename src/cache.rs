@@ -0,0 +1,176 @@
+//! In-memory cache of parsed documents
+//!
+//! Services that extract the same HTML repeatedly (for example with different
+//! [`ExtractOptions`]) can keep the parsed document around instead of
+//! re-parsing it each time. Enabled by the `cache` feature.
+
+use crate::errors::Result;
+use crate::extract::{self, ExtractOptions};
+use crate::extractors::common::html_utils;
+use crate::types::extracted::ExtractedData;
+use indexmap::IndexMap;
+use scraper::Html;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// A least-recently-used cache of parsed documents, keyed by a hash of the HTML
+///
+/// # Thread safety
+///
+/// A `DocumentCache` is neither `Send` nor `Sync`: parsed `scraper` documents
+/// use non-atomic reference counting and cannot cross threads. Use one cache
+/// per thread, for example in a `thread_local!`. Lookups take `&self`, so a
+/// cache can be shared freely within its thread.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::{extract_all_cached, DocumentCache};
+///
+/// let cache = DocumentCache::new(16);
+/// let html = "<title>Hello</title>";
+/// let first = extract_all_cached(&cache, html, None).unwrap();
+/// let second = extract_all_cached(&cache, html, Some("https://example.com/")).unwrap();
+/// assert_eq!(first.meta.title, second.meta.title);
+/// assert_eq!((cache.misses(), cache.hits()), (1, 1));
+/// ```
+#[derive(Debug)]
+pub struct DocumentCache {
+    capacity: usize,
+    /// Least recently used first
+    entries: RefCell<IndexMap<u64, Entry>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    /// The source, compared on lookup so a hash collision is a miss, not a wrong document
+    html: Box<str>,
+    document: Rc<Html>,
+}
+
+impl DocumentCache {
+    /// Create a cache holding up to `capacity` documents (at least one)
+    pub fn new(capacity: usize) -> Self {
+        DocumentCache {
+            capacity: capacity.max(1),
+            entries: RefCell::new(IndexMap::new()),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    /// The parsed document for `html`, parsing and caching it on a miss
+    pub fn get_or_parse(&self, html: &str) -> Rc<Html> {
+        let mut hasher = DefaultHasher::new();
+        html.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry) = entries.shift_remove(&key) {
+            if &*entry.html == html {
+                self.hits.set(self.hits.get() + 1);
+                let document = Rc::clone(&entry.document);
+                // Re-insert as most recently used
+                entries.insert(key, entry);
+                return document;
+            }
+        }
+
+        self.misses.set(self.misses.get() + 1);
+        let document = Rc::new(html_utils::parse_html(html));
+        if entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(key, Entry { html: html.into(), document: Rc::clone(&document) });
+        document
+    }
+
+    /// Number of documents currently cached
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    /// Whether the cache holds no documents
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Lookups answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// Lookups that had to parse the document
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    /// Drop every cached document (the hit and miss counters are kept)
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+/// [`extract_all`](crate::extract_all), reusing a cached parse of `html`
+pub fn extract_all_cached(
+    cache: &DocumentCache,
+    html: &str,
+    base_url: Option<&str>,
+) -> Result<ExtractedData> {
+    extract_all_cached_with_options(cache, html, base_url, &ExtractOptions::default())
+}
+
+/// [`extract_all_with_options`](crate::extract_all_with_options), reusing a cached parse of `html`
+pub fn extract_all_cached_with_options(
+    cache: &DocumentCache,
+    html: &str,
+    base_url: Option<&str>,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    options.check_input_size(html)?;
+    let html = html_utils::html5_markup(html, options.parse_mode);
+    let document = cache.get_or_parse(&html);
+    extract::extract_all_parsed(&html, &document, base_url, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_html_is_parsed_once() {
+        let cache = DocumentCache::new(4);
+        let html = r#"<title>Cached</title><meta property="og:title" content="OG">"#;
+
+        let plain = extract_all_cached(&cache, html, None).unwrap();
+        let options = ExtractOptions { detect_mojibake: true, ..Default::default() };
+        let with_options = extract_all_cached_with_options(&cache, html, None, &options).unwrap();
+
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(plain, crate::extract_all(html, None).unwrap());
+        assert_eq!(with_options.opengraph.title.as_deref(), Some("OG"));
+
+        extract_all_cached(&cache, "<title>Other</title>", None).unwrap();
+        assert_eq!(cache.misses(), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let cache = DocumentCache::new(2);
+        cache.get_or_parse("<p>a</p>");
+        cache.get_or_parse("<p>b</p>");
+        cache.get_or_parse("<p>a</p>"); // a is now the most recent
+        cache.get_or_parse("<p>c</p>"); // evicts b
+
+        assert_eq!(cache.len(), 2);
+        cache.get_or_parse("<p>a</p>");
+        assert_eq!(cache.hits(), 2);
+        cache.get_or_parse("<p>b</p>");
+        assert_eq!(cache.misses(), 4);
+    }
+}
@@ -1,6 +1,7 @@
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum MicroformatError {
     #[error("Failed to parse HTML: {0}")]
     ParseError(String),
@@ -16,6 +17,63 @@ pub enum MicroformatError {
 
     #[error("Extraction failed: {0}")]
     ExtractionFailed(String),
+
+    #[error("Invalid CSS selector: {0}")]
+    InvalidSelector(String),
+
+    #[error("Input too large: {size} bytes exceeds the limit of {limit} bytes")]
+    InputTooLarge { size: usize, limit: usize },
+}
+
+/// Broad category of a [`MicroformatError`], for deciding how to handle it
+///
+/// [`ErrorKind::Parse`] errors come from malformed markup or data in the
+/// document; skipping that document (or that part of it) and carrying on is
+/// usually right. The other kinds point at the input itself (a bad base URL,
+/// a bad selector, oversized input) and will fail again if retried unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Malformed markup, JSON or microformat structure in the document
+    Parse,
+    /// A URL (usually the base URL) could not be parsed
+    InvalidUrl,
+    /// A CSS selector, or a class name used to build one, is invalid
+    SelectorError,
+    /// The input is larger than [`crate::ExtractOptions::max_input_bytes`] allows
+    InputTooLarge,
+}
+
+impl ErrorKind {
+    /// Whether the error concerns one malformed document rather than the
+    /// input or configuration, so processing other documents can go on
+    pub fn is_recoverable(self) -> bool {
+        self == ErrorKind::Parse
+    }
+}
+
+impl MicroformatError {
+    /// The category this error falls into
+    ///
+    /// # Example
+    /// ```rust
+    /// use meta_oxide::{ErrorKind, MicroformatError};
+    ///
+    /// let err = MicroformatError::ParseError("unexpected token".to_string());
+    /// assert_eq!(err.kind(), ErrorKind::Parse);
+    /// assert!(err.kind().is_recoverable());
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            MicroformatError::ParseError(_)
+            | MicroformatError::MissingProperty(_)
+            | MicroformatError::InvalidStructure(_)
+            | MicroformatError::ExtractionFailed(_) => ErrorKind::Parse,
+            MicroformatError::InvalidUrl(_) => ErrorKind::InvalidUrl,
+            MicroformatError::InvalidSelector(_) => ErrorKind::SelectorError,
+            MicroformatError::InputTooLarge { .. } => ErrorKind::InputTooLarge,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, MicroformatError>;
@@ -101,4 +159,36 @@ mod tests {
         use std::error::Error;
         let _source: Option<&(dyn Error + 'static)> = mf_err.source();
     }
+
+    #[test]
+    fn test_error_kinds() {
+        let url_err = url::Url::parse("not a url").unwrap_err();
+        let cases = [
+            (MicroformatError::ParseError("x".into()), ErrorKind::Parse),
+            (MicroformatError::MissingProperty("name".into()), ErrorKind::Parse),
+            (MicroformatError::InvalidStructure("x".into()), ErrorKind::Parse),
+            (MicroformatError::ExtractionFailed("x".into()), ErrorKind::Parse),
+            (MicroformatError::InvalidUrl(url_err), ErrorKind::InvalidUrl),
+            (MicroformatError::InvalidSelector("div[".into()), ErrorKind::SelectorError),
+            (MicroformatError::InputTooLarge { size: 20, limit: 10 }, ErrorKind::InputTooLarge),
+        ];
+        for (err, kind) in cases {
+            assert_eq!(err.kind(), kind, "{:?}", err);
+            assert_eq!(err.kind().is_recoverable(), kind == ErrorKind::Parse);
+            assert!(!err.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_input_too_large_display() {
+        let err = MicroformatError::InputTooLarge { size: 2048, limit: 1024 };
+        assert_eq!(err.to_string(), "Input too large: 2048 bytes exceeds the limit of 1024 bytes");
+    }
+
+    #[test]
+    fn test_invalid_selector_from_create_selector() {
+        let err = crate::html_utils::create_selector("div[").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SelectorError);
+        assert!(err.to_string().contains("div["));
+    }
 }
@@ -0,0 +1,1200 @@
+//! Run every extractor over a document in one call
+
+use crate::errors::{MicroformatError, Result};
+use crate::extractors;
+use crate::extractors::common::html_utils::{self, ParseMode, Scope};
+use crate::extractors::common::prescan::MarkupScan;
+use crate::parser;
+use crate::types::diagnostics::{Diagnostics, Warning, WarningKind};
+use crate::types::extracted::ExtractedData;
+use scraper::{ElementRef, Html};
+use serde::Serialize;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+use url::Url;
+
+/// Options for [`extract_all_with_options`]
+///
+/// The defaults match [`extract_all`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractOptions {
+    /// Skip text inside hidden elements when reading microformats and
+    /// microdata text values
+    ///
+    /// An element is hidden if it has the `hidden` attribute,
+    /// `aria-hidden="true"` or an inline `display: none` style. Attribute
+    /// values (`href`, `content`, ...) are still read; a property whose text
+    /// is all hidden is left out.
+    pub ignore_hidden: bool,
+
+    /// Warn in [`ExtractedData::diagnostics`] when the document text contains
+    /// a high ratio of U+FFFD replacement characters
+    ///
+    /// That usually means the page was decoded with the wrong charset (for
+    /// example windows-1252 bytes declared as UTF-8), so extracted strings
+    /// are garbled.
+    pub detect_mojibake: bool,
+
+    /// Catch a panic in any single extractor instead of aborting the call
+    ///
+    /// The failing extractor's field is left empty and the panic is reported
+    /// as a warning in [`ExtractedData::diagnostics`]; every other extractor
+    /// still runs. Off by default so bugs surface loudly in tests and fuzzing.
+    /// Has no effect when the crate is built with `panic = "abort"`.
+    pub catch_panics: bool,
+
+    /// How relative URLs are resolved against `base_url`
+    pub url_resolution: UrlResolution,
+
+    /// Stop after this many items in each multi-item format (JSON-LD,
+    /// microdata, RDFa and microformats)
+    ///
+    /// Traversal stops once the cap is reached, which saves time on pages
+    /// like product listings with thousands of items when only the first few
+    /// matter. Each format that had more items raises a
+    /// [`WarningKind::ItemsTruncated`] warning. JSON-LD `@graph` members count
+    /// as separate items; for microformats the cap counts every `h-*`
+    /// element, nested ones included, since each is reported as an item.
+    /// `None` (the default) extracts everything.
+    pub max_items_per_format: Option<usize>,
+
+    /// Extract JSON-LD and microdata inside `<noscript>` elements
+    ///
+    /// Parsers treat `<noscript>` content as plain text, so structured data
+    /// placed there for crawlers is otherwise invisible. When set (the
+    /// default), that text is parsed again and its items are appended after
+    /// the document's own, counting toward
+    /// [`max_items_per_format`](Self::max_items_per_format). A block repeated
+    /// inside and outside `<noscript>` is therefore reported twice.
+    pub include_noscript: bool,
+
+    /// Record how long each extractor took in [`Diagnostics::timings`]
+    ///
+    /// Off by default; when off no clock is read. Durations are wall-clock
+    /// time, so they vary between runs and make otherwise identical results
+    /// compare unequal.
+    pub collect_timings: bool,
+
+    /// How the markup is parsed
+    ///
+    /// [`ParseMode::Xml`] reads XHTML served as XML: self-closing elements
+    /// keep their siblings and `xml:lang` wins over `lang`. Ignored by the
+    /// `_from_doc` functions, whose document is already parsed.
+    pub parse_mode: ParseMode,
+
+    /// Refuse documents longer than this many bytes
+    ///
+    /// Such input fails with [`MicroformatError::InputTooLarge`] before it is
+    /// parsed. `None` (the default) accepts any size.
+    pub max_input_bytes: Option<usize>,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        ExtractOptions {
+            ignore_hidden: false,
+            detect_mojibake: false,
+            catch_panics: false,
+            url_resolution: UrlResolution::default(),
+            max_items_per_format: None,
+            include_noscript: true,
+            collect_timings: false,
+            parse_mode: ParseMode::default(),
+            max_input_bytes: None,
+        }
+    }
+}
+
+/// How [`extract_all_with_options`] treats relative URLs
+///
+/// The mode applies to every extractor alike. A URL that cannot be resolved
+/// against a valid base is always kept as written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UrlResolution {
+    /// Resolve against `base_url` if it is a valid absolute URL, otherwise
+    /// keep URLs as written
+    #[default]
+    ResolveWhenPossible,
+
+    /// Resolve every URL, failing with [`MicroformatError::InvalidUrl`] if
+    /// `base_url` is missing or not a valid absolute URL
+    ResolveAll,
+
+    /// Keep URLs as written in the document and ignore `base_url`
+    KeepRelative,
+}
+
+impl ExtractOptions {
+    /// Fail with [`MicroformatError::InputTooLarge`] if `html` exceeds
+    /// [`max_input_bytes`](Self::max_input_bytes)
+    pub(crate) fn check_input_size(&self, html: &str) -> Result<()> {
+        match self.max_input_bytes {
+            Some(limit) if html.len() > limit => {
+                Err(MicroformatError::InputTooLarge { size: html.len(), limit })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl UrlResolution {
+    /// The base URL the extractors should resolve against, if any
+    fn base_url(self, base_url: Option<&str>) -> Result<Option<&str>> {
+        let valid = base_url.filter(|base| Url::parse(base).is_ok());
+        match self {
+            UrlResolution::ResolveWhenPossible => Ok(valid),
+            UrlResolution::ResolveAll => match base_url {
+                Some(base) => Url::parse(base).map(|_| Some(base)).map_err(Into::into),
+                None => Err(MicroformatError::InvalidUrl(url::ParseError::RelativeUrlWithoutBase)),
+            },
+            UrlResolution::KeepRelative => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Name of an extractor that panics instead of running, to test panic isolation
+    static PANIC_IN: std::cell::Cell<Option<&'static str>> = const { std::cell::Cell::new(None) };
+}
+
+/// Run one extractor, falling back to an empty result if it fails
+///
+/// With [`ExtractOptions::catch_panics`] a panic is caught and recorded as a
+/// [`WarningKind::ExtractorPanicked`] warning; otherwise it propagates. With
+/// [`ExtractOptions::collect_timings`] the run's duration is added to the
+/// extractor's entry in [`Diagnostics::timings`].
+fn run<T: Default>(
+    name: &'static str,
+    options: &ExtractOptions,
+    diagnostics: &mut Diagnostics,
+    extractor: impl FnOnce() -> Result<T>,
+) -> T {
+    let started = options.collect_timings.then(Instant::now);
+    let result = run_isolated(name, options, &mut diagnostics.warnings, extractor);
+    if let Some(started) = started {
+        *diagnostics.timings.entry(name.to_string()).or_default() += started.elapsed();
+    }
+    result
+}
+
+/// [`run`] without the timing
+fn run_isolated<T: Default>(
+    name: &'static str,
+    options: &ExtractOptions,
+    warnings: &mut Vec<Warning>,
+    extractor: impl FnOnce() -> Result<T>,
+) -> T {
+    let extractor = || {
+        #[cfg(test)]
+        if PANIC_IN.with(|p| p.get()) == Some(name) {
+            panic!("deliberate panic in {}", name);
+        }
+        extractor()
+    };
+
+    if !options.catch_panics {
+        return extractor().unwrap_or_default();
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(extractor)) {
+        Ok(result) => result.unwrap_or_default(),
+        Err(payload) => {
+            let reason = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            warnings.push(Warning {
+                kind: WarningKind::ExtractorPanicked,
+                message: format!("{} extractor panicked: {}", name, reason),
+            });
+            T::default()
+        }
+    }
+}
+
+/// Version of the combined output shape
+///
+/// Emitted as `schema_version` by [`extract_all_json`] and the Python
+/// `extract_all` dict. It is bumped only when the output changes in a way
+/// that can break consumers (a field removed, renamed or retyped), not on
+/// every release; new fields do not bump it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Share of replacement characters in the document text that triggers a mojibake warning
+const MOJIBAKE_THRESHOLD: f64 = 0.01;
+
+/// Extract ALL supported structured data from HTML
+///
+/// Extractors that fail on malformed input leave their field empty rather than
+/// failing the whole extraction. A cheap pre-scan skips extractors whose markers
+/// cannot be present in the document.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Example
+/// ```rust
+/// let html = r#"<title>Hello</title><meta property="og:title" content="Hello OG">"#;
+/// let data = meta_oxide::extract_all(html, None).unwrap();
+/// assert_eq!(data.meta.title, Some("Hello".to_string()));
+/// assert_eq!(data.opengraph.title, Some("Hello OG".to_string()));
+/// ```
+pub fn extract_all(html: &str, base_url: Option<&str>) -> Result<ExtractedData> {
+    extract_all_with_options(html, base_url, &ExtractOptions::default())
+}
+
+/// Extract ALL supported structured data from many documents
+///
+/// Each document is a `(html, base_url)` pair, and results come back in input
+/// order. With the `parallel` feature the documents are spread across the
+/// rayon thread pool; without it they are extracted one after another.
+///
+/// # Example
+/// ```rust
+/// let docs = vec![
+///     ("<title>One</title>".to_string(), None),
+///     ("<title>Two</title>".to_string(), Some("https://example.com/".to_string())),
+/// ];
+/// let results = meta_oxide::extract_all_batch(&docs);
+/// assert_eq!(results[1].as_ref().unwrap().meta.title.as_deref(), Some("Two"));
+/// ```
+pub fn extract_all_batch(docs: &[(String, Option<String>)]) -> Vec<Result<ExtractedData>> {
+    let extract =
+        |(html, base_url): &(String, Option<String>)| extract_all(html, base_url.as_deref());
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        docs.par_iter().map(extract).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        docs.iter().map(extract).collect()
+    }
+}
+
+/// Extract ALL supported structured data from HTML as pretty-printed JSON
+///
+/// The output is a JSON object with a `schema_version` ([`SCHEMA_VERSION`])
+/// followed by the fields of [`ExtractedData`], one per format (`meta`,
+/// `opengraph`, `twitter`, `jsonld`, ...). Consumers should check
+/// `schema_version` before reading the rest.
+///
+/// The output is deterministic: the same input always produces byte-identical
+/// JSON. Map-like fields keep document order (or, for microformats, sorted
+/// root types), so the result is suitable for snapshot tests.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Example
+/// ```rust
+/// let json = meta_oxide::extract_all_json("<title>Hello</title>", None).unwrap();
+/// assert!(json.contains(r#""title": "Hello""#));
+/// assert!(json.starts_with("{\n  \"schema_version\": 1,"));
+/// ```
+pub fn extract_all_json(html: &str, base_url: Option<&str>) -> Result<String> {
+    #[derive(Serialize)]
+    struct Versioned<'a> {
+        schema_version: u32,
+        #[serde(flatten)]
+        data: &'a ExtractedData,
+    }
+
+    let data = extract_all(html, base_url)?;
+    serde_json::to_string_pretty(&Versioned { schema_version: SCHEMA_VERSION, data: &data })
+        .map_err(|e| MicroformatError::ExtractionFailed(e.to_string()))
+}
+
+/// Extract ALL supported structured data from HTML, with options
+///
+/// Same as [`extract_all`], with the behaviour adjusted by `options`.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::{extract_all_with_options, ExtractOptions};
+///
+/// let html = r#"<div class="h-card"><span class="p-name">Jane</span>
+///     <span class="p-note" hidden>SEO keywords</span></div>"#;
+/// let options = ExtractOptions { ignore_hidden: true, ..Default::default() };
+/// let data = extract_all_with_options(html, None, &options).unwrap();
+/// assert!(data.microformats.contains_key("h-card"));
+/// ```
+pub fn extract_all_with_options(
+    html: &str,
+    base_url: Option<&str>,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    options.check_input_size(html)?;
+    let html = html_utils::html5_markup(html, options.parse_mode);
+    extract_all_parsed(&html, &html_utils::parse_html(&html), base_url, options)
+}
+
+/// Extract structured data only from the parts of a page matching a CSS selector
+///
+/// Useful to skip header, footer and sidebar boilerplate, e.g. with
+/// `scope_selector` set to `main` or `#content`. Every match is extracted in
+/// document order; a match nested inside another match is part of the outer
+/// one and not extracted twice. The matches are read in place, so what they
+/// inherit from the rest of the page still applies: the `lang` of an
+/// ancestor, RDFa `vocab` and `prefix` declarations, and the document's
+/// charset, language and `<base>`. Other markup outside the matches is
+/// ignored, so `<head>` metadata (title, Open Graph, ...) is only seen if the
+/// selector covers it, an item enclosing a match is not extracted, and a
+/// microdata `itemref` pointing outside the scope is not followed. Relative
+/// URLs resolve against `base_url` as usual.
+///
+/// # Errors
+/// [`MicroformatError::InvalidSelector`] if `scope_selector` is not a valid
+/// CSS selector. A selector that matches nothing yields empty data.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::{extract_within, ExtractOptions};
+///
+/// let html = r#"<main><div itemscope itemtype="https://schema.org/Product"></div></main>
+///     <footer><div itemscope itemtype="https://schema.org/Organization"></div></footer>"#;
+/// let data = extract_within(html, None, "main", &ExtractOptions::default()).unwrap();
+/// assert_eq!(data.microdata.len(), 1);
+/// ```
+pub fn extract_within(
+    html: &str,
+    base_url: Option<&str>,
+    scope_selector: &str,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    let selector = html_utils::create_selector(scope_selector)?;
+    options.check_input_size(html)?;
+    let html = html_utils::html5_markup(html, options.parse_mode);
+    let document = html_utils::parse_html(&html);
+
+    let roots: Vec<ElementRef> = document
+        .select(&selector)
+        .filter(|element| {
+            !element.ancestors().filter_map(ElementRef::wrap).any(|a| selector.matches(&a))
+        })
+        .collect();
+    extract_all_scoped(&html, Scope::within(&document, &roots), base_url, options)
+}
+
+/// Extract ALL supported structured data from an already parsed document
+///
+/// For callers that hold a [`scraper::Html`] from their own pipeline and want
+/// to avoid parsing the page again. The result matches [`extract_all`] on the
+/// source, except for checks that need the original bytes: a charset given
+/// only by a byte order mark, and the unclosed-script warning.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::{extract_all_from_doc, Html};
+///
+/// let document = Html::parse_document("<title>Parsed once</title>");
+/// let data = extract_all_from_doc(&document, None).unwrap();
+/// assert_eq!(data.meta.title.as_deref(), Some("Parsed once"));
+/// ```
+pub fn extract_all_from_doc(document: &Html, base_url: Option<&str>) -> Result<ExtractedData> {
+    extract_all_from_doc_with_options(document, base_url, &ExtractOptions::default())
+}
+
+/// [`extract_all_from_doc`], with options
+///
+/// The document is serialized once so the pre-scan can skip formats that are
+/// absent; it is not parsed again.
+pub fn extract_all_from_doc_with_options(
+    document: &Html,
+    base_url: Option<&str>,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    let html = document.html();
+    options.check_input_size(&html)?;
+    extract_all_parsed(&html, document, base_url, options)
+}
+
+/// Run every extractor over `html`, already parsed into `document`
+///
+/// This is the single-parse path: every extractor reads the same document,
+/// except microdata and microformats under [`ExtractOptions::ignore_hidden`],
+/// which see a copy of it with hidden text removed.
+pub(crate) fn extract_all_parsed(
+    html: &str,
+    document: &Html,
+    base_url: Option<&str>,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    extract_all_scoped(html, Scope::document(document), base_url, options)
+}
+
+/// [`extract_all_parsed`], reading only the part of the document within `scope`
+///
+/// The document's `<base>` is read wherever it is.
+fn extract_all_scoped(
+    html: &str,
+    scope: Scope,
+    base_url: Option<&str>,
+    options: &ExtractOptions,
+) -> Result<ExtractedData> {
+    let document = scope.html();
+    let base_url = options.url_resolution.base_url(base_url)?;
+    let scan = MarkupScan::scan(html);
+    // Microdata and microformats read element text, so they see the filtered document
+    let filtered = (options.ignore_hidden && (scan.microdata || scan.microformats))
+        .then(|| html_utils::without_hidden_text(document));
+    let filtered_roots = filtered.as_ref().and_then(|copy| scope.roots_in(copy));
+    let text_scope = match filtered {
+        Some(ref copy) => Scope::in_copy(copy, filtered_roots.as_deref()),
+        None => scope,
+    };
+
+    let mut diagnostics = Diagnostics::default();
+    let mut data = ExtractedData {
+        meta: run("meta", options, &mut diagnostics, || {
+            extractors::meta::extract_parsed(html, scope, base_url)
+        }),
+        base_url: base_url.map(str::to_string),
+        ..Default::default()
+    };
+    diagnostics.warnings.extend(extractors::meta::hreflang_diagnostics(&data.meta));
+    diagnostics.warnings.extend(detect_unclosed_script(html));
+
+    if scan.opengraph {
+        data.opengraph = run("opengraph", options, &mut diagnostics, || {
+            extractors::social::opengraph::extract_in(scope, base_url)
+        });
+    }
+    if scan.twitter {
+        data.twitter = run("twitter", options, &mut diagnostics, || {
+            let config = Default::default();
+            extractors::social::twitter::extract_with_fallback_opts_in(scope, base_url, &config)
+        });
+    }
+    let limit = options.max_items_per_format.unwrap_or(usize::MAX);
+    let noscript = (options.include_noscript && (scan.jsonld || scan.microdata))
+        .then(|| noscript_document(scope))
+        .flatten();
+    if scan.jsonld {
+        let (mut jsonld, mut truncated) = run("jsonld", options, &mut diagnostics, || {
+            extractors::jsonld::extract_limited_from_doc(scope, base_url, limit)
+        });
+        if let (Some(noscript), false) = (&noscript, truncated) {
+            let (more, more_truncated) = run("jsonld", options, &mut diagnostics, || {
+                extractors::jsonld::extract_limited_from_doc(
+                    Scope::document(noscript),
+                    base_url,
+                    limit - jsonld.len(),
+                )
+            });
+            jsonld.extend(more);
+            truncated = more_truncated;
+        }
+        data.jsonld = jsonld;
+        diagnostics.warnings.extend(truncation_warning("jsonld", truncated, limit));
+    }
+    if scan.microdata {
+        let (mut microdata, mut truncated) = run("microdata", options, &mut diagnostics, || {
+            extractors::microdata::extract_limited_from_doc(
+                text_scope,
+                base_url,
+                limit,
+                options.ignore_hidden,
+            )
+        });
+        if let (Some(noscript), false) = (&noscript, truncated) {
+            let (more, more_truncated) = run("microdata", options, &mut diagnostics, || {
+                let remaining = limit - microdata.len();
+                let noscript = Scope::document(noscript);
+                extractors::microdata::extract_limited_from_doc(
+                    noscript, base_url, remaining, false,
+                )
+            });
+            microdata.extend(more);
+            truncated = more_truncated;
+        }
+        data.microdata = microdata;
+        diagnostics.warnings.extend(truncation_warning("microdata", truncated, limit));
+    }
+    if scan.microformats {
+        let (microformats, truncated) = run("microformats", options, &mut diagnostics, || {
+            let lang = data.meta.effective_language();
+            parser::parse_document_limited(
+                text_scope,
+                base_url,
+                lang.as_deref(),
+                limit,
+                options.ignore_hidden,
+            )
+        });
+        data.microformats = microformats;
+        diagnostics.warnings.extend(truncation_warning("microformats", truncated, limit));
+    }
+    if scan.dublin_core {
+        data.dublin_core = run("dublin_core", options, &mut diagnostics, || {
+            extractors::dublin_core::extract_in(scope)
+        });
+    }
+    if scan.rdfa {
+        let (rdfa, truncated) = run("rdfa", options, &mut diagnostics, || {
+            extractors::rdfa::extract_limited_from_doc(scope, base_url, limit)
+        });
+        data.rdfa = rdfa;
+        diagnostics.warnings.extend(truncation_warning("rdfa", truncated, limit));
+    }
+    if scan.links {
+        data.oembed = run("oembed", options, &mut diagnostics, || {
+            extractors::oembed::extract_in(scope, base_url)
+        });
+        data.manifest = run("manifest", options, &mut diagnostics, || {
+            extractors::manifest::extract_link_in(scope, base_url)
+        });
+        data.rel_links = run("rel_links", options, &mut diagnostics, || {
+            extractors::rel_links::extract_with_options_in(scope, base_url, None)
+        });
+        data.resource_hints = run("resource_hints", options, &mut diagnostics, || {
+            extractors::resource_hints::extract_in(scope, base_url)
+        });
+    }
+    if scan.base {
+        data.document_base = run("document_base", options, &mut diagnostics, || {
+            extractors::meta::extract_document_base_from_doc(document, base_url)
+        });
+    }
+    if options.detect_mojibake {
+        diagnostics.warnings.extend(detect_mojibake(scope, data.meta.charset.as_deref()));
+    }
+
+    data.diagnostics = diagnostics;
+    Ok(data)
+}
+
+/// The text of every `<noscript>` element in `scope`, parsed as markup
+///
+/// Returns `None` when there is no non-empty `<noscript>`.
+fn noscript_document(scope: Scope) -> Option<Html> {
+    let selector = html_utils::create_selector("noscript").ok()?;
+    let markup: String = scope.select(&selector).flat_map(|element| element.text()).collect();
+    (!markup.trim().is_empty()).then(|| html_utils::parse_html(&markup))
+}
+
+/// Warn that a format stopped at [`ExtractOptions::max_items_per_format`]
+fn truncation_warning(format: &str, truncated: bool, limit: usize) -> Option<Warning> {
+    truncated.then(|| Warning {
+        kind: WarningKind::ItemsTruncated,
+        message: format!(
+            "{} extraction stopped after {} items; the document has more",
+            format, limit
+        ),
+    })
+}
+
+/// Warn if the document text looks like it was decoded with the wrong charset
+///
+/// Counts U+FFFD replacement characters among the non-whitespace characters of
+/// the text in `scope` (scripts and styles excluded).
+fn detect_mojibake(scope: Scope, declared_charset: Option<&str>) -> Option<Warning> {
+    let text: String = scope.roots().iter().map(html_utils::text_content).collect();
+
+    let (mut total, mut replaced) = (0usize, 0usize);
+    for c in text.chars().filter(|c| !c.is_whitespace()) {
+        total += 1;
+        if c == char::REPLACEMENT_CHARACTER {
+            replaced += 1;
+        }
+    }
+    if replaced == 0 || (replaced as f64) < total as f64 * MOJIBAKE_THRESHOLD {
+        return None;
+    }
+
+    let charset = declared_charset.map_or_else(
+        || "no charset was declared".to_string(),
+        |charset| format!("the declared charset ({}) is probably wrong", charset),
+    );
+    Some(Warning {
+        kind: WarningKind::Mojibake,
+        message: format!(
+            "{} of {} text characters are U+FFFD replacement characters; {}",
+            replaced, total, charset
+        ),
+    })
+}
+
+/// Warn if a `<script>` is never closed and swallows markup after it
+///
+/// The HTML tokenizer reads everything after an unterminated `<script>` as
+/// script text, so every tag up to the end of the document disappears from
+/// the parsed tree. This scans the source the way the tokenizer does
+/// (skipping comments, and script text up to its `</script>`) and warns when
+/// the trailing script text contains tags. A script that merely runs to the
+/// end of a truncated document without swallowing any markup is not reported.
+fn detect_unclosed_script(html: &str) -> Option<Warning> {
+    let bytes = html.as_bytes();
+    let mut pos = 0;
+    let content_start = loop {
+        let open = pos + html[pos..].find('<')?;
+        if bytes[open..].starts_with(b"<!--") {
+            pos = open + 4 + html[open + 4..].find("-->")? + 3;
+        } else if tag_at(bytes, open, b"<script") {
+            let content_start = open + html[open..].find('>')? + 1;
+            match (content_start..bytes.len()).find(|&i| tag_at(bytes, i, b"</script")) {
+                Some(close) => pos = close + 1,
+                None => break content_start,
+            }
+        } else {
+            pos = open + 1;
+        }
+    };
+
+    let swallowed = &bytes[content_start..];
+    let has_markup = swallowed.windows(2).any(|pair| {
+        pair[0] == b'<' && (pair[1].is_ascii_alphabetic() || matches!(pair[1], b'/' | b'!'))
+    });
+    has_markup.then(|| Warning {
+        kind: WarningKind::UnclosedScript,
+        message: format!(
+            "a <script> starting at byte {} is never closed; the {} bytes after it, which \
+             contain markup, were read as script text and not extracted",
+            content_start,
+            swallowed.len()
+        ),
+    })
+}
+
+/// Whether `tag` (e.g. `<script`) starts at `i`, case-insensitively, as a whole tag name
+fn tag_at(bytes: &[u8], i: usize, tag: &[u8]) -> bool {
+    bytes.get(i..i + tag.len()).is_some_and(|candidate| candidate.eq_ignore_ascii_case(tag))
+        && bytes
+            .get(i + tag.len())
+            .is_some_and(|&next| next.is_ascii_whitespace() || matches!(next, b'>' | b'/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::microdata::PropertyValue::Text;
+    use crate::types::rdfa::RdfaValue;
+    use crate::types::PropertyValue;
+
+    /// Replay the fuzzing seed corpus so regressions show up without cargo-fuzz
+    #[test]
+    fn test_fuzz_corpus_does_not_panic() {
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/fuzz/corpus/fuzz_extract_all");
+        let mut replayed = 0;
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let bytes = std::fs::read(entry.unwrap().path()).unwrap();
+            let html = String::from_utf8_lossy(&bytes);
+            extract_all(&html, None).unwrap();
+            extract_all(&html, Some("https://example.com/dir/page.html")).unwrap();
+            replayed += 1;
+        }
+        assert!(replayed > 0);
+    }
+
+    const HIDDEN_NAME: &str = r#"
+        <div class="h-card">
+            <span class="p-name" hidden>Hidden Name</span>
+            <a class="u-url" href="https://example.com/jane" aria-hidden="true">Jane</a>
+        </div>
+        <div itemscope itemtype="https://schema.org/Person">
+            <span itemprop="name" style="display: none">Hidden Person</span>
+            <span itemprop="jobTitle">Engineer</span>
+        </div>
+    "#;
+
+    #[test]
+    fn test_hidden_text_included_by_default() {
+        let data = extract_all(HIDDEN_NAME, None).unwrap();
+        let card = &data.microformats["h-card"][0];
+        assert_eq!(card.properties["name"], vec![PropertyValue::Text("Hidden Name".to_string())]);
+        assert_eq!(data.microdata[0].properties["name"][0], Text("Hidden Person".to_string()));
+    }
+
+    #[test]
+    fn test_ignore_hidden_skips_hidden_text() {
+        let options = ExtractOptions { ignore_hidden: true, ..Default::default() };
+        let data = extract_all_with_options(HIDDEN_NAME, None, &options).unwrap();
+
+        let card = &data.microformats["h-card"][0];
+        assert!(!card.properties.contains_key("name"));
+        // Attribute values of hidden elements are still read
+        assert_eq!(
+            card.properties["url"],
+            vec![PropertyValue::Url("https://example.com/jane".to_string())]
+        );
+
+        let person = &data.microdata[0];
+        assert!(!person.properties.contains_key("name"));
+        assert_eq!(person.properties["jobTitle"][0], Text("Engineer".to_string()));
+    }
+
+    #[test]
+    fn test_deeply_nested_items_do_not_overflow_the_stack() {
+        let depth = 1000;
+        let microdata = format!(
+            "{}x{}",
+            r#"<div itemscope itemprop="a">"#.repeat(depth),
+            "</div>".repeat(depth)
+        );
+        let rdfa = format!(
+            "{}x{}",
+            r#"<div typeof="Thing" property="part">"#.repeat(depth),
+            "</div>".repeat(depth)
+        );
+
+        let data = extract_all(&microdata, None).unwrap();
+        assert_eq!(data.microdata.len(), 1);
+        let data = extract_all(&rdfa, None).unwrap();
+        assert_eq!(data.rdfa.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_mojibake_in_misdeclared_windows_1252() {
+        // "Café – naïve" encoded as windows-1252, but declared as UTF-8
+        let mut bytes = b"<meta charset=\"utf-8\"><title>Caf\xe9</title><p>".to_vec();
+        bytes.extend_from_slice(b"Caf\xe9 \x96 na\xefve r\xe9sum\xe9</p>");
+        let html = String::from_utf8_lossy(&bytes);
+
+        let options = ExtractOptions { detect_mojibake: true, ..Default::default() };
+        let data = extract_all_with_options(&html, None, &options).unwrap();
+        assert!(data.diagnostics.has(WarningKind::Mojibake));
+        assert!(data.diagnostics.warnings[0].message.contains("(utf-8)"));
+
+        // Off by default
+        assert!(extract_all(&html, None).unwrap().diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_detect_mojibake_ignores_clean_text() {
+        let html = r#"<meta charset="utf-8"><title>Café</title><p>Naïve résumé – ok</p>"#;
+        let options = ExtractOptions { detect_mojibake: true, ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+        assert!(data.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_json_output_carries_schema_version() {
+        let json = extract_all_json("<title>Versioned</title>", None).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+
+        // The remaining fields are the ExtractedData shape
+        let data: ExtractedData = serde_json::from_value(value).unwrap();
+        assert_eq!(data, extract_all("<title>Versioned</title>", None).unwrap());
+    }
+
+    #[test]
+    fn test_unclosed_script_before_jsonld_is_reported() {
+        let html = r#"<title>Broken</title>
+            <script>var config = {"broken": true};
+            <!-- the rest of the page -->
+            <script type="application/ld+json">{"@type": "Product", "name": "Lost"}"#;
+        let data = extract_all(html, None).unwrap();
+        assert!(data.jsonld.is_empty());
+        assert!(data.meta.title.is_some());
+        assert!(data.diagnostics.has(WarningKind::UnclosedScript));
+    }
+
+    #[test]
+    fn test_closed_scripts_are_not_reported() {
+        let html = r#"<!-- <script> in a comment -->
+            <SCRIPT src="/app.js"></SCRIPT>
+            <script>if (a < b) { document.write("<p>" + "</p>"); }</script>
+            <p>Done</p>"#;
+        assert!(extract_all(html, None).unwrap().diagnostics.is_empty());
+
+        // Truncated inside a script, but no markup was lost
+        let truncated = "<title>Cut</title><script>var x = 1;";
+        assert!(extract_all(truncated, None).unwrap().diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_hreflang_surfaces_as_diagnostic() {
+        let html = r#"<link rel="alternate" href="/en" hreflang="english">"#;
+        let data = extract_all(html, None).unwrap();
+        assert!(data.diagnostics.has(WarningKind::InvalidHreflang));
+    }
+
+    const RELATIVE_URLS: &str = r#"<link rel="canonical" href="/articles/1">
+        <meta property="og:image" content="images/cover.png">"#;
+
+    fn with_resolution(
+        base_url: Option<&str>,
+        url_resolution: UrlResolution,
+    ) -> Result<ExtractedData> {
+        let options = ExtractOptions { url_resolution, ..Default::default() };
+        extract_all_with_options(RELATIVE_URLS, base_url, &options)
+    }
+
+    #[test]
+    fn test_resolve_when_possible() {
+        let base = Some("https://example.com/blog/");
+        let data = with_resolution(base, UrlResolution::ResolveWhenPossible).unwrap();
+        assert_eq!(data.meta.canonical.as_deref(), Some("https://example.com/articles/1"));
+        assert_eq!(
+            data.opengraph.image.as_deref(),
+            Some("https://example.com/blog/images/cover.png")
+        );
+
+        // Without a usable base, URLs are kept as written
+        for base in [None, Some("not a url")] {
+            let data = with_resolution(base, UrlResolution::ResolveWhenPossible).unwrap();
+            assert_eq!(data.meta.canonical.as_deref(), Some("/articles/1"));
+            assert_eq!(data.opengraph.image.as_deref(), Some("images/cover.png"));
+        }
+    }
+
+    #[test]
+    fn test_resolve_all_requires_a_valid_base() {
+        let base = Some("https://example.com/blog/");
+        let data = with_resolution(base, UrlResolution::ResolveAll).unwrap();
+        assert_eq!(data.meta.canonical.as_deref(), Some("https://example.com/articles/1"));
+        assert_eq!(
+            data.opengraph.image.as_deref(),
+            Some("https://example.com/blog/images/cover.png")
+        );
+
+        for base in [None, Some("not a url")] {
+            let result = with_resolution(base, UrlResolution::ResolveAll);
+            assert!(matches!(result, Err(MicroformatError::InvalidUrl(_))));
+        }
+    }
+
+    #[test]
+    fn test_keep_relative_ignores_base() {
+        let data = with_resolution(Some("https://example.com/blog/"), UrlResolution::KeepRelative)
+            .unwrap();
+        assert_eq!(data.meta.canonical.as_deref(), Some("/articles/1"));
+        assert_eq!(data.opengraph.image.as_deref(), Some("images/cover.png"));
+        assert_eq!(data.rel_links["canonical"], vec!["/articles/1"]);
+    }
+
+    #[test]
+    fn test_batch_matches_sequential() {
+        let docs: Vec<(String, Option<String>)> = (0..100)
+            .map(|i| {
+                let html = format!(
+                    r#"<title>Page {i}</title>
+                    <link rel="canonical" href="/pages/{i}">
+                    <meta property="og:image" content="/img/{i}.png">
+                    <script type="application/ld+json">{{"@type": "Article", "headline": "H{i}"}}</script>
+                    <div class="h-card"><span class="p-name">Author {i}</span></div>"#
+                );
+                let base_url = (i % 2 == 0).then(|| format!("https://example.com/{}/", i));
+                (html, base_url)
+            })
+            .collect();
+
+        let batch = extract_all_batch(&docs);
+        assert_eq!(batch.len(), docs.len());
+        for ((html, base_url), result) in docs.iter().zip(batch) {
+            assert_eq!(result.unwrap(), extract_all(html, base_url.as_deref()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_extraction_types_are_thread_safe() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ExtractedData>();
+        assert_send_sync::<ExtractOptions>();
+        assert_send_sync::<MicroformatError>();
+    }
+
+    #[test]
+    fn test_catch_panics_keeps_other_results() {
+        let html = r#"<title>Still here</title>
+            <meta property="og:title" content="OG">
+            <script type="application/ld+json">{"@type": "Thing"}</script>"#;
+        let options = ExtractOptions { catch_panics: true, ..Default::default() };
+
+        PANIC_IN.with(|p| p.set(Some("opengraph")));
+        let data = extract_all_with_options(html, None, &options);
+        PANIC_IN.with(|p| p.set(None));
+
+        let data = data.unwrap();
+        assert_eq!(data.meta.title.as_deref(), Some("Still here"));
+        assert_eq!(data.jsonld.len(), 1);
+        assert!(data.opengraph.title.is_none());
+        assert!(data.diagnostics.has(WarningKind::ExtractorPanicked));
+        assert!(data.diagnostics.warnings[0].message.contains("opengraph"));
+    }
+
+    #[test]
+    fn test_panics_propagate_by_default() {
+        PANIC_IN.with(|p| p.set(Some("meta")));
+        let result = panic::catch_unwind(|| extract_all("<title>x</title>", None));
+        PANIC_IN.with(|p| p.set(None));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_max_items_per_format_caps_microdata() {
+        let html: String = (0..100)
+            .map(|i| {
+                format!(
+                    r#"<div itemscope itemtype="https://schema.org/Product">
+                        <span itemprop="name">Item {}</span></div>"#,
+                    i
+                )
+            })
+            .collect();
+        let options = ExtractOptions { max_items_per_format: Some(10), ..Default::default() };
+        let data = extract_all_with_options(&html, None, &options).unwrap();
+
+        assert_eq!(data.microdata.len(), 10);
+        assert_eq!(data.microdata[9].properties["name"], vec![Text("Item 9".to_string())]);
+        assert!(data.diagnostics.has(WarningKind::ItemsTruncated));
+        assert!(data.diagnostics.warnings[0].message.starts_with("microdata"));
+
+        // Without a cap everything is extracted and nothing is reported
+        let data = extract_all(&html, None).unwrap();
+        assert_eq!(data.microdata.len(), 100);
+        assert!(!data.diagnostics.has(WarningKind::ItemsTruncated));
+    }
+
+    #[test]
+    fn test_max_items_per_format_exact_count_is_not_truncation() {
+        let html = r#"
+            <script type="application/ld+json">
+                {"@graph": [{"@type": "Organization"}, {"@type": "WebSite"}]}
+            </script>
+            <script type="application/ld+json">{"@type": "Product"}</script>
+            <div class="h-card"><span class="p-name">Jane</span></div>
+            <div class="h-card"><span class="p-name">John</span></div>"#;
+        let options = ExtractOptions { max_items_per_format: Some(2), ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+
+        // Graph members count separately, so the Product is cut
+        assert_eq!(data.jsonld.len(), 2);
+        assert_eq!(data.microformats["h-card"].len(), 2);
+        let truncated: Vec<&str> = data
+            .diagnostics
+            .warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::ItemsTruncated)
+            .map(|w| w.message.split(' ').next().unwrap())
+            .collect();
+        assert_eq!(truncated, vec!["jsonld"]);
+    }
+
+    #[test]
+    fn test_max_items_per_format_caps_rdfa_and_nested_microformats() {
+        let html = r#"
+            <div vocab="https://schema.org/" typeof="Person"><span property="name">Jane</span></div>
+            <div vocab="https://schema.org/" typeof="Person"><span property="name">John</span></div>
+            <div vocab="https://schema.org/"><span property="name">Untyped</span></div>
+            <div class="h-entry"><div class="p-author h-card">Jane</div></div>"#;
+        let options = ExtractOptions { max_items_per_format: Some(2), ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+
+        // Typed roots come first; the vocab-only root is past the cap
+        assert_eq!(data.rdfa.len(), 2);
+        // The nested h-card is an item too, so both elements fill the cap
+        assert_eq!(data.microformats["h-entry"].len(), 1);
+        assert_eq!(data.microformats["h-card"].len(), 1);
+        let truncated: Vec<&str> = data
+            .diagnostics
+            .warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::ItemsTruncated)
+            .map(|w| w.message.split(' ').next().unwrap())
+            .collect();
+        assert_eq!(truncated, vec!["rdfa"]);
+    }
+
+    #[test]
+    fn test_extract_from_pre_parsed_document() {
+        let html = r#"<html><head><title>Widget</title>
+            <meta name="description" content="A fine widget">
+            <script type="application/ld+json">{"@type": "Product", "name": "Widget"}</script>
+            </head><body><div itemscope itemtype="https://schema.org/Offer"></div></body></html>"#;
+        let document = Html::parse_document(html);
+
+        let meta = extractors::meta::extract_from_doc(&document, None).unwrap();
+        assert_eq!(meta.title.as_deref(), Some("Widget"));
+        assert_eq!(meta.description.as_deref(), Some("A fine widget"));
+        let jsonld = extractors::jsonld::extract_from_doc(&document, None).unwrap();
+        assert_eq!(jsonld[0].properties["name"], "Widget");
+
+        // The whole document, without parsing it again, matches extract_all
+        let data = extract_all_from_doc(&document, Some("https://example.com/")).unwrap();
+        assert_eq!(data, extract_all(html, Some("https://example.com/")).unwrap());
+    }
+
+    #[test]
+    fn test_extract_within_scope_selector() {
+        let block = r#"<script type="application/ld+json">
+            {"@type": "Organization", "name": "Acme"}
+        </script>"#;
+        let html = format!(
+            r#"<html><head><title>Page</title></head><body>
+                <div id="content">
+                    {}
+                    <script type="application/ld+json">{{"@type": "Article", "headline": "Story"}}</script>
+                    <section id="inner">
+                        <div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Jo</span></div>
+                    </section>
+                </div>
+                <footer>{}</footer>
+            </body></html>"#,
+            block, block
+        );
+
+        let data = extract_within(&html, None, "#content", &ExtractOptions::default()).unwrap();
+        let types: Vec<_> = data.jsonld.iter().filter_map(|o| o.type_.clone()).collect();
+        assert_eq!(types, vec!["Organization", "Article"]);
+        assert_eq!(data.microdata.len(), 1);
+        // Head metadata is outside the scope
+        assert_eq!(data.meta.title, None);
+
+        // A match nested in another match is not extracted twice
+        let data = extract_within(&html, None, "#content, #inner", &ExtractOptions::default());
+        assert_eq!(data.unwrap().microdata.len(), 1);
+
+        // The whole page sees the footer copy as well
+        assert_eq!(extract_all(&html, None).unwrap().jsonld.len(), 3);
+    }
+
+    #[test]
+    fn test_extract_within_reads_matches_in_place() {
+        let html = r#"<html lang="en-GB"><head><base href="https://example.com/shop/"></head>
+            <body vocab="https://schema.org/">
+                <table><tr class="row" itemscope itemtype="https://schema.org/Product">
+                    <td itemprop="name">Widget</td>
+                </tr></table>
+                <div class="row"><p typeof="Person"><span property="name">Ada</span></p></div>
+                <div class="row"><div class="h-card"><span class="p-name">Jo</span></div></div>
+            </body></html>"#;
+        let whole = extract_all(html, None).unwrap();
+        let data = extract_within(html, None, ".row", &ExtractOptions::default()).unwrap();
+
+        // A bare <tr> keeps its table context
+        assert_eq!(data.microdata, whole.microdata);
+        assert_eq!(data.microdata[0].properties["name"][0], Text("Widget".to_string()));
+        // Inherited RDFa vocab and lang still apply
+        assert_eq!(data.rdfa[..], whole.rdfa[..1]);
+        assert_eq!(data.microformats["h-card"][0].lang.as_deref(), Some("en-GB"));
+        assert_eq!(data.meta.language.as_deref(), Some("en-GB"));
+        // The document's <base> is read even though <head> is out of scope
+        let base = data.document_base.unwrap();
+        assert_eq!(base.href.as_deref(), Some("https://example.com/shop/"));
+    }
+
+    #[test]
+    fn test_extract_within_ignore_hidden() {
+        let html = format!("<main>{}</main><footer>{}</footer>", HIDDEN_NAME, HIDDEN_NAME);
+        let options = ExtractOptions { ignore_hidden: true, ..Default::default() };
+        let data = extract_within(&html, None, "main", &options).unwrap();
+        assert_eq!(data.microdata.len(), 1);
+        assert!(!data.microdata[0].properties.contains_key("name"));
+        assert_eq!(data.microformats["h-card"].len(), 1);
+    }
+
+    #[test]
+    fn test_extract_within_invalid_or_unmatched_selector() {
+        let html = r#"<script type="application/ld+json">{"@type": "Thing"}</script>"#;
+        let err = extract_within(html, None, "div[", &ExtractOptions::default()).unwrap_err();
+        assert!(matches!(err, MicroformatError::InvalidSelector(_)));
+
+        let data = extract_within(html, None, "main", &ExtractOptions::default()).unwrap();
+        assert!(data.jsonld.is_empty());
+    }
+
+    #[test]
+    fn test_noscript_structured_data() {
+        let html = r#"<html><head>
+            <noscript><script type="application/ld+json">{"@type": "Organization"}</script></noscript>
+            </head><body>
+            <script type="application/ld+json">{"@type": "WebSite"}</script>
+            <noscript>
+                <div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Jo</span></div>
+            </noscript>
+            </body></html>"#;
+
+        // Included by default, after the document's own items
+        let data = extract_all(html, None).unwrap();
+        let types: Vec<_> = data.jsonld.iter().filter_map(|o| o.type_.clone()).collect();
+        assert_eq!(types, vec!["WebSite", "Organization"]);
+        assert_eq!(data.microdata[0].properties["name"], vec![Text("Jo".to_string())]);
+
+        let options = ExtractOptions { include_noscript: false, ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+        assert_eq!(data.jsonld.len(), 1);
+        assert!(data.microdata.is_empty());
+
+        // Noscript items count toward the cap
+        let options = ExtractOptions { max_items_per_format: Some(1), ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+        assert_eq!(data.jsonld.len(), 1);
+        assert!(data.diagnostics.has(WarningKind::ItemsTruncated));
+    }
+
+    #[test]
+    fn test_microformats_fall_back_to_document_language() {
+        let html = r#"<head><meta property="og:locale" content="en_GB"></head>
+            <article class="h-entry"><span class="p-name">Colour</span>
+                <p class="p-summary h-cite" lang="fr">Couleur</p>
+            </article>
+            <div class="h-card" lang="en_gb"><span class="p-name">Jo</span></div>"#;
+        let data = extract_all(html, None).unwrap();
+        assert_eq!(data.microformats["h-entry"][0].lang.as_deref(), Some("en-GB"));
+        assert_eq!(data.microformats["h-cite"][0].lang.as_deref(), Some("fr"));
+        // An element's own lang is normalized like the document's
+        assert_eq!(data.microformats["h-card"][0].lang.as_deref(), Some("en-GB"));
+    }
+
+    #[test]
+    fn test_xml_parse_mode() {
+        let html = r#"<div vocab="https://schema.org/" typeof="Person">
+            <span property="jobTitle"/>
+            <span property="name" lang="en" xml:lang="fr">Jeanne</span>
+        </div>"#;
+        let options = ExtractOptions { parse_mode: ParseMode::Xml, ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+        assert_eq!(
+            data.rdfa[0].properties["https://schema.org/name"][0],
+            RdfaValue::LangLiteral { value: "Jeanne".to_string(), language: "fr".to_string() }
+        );
+
+        // HTML5 nests the name inside the "self-closed" jobTitle span
+        let data = extract_all(html, None).unwrap();
+        assert_eq!(
+            data.rdfa[0].properties["https://schema.org/jobTitle"][0],
+            RdfaValue::Literal("Jeanne".to_string())
+        );
+    }
+
+    #[test]
+    fn test_max_input_bytes() {
+        let html = "<title>Too long</title>";
+        let options = ExtractOptions { max_input_bytes: Some(10), ..Default::default() };
+        let err = extract_all_with_options(html, None, &options).unwrap_err();
+        assert!(matches!(err, MicroformatError::InputTooLarge { size: 23, limit: 10 }));
+        assert!(extract_within(html, None, "title", &options).is_err());
+
+        let options = ExtractOptions { max_input_bytes: Some(html.len()), ..Default::default() };
+        let data = extract_all_with_options(html, None, &options).unwrap();
+        assert_eq!(data.meta.title.as_deref(), Some("Too long"));
+    }
+
+    #[test]
+    fn test_collect_timings_per_extractor() {
+        let html = r#"<title>Timed</title>
+            <meta property="og:title" content="Timed">
+            <script type="application/ld+json">{"@type": "WebPage"}</script>
+            <div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Jo</span></div>"#;
+
+        let data = extract_all(html, None).unwrap();
+        assert!(data.diagnostics.timings.is_empty());
+        assert!(!serde_json::to_string(&data).unwrap().contains("timings"));
+
+        let options = ExtractOptions { collect_timings: true, ..Default::default() };
+        let timings = extract_all_with_options(html, None, &options).unwrap().diagnostics.timings;
+        for extractor in ["meta", "opengraph", "jsonld", "microdata"] {
+            assert!(timings.contains_key(extractor), "no timing for {}", extractor);
+        }
+        // Extractors skipped by the pre-scan are not timed
+        assert!(!timings.contains_key("rdfa"));
+        assert_eq!(timings.keys().next().map(String::as_str), Some("meta"));
+    }
+}
@@ -0,0 +1,229 @@
+//! Breadcrumb trail extractor
+//!
+//! Pulls a page's breadcrumb trail from, in order of preference:
+//! 1. JSON-LD `BreadcrumbList` (top level, or a `WebPage`'s `breadcrumb` property)
+//! 2. Microdata `BreadcrumbList` items
+//! 3. `<nav aria-label="breadcrumb">` link lists as a fallback
+
+use crate::errors::Result;
+use crate::extractors::common::{html_utils, url_utils};
+use crate::extractors::{jsonld, microdata};
+use crate::types::breadcrumbs::Breadcrumb;
+use crate::types::jsonld::{BreadcrumbList, JsonLdObject};
+use crate::types::microdata::{MicrodataItem, PropertyValue};
+use scraper::ElementRef;
+use serde_json::Value;
+
+#[cfg(test)]
+mod tests;
+
+/// Extract the page's breadcrumb trail
+///
+/// Returns the first non-empty trail found, preferring structured data over the
+/// HTML navigation fallback. Steps are ordered by position.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Result<Vec<Breadcrumb>>` - The trail, or an empty list if none was found
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<Breadcrumb>> {
+    let trail = from_jsonld(&jsonld::extract(html, base_url)?, base_url);
+    if !trail.is_empty() {
+        return Ok(trail);
+    }
+
+    let trail = from_microdata(&microdata::extract(html, base_url)?);
+    if !trail.is_empty() {
+        return Ok(trail);
+    }
+
+    from_nav(html, base_url)
+}
+
+/// Check whether a `@type` / `itemtype` value names a BreadcrumbList
+fn is_breadcrumb_type(type_name: &str) -> bool {
+    type_name == "BreadcrumbList" || type_name.ends_with("/BreadcrumbList")
+}
+
+fn jsonld_has_type(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::String(s)) => is_breadcrumb_type(s),
+        Some(Value::Array(types)) => types.iter().any(|t| jsonld_has_type(Some(t))),
+        _ => false,
+    }
+}
+
+fn from_jsonld(objects: &[JsonLdObject], base_url: Option<&str>) -> Vec<Breadcrumb> {
+    for object in objects {
+        let list = if jsonld_has_type(object.type_.as_ref()) {
+            serde_json::to_value(object).ok()
+        } else {
+            // WebPage (and subtypes) carry the trail in their `breadcrumb` property
+            object.properties.get("breadcrumb").filter(|v| v.is_object()).cloned()
+        };
+
+        if let Some(list) = list.and_then(|v| serde_json::from_value::<BreadcrumbList>(v).ok()) {
+            let trail = breadcrumbs_from_list(&list, base_url);
+            if !trail.is_empty() {
+                return trail;
+            }
+        }
+    }
+    Vec::new()
+}
+
+fn breadcrumbs_from_list(list: &BreadcrumbList, base_url: Option<&str>) -> Vec<Breadcrumb> {
+    let mut trail: Vec<Breadcrumb> = list
+        .item_list_element
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, element)| {
+            // `item` is either a URL string or a Thing with @id/url/name
+            let item = element.get("item");
+            let url = match item {
+                Some(Value::String(url)) => Some(url.as_str()),
+                Some(Value::Object(thing)) => {
+                    thing.get("@id").or_else(|| thing.get("url")).and_then(Value::as_str)
+                }
+                _ => None,
+            };
+            let name = element
+                .get("name")
+                .and_then(Value::as_str)
+                .or_else(|| item.and_then(|thing| thing.get("name")?.as_str()));
+
+            Breadcrumb {
+                position: parse_position(element.get("position")).unwrap_or(i as u32 + 1),
+                name: name.map(|s| s.trim().to_string()),
+                url: url.map(|u| url_utils::resolve_url(base_url, u).unwrap_or(u.to_string())),
+            }
+        })
+        .collect();
+
+    trail.sort_by_key(|crumb| crumb.position);
+    trail
+}
+
+fn parse_position(value: Option<&Value>) -> Option<u32> {
+    match value? {
+        Value::Number(n) => n.as_u64().map(|n| n as u32),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn from_microdata(items: &[MicrodataItem]) -> Vec<Breadcrumb> {
+    for item in items {
+        let is_list = item.item_type.iter().flatten().any(|t| is_breadcrumb_type(t));
+        if is_list {
+            let trail = breadcrumbs_from_microdata_list(item);
+            if !trail.is_empty() {
+                return trail;
+            }
+        }
+
+        // Look inside WebPage-style containers
+        let nested: Vec<MicrodataItem> = item
+            .properties
+            .values()
+            .flatten()
+            .filter_map(|value| match value {
+                PropertyValue::Item(nested) => Some((**nested).clone()),
+                PropertyValue::Text(_) => None,
+            })
+            .collect();
+        let trail = from_microdata(&nested);
+        if !trail.is_empty() {
+            return trail;
+        }
+    }
+    Vec::new()
+}
+
+fn breadcrumbs_from_microdata_list(list: &MicrodataItem) -> Vec<Breadcrumb> {
+    let mut trail: Vec<Breadcrumb> = list
+        .properties
+        .get("itemListElement")
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(i, value)| {
+            let PropertyValue::Item(list_item) = value else {
+                return None;
+            };
+
+            let (url, item_name) = match list_item.properties.get("item").and_then(|v| v.first()) {
+                // Microdata already resolved URL-valued properties
+                Some(PropertyValue::Text(url)) => (Some(url.trim().to_string()), None),
+                Some(PropertyValue::Item(thing)) => {
+                    (thing.id.clone().or_else(|| text(thing, "url")), text(thing, "name"))
+                }
+                None => (None, None),
+            };
+
+            Some(Breadcrumb {
+                position: text(list_item, "position")
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or(i as u32 + 1),
+                name: text(list_item, "name").or(item_name),
+                url: url.filter(|u| !u.is_empty()),
+            })
+        })
+        .collect();
+
+    trail.sort_by_key(|crumb| crumb.position);
+    trail
+}
+
+/// First non-empty text value of a microdata property
+fn text(item: &MicrodataItem, property: &str) -> Option<String> {
+    item.properties.get(property)?.iter().find_map(|value| match value {
+        PropertyValue::Text(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        _ => None,
+    })
+}
+
+fn from_nav(html: &str, base_url: Option<&str>) -> Result<Vec<Breadcrumb>> {
+    let document = html_utils::parse_html(html);
+    let nav_selector = html_utils::create_selector("nav[aria-label]")?;
+    let li_selector = html_utils::create_selector("li")?;
+    let link_selector = html_utils::create_selector("a[href]")?;
+
+    let Some(nav) = document.select(&nav_selector).find(|nav| {
+        html_utils::get_attr(nav, "aria-label")
+            .is_some_and(|label| label.to_lowercase().contains("breadcrumb"))
+    }) else {
+        return Ok(Vec::new());
+    };
+
+    let resolve = |link: &ElementRef| {
+        html_utils::get_attr(link, "href")
+            .map(|href| url_utils::resolve_url(base_url, &href).unwrap_or(href))
+    };
+
+    // Prefer list items so the unlinked current page is included
+    let mut steps: Vec<(Option<String>, Option<String>)> = nav
+        .select(&li_selector)
+        .map(|li| match li.select(&link_selector).next() {
+            Some(link) => (html_utils::extract_text(&link), resolve(&link)),
+            None => (html_utils::extract_text(&li), None),
+        })
+        .collect();
+
+    if steps.is_empty() {
+        steps = nav
+            .select(&link_selector)
+            .map(|link| (html_utils::extract_text(&link), resolve(&link)))
+            .collect();
+    }
+
+    Ok(steps
+        .into_iter()
+        .filter(|(name, url)| name.is_some() || url.is_some())
+        .enumerate()
+        .map(|(i, (name, url))| Breadcrumb { position: i as u32 + 1, name, url })
+        .collect())
+}
@@ -0,0 +1,131 @@
+//! Tests for breadcrumb extraction
+
+use super::*;
+
+#[test]
+fn test_jsonld_breadcrumb_three_levels() {
+    let html = r#"
+        <script type="application/ld+json">
+        {
+            "@context": "https://schema.org",
+            "@type": "BreadcrumbList",
+            "itemListElement": [
+                {"@type": "ListItem", "position": 2, "name": "Books", "item": "/books"},
+                {"@type": "ListItem", "position": 1, "name": "Home", "item": "https://example.com/"},
+                {"@type": "ListItem", "position": 3, "name": "Science Fiction"}
+            ]
+        }
+        </script>
+    "#;
+    let trail = extract(html, Some("https://example.com/books/sf")).unwrap();
+    assert_eq!(
+        trail,
+        vec![
+            Breadcrumb {
+                position: 1,
+                name: Some("Home".to_string()),
+                url: Some("https://example.com/".to_string()),
+            },
+            Breadcrumb {
+                position: 2,
+                name: Some("Books".to_string()),
+                url: Some("https://example.com/books".to_string()),
+            },
+            Breadcrumb { position: 3, name: Some("Science Fiction".to_string()), url: None },
+        ]
+    );
+}
+
+#[test]
+fn test_jsonld_webpage_breadcrumb_with_thing_items() {
+    let html = r#"
+        <script type="application/ld+json">
+        {
+            "@context": "https://schema.org",
+            "@type": "WebPage",
+            "breadcrumb": {
+                "@type": "BreadcrumbList",
+                "itemListElement": [
+                    {"@type": "ListItem", "position": "1",
+                     "item": {"@id": "https://example.com/", "name": "Home"}}
+                ]
+            }
+        }
+        </script>
+    "#;
+    let trail = extract(html, None).unwrap();
+    assert_eq!(trail.len(), 1);
+    assert_eq!(trail[0].name, Some("Home".to_string()));
+    assert_eq!(trail[0].url, Some("https://example.com/".to_string()));
+}
+
+#[test]
+fn test_microdata_breadcrumb() {
+    let html = r#"
+        <ol itemscope itemtype="https://schema.org/BreadcrumbList">
+            <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+                <a itemprop="item" href="/"><span itemprop="name">Home</span></a>
+                <meta itemprop="position" content="1">
+            </li>
+            <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+                <a itemprop="item" href="/dresses"><span itemprop="name">Dresses</span></a>
+                <meta itemprop="position" content="2">
+            </li>
+        </ol>
+    "#;
+    let trail = extract(html, Some("https://shop.example.com/dresses/red")).unwrap();
+    assert_eq!(trail.len(), 2);
+    assert_eq!(trail[0].name, Some("Home".to_string()));
+    assert_eq!(trail[0].url, Some("https://shop.example.com/".to_string()));
+    assert_eq!(trail[1].position, 2);
+    assert_eq!(trail[1].url, Some("https://shop.example.com/dresses".to_string()));
+}
+
+#[test]
+fn test_nav_breadcrumb_fallback() {
+    let html = r#"
+        <nav aria-label="Breadcrumb">
+            <ol>
+                <li><a href="/">Home</a></li>
+                <li><a href="/docs">Docs</a></li>
+                <li aria-current="page">Installation</li>
+            </ol>
+        </nav>
+    "#;
+    let trail = extract(html, Some("https://example.com/docs/install")).unwrap();
+    assert_eq!(
+        trail,
+        vec![
+            Breadcrumb {
+                position: 1,
+                name: Some("Home".to_string()),
+                url: Some("https://example.com/".to_string()),
+            },
+            Breadcrumb {
+                position: 2,
+                name: Some("Docs".to_string()),
+                url: Some("https://example.com/docs".to_string()),
+            },
+            Breadcrumb { position: 3, name: Some("Installation".to_string()), url: None },
+        ]
+    );
+}
+
+#[test]
+fn test_structured_data_preferred_over_nav() {
+    let html = r#"
+        <nav aria-label="breadcrumb"><a href="/">Nav Home</a></nav>
+        <script type="application/ld+json">
+        {"@type": "BreadcrumbList", "itemListElement": [{"position": 1, "name": "LD Home"}]}
+        </script>
+    "#;
+    let trail = extract(html, None).unwrap();
+    assert_eq!(trail.len(), 1);
+    assert_eq!(trail[0].name, Some("LD Home".to_string()));
+}
+
+#[test]
+fn test_no_breadcrumbs() {
+    let html = r#"<nav aria-label="Main"><a href="/">Home</a></nav>"#;
+    assert!(extract(html, None).unwrap().is_empty());
+}
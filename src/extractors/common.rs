@@ -23,6 +23,12 @@ pub trait Extractor: Debug {
     fn extract(html: &str, base_url: Option<&str>) -> Result<Self::Output>;
 }
 
+/// How deeply nested items (microdata `itemscope`, RDFa `typeof`) are followed
+///
+/// Items nested deeper than this are dropped. Real pages rarely nest more than
+/// a handful of levels; the cap keeps recursion bounded on adversarial input.
+pub(crate) const MAX_NESTING_DEPTH: usize = 64;
+
 /// Utility functions for URL resolution
 pub mod url_utils {
     use url::{ParseError, Url};
@@ -40,44 +46,839 @@ pub mod url_utils {
         }
     }
 
+    /// Resolve a URL like [`resolve_url`], keeping it as written if it is a
+    /// non-http URI (see [`is_non_http_uri`]) or cannot be resolved
+    ///
+    /// `data:` URIs in particular must not go through URL parsing, which may
+    /// re-encode their payload.
+    pub fn resolve_or_keep(base_url: Option<&str>, url: &str) -> String {
+        if is_non_http_uri(url) {
+            return url.to_string();
+        }
+        resolve_url(base_url, url).unwrap_or_else(|_| url.to_string())
+    }
+
+    /// Whether a URL starts with a scheme other than `http`/`https`
+    ///
+    /// Such URIs (`mailto:`, `tel:`, `xmpp:`, `did:`, ...) are already absolute
+    /// and are not hierarchical, so resolving or normalizing them as web URLs
+    /// can only mangle them (e.g. percent-encoding the spaces in a phone number).
+    pub fn is_non_http_uri(url: &str) -> bool {
+        let Some((scheme, _)) = url.trim_start().split_once(':') else {
+            return false;
+        };
+        let mut chars = scheme.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+            && !scheme.eq_ignore_ascii_case("http")
+            && !scheme.eq_ignore_ascii_case("https")
+    }
+
     /// Check if a URL is valid
     #[allow(dead_code)]
     pub fn is_valid_url(url: &str) -> bool {
         Url::parse(url).is_ok()
     }
+
+    /// Options for [`normalize`] and [`crate::types::meta::MetaTags::canonical_normalized`]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NormalizeOptions {
+        /// Query parameters to remove; a trailing `*` matches any suffix (`utm_*`)
+        pub strip_params: Vec<String>,
+        /// Lowercase the host name
+        pub lowercase_host: bool,
+    }
+
+    impl Default for NormalizeOptions {
+        fn default() -> Self {
+            Self {
+                strip_params: vec!["utm_*".to_string(), "fbclid".to_string(), "gclid".to_string()],
+                lowercase_host: true,
+            }
+        }
+    }
+
+    impl NormalizeOptions {
+        fn strips(&self, param: &str) -> bool {
+            self.strip_params.iter().any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => param.starts_with(prefix),
+                None => param == pattern,
+            })
+        }
+    }
+
+    /// Normalize an absolute URL into a canonical form for deduplication
+    ///
+    /// Removes tracking query parameters named in `opts` while keeping the
+    /// remaining parameters (and their encoding) in their original order.
+    /// Default ports (`:80`, `:443`) are always dropped.
+    ///
+    /// Re-exported at the crate root as [`crate::normalize_url`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use meta_oxide::{normalize_url, UrlNormalizeOptions};
+    ///
+    /// let url = "https://Example.com:443/a?utm_source=x&id=7&fbclid=y";
+    /// let normalized = normalize_url(url, &UrlNormalizeOptions::default()).unwrap();
+    /// assert_eq!(normalized, "https://example.com/a?id=7");
+    /// ```
+    pub fn normalize(url: &str, opts: &NormalizeOptions) -> Result<String, ParseError> {
+        let mut parsed = Url::parse(url.trim())?;
+
+        // Parsing already lowercases hosts of http(s) URLs; other schemes keep their case
+        if opts.lowercase_host {
+            if let Some(host) = parsed.host_str().map(str::to_lowercase) {
+                parsed.set_host(Some(&host))?;
+            }
+        }
+
+        if let Some(query) = parsed.query() {
+            let kept: Vec<&str> = query
+                .split('&')
+                .filter(|pair| {
+                    let name = pair.split('=').next().unwrap_or_default();
+                    !pair.is_empty() && !opts.strips(name)
+                })
+                .collect();
+            let query = kept.join("&");
+            parsed.set_query(if query.is_empty() { None } else { Some(&query) });
+        }
+
+        Ok(parsed.to_string())
+    }
 }
 
 /// Utility functions for HTML parsing
 pub mod html_utils {
     use crate::errors::{MicroformatError, Result};
-    use scraper::{Html, Selector};
+    use ego_tree::iter::Edge;
+    use scraper::{ElementRef, Html, Selector};
+    use std::borrow::Cow;
 
     /// Parse HTML and return a document
     pub fn parse_html(html: &str) -> Html {
         Html::parse_document(html)
     }
 
+    /// How markup is interpreted before it reaches the HTML5 parser
+    ///
+    /// Set [`ExtractOptions::parse_mode`](crate::ExtractOptions::parse_mode)
+    /// to choose it for [`crate::extract_all_with_options`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum ParseMode {
+        /// Parse as HTML5, exactly as browsers do for `text/html`
+        #[default]
+        Html5,
+        /// Treat the input as XHTML (XML syntax)
+        ///
+        /// Self-closing non-void elements (`<span/>`) are closed instead of
+        /// swallowing their following siblings, CDATA sections become text,
+        /// XML processing instructions are dropped, and `xml:lang` takes
+        /// precedence over `lang`, as it does in XML documents.
+        Xml,
+    }
+
+    /// Parse a document in the given mode
+    pub fn parse_document(html: &str, mode: ParseMode) -> Html {
+        parse_html(&html5_markup(html, mode))
+    }
+
+    /// `html` rewritten so the HTML5 parser reads it the way `mode` asks
+    pub(crate) fn html5_markup(html: &str, mode: ParseMode) -> Cow<'_, str> {
+        match mode {
+            ParseMode::Html5 => Cow::Borrowed(html),
+            ParseMode::Xml => Cow::Owned(xhtml_to_html5(html)),
+        }
+    }
+
+    /// Elements that never have content, so `<br/>` needs no closing tag
+    const VOID_ELEMENTS: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "meta",
+        "param", "source", "track", "wbr",
+    ];
+
+    /// Rewrite XHTML so the HTML5 parser builds the tree an XML parser would
+    fn xhtml_to_html5(xml: &str) -> String {
+        let mut out = String::with_capacity(xml.len() + xml.len() / 16);
+        let mut rest = xml;
+
+        while let Some(lt) = rest.find('<') {
+            out.push_str(&rest[..lt]);
+            rest = &rest[lt..];
+
+            if rest.starts_with("<!--") {
+                let end = rest.find("-->").map_or(rest.len(), |i| i + 3);
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let end = cdata.find("]]>").unwrap_or(cdata.len());
+                out.push_str(&escape_text(&cdata[..end]));
+                rest = cdata.get(end + 3..).unwrap_or("");
+            } else if rest.starts_with("<?") {
+                let end = rest.find("?>").map_or(rest.len(), |i| i + 2);
+                rest = &rest[end..];
+            } else if rest.starts_with("<!") || rest.starts_with("</") {
+                let end = rest.find('>').map_or(rest.len(), |i| i + 1);
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+            } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let end = tag_end(rest);
+                let (name, self_closing) = rewrite_start_tag(&rest[..end], &mut out);
+                rest = &rest[end..];
+
+                // Raw text elements: copy verbatim, unwrapping CDATA markers
+                if matches!(name.as_str(), "script" | "style") && !self_closing {
+                    let end = find_end_tag(rest, &name);
+                    out.push_str(&rest[..end].replace("<![CDATA[", "").replace("]]>", ""));
+                    rest = &rest[end..];
+                }
+            } else {
+                out.push('<');
+                rest = &rest[1..];
+            }
+        }
+
+        out.push_str(rest);
+        out
+    }
+
+    /// Byte offset of the first `</name` in `text`, ignoring ASCII case
+    fn find_end_tag(text: &str, name: &str) -> usize {
+        let mut from = 0;
+        while let Some(found) = text[from..].find("</") {
+            let start = from + found;
+            let candidate = &text.as_bytes()[start + 2..];
+            if candidate.get(..name.len()).is_some_and(|c| c.eq_ignore_ascii_case(name.as_bytes()))
+            {
+                return start;
+            }
+            from = start + 2;
+        }
+        text.len()
+    }
+
+    /// Byte offset just past the `>` closing the tag at the start of `tag`
+    fn tag_end(tag: &str) -> usize {
+        let mut quote = None;
+        for (i, c) in tag.char_indices() {
+            match (quote, c) {
+                (None, '"' | '\'') => quote = Some(c),
+                (Some(q), _) if c == q => quote = None,
+                (None, '>') => return i + 1,
+                _ => {}
+            }
+        }
+        tag.len()
+    }
+
+    /// Write a start tag in HTML5 form; returns its lowercase name and whether it self-closed
+    fn rewrite_start_tag(tag: &str, out: &mut String) -> (String, bool) {
+        let inner = tag.trim_start_matches('<').trim_end_matches('>');
+        let self_closing = inner.ends_with('/');
+        let inner = inner.trim_end_matches('/');
+
+        let name_end = inner.find(|c: char| c.is_whitespace() || c == '/').unwrap_or(inner.len());
+        let (name, attrs) = inner.split_at(name_end);
+        let attrs = split_attributes(attrs);
+        let has_xml_lang = attrs.iter().any(|(n, _)| n.eq_ignore_ascii_case("xml:lang"));
+
+        out.push('<');
+        out.push_str(name);
+        for (attr_name, value) in &attrs {
+            // xml:lang wins over lang in XML; mirror it into lang for the HTML tree
+            if has_xml_lang && attr_name.eq_ignore_ascii_case("lang") {
+                continue;
+            }
+            out.push(' ');
+            out.push_str(attr_name);
+            if let Some(value) = value {
+                out.push('=');
+                out.push_str(value);
+            }
+            if attr_name.eq_ignore_ascii_case("xml:lang") {
+                out.push_str(" lang=");
+                out.push_str(value.unwrap_or("\"\""));
+            }
+        }
+
+        let name = name.to_ascii_lowercase();
+        if self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+            out.push_str("></");
+            out.push_str(&name);
+            out.push('>');
+        } else if self_closing {
+            out.push_str("/>");
+        } else {
+            out.push('>');
+        }
+        (name, self_closing)
+    }
+
+    /// Split `a="1" b c='2'` into names and raw (still quoted) values
+    fn split_attributes(attrs: &str) -> Vec<(&str, Option<&str>)> {
+        let mut result = Vec::new();
+        let mut rest = attrs.trim_start();
+        while !rest.is_empty() {
+            let name_end = rest.find(|c: char| c.is_whitespace() || c == '=').unwrap_or(rest.len());
+            let (name, after) = rest.split_at(name_end);
+            let after = after.trim_start();
+            match after.strip_prefix('=') {
+                Some(value) => {
+                    let value = value.trim_start();
+                    let end = match value.chars().next() {
+                        Some(q @ ('"' | '\'')) => value[1..].find(q).map_or(value.len(), |i| i + 2),
+                        _ => value.find(char::is_whitespace).unwrap_or(value.len()),
+                    };
+                    result.push((name, Some(&value[..end])));
+                    rest = value[end..].trim_start();
+                }
+                None => {
+                    if !name.is_empty() {
+                        result.push((name, None));
+                    }
+                    rest = after;
+                    if name.is_empty() {
+                        // Stray character (e.g. a lone '/'); skip it
+                        rest = rest.get(1..).unwrap_or("");
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn escape_text(text: &str) -> String {
+        text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+    }
+
+    /// Parse raw HTML bytes, as fetched, into a document
+    ///
+    /// A UTF-8 byte order mark is dropped and invalid UTF-8 sequences are
+    /// replaced with U+FFFD, so arbitrary input always yields a document.
+    pub fn parse_html_bytes(bytes: &[u8]) -> Html {
+        let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+        parse_html(&String::from_utf8_lossy(bytes))
+    }
+
+    /// The part of a parsed document an extractor reads
+    ///
+    /// Either the whole document or the subtrees under some of its elements.
+    /// Subtrees are read in place, so what they inherit from outside (`lang`,
+    /// RDFa `vocab` and `prefix`, table context, ...) stays in effect.
+    #[derive(Clone, Copy)]
+    pub(crate) struct Scope<'a> {
+        document: &'a Html,
+        /// Disjoint subtree roots in document order; `None` for the whole document
+        roots: Option<&'a [ElementRef<'a>]>,
+    }
+
+    impl<'a> Scope<'a> {
+        /// The whole document
+        pub(crate) fn document(document: &'a Html) -> Self {
+            Scope { document, roots: None }
+        }
+
+        /// The subtrees under `roots`, which must be disjoint and in document order
+        pub(crate) fn within(document: &'a Html, roots: &'a [ElementRef<'a>]) -> Self {
+            Scope { document, roots: Some(roots) }
+        }
+
+        /// The document the scope is part of
+        pub(crate) fn html(self) -> &'a Html {
+            self.document
+        }
+
+        /// The subtree roots; the root element for the whole document
+        pub(crate) fn roots(self) -> Vec<ElementRef<'a>> {
+            match self.roots {
+                Some(roots) => roots.to_vec(),
+                None => vec![self.document.root_element()],
+            }
+        }
+
+        /// The roots of the scope, as elements of `copy`, a clone of the document
+        pub(crate) fn roots_in<'b>(self, copy: &'b Html) -> Option<Vec<ElementRef<'b>>> {
+            let roots = self.roots?;
+            Some(
+                roots
+                    .iter()
+                    .filter_map(|root| ElementRef::wrap(copy.tree.get(root.id())?))
+                    .collect(),
+            )
+        }
+
+        /// The same part of `copy`, given the roots from [`roots_in`](Self::roots_in)
+        pub(crate) fn in_copy<'b>(
+            copy: &'b Html,
+            roots: Option<&'b [ElementRef<'b>]>,
+        ) -> Scope<'b> {
+            Scope { document: copy, roots }
+        }
+
+        /// The elements matching `selector`, in document order
+        pub(crate) fn select<'s>(
+            self,
+            selector: &'s Selector,
+        ) -> impl Iterator<Item = ElementRef<'a>> + 's
+        where
+            'a: 's,
+        {
+            let whole = self.roots.is_none().then(|| self.document.select(selector));
+            let within = self.roots.unwrap_or_default().iter().flat_map(move |root| {
+                std::iter::once(*root)
+                    .filter(|root| selector.matches(root))
+                    .chain(root.select(selector))
+            });
+            whole.into_iter().flatten().chain(within)
+        }
+
+        /// Every element, in document order
+        pub(crate) fn elements(self) -> impl Iterator<Item = ElementRef<'a>> {
+            self.roots()
+                .into_iter()
+                .flat_map(|root| root.descendants().filter_map(ElementRef::wrap))
+        }
+
+        /// The ancestors of an element inside the scope, nearest first
+        pub(crate) fn ancestors(
+            self,
+            element: ElementRef<'a>,
+        ) -> impl Iterator<Item = ElementRef<'a>> {
+            let mut inside = !self.is_root(element);
+            element.ancestors().filter_map(ElementRef::wrap).take_while(move |ancestor| {
+                let take = inside;
+                inside = !self.is_root(*ancestor);
+                take
+            })
+        }
+
+        fn is_root(self, element: ElementRef) -> bool {
+            self.roots.is_some_and(|roots| roots.iter().any(|root| root.id() == element.id()))
+        }
+    }
+
     /// Create a CSS selector, returning error if invalid
     pub fn create_selector(selector: &str) -> Result<Selector> {
-        Selector::parse(selector).map_err(|e| {
-            MicroformatError::ParseError(format!("Invalid selector '{}': {:?}", selector, e))
-        })
+        Selector::parse(selector)
+            .map_err(|e| MicroformatError::InvalidSelector(format!("'{}': {:?}", selector, e)))
+    }
+
+    /// Whether an element is hidden from readers
+    ///
+    /// Checks the `hidden` attribute, `aria-hidden="true"` and, best-effort, an
+    /// inline `display: none` style. Stylesheets are not consulted.
+    pub fn is_hidden(element: &scraper::ElementRef) -> bool {
+        let el = element.value();
+        el.attr("hidden").is_some()
+            || el.attr("aria-hidden").is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+            || el.attr("style").is_some_and(|style| {
+                let style: String =
+                    style.chars().filter(|c| !c.is_ascii_whitespace()).collect::<String>();
+                style.to_ascii_lowercase().split(';').any(|decl| decl == "display:none")
+            })
+    }
+
+    /// A copy of the document without the text inside hidden elements
+    ///
+    /// The elements themselves are kept, and their attributes (`href`,
+    /// `content`, `datetime`, ...) are untouched, so only text values are
+    /// affected. See [`is_hidden`] for what counts as hidden. Node ids are the
+    /// same as in `document`.
+    pub fn without_hidden_text(document: &Html) -> Html {
+        let hidden_text: Vec<_> = document
+            .root_element()
+            .descendants()
+            .filter_map(scraper::ElementRef::wrap)
+            .filter(is_hidden)
+            .flat_map(|el| el.descendants().filter(|node| node.value().is_text()).map(|n| n.id()))
+            .collect();
+
+        let mut filtered = document.clone();
+        for id in hidden_text {
+            if let Some(mut node) = filtered.tree.get_mut(id) {
+                node.detach();
+            }
+        }
+        filtered
     }
 
-    /// Extract text content from an element, trimming whitespace
+    /// Attributes holding a single URL, rewritten by [`resolve_fragment_urls`]
+    const URL_ATTRIBUTES: &[&str] = &["href", "src", "poster", "cite"];
+
+    /// Resolve the relative URLs in an HTML fragment against `base_url`
+    ///
+    /// Rewrites `href`, `src`, `poster` and `cite` attributes and returns the
+    /// re-serialized fragment. URLs that cannot be resolved are left as
+    /// written; without a base URL the fragment is returned unchanged.
+    pub fn resolve_fragment_urls(fragment: &str, base_url: Option<&str>) -> String {
+        if base_url.is_none() {
+            return fragment.to_string();
+        }
+
+        let mut doc = Html::parse_fragment(fragment);
+        let ids: Vec<_> = doc.root_element().descendants().map(|node| node.id()).collect();
+        for id in ids {
+            let Some(mut node) = doc.tree.get_mut(id) else { continue };
+            let scraper::Node::Element(element) = node.value() else { continue };
+            for (name, value) in element.attrs.iter_mut() {
+                if URL_ATTRIBUTES.contains(&name.local.as_ref()) {
+                    if let Ok(resolved) = super::url_utils::resolve_url(base_url, value) {
+                        *value = resolved.into();
+                    }
+                }
+            }
+        }
+        doc.root_element().inner_html()
+    }
+
+    /// Elements whose text content is never rendered
+    const NON_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+    /// Normalized text content of an element
+    ///
+    /// This is the text policy shared by every extractor:
+    /// - text inside `<script>` and `<style>` is skipped
+    /// - `<br>` counts as whitespace, so `a<br>b` gives `a b`
+    /// - runs of HTML whitespace (space, tab, newline, form feed) collapse to one space
+    /// - leading and trailing whitespace is trimmed
+    ///
+    /// Returns an empty string when the element has no text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use meta_oxide::html_utils::{create_selector, parse_html, text_content};
+    ///
+    /// let doc = parse_html("<p>  Hello<br>world <script>track()</script></p>");
+    /// let p = doc.select(&create_selector("p").unwrap()).next().unwrap();
+    /// assert_eq!(text_content(&p), "Hello world");
+    /// ```
+    pub fn text_content(element: &scraper::ElementRef) -> String {
+        let mut raw = String::new();
+        collect_text(element, &mut raw);
+        raw.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Walks the subtree iteratively, so deeply nested markup cannot overflow the stack
+    fn collect_text(element: &scraper::ElementRef, out: &mut String) {
+        // The script or style element being skipped, if any
+        let mut skipping = None;
+        for edge in element.traverse() {
+            match edge {
+                Edge::Open(node) if skipping.is_none() => match node.value() {
+                    scraper::Node::Text(text) => out.push_str(text),
+                    scraper::Node::Element(el) if el.name() == "br" => out.push(' '),
+                    scraper::Node::Element(el)
+                        if NON_TEXT_ELEMENTS.contains(&el.name()) && node.id() != element.id() =>
+                    {
+                        skipping = Some(node.id());
+                    }
+                    _ => {}
+                },
+                Edge::Close(node) if skipping == Some(node.id()) => skipping = None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract text content from an element, or `None` if it has none
+    ///
+    /// Follows the [`text_content`] whitespace and script/style policy.
     pub fn extract_text(element: &scraper::ElementRef) -> Option<String> {
-        let text = element.text().collect::<String>();
-        let trimmed = text.trim();
-        if trimmed.is_empty() {
+        let text = text_content(element);
+        if text.is_empty() {
             None
         } else {
-            Some(trimmed.to_string())
+            Some(text)
         }
     }
 
     /// Get attribute value from an element
+    ///
+    /// Names match ASCII case-insensitively. The HTML5 parser lowercases
+    /// attribute names (also in [`ParseMode::Xml`], whose markup goes through
+    /// it), but keeps the case of those it adjusts inside SVG and MathML, so
+    /// `itemProp` or `PROPERTY` is found whatever the element.
     pub fn get_attr(element: &scraper::ElementRef, attr: &str) -> Option<String> {
-        element.value().attr(attr).map(|s| s.to_string())
+        let value = element.value();
+        value
+            .attr(attr)
+            .or_else(|| {
+                value.attrs().find(|(name, _)| name.eq_ignore_ascii_case(attr)).map(|(_, v)| v)
+            })
+            .map(|s| s.to_string())
+    }
+
+    /// Split a `srcset`/`imagesrcset` value into (url, descriptor) candidates
+    ///
+    /// Follows the HTML candidate syntax: a URL, then an optional width (`480w`)
+    /// or density (`2x`) descriptor, candidates separated by commas. Commas inside
+    /// a URL are kept, since a URL only ends at whitespace.
+    pub fn parse_srcset(srcset: &str) -> Vec<(String, Option<String>)> {
+        let mut candidates = Vec::new();
+        let mut rest = srcset;
+
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            if rest.is_empty() {
+                break;
+            }
+
+            let url_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let (url, after) = rest.split_at(url_end);
+            rest = after;
+
+            // A trailing comma on the URL ends the candidate with no descriptor
+            let descriptor = if url.ends_with(',') {
+                None
+            } else {
+                let end = rest.find(',').unwrap_or(rest.len());
+                let descriptor = rest[..end].trim();
+                rest = &rest[end..];
+                (!descriptor.is_empty()).then(|| descriptor.to_string())
+            };
+
+            let url = url.trim_end_matches(',');
+            if !url.is_empty() {
+                candidates.push((url.to_string(), descriptor));
+            }
+        }
+
+        candidates
+    }
+}
+
+/// ISO 8601 date/time and duration parsing
+///
+/// Only the profiles that show up in HTML metadata are supported: calendar
+/// dates, `T`- or space-separated times with optional seconds, fractions and
+/// UTC offset, and `PnYnMnWnDTnHnMnS` durations.
+pub mod datetime_utils {
+    /// A parsed ISO 8601 date or date-time
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DateTime {
+        pub year: i32,
+        pub month: u32,
+        pub day: u32,
+        pub hour: u32,
+        pub minute: u32,
+        pub second: u32,
+        /// UTC offset in minutes; `None` for local (floating) times
+        pub offset_minutes: Option<i32>,
+    }
+
+    impl DateTime {
+        /// The calendar date as written, ignoring time and offset
+        pub fn date(&self) -> (i32, u32, u32) {
+            (self.year, self.month, self.day)
+        }
+
+        /// Seconds since the Unix epoch; floating times are taken as UTC
+        pub fn timestamp(&self) -> i64 {
+            let days = days_from_civil(self.year, self.month, self.day);
+            let seconds = days * 86_400
+                + i64::from(self.hour) * 3_600
+                + i64::from(self.minute) * 60
+                + i64::from(self.second);
+            seconds - i64::from(self.offset_minutes.unwrap_or(0)) * 60
+        }
+    }
+
+    /// Parse `2024-05-15`, `2024-05-15T09:00`, `2024-05-15 09:00:30.5+02:00`, ...
+    pub fn parse_datetime(value: &str) -> Option<DateTime> {
+        let value = value.trim();
+        let (date, time) = match value.find(['T', 't', ' ']) {
+            Some(i) => (&value[..i], Some(&value[i + 1..])),
+            None => (value, None),
+        };
+
+        let mut parts = date.splitn(3, '-');
+        let year: i32 = parse_digits(parts.next()?, 4)?;
+        let month: u32 = parse_digits(parts.next()?, 2)?;
+        let day: u32 = parse_digits(parts.next()?, 2)?;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        let mut datetime =
+            DateTime { year, month, day, hour: 0, minute: 0, second: 0, offset_minutes: None };
+        let Some(time) = time else {
+            return Some(datetime);
+        };
+
+        // Split off the UTC offset
+        let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+            (clock, Some(0))
+        } else if let Some(i) = time.rfind(['+', '-']) {
+            (&time[..i], Some(parse_offset(&time[i..])?))
+        } else {
+            (time, None)
+        };
+
+        let mut fields = clock.splitn(3, ':');
+        datetime.hour = parse_digits(fields.next()?, 2)?;
+        datetime.minute = parse_digits(fields.next()?, 2)?;
+        if let Some(second) = fields.next() {
+            let whole = second.split_once(['.', ',']).map_or(second, |(whole, _)| whole);
+            datetime.second = parse_digits(whole, 2)?;
+        }
+        if datetime.hour > 23 || datetime.minute > 59 || datetime.second > 60 {
+            return None;
+        }
+        datetime.offset_minutes = offset;
+        Some(datetime)
+    }
+
+    /// Parse an ISO 8601 duration (`PT2H30M`, `P3D`, `P1W`) into whole seconds
+    ///
+    /// Years and months have no fixed length; they are counted as 365 and 30
+    /// days respectively. Durations too long for a `u64` of seconds are `None`.
+    pub fn parse_duration(value: &str) -> Option<u64> {
+        let rest = value.trim().strip_prefix(['P', 'p'])?;
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut seconds = 0.0;
+        let mut in_time = false;
+        let mut number = String::new();
+        let mut saw_component = false;
+        for c in rest.chars() {
+            match c.to_ascii_uppercase() {
+                'T' if !in_time && number.is_empty() => in_time = true,
+                '0'..='9' | '.' | ',' => number.push(if c == ',' { '.' } else { c }),
+                unit => {
+                    let amount: f64 = number.parse().ok()?;
+                    number.clear();
+                    let factor = match (unit, in_time) {
+                        ('Y', false) => 365.0 * 86_400.0,
+                        ('M', false) => 30.0 * 86_400.0,
+                        ('W', false) => 7.0 * 86_400.0,
+                        ('D', false) => 86_400.0,
+                        ('H', true) => 3_600.0,
+                        ('M', true) => 60.0,
+                        ('S', true) => 1.0,
+                        _ => return None,
+                    };
+                    seconds += amount * factor;
+                    saw_component = true;
+                }
+            }
+        }
+
+        // Too long to count in seconds is as unusable as malformed
+        let in_range = seconds.is_finite() && seconds.round() < u64::MAX as f64;
+        (saw_component && number.is_empty() && in_range).then_some(seconds.round() as u64)
+    }
+
+    fn parse_digits<T: std::str::FromStr>(value: &str, len: usize) -> Option<T> {
+        if value.len() != len || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        value.parse().ok()
+    }
+
+    /// `+02:00`, `-0530` or `+01` as minutes east of UTC
+    fn parse_offset(value: &str) -> Option<i32> {
+        let sign = if value.starts_with('-') { -1 } else { 1 };
+        let digits = value[1..].replace(':', "");
+        let (hours, minutes) = match digits.len() {
+            2 => (parse_digits::<i32>(&digits, 2)?, 0),
+            4 => (parse_digits::<i32>(&digits[..2], 2)?, parse_digits::<i32>(&digits[2..], 2)?),
+            _ => return None,
+        };
+        Some(sign * (hours * 60 + minutes))
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Days since 1970-01-01 of a proleptic Gregorian date
+    fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+        let year = i64::from(year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(month);
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+}
+
+/// Cheap substring pre-scan used by `extract_all` to skip extractors that cannot match
+///
+/// The scan is conservative: a flag is only `false` when the corresponding markup
+/// cannot possibly be present. Attribute names are matched directly; markers that
+/// live in attribute values also match whenever the document contains character
+/// references, since those could spell out the marker.
+pub mod prescan {
+    /// Which extractors a document may contain data for
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct MarkupScan {
+        pub jsonld: bool,
+        pub microdata: bool,
+        pub rdfa: bool,
+        pub opengraph: bool,
+        pub twitter: bool,
+        pub microformats: bool,
+        pub dublin_core: bool,
+        /// `rel` attributes (rel-* links, manifest, oEmbed, resource hints)
+        pub links: bool,
+        /// A `<base>` element
+        pub base: bool,
+    }
+
+    impl MarkupScan {
+        /// Scan raw HTML for structured-data markers
+        pub fn scan(html: &str) -> Self {
+            let lower = html.to_ascii_lowercase();
+            let has = |marker: &str| lower.contains(marker);
+            // Any character reference could encode punctuation; numeric ones could encode letters
+            let any_ref = has("&");
+            let numeric_ref = has("&#");
+
+            let opengraph = has("property");
+
+            Self {
+                jsonld: has("json") || numeric_ref,
+                microdata: has("itemscope"),
+                rdfa: has("typeof") || has("vocab"),
+                opengraph,
+                // Twitter extraction falls back to Open Graph values
+                twitter: opengraph || has("twitter") || numeric_ref,
+                microformats: has("h-") || any_ref,
+                dublin_core: has("dc") || numeric_ref,
+                links: has("rel"),
+                base: has("<base"),
+            }
+        }
+
+        /// A scan that runs every extractor (no fast path)
+        #[cfg(test)]
+        pub fn full() -> Self {
+            Self {
+                jsonld: true,
+                microdata: true,
+                rdfa: true,
+                opengraph: true,
+                twitter: true,
+                microformats: true,
+                dublin_core: true,
+                links: true,
+                base: true,
+            }
+        }
     }
 }
 
@@ -85,6 +886,105 @@ pub mod html_utils {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_fragment_urls() {
+        let fragment = r#"<p><a href="/post">Post</a> <img src="pic.jpg" alt="x"></p>"#;
+        let resolved =
+            html_utils::resolve_fragment_urls(fragment, Some("https://example.com/blog/"));
+        assert!(resolved.contains(r#"href="https://example.com/post""#));
+        assert!(resolved.contains(r#"src="https://example.com/blog/pic.jpg""#));
+        assert_eq!(html_utils::resolve_fragment_urls(fragment, None), fragment);
+    }
+
+    #[test]
+    fn test_get_attr_ignores_case() {
+        let doc =
+            html_utils::parse_html(r#"<svg viewBox="0 0 10 10"></svg><p ITEMPROP="name"></p>"#);
+        let svg = doc.select(&html_utils::create_selector("svg").unwrap()).next().unwrap();
+        let p = doc.select(&html_utils::create_selector("p").unwrap()).next().unwrap();
+        // SVG keeps the adjusted camel case name; everything else is lowercased
+        assert_eq!(html_utils::get_attr(&svg, "viewbox").as_deref(), Some("0 0 10 10"));
+        assert_eq!(html_utils::get_attr(&svg, "viewBox").as_deref(), Some("0 0 10 10"));
+        assert_eq!(html_utils::get_attr(&p, "itemProp").as_deref(), Some("name"));
+    }
+
+    #[test]
+    fn test_xml_mode_closes_self_closing_elements() {
+        use html_utils::{parse_document, ParseMode};
+
+        let markup = r#"<div id="outer"><span id="empty"/><span id="next">text</span></div>"#;
+        let count_children = |mode| {
+            let doc = parse_document(markup, mode);
+            let selector = html_utils::create_selector("#empty > *").unwrap();
+            doc.select(&selector).count()
+        };
+        // HTML5 ignores the trailing slash, so #next ends up inside #empty
+        assert_eq!(count_children(ParseMode::Html5), 1);
+        assert_eq!(count_children(ParseMode::Xml), 0);
+    }
+
+    #[test]
+    fn test_xml_mode_cdata_and_processing_instructions() {
+        use html_utils::{parse_document, ParseMode};
+
+        let markup = r#"<?xml version="1.0"?><p id="p"><![CDATA[a < b & c]]></p><br/>
+            <script type="application/ld+json">//<![CDATA[
+            {"@type": "Thing"}
+            //]]></script>"#;
+        let doc = parse_document(markup, ParseMode::Xml);
+        let p = doc.select(&html_utils::create_selector("#p").unwrap()).next().unwrap();
+        assert_eq!(p.text().collect::<String>(), "a < b & c");
+        let script = doc.select(&html_utils::create_selector("script").unwrap()).next().unwrap();
+        assert!(!script.inner_html().contains("CDATA"));
+    }
+
+    #[test]
+    fn test_xml_mode_many_scripts() {
+        use html_utils::{parse_document, ParseMode};
+
+        // Each script's end tag is found without rescanning the rest of the document
+        let markup = "<SCRIPT>var a = 1;</SCRIPT>".repeat(20_000);
+        let doc = parse_document(&markup, ParseMode::Xml);
+        assert_eq!(doc.select(&html_utils::create_selector("script").unwrap()).count(), 20_000);
+    }
+
+    #[test]
+    fn test_parse_datetime_forms() {
+        use datetime_utils::parse_datetime;
+
+        let date = parse_datetime("2024-05-15").unwrap();
+        assert_eq!(date.date(), (2024, 5, 15));
+        assert_eq!(date.timestamp(), 1_715_731_200);
+
+        let local = parse_datetime("2024-05-15T09:30").unwrap();
+        assert_eq!((local.hour, local.minute, local.offset_minutes), (9, 30, None));
+
+        let offset = parse_datetime("2024-05-15 09:30:15.25+02:00").unwrap();
+        assert_eq!(offset.second, 15);
+        assert_eq!(offset.timestamp(), local.timestamp() + 15 - 7_200);
+        assert_eq!(parse_datetime("2024-05-15T07:30:15Z").unwrap().timestamp(), offset.timestamp());
+
+        assert!(parse_datetime("2023-02-29").is_none());
+        assert!(parse_datetime("May 15, 2024").is_none());
+        assert!(parse_datetime("2024-05-15T25:00").is_none());
+    }
+
+    #[test]
+    fn test_parse_duration_forms() {
+        use datetime_utils::parse_duration;
+
+        assert_eq!(parse_duration("PT2H30M"), Some(9_000));
+        assert_eq!(parse_duration("P1DT1S"), Some(86_401));
+        assert_eq!(parse_duration("P2W"), Some(1_209_600));
+        assert_eq!(parse_duration("PT0.5S"), Some(1));
+        assert_eq!(parse_duration("P"), None);
+        assert_eq!(parse_duration("PT"), None);
+        assert_eq!(parse_duration("P1H"), None);
+        assert_eq!(parse_duration("2 hours"), None);
+        assert_eq!(parse_duration("P99999999999999999999999Y"), None);
+        assert_eq!(parse_duration(&format!("PT{}S", "9".repeat(400))), None);
+    }
+
     #[test]
     fn test_resolve_url_relative() {
         let result = url_utils::resolve_url(Some("https://example.com/page"), "../other");
@@ -103,6 +1003,64 @@ mod tests {
         assert_eq!(result.unwrap(), "https://example.com/");
     }
 
+    #[test]
+    fn test_parse_html_bytes_handles_bom_and_invalid_utf8() {
+        let document = html_utils::parse_html_bytes(b"\xEF\xBB\xBF<title>Caf\xE9</title>");
+        let selector = html_utils::create_selector("title").unwrap();
+        let title = document.select(&selector).next().unwrap();
+        assert_eq!(html_utils::extract_text(&title), Some("Caf\u{FFFD}".to_string()));
+    }
+
+    #[test]
+    fn test_parse_srcset() {
+        let candidates = html_utils::parse_srcset(
+            "/img/a.jpg 480w, /img/b,c.jpg 800w,/img/d.jpg, /img/e.jpg 2x",
+        );
+        assert_eq!(
+            candidates,
+            vec![
+                ("/img/a.jpg".to_string(), Some("480w".to_string())),
+                ("/img/b,c.jpg".to_string(), Some("800w".to_string())),
+                ("/img/d.jpg".to_string(), None),
+                ("/img/e.jpg".to_string(), Some("2x".to_string())),
+            ]
+        );
+        assert!(html_utils::parse_srcset("  ").is_empty());
+    }
+
+    #[test]
+    fn test_normalize_strips_utm_params() {
+        let opts = url_utils::NormalizeOptions::default();
+        let url = "https://example.com/post?utm_source=news&id=7&utm_medium=email&fbclid=abc";
+        assert_eq!(url_utils::normalize(url, &opts).unwrap(), "https://example.com/post?id=7");
+        assert_eq!(
+            url_utils::normalize("https://example.com/?utm_campaign=x", &opts).unwrap(),
+            "https://example.com/"
+        );
+    }
+
+    #[test]
+    fn test_normalize_preserves_other_params() {
+        let opts = url_utils::NormalizeOptions::default();
+        let url = "https://example.com/search?q=rust%20html&page=2";
+        assert_eq!(url_utils::normalize(url, &opts).unwrap(), url);
+
+        let opts = url_utils::NormalizeOptions { strip_params: vec!["page".to_string()], ..opts };
+        assert_eq!(
+            url_utils::normalize(url, &opts).unwrap(),
+            "https://example.com/search?q=rust%20html"
+        );
+    }
+
+    #[test]
+    fn test_normalize_host_and_port() {
+        let opts = url_utils::NormalizeOptions::default();
+        assert_eq!(
+            url_utils::normalize("HTTPS://Example.COM:443/Path", &opts).unwrap(),
+            "https://example.com/Path"
+        );
+    }
+
     #[test]
     fn test_is_valid_url() {
         assert!(url_utils::is_valid_url("https://example.com"));
@@ -119,6 +1077,65 @@ mod tests {
         assert_eq!(html_utils::extract_text(&element), Some("Hello World".to_string()));
     }
 
+    #[test]
+    fn test_extract_text_collapses_whitespace_runs() {
+        let html = html_utils::parse_html("<p>Hello \n\t   big\n\n  World</p>");
+        let selector = html_utils::create_selector("p").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(html_utils::extract_text(&element), Some("Hello big World".to_string()));
+    }
+
+    #[test]
+    fn test_extract_text_trims_across_nested_elements() {
+        let html = html_utils::parse_html("<p>\n  <b> Hello</b> <i>World </i>\n</p>");
+        let selector = html_utils::create_selector("p").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(html_utils::extract_text(&element), Some("Hello World".to_string()));
+    }
+
+    #[test]
+    fn test_extract_text_skips_script_and_style() {
+        let html = html_utils::parse_html(
+            "<div>Jane<script>var tracking = 1;</script> Doe<style>.x{color:red}</style></div>",
+        );
+        let selector = html_utils::create_selector("div").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(html_utils::extract_text(&element), Some("Jane Doe".to_string()));
+    }
+
+    #[test]
+    fn test_extract_text_br_is_a_space() {
+        let html = html_utils::parse_html("<address>123 Main St<br>Springfield<br/>USA</address>");
+        let selector = html_utils::create_selector("address").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(
+            html_utils::extract_text(&element),
+            Some("123 Main St Springfield USA".to_string())
+        );
+    }
+
+    #[test]
+    fn test_text_content_empty_element() {
+        let html = html_utils::parse_html("<p><script>x()</script> <br> </p>");
+        let selector = html_utils::create_selector("p").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(html_utils::text_content(&element), "");
+        assert_eq!(html_utils::extract_text(&element), None);
+    }
+
+    #[test]
+    fn test_text_content_deeply_nested() {
+        let depth = 100_000;
+        let html = html_utils::parse_html(&format!(
+            "<div>{}deep<script>x()</script>{}</div>",
+            "<span>".repeat(depth),
+            "</span>".repeat(depth)
+        ));
+        let selector = html_utils::create_selector("div").unwrap();
+        let element = html.select(&selector).next().unwrap();
+        assert_eq!(html_utils::text_content(&element), "deep");
+    }
+
     #[test]
     fn test_extract_text_empty() {
         let html = html_utils::parse_html("<p>   </p>");
@@ -209,4 +1226,52 @@ mod tests {
         let result = html_utils::create_selector("");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_prescan_plain_document() {
+        let scan =
+            prescan::MarkupScan::scan("<html><head><title>Hi</title></head><p>Text</p></html>");
+        assert!(!scan.jsonld);
+        assert!(!scan.microdata);
+        assert!(!scan.rdfa);
+        assert!(!scan.opengraph);
+        assert!(!scan.twitter);
+        assert!(!scan.microformats);
+        assert!(!scan.dublin_core);
+        assert!(!scan.links);
+    }
+
+    #[test]
+    fn test_prescan_detects_markers() {
+        let html = r#"
+            <script type="application/ld+json">{}</script>
+            <div itemscope typeof="Person" class="h-card"></div>
+            <meta property="og:title" content="T">
+            <link rel="me" href="/me">
+        "#;
+        let scan = prescan::MarkupScan::scan(html);
+        assert!(scan.jsonld);
+        assert!(scan.microdata);
+        assert!(scan.rdfa);
+        assert!(scan.opengraph);
+        assert!(scan.twitter);
+        assert!(scan.microformats);
+        assert!(scan.links);
+    }
+
+    #[test]
+    fn test_prescan_is_case_insensitive() {
+        let scan = prescan::MarkupScan::scan(r#"<DIV ITEMSCOPE CLASS="H-CARD"></DIV>"#);
+        assert!(scan.microdata);
+        assert!(scan.microformats);
+    }
+
+    #[test]
+    fn test_prescan_character_references_are_conservative() {
+        // A numeric reference could spell out any marker, so nothing value-based is skipped
+        let scan = prescan::MarkupScan::scan(r#"<div class="h&#45;card"></div>"#);
+        assert!(scan.microformats);
+        assert!(scan.jsonld);
+        assert!(scan.twitter);
+    }
 }
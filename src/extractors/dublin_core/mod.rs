@@ -3,8 +3,9 @@
 //! Extracts Dublin Core metadata elements from HTML meta tags.
 
 use crate::errors::Result;
-use crate::extractors::common::html_utils;
+use crate::extractors::common::html_utils::{self, Scope};
 use crate::types::dublin_core::DublinCore;
+use scraper::Html;
 
 #[cfg(test)]
 mod tests;
@@ -17,12 +18,21 @@ mod tests;
 /// # Returns
 /// * `Result<DublinCore>` - Extracted Dublin Core metadata or error
 pub fn extract(html: &str) -> Result<DublinCore> {
-    let document = html_utils::parse_html(html);
+    extract_from_doc(&html_utils::parse_html(html))
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html) -> Result<DublinCore> {
+    extract_in(Scope::document(document))
+}
+
+/// [`extract_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_in(scope: Scope) -> Result<DublinCore> {
     let mut dc = DublinCore::default();
 
     // Extract Dublin Core meta tags (both DC. and dc. prefixes)
     if let Ok(selector) = html_utils::create_selector("meta[name][content]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(name), Some(content)) =
                 (html_utils::get_attr(&element, "name"), html_utils::get_attr(&element, "content"))
             {
@@ -0,0 +1,45 @@
+//! Embedded JSON state extractor
+//!
+//! Extracts `<script type="application/json">` blocks such as Next.js
+//! `__NEXT_DATA__`. JSON-LD (`application/ld+json`) is handled by
+//! [`crate::extractors::jsonld`] and is not included.
+
+use crate::errors::Result;
+use crate::extractors::common::html_utils;
+use crate::types::embedded_json::EmbeddedJson;
+
+#[cfg(test)]
+mod tests;
+
+/// Extract embedded JSON blocks from HTML
+///
+/// Blocks whose content is not valid JSON are skipped.
+///
+/// # Arguments
+/// * `html` - The HTML content
+///
+/// # Returns
+/// * `Result<Vec<EmbeddedJson>>` - Parsed blocks in document order
+pub fn extract(html: &str) -> Result<Vec<EmbeddedJson>> {
+    let document = html_utils::parse_html(html);
+    let selector = html_utils::create_selector("script[type]")?;
+
+    let blocks = document
+        .select(&selector)
+        .filter(|element| html_utils::get_attr(element, "type").is_some_and(|t| is_json_type(&t)))
+        .filter_map(|element| {
+            let content: String = element.text().collect();
+            let data = serde_json::from_str(content.trim()).ok()?;
+            let id = html_utils::get_attr(&element, "id").filter(|id| !id.is_empty());
+            Some(EmbeddedJson { id, data })
+        })
+        .collect();
+
+    Ok(blocks)
+}
+
+/// Whether a script `type` is `application/json`, ignoring case and parameters
+fn is_json_type(script_type: &str) -> bool {
+    let essence = script_type.split(';').next().unwrap_or_default();
+    essence.trim().eq_ignore_ascii_case("application/json")
+}
@@ -0,0 +1,32 @@
+//! Tests for embedded JSON extraction
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn test_next_data() {
+    let html = r#"
+        <script id="__NEXT_DATA__" type="application/json">
+            {"props": {"pageProps": {"title": "Hello", "id": 42}}, "page": "/posts/[slug]"}
+        </script>
+    "#;
+    let blocks = extract(html).unwrap();
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].id.as_deref(), Some("__NEXT_DATA__"));
+    assert_eq!(blocks[0].data["props"]["pageProps"]["title"], "Hello");
+    assert_eq!(blocks[0].data["page"], json!("/posts/[slug]"));
+}
+
+#[test]
+fn test_invalid_json_and_other_types_skipped() {
+    let html = r#"
+        <script type="application/json">window.__STATE__ = {"a": 1};</script>
+        <script type="application/ld+json">{"@type": "Article"}</script>
+        <script type="text/javascript">{"not": "json state"}</script>
+        <script type="Application/JSON; charset=utf-8">[1, 2]</script>
+    "#;
+    let blocks = extract(html).unwrap();
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].id, None);
+    assert_eq!(blocks[0].data, json!([1, 2]));
+}
@@ -0,0 +1,67 @@
+//! Legacy geotagging meta tags
+//!
+//! Extracts `geo.position`, `ICBM`, `geo.region` and `geo.placename`.
+
+use crate::errors::Result;
+use crate::extractors::common::html_utils;
+use crate::types::geo::GeoMeta;
+
+#[cfg(test)]
+mod tests;
+
+/// Extract geotagging meta tags from HTML
+///
+/// Coordinates come from `geo.position` (`"lat;lon"`) or `ICBM` (`"lat, lon"`);
+/// both separators are accepted in either tag. When both tags carry valid
+/// coordinates `geo.position` wins, and a malformed `geo.position` falls back
+/// to `ICBM`. Out-of-range or unparsable coordinates are ignored.
+///
+/// # Arguments
+/// * `html` - The HTML content
+///
+/// # Returns
+/// * `Result<GeoMeta>` - Extracted location or error
+pub fn extract(html: &str) -> Result<GeoMeta> {
+    let document = html_utils::parse_html(html);
+    let mut geo = GeoMeta::default();
+    let mut position = None;
+    let mut icbm = None;
+
+    if let Ok(selector) = html_utils::create_selector("meta[name][content]") {
+        for element in document.select(&selector) {
+            if let (Some(name), Some(content)) =
+                (html_utils::get_attr(&element, "name"), html_utils::get_attr(&element, "content"))
+            {
+                let content = content.trim().to_string();
+                if content.is_empty() {
+                    continue;
+                }
+
+                match name.to_lowercase().as_str() {
+                    "geo.position" => position = position.or(parse_coordinates(&content)),
+                    "icbm" => icbm = icbm.or(parse_coordinates(&content)),
+                    "geo.region" => geo.region = Some(content),
+                    "geo.placename" => geo.placename = Some(content),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if let Some((latitude, longitude)) = position.or(icbm) {
+        geo.latitude = Some(latitude);
+        geo.longitude = Some(longitude);
+    }
+
+    Ok(geo)
+}
+
+/// Parse `"lat;lon"` or `"lat, lon"` into a valid coordinate pair
+pub fn parse_coordinates(value: &str) -> Option<(f64, f64)> {
+    let (lat, lon) = value.split_once(';').or_else(|| value.split_once(','))?;
+    let latitude: f64 = lat.trim().parse().ok()?;
+    let longitude: f64 = lon.trim().parse().ok()?;
+
+    let in_range = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+    in_range.then_some((latitude, longitude))
+}
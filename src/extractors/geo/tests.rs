@@ -0,0 +1,69 @@
+//! Tests for geotagging meta tag extraction
+
+use super::*;
+
+#[test]
+fn test_geo_position_semicolon() {
+    let html = r#"<meta name="geo.position" content="40.7128;-74.0060">"#;
+    let geo = extract(html).unwrap();
+    assert_eq!(geo.latitude, Some(40.7128));
+    assert_eq!(geo.longitude, Some(-74.006));
+}
+
+#[test]
+fn test_icbm_comma() {
+    let html = r#"<meta name="ICBM" content="51.5074, -0.1278">"#;
+    let geo = extract(html).unwrap();
+    assert_eq!(geo.latitude, Some(51.5074));
+    assert_eq!(geo.longitude, Some(-0.1278));
+}
+
+#[test]
+fn test_geo_position_preferred_over_icbm() {
+    let html = r#"
+        <meta name="ICBM" content="51.5074, -0.1278">
+        <meta name="geo.position" content="48.8566; 2.3522">
+    "#;
+    let geo = extract(html).unwrap();
+    assert_eq!(geo.latitude, Some(48.8566));
+    assert_eq!(geo.longitude, Some(2.3522));
+}
+
+#[test]
+fn test_malformed_geo_position_falls_back_to_icbm() {
+    let html = r#"
+        <meta name="geo.position" content="somewhere;nice">
+        <meta name="ICBM" content="35.6762, 139.6503">
+    "#;
+    let geo = extract(html).unwrap();
+    assert_eq!(geo.latitude, Some(35.6762));
+    assert_eq!(geo.longitude, Some(139.6503));
+}
+
+#[test]
+fn test_region_and_placename() {
+    let html = r#"
+        <meta name="geo.region" content="US-NY">
+        <meta name="geo.placename" content="New York City">
+    "#;
+    let geo = extract(html).unwrap();
+    assert_eq!(geo.region, Some("US-NY".to_string()));
+    assert_eq!(geo.placename, Some("New York City".to_string()));
+    assert_eq!(geo.latitude, None);
+}
+
+#[test]
+fn test_malformed_coordinates_yield_none() {
+    for content in ["40.7128", "abc;def", "95.0;10.0", "10.0;200.0", ";"] {
+        let html = format!(r#"<meta name="geo.position" content="{}">"#, content);
+        let geo = extract(&html).unwrap();
+        assert_eq!(geo.latitude, None, "content {:?}", content);
+        assert_eq!(geo.longitude, None, "content {:?}", content);
+    }
+}
+
+#[test]
+fn test_no_geo_tags() {
+    let geo = extract("<html><head><title>Plain</title></head></html>").unwrap();
+    assert_eq!(geo, GeoMeta::default());
+}
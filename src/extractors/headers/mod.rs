@@ -0,0 +1,141 @@
+//! HTTP header metadata extractor
+//!
+//! Reads the `Link` (RFC 8288), `X-Robots-Tag` and `Content-Language`
+//! response headers, for responses without HTML to extract from.
+
+use crate::extractors::common::url_utils;
+use crate::extractors::meta::is_crawler_name;
+use crate::types::headers::HeaderMetadata;
+use crate::types::meta::{AlternateLink, RobotsDirective};
+
+#[cfg(test)]
+mod tests;
+
+/// Extract metadata from HTTP response headers
+///
+/// Header names are matched case-insensitively and repeated headers are all
+/// read. `X-Robots-Tag` values prefixed with a crawler name
+/// (`googlebot: noindex`) go to `bot_robots`; the rest are combined into
+/// `robots`.
+///
+/// # Arguments
+/// * `headers` - Response headers as `(name, value)` pairs
+/// * `base_url` - Optional base URL (usually the response URL) for resolving relative targets
+///
+/// # Returns
+/// * `HeaderMetadata` - Metadata found in the headers
+pub fn extract_from_headers(
+    headers: &[(String, String)],
+    base_url: Option<&str>,
+) -> HeaderMetadata {
+    let mut metadata = HeaderMetadata::default();
+    let mut robots = Vec::new();
+
+    for (name, value) in headers {
+        match name.trim().to_lowercase().as_str() {
+            "link" => add_links(&mut metadata, value, base_url),
+            "x-robots-tag" => match crawler_prefix(value) {
+                Some((bot, directives)) => {
+                    let directive = RobotsDirective::parse(directives);
+                    let merged = match metadata.bot_robots.get(&bot) {
+                        Some(existing) => existing.merge(&directive),
+                        None => directive,
+                    };
+                    metadata.bot_robots.insert(bot, merged);
+                }
+                None => robots.push(value.trim()),
+            },
+            "content-language" => metadata.content_language.extend(
+                value.split(',').map(str::trim).filter(|tag| !tag.is_empty()).map(String::from),
+            ),
+            _ => {}
+        }
+    }
+
+    if !robots.is_empty() {
+        metadata.robots = Some(RobotsDirective::parse(&robots.join(", ")));
+    }
+    metadata
+}
+
+/// Split `googlebot: noindex` into the lowercase crawler name and its directives
+///
+/// Directives that take a value (`unavailable_after: ...`) are not crawler names.
+fn crawler_prefix(value: &str) -> Option<(String, &str)> {
+    let (name, directives) = value.split_once(':')?;
+    let name = name.trim().to_lowercase();
+    is_crawler_name(&name).then_some((name, directives))
+}
+
+/// Add the targets of one `Link` header value
+fn add_links(metadata: &mut HeaderMetadata, value: &str, base_url: Option<&str>) {
+    for link in split_outside_quotes(value, ',') {
+        let mut parts = split_outside_quotes(link, ';').into_iter();
+        let Some(target) = parts.next().map(str::trim) else {
+            continue;
+        };
+        let Some(target) = target.strip_prefix('<').and_then(|t| t.strip_suffix('>')) else {
+            continue;
+        };
+        let target = target.trim();
+        let href = if url_utils::is_non_http_uri(target) {
+            target.to_string()
+        } else {
+            url_utils::resolve_url(base_url, target).unwrap_or_else(|_| target.to_string())
+        };
+
+        let mut rel = None;
+        let (mut hreflang, mut media, mut link_type) = (None, None, None);
+        for param in parts {
+            let Some((key, value)) = param.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim().to_lowercase().as_str() {
+                // Only the first occurrence of a parameter counts
+                "rel" => rel = rel.or(Some(value)),
+                "hreflang" => hreflang = hreflang.or(Some(value)),
+                "media" => media = media.or(Some(value)),
+                "type" => link_type = link_type.or(Some(value)),
+                _ => {}
+            }
+        }
+
+        for rel_value in rel.iter().flat_map(|rel| rel.split_whitespace()) {
+            let rel_value = rel_value.to_lowercase();
+            match rel_value.as_str() {
+                "canonical" if metadata.canonical.is_none() => {
+                    metadata.canonical = Some(href.clone())
+                }
+                "alternate" => metadata.alternate.push(AlternateLink {
+                    href: href.clone(),
+                    hreflang: hreflang.clone(),
+                    media: media.clone(),
+                    r#type: link_type.clone(),
+                }),
+                _ => {}
+            }
+            metadata.rel_links.entry(rel_value).or_default().push(href.clone());
+        }
+    }
+}
+
+/// Split on `separator` where it is not inside `<...>` or a quoted string
+fn split_outside_quotes(value: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut start, mut in_quotes, mut in_target) = (0, false, false);
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' if !in_target => in_quotes = !in_quotes,
+            '<' if !in_quotes => in_target = true,
+            '>' if !in_quotes => in_target = false,
+            c if c == separator && !in_quotes && !in_target => {
+                parts.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts.into_iter().filter(|part| !part.trim().is_empty()).collect()
+}
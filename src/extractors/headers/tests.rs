@@ -0,0 +1,65 @@
+//! Tests for HTTP header metadata extraction
+
+use super::*;
+
+fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+#[test]
+fn test_link_header_canonical() {
+    let headers = headers(&[(
+        "Link",
+        r#"<https://example.com/report>; rel="canonical", </report-de.pdf>; rel="alternate"; hreflang="de"; type="application/pdf""#,
+    )]);
+    let metadata = extract_from_headers(&headers, Some("https://example.com/files/report.pdf"));
+
+    assert_eq!(metadata.canonical.as_deref(), Some("https://example.com/report"));
+    assert_eq!(metadata.alternate.len(), 1);
+    assert_eq!(metadata.alternate[0].href, "https://example.com/report-de.pdf");
+    assert_eq!(metadata.alternate[0].hreflang.as_deref(), Some("de"));
+    assert_eq!(metadata.alternate[0].r#type.as_deref(), Some("application/pdf"));
+    assert_eq!(metadata.rel_links["canonical"], vec!["https://example.com/report"]);
+}
+
+#[test]
+fn test_link_header_quoted_commas_and_multiple_rels() {
+    let headers = headers(&[
+        ("link", r#"</a,b>; rel="preload next"; title="One, two""#),
+        ("LINK", "<https://example.com/c>; rel=prev"),
+    ]);
+    let metadata = extract_from_headers(&headers, Some("https://example.com/"));
+
+    assert_eq!(metadata.rel_links["preload"], vec!["https://example.com/a,b"]);
+    assert_eq!(metadata.rel_links["next"], vec!["https://example.com/a,b"]);
+    assert_eq!(metadata.rel_links["prev"], vec!["https://example.com/c"]);
+    assert!(metadata.canonical.is_none());
+}
+
+#[test]
+fn test_x_robots_tag_noindex() {
+    let headers = headers(&[
+        ("X-Robots-Tag", "noindex"),
+        ("x-robots-tag", "nofollow"),
+        ("X-Robots-Tag", "googlebot: noarchive"),
+        ("X-Robots-Tag", "unavailable_after: 25 Jun 2030 15:00:00 PST"),
+    ]);
+    let metadata = extract_from_headers(&headers, None);
+
+    let robots = metadata.robots.unwrap();
+    assert_eq!(robots.index, Some(false));
+    assert_eq!(robots.follow, Some(false));
+    assert_eq!(metadata.bot_robots["googlebot"].archive, Some(false));
+    assert_eq!(metadata.bot_robots.len(), 1);
+}
+
+#[test]
+fn test_content_language() {
+    let headers =
+        headers(&[("Content-Language", "de-DE, en-CA"), ("Content-Type", "application/pdf")]);
+    let metadata = extract_from_headers(&headers, None);
+
+    assert_eq!(metadata.content_language, vec!["de-DE", "en-CA"]);
+    assert!(metadata.robots.is_none());
+    assert!(metadata.rel_links.is_empty());
+}
@@ -0,0 +1,211 @@
+//! A subset of the Schema.org type hierarchy
+//!
+//! Covers the types commonly found in JSON-LD on the web, so filters can ask
+//! for `LocalBusiness` and still see a `Restaurant`. Types missing from the
+//! table only match themselves.
+
+use serde_json::Value;
+
+/// `(type, direct supertype)` pairs; a type with several supertypes appears once per supertype
+const SUPERTYPES: &[(&str, &str)] = &[
+    // Creative works
+    ("CreativeWork", "Thing"),
+    ("Article", "CreativeWork"),
+    ("NewsArticle", "Article"),
+    ("Report", "Article"),
+    ("ScholarlyArticle", "Article"),
+    ("SocialMediaPosting", "Article"),
+    ("BlogPosting", "SocialMediaPosting"),
+    ("TechArticle", "Article"),
+    ("Blog", "CreativeWork"),
+    ("Book", "CreativeWork"),
+    ("Comment", "CreativeWork"),
+    ("Answer", "Comment"),
+    ("Question", "Comment"),
+    ("Course", "CreativeWork"),
+    ("Dataset", "CreativeWork"),
+    ("HowTo", "CreativeWork"),
+    ("Recipe", "HowTo"),
+    ("HowToStep", "CreativeWork"),
+    ("HowToStep", "ListItem"),
+    ("MediaObject", "CreativeWork"),
+    ("AudioObject", "MediaObject"),
+    ("ImageObject", "MediaObject"),
+    ("VideoObject", "MediaObject"),
+    ("Movie", "CreativeWork"),
+    ("MusicPlaylist", "CreativeWork"),
+    ("MusicAlbum", "MusicPlaylist"),
+    ("MusicRecording", "CreativeWork"),
+    ("Review", "CreativeWork"),
+    ("SoftwareApplication", "CreativeWork"),
+    ("MobileApplication", "SoftwareApplication"),
+    ("WebApplication", "SoftwareApplication"),
+    ("VideoGame", "SoftwareApplication"),
+    ("WebPage", "CreativeWork"),
+    ("AboutPage", "WebPage"),
+    ("CheckoutPage", "WebPage"),
+    ("CollectionPage", "WebPage"),
+    ("ContactPage", "WebPage"),
+    ("FAQPage", "WebPage"),
+    ("ItemPage", "WebPage"),
+    ("MedicalWebPage", "WebPage"),
+    ("ProfilePage", "WebPage"),
+    ("QAPage", "WebPage"),
+    ("RealEstateListing", "WebPage"),
+    ("SearchResultsPage", "WebPage"),
+    ("WebSite", "CreativeWork"),
+    // Events
+    ("Event", "Thing"),
+    ("BusinessEvent", "Event"),
+    ("EducationEvent", "Event"),
+    ("Festival", "Event"),
+    ("MusicEvent", "Event"),
+    ("SportsEvent", "Event"),
+    ("TheaterEvent", "Event"),
+    // Organizations
+    ("Organization", "Thing"),
+    ("Airline", "Organization"),
+    ("Corporation", "Organization"),
+    ("EducationalOrganization", "Organization"),
+    ("CollegeOrUniversity", "EducationalOrganization"),
+    ("School", "EducationalOrganization"),
+    ("GovernmentOrganization", "Organization"),
+    ("MedicalOrganization", "Organization"),
+    ("NewsMediaOrganization", "Organization"),
+    ("NGO", "Organization"),
+    ("OnlineBusiness", "Organization"),
+    ("OnlineStore", "OnlineBusiness"),
+    ("PerformingGroup", "Organization"),
+    ("MusicGroup", "PerformingGroup"),
+    ("SportsOrganization", "Organization"),
+    ("SportsTeam", "SportsOrganization"),
+    // Local businesses are both organizations and places
+    ("LocalBusiness", "Organization"),
+    ("LocalBusiness", "Place"),
+    ("AutomotiveBusiness", "LocalBusiness"),
+    ("ChildCare", "LocalBusiness"),
+    ("Dentist", "LocalBusiness"),
+    ("EntertainmentBusiness", "LocalBusiness"),
+    ("FinancialService", "LocalBusiness"),
+    ("BankOrCreditUnion", "FinancialService"),
+    ("FoodEstablishment", "LocalBusiness"),
+    ("Bakery", "FoodEstablishment"),
+    ("BarOrPub", "FoodEstablishment"),
+    ("Brewery", "FoodEstablishment"),
+    ("CafeOrCoffeeShop", "FoodEstablishment"),
+    ("FastFoodRestaurant", "FoodEstablishment"),
+    ("IceCreamShop", "FoodEstablishment"),
+    ("Restaurant", "FoodEstablishment"),
+    ("Winery", "FoodEstablishment"),
+    ("HealthAndBeautyBusiness", "LocalBusiness"),
+    ("HomeAndConstructionBusiness", "LocalBusiness"),
+    ("LegalService", "LocalBusiness"),
+    ("LodgingBusiness", "LocalBusiness"),
+    ("BedAndBreakfast", "LodgingBusiness"),
+    ("Hostel", "LodgingBusiness"),
+    ("Hotel", "LodgingBusiness"),
+    ("Motel", "LodgingBusiness"),
+    ("Resort", "LodgingBusiness"),
+    ("MedicalBusiness", "LocalBusiness"),
+    ("ProfessionalService", "LocalBusiness"),
+    ("RealEstateAgent", "LocalBusiness"),
+    ("SportsActivityLocation", "LocalBusiness"),
+    ("Store", "LocalBusiness"),
+    ("BookStore", "Store"),
+    ("ClothingStore", "Store"),
+    ("ElectronicsStore", "Store"),
+    ("FurnitureStore", "Store"),
+    ("GroceryStore", "Store"),
+    ("HardwareStore", "Store"),
+    ("TravelAgency", "LocalBusiness"),
+    // Places
+    ("Place", "Thing"),
+    ("Accommodation", "Place"),
+    ("AdministrativeArea", "Place"),
+    ("City", "AdministrativeArea"),
+    ("Country", "AdministrativeArea"),
+    ("State", "AdministrativeArea"),
+    ("CivicStructure", "Place"),
+    ("Landform", "Place"),
+    ("Residence", "Place"),
+    ("TouristAttraction", "Place"),
+    // People and products
+    ("Person", "Thing"),
+    ("Product", "Thing"),
+    ("IndividualProduct", "Product"),
+    ("ProductGroup", "Product"),
+    ("ProductModel", "Product"),
+    ("Vehicle", "Product"),
+    ("Car", "Vehicle"),
+    // Intangibles
+    ("Intangible", "Thing"),
+    ("Brand", "Intangible"),
+    ("ItemList", "Intangible"),
+    ("BreadcrumbList", "ItemList"),
+    ("JobPosting", "Intangible"),
+    ("ListItem", "Intangible"),
+    ("Offer", "Intangible"),
+    ("AggregateOffer", "Offer"),
+    ("Rating", "Intangible"),
+    ("AggregateRating", "Rating"),
+    ("Action", "Thing"),
+    ("SearchAction", "Action"),
+];
+
+/// Strip a Schema.org prefix (`https://schema.org/`, `schema:`) from a type name
+fn local_name(type_name: &str) -> &str {
+    ["https://schema.org/", "http://schema.org/", "schema:"]
+        .iter()
+        .find_map(|prefix| type_name.strip_prefix(prefix))
+        .unwrap_or(type_name)
+}
+
+/// Whether `type_name` is `ancestor` or one of its subtypes
+///
+/// Both names may carry a Schema.org prefix (`https://schema.org/Restaurant`).
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::jsonld::hierarchy::is_subtype_of;
+///
+/// assert!(is_subtype_of("Restaurant", "Organization"));
+/// assert!(!is_subtype_of("Organization", "Restaurant"));
+/// ```
+pub fn is_subtype_of(type_name: &str, ancestor: &str) -> bool {
+    let (type_name, ancestor) = (local_name(type_name), local_name(ancestor));
+    type_name == ancestor
+        || SUPERTYPES
+            .iter()
+            .filter(|(child, _)| *child == type_name)
+            .any(|(_, parent)| is_subtype_of(parent, ancestor))
+}
+
+/// Whether an `@type` value (a string or a list) names `ancestor` or a subtype
+pub(crate) fn type_value_is_subtype_of(type_: Option<&Value>, ancestor: &str) -> bool {
+    match type_ {
+        Some(Value::String(t)) => is_subtype_of(t, ancestor),
+        Some(Value::Array(types)) => {
+            types.iter().filter_map(Value::as_str).any(|t| is_subtype_of(t, ancestor))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiple_inheritance() {
+        assert!(is_subtype_of("Hotel", "Place"));
+        assert!(is_subtype_of("Hotel", "Organization"));
+        assert!(is_subtype_of("https://schema.org/Hotel", "schema:Thing"));
+        assert!(!is_subtype_of("Hotel", "CreativeWork"));
+    }
+
+    #[test]
+    fn test_unknown_types_match_only_themselves() {
+        assert!(is_subtype_of("CustomThing", "CustomThing"));
+        assert!(!is_subtype_of("CustomThing", "Thing"));
+    }
+}
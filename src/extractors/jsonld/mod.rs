@@ -4,9 +4,14 @@
 //! Enables Google Rich Results, AI/LLM training data, and rich metadata.
 
 use crate::errors::Result;
-use crate::extractors::common::html_utils;
-use crate::types::jsonld::JsonLdObject;
-use scraper::Selector;
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use crate::types::jsonld::{IdCollision, JsonLdObject, SearchAction};
+use indexmap::IndexMap;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+
+pub mod hierarchy;
 
 #[cfg(test)]
 mod tests;
@@ -17,48 +22,180 @@ mod tests;
 ///
 /// # Arguments
 /// * `html` - The HTML content
-/// * `_base_url` - Optional base URL (not used for JSON-LD)
+/// * `base_url` - Optional base URL for resolving relative `@id` values
 ///
 /// # Returns
 /// * `Result<Vec<JsonLdObject>>` - All JSON-LD objects found
-pub fn extract(html: &str, _base_url: Option<&str>) -> Result<Vec<JsonLdObject>> {
-    let document = html_utils::parse_html(html);
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<JsonLdObject>> {
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<Vec<JsonLdObject>> {
+    Ok(extract_limited_from_doc(Scope::document(document), base_url, usize::MAX)?.0)
+}
+
+/// [`extract_from_doc`], stopping after `limit` objects
+///
+/// `@graph` members count as separate objects. The flag is set when the
+/// document has more objects than `limit`. Only scripts within `scope` are read.
+pub(crate) fn extract_limited_from_doc(
+    scope: Scope,
+    base_url: Option<&str>,
+    limit: usize,
+) -> Result<(Vec<JsonLdObject>, bool)> {
+    let (objects, truncated) = extract_with_raw_limited_from_doc(scope, base_url, limit)?;
+    Ok((objects.into_iter().map(|(obj, _)| obj).collect(), truncated))
+}
+
+/// Extract all JSON-LD objects, each paired with the script text it came from
+///
+/// The raw string is the exact content of the `<script>` element, whitespace
+/// included, so it can be hashed or re-emitted byte for byte. Members of a
+/// `@graph` are each paired with the whole block.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative `@id` values
+///
+/// # Returns
+/// * `Result<Vec<(JsonLdObject, String)>>` - Objects with their source text
+pub fn extract_with_raw(html: &str, base_url: Option<&str>) -> Result<Vec<(JsonLdObject, String)>> {
+    extract_with_raw_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract_with_raw`] on an already parsed document
+pub fn extract_with_raw_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<Vec<(JsonLdObject, String)>> {
+    Ok(extract_with_raw_limited_from_doc(Scope::document(document), base_url, usize::MAX)?.0)
+}
+
+/// [`extract_with_raw_from_doc`], stopping after `limit` objects
+fn extract_with_raw_limited_from_doc(
+    scope: Scope,
+    base_url: Option<&str>,
+    limit: usize,
+) -> Result<(Vec<(JsonLdObject, String)>, bool)> {
     let mut objects = Vec::new();
 
     // Find all <script type="application/ld+json"> tags
     let selector = match Selector::parse("script[type='application/ld+json']") {
         Ok(s) => s,
-        Err(_) => return Ok(objects),
+        Err(_) => return Ok((objects, false)),
     };
 
-    for script in document.select(&selector) {
-        // Get the text content of the script tag
-        let json_text: String = script.text().collect();
-        let json_text = json_text.trim();
+    for script in scope.select(&selector) {
+        let mut found = parse_script(&script, base_url);
+        if objects.len() + found.len() > limit {
+            found.truncate(limit - objects.len());
+            objects.extend(found);
+            return Ok((objects, true));
+        }
+        objects.extend(found);
+    }
+
+    Ok((objects, false))
+}
 
-        if json_text.is_empty() {
-            continue;
+/// Parse one JSON-LD script into its objects, flattening a top-level `@graph`
+fn parse_script(script: &ElementRef, base_url: Option<&str>) -> Vec<(JsonLdObject, String)> {
+    // Get the text content of the script tag
+    let raw: String = script.text().collect();
+    let json_text = unwrap_script_body(&raw);
+
+    if json_text.is_empty() {
+        return Vec::new();
+    }
+
+    // Parse JSON
+    match serde_json::from_str::<JsonLdObject>(json_text) {
+        Ok(mut obj) => {
+            if base_url.is_some() {
+                resolve_ids(&mut obj, base_url);
+            }
+
+            // If object has @graph, extract all items from graph
+            if let Some(graph) = obj.graph.take() {
+                graph.into_iter().map(|member| (member, raw.clone())).collect()
+            } else {
+                vec![(obj, raw)]
+            }
+        }
+        Err(e) => {
+            // Log parse error but continue with other scripts
+            eprintln!("JSON-LD parse error: {}", e);
+            Vec::new()
         }
+    }
+}
 
-        // Parse JSON
-        match serde_json::from_str::<JsonLdObject>(json_text) {
-            Ok(obj) => {
-                // If object has @graph, extract all items from graph
-                if let Some(ref graph) = obj.graph {
-                    objects.extend(graph.clone());
-                } else {
-                    objects.push(obj);
+/// Strip what pages wrap JSON-LD in before it reaches the JSON parser
+///
+/// Handles a leading byte order mark and any nesting of `<!-- ... -->` and
+/// `<![CDATA[ ... ]]>`, with markers optionally hidden from script engines
+/// behind `//` or `/* */` (`//<![CDATA[` ... `//]]>`).
+fn unwrap_script_body(text: &str) -> &str {
+    let mut text = text.trim_start_matches('\u{feff}').trim();
+    while let Some(inner) =
+        unwrap_markers(text, "<!--", "-->").or_else(|| unwrap_markers(text, "<![CDATA[", "]]>"))
+    {
+        text = inner.trim_start_matches('\u{feff}').trim();
+    }
+    text
+}
+
+/// `text` without the surrounding `open` and `close` markers, if it has both
+fn unwrap_markers<'a>(text: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let comment_start = |t: &'a str| t.strip_prefix("//").or_else(|| t.strip_prefix("/*"));
+    let rest = comment_start(text).map_or(text, str::trim_start).strip_prefix(open)?;
+    let rest = rest.strip_prefix("*/").unwrap_or(rest);
+
+    let rest = rest.strip_suffix("*/").unwrap_or(rest).strip_suffix(close)?.trim_end();
+    Some(rest.strip_suffix("//").or_else(|| rest.strip_suffix("/*")).unwrap_or(rest))
+}
+
+/// Resolve relative `@id` IRIs against the document base
+///
+/// Covers the object itself, its `@graph` members, and nested node objects in
+/// property values. Blank node identifiers (`_:b0`) are left untouched.
+fn resolve_ids(obj: &mut JsonLdObject, base_url: Option<&str>) {
+    if let Some(ref id) = obj.id {
+        obj.id = Some(resolve_id(id, base_url));
+    }
+    for member in obj.graph.iter_mut().flatten() {
+        resolve_ids(member, base_url);
+    }
+    for value in obj.properties.values_mut() {
+        resolve_value_ids(value, base_url);
+    }
+}
+
+fn resolve_value_ids(value: &mut Value, base_url: Option<&str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, nested) in map.iter_mut() {
+                match nested {
+                    Value::String(id) if key == "@id" => *id = resolve_id(id, base_url),
+                    _ => resolve_value_ids(nested, base_url),
                 }
             }
-            Err(e) => {
-                // Log parse error but continue with other scripts
-                eprintln!("JSON-LD parse error: {}", e);
-                continue;
+        }
+        Value::Array(values) => {
+            for nested in values {
+                resolve_value_ids(nested, base_url);
             }
         }
+        _ => {}
     }
+}
 
-    Ok(objects)
+fn resolve_id(id: &str, base_url: Option<&str>) -> String {
+    if id.starts_with("_:") {
+        return id.to_string();
+    }
+    url_utils::resolve_url(base_url, id).unwrap_or(id.to_string())
 }
 
 /// Extract JSON-LD objects of a specific type
@@ -92,3 +229,137 @@ pub fn extract_by_type(html: &str, type_name: &str) -> Result<Vec<JsonLdObject>>
 
     Ok(filtered)
 }
+
+/// Extract JSON-LD objects of a specific type or any of its Schema.org subtypes
+///
+/// Unlike [`extract_by_type`], filtering by `LocalBusiness` also returns a
+/// `Restaurant`. Subtypes are looked up in an embedded subset of the
+/// Schema.org hierarchy (see [`hierarchy`]); unknown types match only
+/// themselves.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `type_name` - The @type to filter for (e.g., "LocalBusiness", "CreativeWork")
+///
+/// # Returns
+/// * `Result<Vec<JsonLdObject>>` - Filtered JSON-LD objects
+pub fn extract_by_type_deep(html: &str, type_name: &str) -> Result<Vec<JsonLdObject>> {
+    Ok(extract(html, None)?
+        .into_iter()
+        .filter(|obj| hierarchy::type_value_is_subtype_of(obj.type_.as_ref(), type_name))
+        .collect())
+}
+
+/// Report `@id` values used by more than one different object
+///
+/// Consumers merge objects by `@id`, so two different objects sharing one
+/// (a common CMS bug) make references ambiguous. Repeating an identical
+/// object, or a bare `{"@id": ...}` reference, is not a collision; `@context`
+/// is ignored when comparing. Collisions are listed in order of first use,
+/// each with the positions of every object carrying the id.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::jsonld::{extract, validate_ids};
+///
+/// let html = r##"
+///     <script type="application/ld+json">{"@id": "#org", "@type": "Organization"}</script>
+///     <script type="application/ld+json">{"@id": "#org", "@type": "Person"}</script>"##;
+/// let collisions = validate_ids(&extract(html, None).unwrap());
+/// assert_eq!(collisions[0].id, "#org");
+/// assert_eq!(collisions[0].indices, vec![0, 1]);
+/// ```
+pub fn validate_ids(objects: &[JsonLdObject]) -> Vec<IdCollision> {
+    let mut by_id: IndexMap<&str, Vec<usize>> = IndexMap::new();
+    for (index, object) in objects.iter().enumerate() {
+        let is_reference = object.type_.is_none() && object.properties.is_empty();
+        if let (Some(id), false) = (object.id.as_deref(), is_reference) {
+            by_id.entry(id).or_default().push(index);
+        }
+    }
+
+    let same = |a: &JsonLdObject, b: &JsonLdObject| {
+        a.type_ == b.type_ && a.properties == b.properties && a.graph == b.graph
+    };
+    by_id
+        .into_iter()
+        .filter(|(_, indices)| {
+            let first = &objects[indices[0]];
+            indices[1..].iter().any(|&i| !same(first, &objects[i]))
+        })
+        .map(|(id, indices)| IdCollision { id: id.to_string(), indices })
+        .collect()
+}
+
+/// Find the sitelinks search box declared by a WebSite object
+///
+/// Looks at the `potentialAction` of each `WebSite` (including `@graph`
+/// members) for a `SearchAction`. Its `target` may be a URL template string or
+/// an `EntryPoint` object with a `urlTemplate`. The query parameter name comes
+/// from `query-input` (`"required name=search_term_string"` or an object with
+/// `valueName`), falling back to the first `{placeholder}` in the template.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::jsonld::{extract, search_action};
+///
+/// let html = r#"<script type="application/ld+json">{
+///     "@type": "WebSite",
+///     "potentialAction": {
+///         "@type": "SearchAction",
+///         "target": "https://example.com/search?q={query}",
+///         "query-input": "required name=query"
+///     }
+/// }</script>"#;
+/// let action = search_action(&extract(html, None).unwrap()).unwrap();
+/// assert_eq!(action.target, "https://example.com/search?q={query}");
+/// assert_eq!(action.query_param.as_deref(), Some("query"));
+/// ```
+pub fn search_action(objects: &[JsonLdObject]) -> Option<SearchAction> {
+    objects.iter().find_map(|object| {
+        let own = hierarchy::type_value_is_subtype_of(object.type_.as_ref(), "WebSite")
+            .then(|| object.properties.get("potentialAction"))
+            .flatten()
+            .and_then(search_action_from);
+        own.or_else(|| search_action(object.graph.as_deref()?))
+    })
+}
+
+/// The first `SearchAction` in a `potentialAction` value (an object or a list)
+fn search_action_from(value: &Value) -> Option<SearchAction> {
+    if let Value::Array(actions) = value {
+        return actions.iter().find_map(search_action_from);
+    }
+    if !hierarchy::type_value_is_subtype_of(value.get("@type"), "SearchAction") {
+        return None;
+    }
+
+    let target = url_template(value.get("target")?)?;
+    let query_param = value
+        .get("query-input")
+        .and_then(|input| match input {
+            Value::String(spec) => spec
+                .split_whitespace()
+                .find_map(|token| token.strip_prefix("name="))
+                .map(String::from),
+            input => input.get("valueName")?.as_str().map(String::from),
+        })
+        .or_else(|| {
+            let start = target.find('{')? + 1;
+            let end = start + target[start..].find('}')?;
+            Some(target[start..end].to_string())
+        })
+        .filter(|name| !name.is_empty());
+
+    Some(SearchAction { target, query_param })
+}
+
+/// A `target` as a URL template: a string, an `EntryPoint`, or the first usable of a list
+fn url_template(target: &Value) -> Option<String> {
+    let template = match target {
+        Value::String(template) => template.trim(),
+        Value::Array(targets) => return targets.iter().find_map(url_template),
+        entry_point => entry_point.get("urlTemplate")?.as_str()?.trim(),
+    };
+    (!template.is_empty()).then(|| template.to_string())
+}
@@ -1,6 +1,8 @@
 //! Tests for JSON-LD extraction
 
-use crate::extractors::jsonld::{extract, extract_by_type};
+use crate::extractors::jsonld::{
+    extract, extract_by_type, extract_by_type_deep, extract_with_raw, search_action, validate_ids,
+};
 
 #[cfg(test)]
 mod jsonld_tests {
@@ -68,6 +70,35 @@ mod jsonld_tests {
         assert_eq!(objects.len(), 2);
     }
 
+    #[test]
+    fn test_extract_by_type_deep_matches_subtypes() {
+        let html = r#"
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Restaurant", "name": "Luigi's"}
+            </script>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "NewsMediaOrganization", "name": "Daily"}
+            </script>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Article", "headline": "News"}
+            </script>
+        "#;
+
+        // The exact match does not see the subtype
+        assert!(extract_by_type(html, "LocalBusiness").unwrap().is_empty());
+
+        let businesses = extract_by_type_deep(html, "LocalBusiness").unwrap();
+        assert_eq!(businesses.len(), 1);
+        assert_eq!(businesses[0].properties["name"], "Luigi's");
+
+        let organizations = extract_by_type_deep(html, "Organization").unwrap();
+        assert_eq!(organizations.len(), 2);
+        assert_eq!(organizations[1].properties["name"], "Daily");
+
+        assert_eq!(extract_by_type_deep(html, "Thing").unwrap().len(), 3);
+        assert_eq!(extract_by_type_deep(html, "Article").unwrap().len(), 1);
+    }
+
     #[test]
     fn test_extract_by_type() {
         let html = r#"
@@ -225,6 +256,40 @@ mod jsonld_tests {
         assert_eq!(objects[0].id, Some("https://example.com/article/123".to_string()));
     }
 
+    #[test]
+    fn test_extract_relative_id_resolved() {
+        let html = r##"
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "Product",
+                "@id": "/products/1#product",
+                "brand": {"@id": "#brand", "name": "Acme"},
+                "offers": [{"@id": "_:offer1", "price": "9.99"}]
+            }
+            </script>
+        "##;
+
+        let objects = extract(html, Some("https://shop.example.com/products/1")).unwrap();
+        let product = &objects[0];
+        assert_eq!(product.id, Some("https://shop.example.com/products/1#product".to_string()));
+        assert_eq!(product.properties["brand"]["@id"], "https://shop.example.com/products/1#brand");
+        // Blank node identifiers are not IRIs
+        assert_eq!(product.properties["offers"][0]["@id"], "_:offer1");
+    }
+
+    #[test]
+    fn test_extract_graph_ids_resolved() {
+        let html = r#"
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@graph": [{"@type": "WebSite", "@id": "/#website"}]}
+            </script>
+        "#;
+
+        let objects = extract(html, Some("https://example.com/blog/post")).unwrap();
+        assert_eq!(objects[0].id, Some("https://example.com/#website".to_string()));
+    }
+
     #[test]
     fn test_extract_empty_script() {
         let html = r#"
@@ -752,4 +817,157 @@ mod jsonld_tests {
         let objects = extract(html, None).unwrap();
         assert_eq!(objects.len(), 1);
     }
+
+    #[test]
+    fn test_extract_with_raw_preserves_script_text() {
+        let raw = "\n  {\"@type\": \"Article\",\n\t\"headline\":  \"Spaced\"}  \n";
+        let html = format!(r#"<script type="application/ld+json">{}</script>"#, raw);
+
+        let objects = extract_with_raw(&html, None).unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].0.type_, Some(serde_json::json!("Article")));
+        assert_eq!(objects[0].1, raw);
+    }
+
+    #[test]
+    fn test_extract_with_raw_graph_members_share_block() {
+        let raw = r#"{"@context": "https://schema.org", "@graph": [
+            {"@type": "WebSite", "name": "Site"},
+            {"@type": "Organization", "name": "Org"}
+        ]}"#;
+        let html = format!(
+            r#"<script type="application/ld+json">{}</script>
+               <script type="application/ld+json">{{"@type": "Person"}}</script>"#,
+            raw
+        );
+
+        let objects = extract_with_raw(&html, None).unwrap();
+        assert_eq!(objects.len(), 3);
+        assert_eq!(objects[0].1, raw);
+        assert_eq!(objects[1].1, raw);
+        assert_eq!(objects[2].1, r#"{"@type": "Person"}"#);
+    }
+
+    #[test]
+    fn test_validate_ids_clean_document() {
+        let html = r#"<script type="application/ld+json">{"@graph": [
+            {"@id": "https://example.com/#org", "@type": "Organization", "name": "Acme"},
+            {"@id": "https://example.com/#site", "@type": "WebSite",
+             "publisher": {"@id": "https://example.com/#org"}}
+        ]}</script>"#;
+        let objects = extract(html, None).unwrap();
+        assert!(validate_ids(&objects).is_empty());
+    }
+
+    #[test]
+    fn test_validate_ids_identical_objects_are_not_collisions() {
+        // The same object emitted twice, once with its own @context
+        let html = r##"
+            <script type="application/ld+json">
+                {"@context": "https://schema.org", "@id": "#org", "@type": "Organization", "name": "Acme"}
+            </script>
+            <script type="application/ld+json">{"@graph": [
+                {"@id": "#org", "@type": "Organization", "name": "Acme"},
+                {"@id": "#org"}
+            ]}</script>"##;
+        let objects = extract(html, None).unwrap();
+        assert_eq!(objects.len(), 3);
+        assert!(validate_ids(&objects).is_empty());
+    }
+
+    #[test]
+    fn test_validate_ids_reports_different_objects() {
+        let html = r##"
+            <script type="application/ld+json">{"@id": "#main", "@type": "Article", "headline": "A"}</script>
+            <script type="application/ld+json">{"@id": "#other", "@type": "Person"}</script>
+            <script type="application/ld+json">{"@id": "#main", "@type": "Product", "name": "B"}</script>"##;
+        let objects = extract(html, Some("https://example.com/page")).unwrap();
+        let collisions = validate_ids(&objects);
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(collisions[0].id, "https://example.com/page#main");
+        assert_eq!(collisions[0].indices, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_search_action_string_target() {
+        let html = r#"<script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@type": "WebSite",
+            "url": "https://example.com/",
+            "potentialAction": {
+                "@type": "SearchAction",
+                "target": "https://example.com/search?q={search_term_string}",
+                "query-input": "required name=search_term_string"
+            }
+        }</script>"#;
+        let action = search_action(&extract(html, None).unwrap()).unwrap();
+        assert_eq!(action.target, "https://example.com/search?q={search_term_string}");
+        assert_eq!(action.query_param.as_deref(), Some("search_term_string"));
+    }
+
+    #[test]
+    fn test_search_action_entry_point_target() {
+        let html = r#"<script type="application/ld+json">{"@graph": [
+            {"@type": "Organization", "name": "Acme"},
+            {"@type": "WebSite", "potentialAction": [
+                {"@type": "ReadAction", "target": "https://example.com/"},
+                {"@type": "SearchAction",
+                 "target": {"@type": "EntryPoint", "urlTemplate": "https://example.com/find?s={term}"},
+                 "query-input": {"@type": "PropertyValueSpecification", "valueRequired": true,
+                                 "valueName": "term"}}
+            ]}
+        ]}</script>"#;
+        let action = search_action(&extract(html, None).unwrap()).unwrap();
+        assert_eq!(action.target, "https://example.com/find?s={term}");
+        assert_eq!(action.query_param.as_deref(), Some("term"));
+    }
+
+    #[test]
+    fn test_search_action_absent() {
+        let html = r#"<script type="application/ld+json">{
+            "@type": "Organization",
+            "potentialAction": {"@type": "SearchAction", "target": "https://example.com/?q={q}"}
+        }</script>
+        <script type="application/ld+json">{"@type": "WebSite", "name": "No search"}</script>"#;
+        assert_eq!(search_action(&extract(html, None).unwrap()), None);
+    }
+
+    #[test]
+    fn test_bom_prefixed_script() {
+        let html =
+            "<script type=\"application/ld+json\">\u{feff}{\"@type\": \"Organization\"}</script>";
+        let objects = extract(html, None).unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].type_, Some(serde_json::json!("Organization")));
+    }
+
+    #[test]
+    fn test_cdata_wrapped_script() {
+        for body in [
+            "//<![CDATA[\n{\"@type\": \"Event\"}\n//]]>",
+            "/*<![CDATA[*/ {\"@type\": \"Event\"} /*]]>*/",
+            "<![CDATA[{\"@type\": \"Event\"}]]>",
+        ] {
+            let html = format!(r#"<script type="application/ld+json">{}</script>"#, body);
+            let objects = extract(&html, None).unwrap();
+            assert_eq!(objects.len(), 1, "failed to parse {:?}", body);
+            assert_eq!(objects[0].type_, Some(serde_json::json!("Event")));
+        }
+    }
+
+    #[test]
+    fn test_html_comment_wrapped_script() {
+        let html = r#"<script type="application/ld+json">
+            <!--
+            {"@type": "Product", "name": "Widget"}
+            //-->
+        </script>"#;
+        let objects = extract(html, None).unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].properties["name"], "Widget");
+
+        // The raw text is still reported as written
+        let raw = &extract_with_raw(html, None).unwrap()[0].1;
+        assert!(raw.contains("<!--"));
+    }
 }
@@ -4,8 +4,10 @@
 //! Handles link discovery and JSON parsing with URL resolution.
 
 use crate::errors::{MicroformatError, Result};
-use crate::extractors::common::{html_utils, url_utils};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
 use crate::types::manifest::{ManifestDiscovery, WebAppManifest};
+use scraper::Html;
 
 #[cfg(test)]
 mod tests;
@@ -30,12 +32,20 @@ mod tests;
 /// assert_eq!(discovery.href, Some("https://example.com/manifest.json".to_string()));
 /// ```
 pub fn extract_link(html: &str, base_url: Option<&str>) -> Result<ManifestDiscovery> {
-    let doc = html_utils::parse_html(html);
+    extract_link_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract_link`] on an already parsed document
+pub fn extract_link_from_doc(doc: &Html, base_url: Option<&str>) -> Result<ManifestDiscovery> {
+    extract_link_in(Scope::document(doc), base_url)
+}
 
+/// [`extract_link_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_link_in(scope: Scope, base_url: Option<&str>) -> Result<ManifestDiscovery> {
     // Find <link rel="manifest" href="...">
     let selector = html_utils::create_selector("link[rel=manifest][href]")?;
 
-    if let Some(link) = doc.select(&selector).next() {
+    if let Some(link) = scope.select(&selector).next() {
         if let Some(href) = html_utils::get_attr(&link, "href") {
             // Resolve URL if base_url is provided
             let resolved = if let Some(base) = base_url {
@@ -108,8 +118,10 @@ pub fn parse_manifest(json: &str, base_url: Option<&str>) -> Result<WebAppManife
 
         // Resolve shortcut URLs and icons
         for shortcut in &mut manifest.shortcuts {
-            if let Ok(resolved) = url_utils::resolve_url(Some(base), &shortcut.url) {
-                shortcut.url = resolved;
+            if !shortcut.url.is_empty() {
+                if let Ok(resolved) = url_utils::resolve_url(Some(base), &shortcut.url) {
+                    shortcut.url = resolved;
+                }
             }
             for icon in &mut shortcut.icons {
                 if let Ok(resolved) = url_utils::resolve_url(Some(base), &icon.src) {
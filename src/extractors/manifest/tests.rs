@@ -167,6 +167,54 @@ fn test_parse_manifest_shortcuts() {
     assert_eq!(result.shortcuts[1].icons[0].src, "https://example.com/search-icon.png");
 }
 
+#[test]
+fn test_parse_manifest_display_override() {
+    let json = r#"{
+        "name": "Desktop PWA",
+        "display": "standalone",
+        "display_override": ["window-controls-overlay", "standalone"]
+    }"#;
+    let result = parse_manifest(json, None).unwrap();
+    assert_eq!(result.display_override, vec!["window-controls-overlay", "standalone"]);
+    assert!(result.validate().is_empty());
+}
+
+#[test]
+fn test_parse_manifest_unknown_display_override() {
+    let json = r#"{"display": "kiosk", "display_override": ["hologram", "minimal-ui"]}"#;
+    let result = parse_manifest(json, None).unwrap();
+    assert_eq!(result.display, Some("kiosk".to_string()));
+    assert_eq!(result.display_override.len(), 2);
+    assert_eq!(
+        result.validate(),
+        vec!["unknown display mode 'kiosk'", "unknown display_override mode 'hologram'"]
+    );
+}
+
+#[test]
+fn test_validate_shortcut_missing_name() {
+    let json = r#"{
+        "name": "Test",
+        "shortcuts": [
+            {"name": "Inbox", "url": "/inbox"},
+            {"url": "/compose"}
+        ]
+    }"#;
+    let result = parse_manifest(json, Some("https://example.com")).unwrap();
+    assert_eq!(result.shortcuts.len(), 2);
+    assert_eq!(result.shortcuts[1].name, "");
+    assert_eq!(result.shortcuts[1].url, "https://example.com/compose");
+    assert_eq!(result.validate(), vec!["shortcut 1 is missing a name"]);
+}
+
+#[test]
+fn test_validate_shortcut_missing_url() {
+    let json = r#"{"shortcuts": [{"name": "Compose"}]}"#;
+    let result = parse_manifest(json, Some("https://example.com")).unwrap();
+    assert_eq!(result.shortcuts[0].url, "");
+    assert_eq!(result.validate(), vec!["shortcut 0 is missing a url"]);
+}
+
 #[test]
 fn test_parse_manifest_related_applications() {
     let json = r##"
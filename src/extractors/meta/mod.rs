@@ -3,8 +3,14 @@
 //! Extracts basic meta tags that virtually all websites use.
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use crate::types::meta::{AlternateLink, FeedLink, MetaTags, RobotsDirective};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use crate::types::diagnostics::{Warning, WarningKind};
+use crate::types::meta::{
+    AlternateLink, AppleItunesApp, DocumentBase, FeedLink, FormatDetection, GooglePlayApp,
+    MetaTags, RobotsDirective, ThemeColor,
+};
+use scraper::Html;
 
 #[cfg(test)]
 mod tests;
@@ -18,31 +24,71 @@ mod tests;
 /// # Returns
 /// * `Result<MetaTags>` - Extracted meta tags or error
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
-    let document = html_utils::parse_html(html);
+    extract_parsed(html, Scope::document(&html_utils::parse_html(html)), base_url)
+}
+
+/// [`extract`] on `html` already parsed, reading the tags within `scope`
+///
+/// The source is still needed for the byte order mark, which the parser drops.
+pub(crate) fn extract_parsed(html: &str, scope: Scope, base_url: Option<&str>) -> Result<MetaTags> {
+    let mut meta = extract_in(scope, base_url)?;
+
+    // Charset precedence per the spec: BOM > <meta charset> > http-equiv
+    if let Some(encoding) = charset_from_bom(html.as_bytes()) {
+        meta.charset = Some(encoding.to_string());
+        meta.charset_source = Some("bom".to_string());
+    }
+
+    Ok(meta)
+}
+
+/// [`extract`] on an already parsed document
+///
+/// The parser drops a byte order mark, so a charset declared only by a BOM
+/// is not seen here.
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<MetaTags> {
+    extract_in(Scope::document(document), base_url)
+}
+
+/// [`extract_from_doc`] on the tags within `scope`
+///
+/// The charset, and the language and direction on `<html>`, apply to the
+/// whole document, so they are read wherever the scope is.
+pub(crate) fn extract_in(scope: Scope, base_url: Option<&str>) -> Result<MetaTags> {
+    let document = scope.html();
     let mut meta = MetaTags::default();
 
     // Extract title
     if let Ok(selector) = html_utils::create_selector("title") {
-        meta.title = document.select(&selector).next().and_then(|e| html_utils::extract_text(&e));
+        meta.title = scope.select(&selector).next().and_then(|e| html_utils::extract_text(&e));
     }
 
-    // Extract charset
+    // Extract charset: <meta charset> > http-equiv (a BOM is handled by `extract_parsed`).
+    // The first usable declaration wins; empty or malformed ones are skipped
     if let Ok(selector) = html_utils::create_selector("meta[charset]") {
-        meta.charset =
-            document.select(&selector).next().and_then(|e| html_utils::get_attr(&e, "charset"));
+        meta.charset = document
+            .select(&selector)
+            .filter_map(|e| html_utils::get_attr(&e, "charset"))
+            .find_map(|charset| clean_charset(&charset));
+        if meta.charset.is_some() {
+            meta.charset_source = Some("meta".to_string());
+        }
     }
 
     // Extract charset from Content-Type
     if meta.charset.is_none() {
-        if let Ok(selector) = html_utils::create_selector(r#"meta[http-equiv="Content-Type"]"#) {
+        if let Ok(selector) = html_utils::create_selector("meta[http-equiv][content]") {
             meta.charset = document
                 .select(&selector)
-                .next()
-                .and_then(|e| html_utils::get_attr(&e, "content"))
-                .and_then(|content| {
-                    // Extract charset from "text/html; charset=UTF-8"
-                    content.split("charset=").nth(1).map(|s| s.trim().to_string())
-                });
+                .filter(|e| {
+                    html_utils::get_attr(e, "http-equiv")
+                        .is_some_and(|v| v.trim().eq_ignore_ascii_case("content-type"))
+                })
+                .filter_map(|e| html_utils::get_attr(&e, "content"))
+                .find_map(|content| charset_from_content_type(&content));
+            if meta.charset.is_some() {
+                meta.charset_source = Some("http-equiv".to_string());
+            }
         }
     }
 
@@ -51,10 +97,11 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
         meta.language =
             document.select(&selector).next().and_then(|e| html_utils::get_attr(&e, "lang"));
     }
+    meta.xml_lang = html_utils::get_attr(&document.root_element(), "xml:lang");
 
     // Extract meta name tags
     if let Ok(selector) = html_utils::create_selector("meta[name][content]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(name), Some(content)) =
                 (html_utils::get_attr(&element, "name"), html_utils::get_attr(&element, "content"))
             {
@@ -76,10 +123,22 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
                     }
                     "author" => meta.author = Some(content),
                     "generator" => meta.generator = Some(content),
+                    "copyright" => meta.copyright = Some(content),
                     "viewport" => meta.viewport = Some(content),
-                    "theme-color" => meta.theme_color = Some(content),
+                    "theme-color" => {
+                        let media = html_utils::get_attr(&element, "media")
+                            .map(|media| media.trim().to_string())
+                            .filter(|media| !media.is_empty());
+                        meta.theme_colors.push(ThemeColor { color: content.clone(), media });
+                        meta.theme_color = Some(content);
+                    }
+                    "color-scheme" => meta.color_scheme = parse_color_scheme(&content),
                     "application-name" => meta.application_name = Some(content),
                     "referrer" => meta.referrer = Some(content),
+                    "rating" => meta.content_rating = Some(content),
+                    "dc.language" | "dcterms.language" => {
+                        meta.dc_language.get_or_insert(content);
+                    }
                     "robots" => meta.robots = Some(RobotsDirective::parse(&content)),
                     "googlebot" => meta.googlebot = Some(RobotsDirective::parse(&content)),
                     // Site verification tags (Phase 6)
@@ -91,6 +150,11 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
                     "facebook-domain-verification" => {
                         meta.facebook_domain_verification = Some(content)
                     }
+                    "norton-safeweb-site-verification" => {
+                        meta.norton_safeweb_site_verification = Some(content)
+                    }
+                    "alexaverifyid" => meta.alexa_verify_id = Some(content),
+                    "baidu-site-verification" => meta.baidu_site_verification = Some(content),
                     // Analytics tags (Phase 6)
                     "google-analytics" => meta.google_analytics = Some(content),
                     // PWA meta tags (Phase 8)
@@ -104,13 +168,26 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
                     }
                     "apple-mobile-web-app-title" => meta.apple_mobile_web_app_title = Some(content),
                     // Mobile App Links (Phase 8)
-                    "apple-itunes-app" => meta.apple_itunes_app = Some(content),
-                    "google-play-app" => meta.google_play_app = Some(content),
-                    "format-detection" => meta.format_detection = Some(content),
+                    "apple-itunes-app" => {
+                        meta.apple_itunes_app_banner = AppleItunesApp::parse(&content);
+                        meta.apple_itunes_app = Some(content);
+                    }
+                    "google-play-app" => {
+                        meta.google_play_app_banner = GooglePlayApp::parse(&content);
+                        meta.google_play_app = Some(content);
+                    }
+                    "format-detection" => {
+                        meta.format_detection_flags = Some(FormatDetection::parse(&content));
+                        meta.format_detection = Some(content);
+                    }
                     // Microsoft/Windows meta tags (Phase 8)
                     "msapplication-tilecolor" => meta.msapplication_tile_color = Some(content),
                     "msapplication-tileimage" => meta.msapplication_tile_image = Some(content),
                     "msapplication-config" => meta.msapplication_config = Some(content),
+                    // Robots directives for other crawlers (bingbot, googlebot-news, ...)
+                    bot if is_crawler_name(bot) => {
+                        meta.bot_robots.insert(bot.to_string(), RobotsDirective::parse(&content));
+                    }
                     _ => {}
                 }
             }
@@ -119,7 +196,7 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
 
     // Extract link tags
     if let Ok(selector) = html_utils::create_selector("link[rel][href]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(rel), Some(href)) =
                 (html_utils::get_attr(&element, "rel"), html_utils::get_attr(&element, "href"))
             {
@@ -185,7 +262,7 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
 
     // Extract meta property tags (for Facebook, etc.)
     if let Ok(selector) = html_utils::create_selector("meta[property][content]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(property), Some(content)) = (
                 html_utils::get_attr(&element, "property"),
                 html_utils::get_attr(&element, "content"),
@@ -198,11 +275,243 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<MetaTags> {
                 match property.to_lowercase().as_str() {
                     "fb:app_id" => meta.fb_app_id = Some(content),
                     "fb:pages" => meta.fb_pages = Some(content),
+                    "og:locale" => {
+                        meta.og_locale.get_or_insert(content);
+                    }
                     _ => {}
                 }
             }
         }
     }
 
+    // Security headers; the first value of each header is kept
+    if let Ok(selector) = html_utils::create_selector("meta[http-equiv][content]") {
+        for element in scope.select(&selector) {
+            let name = html_utils::get_attr(&element, "http-equiv")
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+            let content = html_utils::get_attr(&element, "content").unwrap_or_default();
+            if SECURITY_HEADERS.contains(&name.as_str()) && !content.trim().is_empty() {
+                meta.security_headers.entry(name).or_insert_with(|| content.trim().to_string());
+            }
+        }
+    }
+
+    // Legacy PICS content label, used when no rating meta tag is present
+    if let Ok(selector) = html_utils::create_selector("meta[http-equiv][content]") {
+        meta.content_rating = meta.content_rating.take().or_else(|| {
+            scope
+                .select(&selector)
+                .find(|e| {
+                    html_utils::get_attr(e, "http-equiv")
+                        .is_some_and(|v| v.eq_ignore_ascii_case("pics-label"))
+                })
+                .and_then(|e| html_utils::get_attr(&e, "content"))
+                .map(|content| content.trim().to_string())
+                .filter(|content| !content.is_empty())
+        });
+    }
+
+    meta.is_adult = meta.content_rating.as_deref().is_some_and(is_adult_rating);
+
+    // An explicit, valid <html dir> wins; otherwise infer from the language
+    meta.text_direction = html_utils::get_attr(&document.root_element(), "dir")
+        .map(|dir| dir.trim().to_ascii_lowercase())
+        .filter(|dir| matches!(dir.as_str(), "ltr" | "rtl" | "auto"))
+        .or_else(|| meta.effective_language().map(|tag| language_direction(&tag).to_string()));
+
     Ok(meta)
 }
+
+/// Scripts written right to left (ISO 15924)
+const RTL_SCRIPTS: &[&str] = &["arab", "hebr", "thaa", "syrc", "nkoo", "adlm", "rohg", "mand"];
+
+/// Languages written right to left in their default script
+const RTL_LANGUAGES: &[&str] =
+    &["ar", "arc", "ckb", "dv", "fa", "he", "iw", "ji", "ks", "ps", "sd", "syr", "ug", "ur", "yi"];
+
+/// The text direction a BCP 47 tag implies: `rtl` or `ltr`
+///
+/// An explicit script subtag decides (`az-Arab` is rtl, `ku-Latn` ltr);
+/// otherwise the language's usual script does.
+fn language_direction(tag: &str) -> &'static str {
+    let mut subtags = tag.split(['-', '_']).map(str::to_ascii_lowercase);
+    let language = subtags.next().unwrap_or_default();
+    let rtl = match subtags
+        .find(|subtag| subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+    {
+        Some(script) => RTL_SCRIPTS.contains(&script.as_str()),
+        None => RTL_LANGUAGES.contains(&language.as_str()),
+    };
+    if rtl {
+        "rtl"
+    } else {
+        "ltr"
+    }
+}
+
+/// `http-equiv` names collected into [`MetaTags::security_headers`]
+const SECURITY_HEADERS: &[&str] = &[
+    "content-security-policy",
+    "content-security-policy-report-only",
+    "referrer-policy",
+    "x-frame-options",
+    "x-content-type-options",
+    "x-xss-protection",
+    "strict-transport-security",
+    "permissions-policy",
+];
+
+/// Extract the document's `<base href>` and `<base target>`
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional document URL for resolving a relative `<base href>`
+///
+/// # Returns
+/// * `Result<Option<DocumentBase>>` - `None` if there is no usable `<base>` element
+pub fn extract_document_base(html: &str, base_url: Option<&str>) -> Result<Option<DocumentBase>> {
+    extract_document_base_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract_document_base`] on an already parsed document
+pub fn extract_document_base_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<Option<DocumentBase>> {
+    let selector = html_utils::create_selector("base")?;
+    let mut base = DocumentBase::default();
+
+    for element in document.select(&selector) {
+        let attr = |name| html_utils::get_attr(&element, name).filter(|v| !v.trim().is_empty());
+        if base.href.is_none() {
+            base.href = attr("href").map(|href| {
+                let href = href.trim().to_string();
+                url_utils::resolve_url(base_url, &href).unwrap_or(href)
+            });
+        }
+        if base.target.is_none() {
+            base.target = attr("target").map(|target| target.trim().to_string());
+        }
+    }
+
+    Ok((base != DocumentBase::default()).then_some(base))
+}
+
+/// Check that an `hreflang` value has the basic BCP 47 shape
+///
+/// Accepts `language[-script][-region]` (`en`, `en-US`, `zh-Hant`, `zh-Hant-TW`,
+/// `es-419`) and the special `x-default`, case-insensitively. The language is
+/// two or three letters, the script four letters and the region two letters
+/// or three digits; `en_US` and `english` are rejected.
+pub fn hreflang_valid(tag: &str) -> bool {
+    if tag.eq_ignore_ascii_case("x-default") {
+        return true;
+    }
+
+    let mut subtags = tag.split('-');
+    let language = subtags.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return false;
+    }
+
+    let mut rest = subtags.peekable();
+    rest.next_if(|s| s.len() == 4 && s.bytes().all(|b| b.is_ascii_alphabetic()));
+    rest.next_if(|s| {
+        (s.len() == 2 && s.bytes().all(|b| b.is_ascii_alphabetic()))
+            || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+    });
+    rest.next().is_none()
+}
+
+/// Warnings for alternate links whose `hreflang` fails [`hreflang_valid`]
+pub fn hreflang_diagnostics(meta: &MetaTags) -> Vec<Warning> {
+    meta.alternate
+        .iter()
+        .filter_map(|link| {
+            let hreflang = link.hreflang.as_deref()?;
+            (!hreflang_valid(hreflang)).then(|| Warning {
+                kind: WarningKind::InvalidHreflang,
+                message: format!(
+                    "alternate link {} has invalid hreflang \"{}\"",
+                    link.href, hreflang
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Rating values that mark a page as adult content
+const ADULT_RATINGS: &[&str] = &["adult", "mature", "restricted", "explicit", "nsfw", "18+", "xxx"];
+
+/// Classify a content rating (or PICS label) as adult content
+///
+/// Matches the RTA ("Restricted To Adults") label anywhere in the value and
+/// common adult rating keywords; `general`, `safe for kids` and the like are not adult.
+fn is_adult_rating(rating: &str) -> bool {
+    let rating = rating.trim().to_lowercase();
+    rating.contains("rta-5042-1996-1400-1577-rta") || ADULT_RATINGS.contains(&rating.as_str())
+}
+
+/// Keywords allowed in a `color-scheme` meta tag
+const COLOR_SCHEME_KEYWORDS: &[&str] = &["light", "dark", "normal", "only"];
+
+/// Parse a `color-scheme` value into its lowercase keywords
+///
+/// Unknown tokens are dropped; returns `None` if nothing valid remains.
+fn parse_color_scheme(content: &str) -> Option<Vec<String>> {
+    let tokens: Vec<String> = content
+        .split_ascii_whitespace()
+        .map(|token| token.to_ascii_lowercase())
+        .filter(|token| COLOR_SCHEME_KEYWORDS.contains(&token.as_str()))
+        .collect();
+    (!tokens.is_empty()).then_some(tokens)
+}
+
+/// Read the `charset` parameter of a Content-Type value
+///
+/// Handles `text/html; charset=UTF-8`, spaces around `=` (`charset = utf-8`),
+/// quoted values and any parameter order. Returns `None` when there is no
+/// charset parameter or it is empty.
+fn charset_from_content_type(content: &str) -> Option<String> {
+    content.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("charset") {
+            clean_charset(value)
+        } else {
+            None
+        }
+    })
+}
+
+/// Trim whitespace and surrounding quotes from a charset label, dropping empty ones
+fn clean_charset(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'').trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Detect the character encoding declared by a byte order mark
+///
+/// A BOM takes precedence over any in-document declaration. Returns the
+/// encoding label, or `None` if the input does not start with a known BOM.
+pub fn charset_from_bom(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        Some("UTF-8")
+    } else if bytes.starts_with(&[0xFE, 0xFF]) {
+        Some("UTF-16BE")
+    } else if bytes.starts_with(&[0xFF, 0xFE]) {
+        Some("UTF-16LE")
+    } else {
+        None
+    }
+}
+
+/// Crawler names that don't follow the `*bot` naming convention
+const CRAWLER_NAMES: &[&str] =
+    &["slurp", "yandex", "baiduspider", "googlebot-news", "googlebot-image", "googlebot-video"];
+
+/// Whether a lowercase meta name addresses a specific crawler
+pub(crate) fn is_crawler_name(name: &str) -> bool {
+    name.ends_with("bot") || CRAWLER_NAMES.contains(&name)
+}
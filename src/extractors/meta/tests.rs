@@ -2,8 +2,12 @@
 //!
 //! These tests are written FIRST (TDD approach) to define the expected behavior.
 
-use crate::extractors::meta::extract;
-use crate::types::meta::MetaTags;
+use crate::extractors::meta::{
+    extract, extract_document_base, hreflang_diagnostics, hreflang_valid,
+};
+use crate::types::meta::{
+    AppleItunesApp, FormatDetection, GooglePlayApp, MetaTags, RobotsDirective, ThemeColor,
+};
 
 #[cfg(test)]
 mod meta_extraction_tests {
@@ -56,6 +60,13 @@ mod meta_extraction_tests {
         assert_eq!(meta.generator, Some("WordPress 6.0".to_string()));
     }
 
+    #[test]
+    fn test_extract_copyright() {
+        let html = r#"<meta name="Copyright" content="© 2024 Acme Corp">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.copyright, Some("© 2024 Acme Corp".to_string()));
+    }
+
     // ========== CANONICAL AND LINKS ==========
 
     #[test]
@@ -106,6 +117,126 @@ mod meta_extraction_tests {
         assert_eq!(meta.shortlink, Some("https://example.com/?p=123".to_string()));
     }
 
+    // ========== FORMAT DETECTION ==========
+
+    #[test]
+    fn test_extract_format_detection_telephone() {
+        let html = r#"<meta name="format-detection" content="telephone=no">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.format_detection.as_deref(), Some("telephone=no"));
+        assert_eq!(
+            meta.format_detection_flags,
+            Some(FormatDetection { telephone: Some(false), ..Default::default() })
+        );
+    }
+
+    #[test]
+    fn test_format_detection_combined_flags() {
+        let flags = FormatDetection::parse("telephone=no, address=no; Email=YES;date=no");
+        assert_eq!(
+            flags,
+            FormatDetection {
+                telephone: Some(false),
+                address: Some(false),
+                email: Some(true),
+                date: Some(false),
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_detection_ignores_unknown_keys() {
+        let flags = FormatDetection::parse("fax=no, telephone=maybe, address=no, bogus");
+        assert_eq!(flags, FormatDetection { address: Some(false), ..Default::default() });
+    }
+
+    // ========== TEXT DIRECTION ==========
+
+    #[test]
+    fn test_text_direction_explicit_dir() {
+        let html = r#"<html lang="en" dir="RTL"><head></head></html>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.text_direction.as_deref(), Some("rtl"));
+
+        let html = r#"<html lang="ar" dir="auto"><head></head></html>"#;
+        assert_eq!(extract(html, None).unwrap().text_direction.as_deref(), Some("auto"));
+    }
+
+    #[test]
+    fn test_text_direction_inferred_from_arabic_lang() {
+        let html = r#"<html lang="ar-EG"><head><title>مرحبا</title></head></html>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.text_direction.as_deref(), Some("rtl"));
+
+        // The script subtag overrides the language's usual script
+        let html = r#"<html lang="az-Arab"><head></head></html>"#;
+        assert_eq!(extract(html, None).unwrap().text_direction.as_deref(), Some("rtl"));
+        let html = r#"<html lang="ku-Latn" dir="sideways"><head></head></html>"#;
+        assert_eq!(extract(html, None).unwrap().text_direction.as_deref(), Some("ltr"));
+    }
+
+    #[test]
+    fn test_text_direction_english_defaults_to_ltr() {
+        let html = r#"<html lang="en-US"><head></head></html>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.text_direction.as_deref(), Some("ltr"));
+
+        // Without a dir or any language there is nothing to go on
+        let meta = extract("<html><head></head></html>", None).unwrap();
+        assert_eq!(meta.text_direction, None);
+    }
+
+    // ========== SMART APP BANNERS ==========
+
+    #[test]
+    fn test_apple_itunes_app_id_only() {
+        let html = r#"<meta name="apple-itunes-app" content="app-id=284882215">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.apple_itunes_app.as_deref(), Some("app-id=284882215"));
+        assert_eq!(
+            meta.apple_itunes_app_banner,
+            Some(AppleItunesApp {
+                app_id: "284882215".to_string(),
+                app_argument: None,
+                affiliate_data: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_apple_itunes_app_with_argument() {
+        // The comma inside the URL stays part of the argument
+        let banner =
+            AppleItunesApp::parse("app-id=284882215, app-argument=myapp://open?ids=1,2&ref=banner")
+                .unwrap();
+        assert_eq!(banner.app_id, "284882215");
+        assert_eq!(banner.app_argument.as_deref(), Some("myapp://open?ids=1,2&ref=banner"));
+        assert_eq!(banner.affiliate_data, None);
+    }
+
+    #[test]
+    fn test_apple_itunes_app_with_affiliate_data() {
+        let banner = AppleItunesApp::parse(
+            "App-ID=284882215, affiliate-data=at=1000l3Vb&ct=banner, app-argument=https://example.com/a",
+        )
+        .unwrap();
+        assert_eq!(banner.affiliate_data.as_deref(), Some("at=1000l3Vb&ct=banner"));
+        assert_eq!(banner.app_argument.as_deref(), Some("https://example.com/a"));
+
+        // Without an app-id there is no banner to describe
+        assert_eq!(AppleItunesApp::parse("app-argument=myapp://home"), None);
+    }
+
+    #[test]
+    fn test_google_play_app() {
+        let html = r#"<meta name="google-play-app" content="app-id=com.example.android">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(
+            meta.google_play_app_banner,
+            Some(GooglePlayApp { app_id: "com.example.android".to_string(), app_argument: None })
+        );
+    }
+
     // ========== ROBOTS DIRECTIVES ==========
 
     #[test]
@@ -138,6 +269,54 @@ mod meta_extraction_tests {
         assert_eq!(googlebot.translate, Some(false));
     }
 
+    #[test]
+    fn test_effective_robots_bot_overrides_index() {
+        let html = r#"
+            <meta name="robots" content="index, follow">
+            <meta name="googlebot" content="noindex">
+        "#;
+        let meta = extract(html, None).unwrap();
+        let effective = meta.effective_robots("googlebot");
+        assert_eq!(effective.index, Some(false));
+        assert_eq!(effective.follow, Some(true));
+        // Other crawlers only see the generic directive
+        assert_eq!(meta.effective_robots("bingbot").index, Some(true));
+    }
+
+    #[test]
+    fn test_effective_robots_bot_overrides_only_snippet() {
+        let html = r#"
+            <meta name="robots" content="noindex, nofollow">
+            <meta name="googlebot" content="nosnippet">
+        "#;
+        let meta = extract(html, None).unwrap();
+        let effective = meta.effective_robots("Googlebot");
+        assert_eq!(effective.index, Some(false));
+        assert_eq!(effective.follow, Some(false));
+        assert_eq!(effective.snippet, Some(false));
+    }
+
+    #[test]
+    fn test_effective_robots_arbitrary_bot() {
+        let html = r#"
+            <meta name="robots" content="index, follow">
+            <meta name="bingbot" content="nofollow">
+            <meta name="googlebot-news" content="noindex">
+        "#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.bot_robots.len(), 2);
+        let bing = meta.effective_robots("bingbot");
+        assert_eq!(bing.index, Some(true));
+        assert_eq!(bing.follow, Some(false));
+        assert_eq!(meta.effective_robots("googlebot-news").index, Some(false));
+    }
+
+    #[test]
+    fn test_effective_robots_without_directives() {
+        let meta = extract("<title>Test</title>", None).unwrap();
+        assert_eq!(meta.effective_robots("googlebot"), RobotsDirective::default());
+    }
+
     // ========== VIEWPORT AND MOBILE ==========
 
     #[test]
@@ -154,6 +333,52 @@ mod meta_extraction_tests {
         assert_eq!(meta.theme_color, Some("#ff0000".to_string()));
     }
 
+    #[test]
+    fn test_extract_theme_colors_with_media() {
+        let html = r##"
+            <meta name="theme-color" media="(prefers-color-scheme: light)" content="#ffffff">
+            <meta name="theme-color" media="(prefers-color-scheme: dark)" content="#000000">
+        "##;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(
+            meta.theme_colors,
+            vec![
+                ThemeColor {
+                    color: "#ffffff".to_string(),
+                    media: Some("(prefers-color-scheme: light)".to_string())
+                },
+                ThemeColor {
+                    color: "#000000".to_string(),
+                    media: Some("(prefers-color-scheme: dark)".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_color_scheme_light_dark() {
+        let html = r#"<meta name="color-scheme" content="Light  DARK">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.color_scheme, Some(vec!["light".to_string(), "dark".to_string()]));
+    }
+
+    #[test]
+    fn test_extract_color_scheme_single() {
+        let html = r#"<meta name="color-scheme" content="dark">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.color_scheme, Some(vec!["dark".to_string()]));
+    }
+
+    #[test]
+    fn test_extract_color_scheme_filters_invalid_tokens() {
+        let html = r#"<meta name="color-scheme" content="only sepia light">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.color_scheme, Some(vec!["only".to_string(), "light".to_string()]));
+
+        let html = r#"<meta name="color-scheme" content="sepia">"#;
+        assert_eq!(extract(html, None).unwrap().color_scheme, None);
+    }
+
     // ========== CHARSET AND LANGUAGE ==========
 
     #[test]
@@ -177,6 +402,136 @@ mod meta_extraction_tests {
         assert_eq!(meta.language, Some("en-US".to_string()));
     }
 
+    #[test]
+    fn test_effective_language_xml_lang_fallback() {
+        let html = r#"<html xml:lang="fr-CA"><head><title>T</title></head></html>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.language, None);
+        assert_eq!(meta.xml_lang.as_deref(), Some("fr-CA"));
+        assert_eq!(meta.effective_language().as_deref(), Some("fr-CA"));
+
+        let html = r#"<html lang="de" xml:lang="fr"></html>"#;
+        assert_eq!(extract(html, None).unwrap().effective_language().as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_effective_language_og_locale_and_dc_fallback() {
+        let html = r#"
+            <meta name="DC.language" content="es">
+            <meta property="og:locale" content="pt_BR">
+        "#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.og_locale.as_deref(), Some("pt_BR"));
+        assert_eq!(meta.dc_language.as_deref(), Some("es"));
+        assert_eq!(meta.effective_language().as_deref(), Some("pt-BR"));
+
+        let html = r#"<meta name="DC.language" content="es">"#;
+        assert_eq!(extract(html, None).unwrap().effective_language().as_deref(), Some("es"));
+    }
+
+    #[test]
+    fn test_effective_language_normalizes_case() {
+        let meta = MetaTags::default().with_language("EN-us");
+        assert_eq!(meta.effective_language().as_deref(), Some("en-US"));
+
+        let meta = MetaTags::default().with_language("ZH-hant-tw");
+        assert_eq!(meta.effective_language().as_deref(), Some("zh-Hant-TW"));
+
+        let meta = MetaTags::default().with_language(" ");
+        assert_eq!(meta.effective_language(), None);
+    }
+
+    #[test]
+    fn test_effective_language_non_ascii_subtag() {
+        let html = r#"<html lang="en-éab"><head><title>T</title></head></html>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.effective_language().as_deref(), Some("en-éab"));
+        assert!(crate::extract_all(html, None).is_ok());
+    }
+
+    #[test]
+    fn test_charset_http_equiv_without_charset() {
+        let html = r#"<meta http-equiv="Content-Type" content="text/html">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, None);
+        assert_eq!(meta.charset_source, None);
+    }
+
+    #[test]
+    fn test_charset_http_equiv_spaced_and_lowercase_header() {
+        let html = r#"<meta http-equiv="content-type" content="text/html ; charset = utf-8 ">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, Some("utf-8".to_string()));
+        assert_eq!(meta.charset_source, Some("http-equiv".to_string()));
+    }
+
+    #[test]
+    fn test_charset_http_equiv_quoted_value() {
+        let html = r#"<meta http-equiv="Content-Type" content='text/html; charset="ISO-8859-1"'>"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, Some("ISO-8859-1".to_string()));
+
+        let html = r#"<meta http-equiv="Content-Type" content="text/html; charset=''">"#;
+        assert_eq!(extract(html, None).unwrap().charset, None);
+    }
+
+    #[test]
+    fn test_charset_empty_meta_charset_is_skipped() {
+        let html = r#"
+            <meta charset="  ">
+            <meta charset=" windows-1252 ">
+            <meta charset="UTF-8">
+        "#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, Some("windows-1252".to_string()));
+        assert_eq!(meta.charset_source, Some("meta".to_string()));
+    }
+
+    #[test]
+    fn test_charset_source_meta() {
+        let html = r#"<meta charset="UTF-8">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset_source, Some("meta".to_string()));
+    }
+
+    #[test]
+    fn test_charset_meta_wins_over_http_equiv() {
+        let html = r#"
+            <meta http-equiv="Content-Type" content="text/html; charset=ISO-8859-1">
+            <meta charset="UTF-8">
+        "#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, Some("UTF-8".to_string()));
+        assert_eq!(meta.charset_source, Some("meta".to_string()));
+    }
+
+    #[test]
+    fn test_charset_source_http_equiv() {
+        let html = r#"<meta http-equiv="Content-Type" content="text/html; charset=ISO-8859-1">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset_source, Some("http-equiv".to_string()));
+    }
+
+    #[test]
+    fn test_charset_bom_overrides_declarations() {
+        let html = "\u{FEFF}<html><head>\
+            <meta http-equiv=\"Content-Type\" content=\"text/html; charset=ISO-8859-1\">\
+            <meta charset=\"windows-1252\">\
+            </head></html>";
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.charset, Some("UTF-8".to_string()));
+        assert_eq!(meta.charset_source, Some("bom".to_string()));
+    }
+
+    #[test]
+    fn test_charset_from_bom_bytes() {
+        use crate::extractors::meta::charset_from_bom;
+        assert_eq!(charset_from_bom(&[0xEF, 0xBB, 0xBF, b'<']), Some("UTF-8"));
+        assert_eq!(charset_from_bom(&[0xFE, 0xFF, 0x00, b'<']), Some("UTF-16BE"));
+        assert_eq!(charset_from_bom(&[0xFF, 0xFE, b'<', 0x00]), Some("UTF-16LE"));
+        assert_eq!(charset_from_bom(b"<html>"), None);
+    }
+
     // ========== APPLICATION AND REFERRER ==========
 
     #[test]
@@ -193,6 +548,44 @@ mod meta_extraction_tests {
         assert_eq!(meta.referrer, Some("no-referrer".to_string()));
     }
 
+    #[test]
+    fn test_security_meta_parses_csp() {
+        let html = r#"
+            <meta http-equiv="Content-Security-Policy"
+                  content="default-src 'self'; script-src 'self' https://cdn.example.com; ;SCRIPT-SRC 'none'">
+            <meta http-equiv="X-Frame-Options" content="DENY">
+            <meta http-equiv="Referrer-Policy" content="strict-origin">
+            <meta http-equiv="refresh" content="30">
+        "#;
+        let security = extract(html, None).unwrap().security_meta();
+
+        let csp: Vec<(&str, Vec<&str>)> = security
+            .csp
+            .iter()
+            .map(|(name, sources)| (name.as_str(), sources.iter().map(String::as_str).collect()))
+            .collect();
+        // The repeated script-src is ignored
+        assert_eq!(
+            csp,
+            vec![
+                ("default-src", vec!["'self'"]),
+                ("script-src", vec!["'self'", "https://cdn.example.com"]),
+            ]
+        );
+        assert_eq!(security.referrer_policy.as_deref(), Some("strict-origin"));
+        assert_eq!(security.headers.get("x-frame-options").map(String::as_str), Some("DENY"));
+        assert!(!security.headers.contains_key("refresh"));
+    }
+
+    #[test]
+    fn test_security_meta_prefers_meta_referrer() {
+        let html = r#"<meta name="referrer" content="no-referrer">
+            <meta http-equiv="Referrer-Policy" content="origin">"#;
+        let security = extract(html, None).unwrap().security_meta();
+        assert_eq!(security.referrer_policy.as_deref(), Some("no-referrer"));
+        assert!(security.csp.is_empty());
+    }
+
     // ========== EDGE CASES ==========
 
     #[test]
@@ -687,6 +1080,46 @@ mod meta_extraction_tests {
         assert_eq!(meta.yandex_verification, Some("yandex789".to_string()));
     }
 
+    #[test]
+    fn test_verification_tokens_map() {
+        let html = r#"
+            <meta name="google-site-verification" content="google123">
+            <meta name="msvalidate.01" content="bing456">
+            <meta name="yandex-verification" content="yandex789">
+        "#;
+        let meta = extract(html, None).unwrap();
+        let tokens = meta.verification_tokens();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens.get("google"), Some(&"google123".to_string()));
+        assert_eq!(tokens.get("bing"), Some(&"bing456".to_string()));
+        assert_eq!(tokens.get("yandex"), Some(&"yandex789".to_string()));
+    }
+
+    #[test]
+    fn test_additional_verification_providers() {
+        let html = r#"
+            <meta name="norton-safeweb-site-verification" content="norton123">
+            <meta name="alexaVerifyID" content="alexa456">
+            <meta name="baidu-site-verification" content="code-baidu789">
+        "#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.norton_safeweb_site_verification, Some("norton123".to_string()));
+        assert_eq!(meta.alexa_verify_id, Some("alexa456".to_string()));
+        assert_eq!(meta.baidu_site_verification, Some("code-baidu789".to_string()));
+
+        let tokens = meta.verification_tokens();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens.get("norton"), Some(&"norton123".to_string()));
+        assert_eq!(tokens.get("alexa"), Some(&"alexa456".to_string()));
+        assert_eq!(tokens.get("baidu"), Some(&"code-baidu789".to_string()));
+    }
+
+    #[test]
+    fn test_verification_tokens_empty() {
+        let meta = extract("<title>No tokens</title>", None).unwrap();
+        assert!(meta.verification_tokens().is_empty());
+    }
+
     // Phase 8: Apple Mobile Meta Tests
     #[test]
     fn test_apple_mobile_web_app_capable() {
@@ -815,4 +1248,101 @@ mod meta_extraction_tests {
         let meta = extract(html, Some("https://example.com/subdir/")).unwrap();
         assert_eq!(meta.icon, Some("https://example.com/subdir/favicon.ico".to_string()));
     }
+
+    #[test]
+    fn test_content_rating_rta_label() {
+        let html = r#"<meta name="RATING" content="RTA-5042-1996-1400-1577-RTA">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.content_rating, Some("RTA-5042-1996-1400-1577-RTA".to_string()));
+        assert!(meta.is_adult);
+    }
+
+    #[test]
+    fn test_content_rating_mature() {
+        let html = r#"<meta name="rating" content="Mature">"#;
+        let meta = extract(html, None).unwrap();
+        assert_eq!(meta.content_rating, Some("Mature".to_string()));
+        assert!(meta.is_adult);
+    }
+
+    #[test]
+    fn test_content_rating_pics_label() {
+        let html = r#"<meta http-equiv="PICS-Label" content='(PICS-1.1 "http://www.rtalabel.org" l gen true for "http://example.com" r (n 0 s 0 v 0 l 0) "RTA-5042-1996-1400-1577-RTA")'>"#;
+        let meta = extract(html, None).unwrap();
+        assert!(meta.content_rating.unwrap().starts_with("(PICS-1.1"));
+        assert!(meta.is_adult);
+    }
+
+    #[test]
+    fn test_content_rating_safe_page() {
+        let meta = extract(r#"<meta name="rating" content="general">"#, None).unwrap();
+        assert_eq!(meta.content_rating, Some("general".to_string()));
+        assert!(!meta.is_adult);
+
+        let meta = extract("<title>Safe</title>", None).unwrap();
+        assert_eq!(meta.content_rating, None);
+        assert!(!meta.is_adult);
+    }
+
+    // ========== HREFLANG VALIDATION ==========
+
+    #[test]
+    fn test_hreflang_valid_accepts_bcp47_tags() {
+        for tag in ["en", "en-US", "zh-Hant", "zh-Hant-TW", "es-419", "x-default", "X-Default"] {
+            assert!(hreflang_valid(tag), "{} should be valid", tag);
+        }
+    }
+
+    #[test]
+    fn test_hreflang_valid_rejects_malformed_tags() {
+        for tag in ["en_US", "english", "", "en-", "e", "en-USA-1", "en-US-x"] {
+            assert!(!hreflang_valid(tag), "{} should be invalid", tag);
+        }
+    }
+
+    #[test]
+    fn test_hreflang_diagnostics_reports_invalid_tags() {
+        let html = r#"
+            <link rel="alternate" href="https://example.com/en" hreflang="en-US">
+            <link rel="alternate" href="https://example.com/us" hreflang="en_US">
+            <link rel="alternate" href="https://example.com/any" hreflang="x-default">
+        "#;
+        let meta = extract(html, None).unwrap();
+        let warnings = hreflang_diagnostics(&meta);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("en_US"));
+        assert!(warnings[0].message.contains("https://example.com/us"));
+    }
+
+    // ========== DOCUMENT BASE ==========
+
+    #[test]
+    fn test_document_base_href_and_target() {
+        let html = r#"<head><base href="/docs/" target="_blank"></head>"#;
+        let base = extract_document_base(html, Some("https://example.com/page")).unwrap().unwrap();
+        assert_eq!(base.href.as_deref(), Some("https://example.com/docs/"));
+        assert_eq!(base.target.as_deref(), Some("_blank"));
+    }
+
+    #[test]
+    fn test_document_base_href_only() {
+        let html =
+            r#"<base href="https://cdn.example.com/"><base href="https://ignored.example/">"#;
+        let base = extract_document_base(html, None).unwrap().unwrap();
+        assert_eq!(base.href.as_deref(), Some("https://cdn.example.com/"));
+        assert_eq!(base.target, None);
+    }
+
+    #[test]
+    fn test_document_base_target_only() {
+        let html = r#"<base target="_top"><base href="https://example.com/">"#;
+        let base = extract_document_base(html, None).unwrap().unwrap();
+        assert_eq!(base.target.as_deref(), Some("_top"));
+        // href comes from the first <base> that has one
+        assert_eq!(base.href.as_deref(), Some("https://example.com/"));
+
+        let base = extract_document_base(r#"<base target="_self">"#, None).unwrap().unwrap();
+        assert_eq!(base.href, None);
+        assert_eq!(extract_document_base("<title>None</title>", None).unwrap(), None);
+    }
 }
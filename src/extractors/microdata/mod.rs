@@ -4,9 +4,13 @@
 //! with Schema.org vocabulary.
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use crate::types::microdata::MicrodataItem;
-use scraper::{ElementRef, Selector};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::{url_utils, MAX_NESTING_DEPTH};
+use crate::types::microdata::{vocabulary_of, MicrodataItem, PropertyValue};
+use crate::types::rdfa::{RdfaValue, Triple};
+use ego_tree::NodeId;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::{HashMap, HashSet};
 
 #[cfg(test)]
 mod tests;
@@ -22,48 +26,234 @@ mod tests;
 /// # Returns
 /// * `Result<Vec<MicrodataItem>>` - All microdata items found
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<MicrodataItem>> {
-    let document = html_utils::parse_html(html);
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<Vec<MicrodataItem>> {
+    Ok(extract_limited_from_doc(Scope::document(document), base_url, usize::MAX, false)?.0)
+}
+
+/// [`extract_from_doc`], stopping after `limit` top-level items
+///
+/// The flag is set when the document has more items than `limit`. With
+/// `skip_empty_text`, properties with an empty value are left out (for a
+/// document whose hidden text was removed).
+///
+/// Only items within `scope` are read; one whose enclosing item is outside
+/// the scope is reported at the top level.
+pub(crate) fn extract_limited_from_doc(
+    scope: Scope,
+    base_url: Option<&str>,
+    limit: usize,
+    skip_empty_text: bool,
+) -> Result<(Vec<MicrodataItem>, bool)> {
     let mut items = Vec::new();
+    let refs = ItemRefs::new(scope);
 
     // Find all top-level itemscope elements (not nested)
     let itemscope_selector = Selector::parse("[itemscope]").unwrap();
 
-    for element in document.select(&itemscope_selector) {
+    for element in scope.select(&itemscope_selector) {
         // Skip if this is a nested itemscope (will be handled as property)
-        if !is_top_level_itemscope(&element) {
+        if !is_top_level_itemscope(scope, element, &refs) {
             continue;
         }
+        if items.len() == limit {
+            return Ok((items, true));
+        }
 
-        if let Ok(item) = extract_item(&element, base_url) {
+        if let Ok(item) = extract_item(&element, base_url, &refs, &mut Crawl::new(skip_empty_text))
+        {
             items.push(item);
         }
     }
 
-    Ok(items)
+    Ok((items, false))
 }
 
-/// Check if an itemscope element is top-level (not nested in another itemscope)
-fn is_top_level_itemscope(element: &ElementRef) -> bool {
-    // Check if any ancestor has itemscope attribute and this element has itemprop
-    // If so, this is a nested item and should not be extracted at top level
-    if element.value().attr("itemprop").is_some() {
-        // This element has itemprop, check if there's a parent itemscope
-        let mut parent = element.parent();
-        while let Some(node) = parent {
-            if let Some(parent_element) = ElementRef::wrap(node) {
-                if parent_element.value().attr("itemscope").is_some() {
-                    // Found parent with itemscope, this is nested
-                    return false;
-                }
+/// Elements that `itemref` attributes point at
+///
+/// Empty, and never consulted, unless the scope uses `itemref`. Only
+/// elements within the scope can be referenced.
+struct ItemRefs<'a> {
+    /// Elements by `id`; the first element wins when an id repeats
+    by_id: HashMap<&'a str, ElementRef<'a>>,
+    /// Nodes referenced by at least one item's `itemref`
+    referenced: HashSet<NodeId>,
+}
+
+impl<'a> ItemRefs<'a> {
+    fn new(scope: Scope<'a>) -> Self {
+        let mut refs = ItemRefs { by_id: HashMap::new(), referenced: HashSet::new() };
+        let Ok(itemref) = html_utils::create_selector("[itemscope][itemref]") else {
+            return refs;
+        };
+        let items: Vec<ElementRef> = scope.select(&itemref).collect();
+        if items.is_empty() {
+            return refs;
+        }
+
+        for element in scope.elements() {
+            if let Some(id) = element.value().attr("id") {
+                refs.by_id.entry(id).or_insert(element);
             }
-            parent = node.parent();
         }
+        for item in items {
+            let targets: Vec<ElementRef> = refs.targets(&item).collect();
+            refs.referenced.extend(targets.iter().map(|target| target.id()));
+        }
+        refs
+    }
+
+    /// The elements a scope's `itemref` names, skipping ids that are missing,
+    /// inside the scope (already read) or around it (a cycle)
+    fn targets<'s>(
+        &'s self,
+        scope: &'s ElementRef<'a>,
+    ) -> impl Iterator<Item = ElementRef<'a>> + 's {
+        scope
+            .value()
+            .attr("itemref")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|id| self.by_id.get(id).copied())
+            .filter(move |target| {
+                target.id() != scope.id()
+                    && !target.ancestors().any(|node| node.id() == scope.id())
+                    && !scope.ancestors().any(|node| node.id() == target.id())
+            })
+    }
+}
+
+/// `rdf:type`, the predicate emitted for `itemtype`
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// Extract microdata as RDF triples
+///
+/// Subjects are the `itemid` of each item, or blank nodes (`_:m0`, `_:m1`, ...)
+/// numbered in document order; the `m` prefix keeps them distinct from RDFa's
+/// blank nodes when both are combined. Property names are expanded against the
+/// vocabulary of the item's type (`https://schema.org/Person` gives
+/// `https://schema.org/`). Values that are absolute http(s) URLs become IRIs.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Result<Vec<Triple>>` - Triples in document order
+pub fn extract_triples(html: &str, base_url: Option<&str>) -> Result<Vec<Triple>> {
+    let mut triples = Vec::new();
+    let mut blank_nodes = 0;
+    for item in extract(html, base_url)? {
+        item_triples(&item, None, &mut blank_nodes, &mut triples);
+    }
+    Ok(triples)
+}
+
+/// Emit the triples of one item (and its nested items), returning its subject
+fn item_triples(
+    item: &MicrodataItem,
+    inherited_vocab: Option<&str>,
+    blank_nodes: &mut usize,
+    triples: &mut Vec<Triple>,
+) -> String {
+    let types = item.item_type.as_deref().unwrap_or_default();
+    let vocab = types.first().and_then(|t| vocabulary_of(t)).or(inherited_vocab);
+    let subject = match item.id {
+        Some(ref id) => id.clone(),
+        None => {
+            let id = format!("_:m{}", blank_nodes);
+            *blank_nodes += 1;
+            id
+        }
+    };
+
+    for item_type in types {
+        triples.push(Triple {
+            subject: subject.clone(),
+            predicate: RDF_TYPE.to_string(),
+            object: RdfaValue::Resource(item_type.clone()),
+        });
+    }
+
+    for (name, values) in &item.properties {
+        let predicate = match vocab {
+            Some(vocab) if !name.contains(':') => format!("{}{}", vocab, name),
+            _ => name.clone(),
+        };
+        for value in values {
+            let object = match value {
+                PropertyValue::Item(nested) => {
+                    RdfaValue::Resource(item_triples(nested, vocab, blank_nodes, triples))
+                }
+                PropertyValue::Text(text) if is_absolute_http_url(text) => {
+                    RdfaValue::Resource(text.clone())
+                }
+                PropertyValue::Text(text) => RdfaValue::Literal(text.clone()),
+            };
+            triples.push(Triple { subject: subject.clone(), predicate: predicate.clone(), object });
+        }
+    }
+
+    subject
+}
+
+fn is_absolute_http_url(value: &str) -> bool {
+    url::Url::parse(value).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+/// Check if an itemscope element is top-level (not a property of another item)
+///
+/// An item with `itemprop` is a property of the nearest enclosing item, or of
+/// an item whose `itemref` points at it or one of its ancestors. An item with
+/// `itemprop` that belongs to no item is still reported at the top level.
+fn is_top_level_itemscope(scope: Scope, element: ElementRef, refs: &ItemRefs) -> bool {
+    if element.value().attr("itemprop").is_none() {
+        return true;
+    }
+    if refs.referenced.contains(&element.id()) {
+        return false;
+    }
+    !scope.ancestors(element).any(|ancestor| {
+        refs.referenced.contains(&ancestor.id()) || ancestor.value().attr("itemscope").is_some()
+    })
+}
+
+/// Nested items kept per top-level item
+///
+/// Without a cycle, `itemref`s can still fan out: items that all reference
+/// each other are reachable along every ordering of them.
+const MAX_NESTED_ITEMS: usize = 10_000;
+
+/// State of the crawl below one top-level item
+struct Crawl {
+    /// The items enclosing the one being read, outermost first
+    path: Vec<NodeId>,
+    /// Nested items read so far
+    nested: usize,
+    /// Leave out properties whose value is empty
+    skip_empty_text: bool,
+}
+
+impl Crawl {
+    fn new(skip_empty_text: bool) -> Self {
+        Crawl { path: Vec::new(), nested: 0, skip_empty_text }
     }
-    true
 }
 
 /// Extract a single microdata item from an element
-fn extract_item(element: &ElementRef, base_url: Option<&str>) -> Result<MicrodataItem> {
+///
+/// Nested items beyond [`MAX_NESTING_DEPTH`] are dropped, and so is an item
+/// already on the crawl path, which `itemref`s that point at each other would
+/// otherwise revisit without end.
+fn extract_item(
+    element: &ElementRef,
+    base_url: Option<&str>,
+    refs: &ItemRefs,
+    crawl: &mut Crawl,
+) -> Result<MicrodataItem> {
     let mut item = MicrodataItem::new();
 
     // Extract itemtype
@@ -74,41 +264,52 @@ fn extract_item(element: &ElementRef, base_url: Option<&str>) -> Result<Microdat
         }
     }
 
-    // Extract itemid
+    // Extract itemid (a URL, so resolve it like any other)
     if let Some(itemid) = element.value().attr("itemid") {
-        item.id = Some(itemid.to_string());
+        let itemid = itemid.trim();
+        item.id = Some(url_utils::resolve_url(base_url, itemid).unwrap_or(itemid.to_string()));
     }
 
     // Extract properties (itemprop children)
-    extract_properties(element, &mut item, base_url)?;
+    crawl.path.push(element.id());
+    let result = extract_properties(element, &mut item, base_url, refs, crawl);
+    crawl.path.pop();
+    result?;
 
     Ok(item)
 }
 
 /// Extract all properties from an itemscope element
+///
+/// Properties inside the element come first, then those in the elements its
+/// `itemref` names, in `itemref` order.
 fn extract_properties(
     scope: &ElementRef,
     item: &mut MicrodataItem,
     base_url: Option<&str>,
+    refs: &ItemRefs,
+    crawl: &mut Crawl,
 ) -> Result<()> {
     // Find all descendants with itemprop attribute within this scope
     for descendant in scope.descendants() {
         if let Some(element) = ElementRef::wrap(descendant) {
-            if let Some(prop_name) = element.value().attr("itemprop") {
-                // Check if this property belongs to this scope or a nested scope
-                if belongs_to_scope(scope, &element) {
-                    // Extract property value
-                    if element.value().attr("itemscope").is_some() {
-                        // This is a nested item
-                        if let Ok(nested_item) = extract_item(&element, base_url) {
-                            item.add_item_property(prop_name.to_string(), nested_item);
-                        }
-                    } else {
-                        // This is a text/URL property
-                        if let Some(value) = extract_property_value(&element, base_url) {
-                            item.add_text_property(prop_name.to_string(), value);
-                        }
-                    }
+            // Check if this property belongs to this scope or a nested scope
+            if is_property(&element) && belongs_to_scope(scope, &element) {
+                add_property(&element, item, base_url, refs, crawl);
+            }
+        }
+    }
+
+    for target in refs.targets(scope) {
+        add_property(&target, item, base_url, refs, crawl);
+        // A referenced item keeps its own properties
+        if target.value().attr("itemscope").is_some() {
+            continue;
+        }
+        for descendant in target.descendants().skip(1) {
+            if let Some(element) = ElementRef::wrap(descendant) {
+                if is_property(&element) && belongs_to_scope(&target, &element) {
+                    add_property(&element, item, base_url, refs, crawl);
                 }
             }
         }
@@ -117,6 +318,44 @@ fn extract_properties(
     Ok(())
 }
 
+fn is_property(element: &ElementRef) -> bool {
+    element.value().attr("itemprop").is_some()
+}
+
+/// Add an element's `itemprop` values to `item`; elements without one are ignored
+fn add_property(
+    element: &ElementRef,
+    item: &mut MicrodataItem,
+    base_url: Option<&str>,
+    refs: &ItemRefs,
+    crawl: &mut Crawl,
+) {
+    let Some(prop_name) = element.value().attr("itemprop") else {
+        return;
+    };
+    // Extract property value
+    if element.value().attr("itemscope").is_some() {
+        // This is a nested item
+        if crawl.path.len() > MAX_NESTING_DEPTH
+            || crawl.path.contains(&element.id())
+            || crawl.nested == MAX_NESTED_ITEMS
+        {
+            return;
+        }
+        crawl.nested += 1;
+        if let Ok(nested_item) = extract_item(element, base_url, refs, crawl) {
+            item.add_item_property(prop_name.to_string(), nested_item);
+        }
+    } else {
+        // This is a text/URL property
+        let value = extract_property_value(element, base_url)
+            .filter(|value| !(crawl.skip_empty_text && value.is_empty()));
+        if let Some(value) = value {
+            item.add_text_property(prop_name.to_string(), value);
+        }
+    }
+}
+
 /// Check if an itemprop element belongs to the given scope
 fn belongs_to_scope(scope: &ElementRef, prop_element: &ElementRef) -> bool {
     // The property belongs to this scope if there's no intervening itemscope
@@ -164,25 +403,22 @@ fn extract_property_value(element: &ElementRef, base_url: Option<&str>) -> Optio
         "data" => element.value().attr("value").map(|s| s.to_string()),
         // meter uses value attribute
         "meter" => element.value().attr("value").map(|s| s.to_string()),
-        // time uses datetime attribute if present, otherwise text content
+        // time uses datetime attribute if present, then content, otherwise text content
         "time" => {
-            if let Some(datetime) = element.value().attr("datetime") {
+            if let Some(datetime) =
+                element.value().attr("datetime").or(element.value().attr("content"))
+            {
                 Some(datetime.to_string())
             } else {
-                let text: String = element.text().collect();
-                if !text.trim().is_empty() {
-                    Some(text)
-                } else {
-                    None
-                }
+                html_utils::extract_text(element)
             }
         }
-        // For all other elements, use text content
-        _ => {
-            let text: String = element.text().collect();
-            // Return empty string instead of None to preserve empty properties
-            Some(text)
-        }
+        // For all other elements, a machine-readable content attribute overrides the text
+        // (empty string instead of None to preserve empty properties)
+        _ => Some(match element.value().attr("content") {
+            Some(content) => content.to_string(),
+            None => html_utils::text_content(element),
+        }),
     }?;
 
     // Resolve relative URLs if needed
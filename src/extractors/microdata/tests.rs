@@ -152,6 +152,34 @@ fn test_extract_with_itemid() {
     assert_eq!(items[0].id, Some("person-123".to_string()));
 }
 
+#[test]
+fn test_extract_relative_itemid_resolved() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Product" itemid="/products/1">
+        <span itemprop="name">Widget</span>
+    </div>
+    "#;
+
+    let items = extract(html, Some("https://shop.example.com/catalog/")).unwrap();
+    assert_eq!(items[0].id, Some("https://shop.example.com/products/1".to_string()));
+}
+
+#[test]
+fn test_extract_absolute_itemid_untouched() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Book" itemid="urn:isbn:0-330-34032-8">
+        <span itemprop="name">The Book</span>
+    </div>
+    <div itemscope itemtype="https://schema.org/Product" itemid="https://cdn.example.net/p/2">
+        <span itemprop="name">Gadget</span>
+    </div>
+    "#;
+
+    let items = extract(html, Some("https://shop.example.com/")).unwrap();
+    assert_eq!(items[0].id, Some("urn:isbn:0-330-34032-8".to_string()));
+    assert_eq!(items[1].id, Some("https://cdn.example.net/p/2".to_string()));
+}
+
 #[test]
 fn test_extract_multiple_types() {
     let html = r#"
@@ -391,3 +419,134 @@ fn test_extract_mixed_with_regular_content() {
     let items = extract(html, None).unwrap();
     assert_eq!(items.len(), 1);
 }
+
+#[test]
+fn test_extract_content_attribute_overrides_text() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Offer">
+        <span itemprop="price" content="19.99">$19.99</span>
+        <span itemprop="priceCurrency">USD</span>
+    </div>
+    "#;
+
+    let items = extract(html, None).unwrap();
+    let props = &items[0].properties;
+    assert_eq!(props["price"][0], PropertyValue::Text("19.99".to_string()));
+    assert_eq!(props["priceCurrency"][0], PropertyValue::Text("USD".to_string()));
+}
+
+#[test]
+fn test_extract_time_datetime_takes_precedence_over_content() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Event">
+        <time itemprop="startDate" datetime="2024-05-01" content="2024-06-01">May 1</time>
+        <time itemprop="endDate" content="2024-05-02">May 2</time>
+    </div>
+    "#;
+
+    let items = extract(html, None).unwrap();
+    let props = &items[0].properties;
+    assert_eq!(props["startDate"][0], PropertyValue::Text("2024-05-01".to_string()));
+    assert_eq!(props["endDate"][0], PropertyValue::Text("2024-05-02".to_string()));
+}
+
+#[test]
+fn test_xml_mode_mixed_case_attributes() {
+    use crate::extractors::common::html_utils::{parse_document, ParseMode};
+
+    let xhtml = r#"<?xml version="1.0"?>
+        <div itemScope="" itemType="https://schema.org/Person" ITEMID="urn:ada">
+            <span itemProp="name">Ada</span>
+            <meta ITEMPROP="jobTitle" CONTENT="Engineer"/>
+        </div>"#;
+    let items = extract_from_doc(&parse_document(xhtml, ParseMode::Xml), None).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_type, Some(vec!["https://schema.org/Person".to_string()]));
+    assert_eq!(items[0].id.as_deref(), Some("urn:ada"));
+    assert_eq!(items[0].properties["name"], vec![PropertyValue::Text("Ada".to_string())]);
+    assert_eq!(items[0].properties["jobTitle"], vec![PropertyValue::Text("Engineer".to_string())]);
+}
+
+#[test]
+fn test_itemref_only_item_is_nested_not_top_level() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Person" itemref="addr n">
+        <span itemprop="jobTitle">Engineer</span>
+    </div>
+    <span id="n" itemprop="name">Ada</span>
+    <div id="addr" itemprop="address" itemscope itemtype="https://schema.org/PostalAddress">
+        <span itemprop="addressLocality">London</span>
+    </div>
+    "#;
+
+    let items = extract(html, None).unwrap();
+    assert_eq!(items.len(), 1);
+
+    let props = &items[0].properties;
+    assert_eq!(props["jobTitle"], vec![PropertyValue::Text("Engineer".to_string())]);
+    assert_eq!(props["name"], vec![PropertyValue::Text("Ada".to_string())]);
+    match &props["address"][0] {
+        PropertyValue::Item(address) => {
+            assert_eq!(
+                address.item_type,
+                Some(vec!["https://schema.org/PostalAddress".to_string()])
+            );
+            assert_eq!(
+                address.properties["addressLocality"],
+                vec![PropertyValue::Text("London".to_string())]
+            );
+        }
+        other => panic!("expected nested address, got {:?}", other),
+    }
+    assert!(!props.contains_key("addressLocality"));
+}
+
+#[test]
+fn test_three_level_nesting_has_one_top_level_item() {
+    let html = r#"
+    <div itemscope itemtype="https://schema.org/Event">
+        <span itemprop="name">Launch</span>
+        <div itemprop="location" itemscope itemtype="https://schema.org/Place">
+            <span itemprop="name">Hall</span>
+            <div itemprop="address" itemscope itemtype="https://schema.org/PostalAddress">
+                <span itemprop="addressLocality">Paris</span>
+            </div>
+        </div>
+    </div>
+    "#;
+
+    let items = extract(html, None).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_type, Some(vec!["https://schema.org/Event".to_string()]));
+    assert_eq!(items[0].properties["name"], vec![PropertyValue::Text("Launch".to_string())]);
+
+    let PropertyValue::Item(place) = &items[0].properties["location"][0] else {
+        panic!("expected nested location");
+    };
+    assert_eq!(place.properties["name"], vec![PropertyValue::Text("Hall".to_string())]);
+    let PropertyValue::Item(address) = &place.properties["address"][0] else {
+        panic!("expected nested address");
+    };
+    assert_eq!(
+        address.properties["addressLocality"],
+        vec![PropertyValue::Text("Paris".to_string())]
+    );
+}
+
+#[test]
+fn test_itemref_cycle_between_siblings_terminates() {
+    let html = r#"<div itemscope itemref="a"></div><div id="a" itemprop="p" itemscope itemref="b c"></div><div id="b" itemprop="p" itemscope itemref="a c"></div><div id="c" itemprop="p" itemscope itemref="a b"></div>"#;
+
+    let items = extract(html, None).unwrap();
+    assert_eq!(items.len(), 1);
+
+    // a holds b and c; neither may lead back to an item on its own path
+    let PropertyValue::Item(a) = &items[0].properties["p"][0] else {
+        panic!("expected nested item");
+    };
+    assert_eq!(a.properties["p"].len(), 2);
+    let PropertyValue::Item(b) = &a.properties["p"][0] else {
+        panic!("expected nested item");
+    };
+    assert_eq!(b.properties["p"].len(), 1);
+}
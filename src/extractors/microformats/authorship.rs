@@ -0,0 +1,87 @@
+//! Simplified IndieWeb authorship discovery
+//!
+//! See <https://indieweb.org/authorship-spec>. Only the two most common
+//! paths are followed: an h-entry's nested `p-author h-card`, and the page's
+//! `rel="author"` link. Author pages are never fetched.
+
+use super::{hcard, hentry};
+use crate::extractors::rel_links;
+use crate::types::HCard;
+
+/// Find the author of the post on a page
+///
+/// Prefers the nested `p-author h-card` of the first h-entry that has one.
+/// Otherwise follows the first `rel="author"` link: if an h-card on the page
+/// has that `u-url` it is returned, else a card holding only the URL.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Option<HCard>` - The author, or `None` if neither source is present
+pub fn find_author(html: &str, base_url: Option<&str>) -> Option<HCard> {
+    let entries = hentry::extract(html, base_url).unwrap_or_default();
+    if let Some(author) = entries.into_iter().find_map(|entry| entry.author) {
+        return Some(*author);
+    }
+
+    let url = rel_links::extract(html, base_url).ok()?.get("author")?.first()?.clone();
+    let inline = hcard::extract(html, base_url)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|card| card.url.as_deref() == Some(url.as_str()));
+    Some(inline.unwrap_or(HCard { url: Some(url), ..Default::default() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_p_author_hcard() {
+        let html = r#"
+            <link rel="author" href="/about">
+            <article class="h-entry">
+                <h1 class="p-name">Post</h1>
+                <div class="p-author h-card">
+                    <span class="p-name">Jane Doe</span>
+                    <a class="u-url" href="/jane">Jane</a>
+                </div>
+            </article>
+        "#;
+        let author = find_author(html, Some("https://example.com/post")).unwrap();
+        assert_eq!(author.name.as_deref(), Some("Jane Doe"));
+        assert_eq!(author.url.as_deref(), Some("https://example.com/jane"));
+    }
+
+    #[test]
+    fn test_rel_author_link() {
+        let html = r#"
+            <link rel="author" href="/about">
+            <article class="h-entry"><h1 class="p-name">Post</h1></article>
+        "#;
+        let author = find_author(html, Some("https://example.com/post")).unwrap();
+        assert_eq!(author.url.as_deref(), Some("https://example.com/about"));
+        assert!(author.name.is_none());
+
+        // The rel-author target is an h-card on the same page
+        let html = r#"
+            <a rel="author" href="https://example.com/about">About</a>
+            <footer class="h-card">
+                <a class="p-name u-url" href="https://example.com/about">Jane Doe</a>
+            </footer>
+        "#;
+        let author = find_author(html, None).unwrap();
+        assert_eq!(author.name.as_deref(), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_no_author() {
+        let html = r#"
+            <article class="h-entry"><h1 class="p-name">Post</h1></article>
+            <div class="h-card"><span class="p-name">Not linked</span></div>
+        "#;
+        assert!(find_author(html, None).is_none());
+    }
+}
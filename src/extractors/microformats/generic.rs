@@ -0,0 +1,211 @@
+//! Generic microformats2 extractor for any `h-*` root
+//!
+//! The bespoke modules (`hcard`, `hentry`, ...) map well-known vocabularies to
+//! typed structs. Custom or newer vocabularies (`h-review-aggregate`,
+//! `h-widget`, ...) can be read here with the generic microformats2 property
+//! algorithm instead, without a dedicated module.
+
+use crate::errors::{MicroformatError, Result};
+use crate::extractors::common::{html_utils, MAX_NESTING_DEPTH};
+use crate::extractors::meta;
+use crate::parser;
+use crate::types::microformats::{GenericMicroformat, PropertyValue};
+use indexmap::IndexMap;
+use scraper::ElementRef;
+
+/// Extract every element with the given `h-*` root class
+///
+/// Properties are read from `p-*`, `u-*`, `dt-*` and `e-*` classes. A nested
+/// `h-*` element is parsed as its own item: with a property class it becomes
+/// a [`PropertyValue::Nested`] value of that property, otherwise a child in
+/// `children`. Either way its properties stay with the nested item. Items, nested
+/// ones included, with no `lang` in the markup get the document language
+/// ([`MetaTags::effective_language`](crate::types::meta::MetaTags::effective_language)).
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+/// * `root_class` - The root class to look for, e.g. `h-widget`
+///
+/// # Returns
+/// * `Result<Vec<GenericMicroformat>>` - One item per root element, in document
+///   order, or an error if `root_class` is not a valid `h-*` class name
+pub fn extract_generic(
+    html: &str,
+    base_url: Option<&str>,
+    root_class: &str,
+) -> Result<Vec<GenericMicroformat>> {
+    if !is_root_class(root_class) {
+        return Err(MicroformatError::InvalidSelector(format!(
+            "invalid microformat root class '{}'",
+            root_class
+        )));
+    }
+
+    let document = html_utils::parse_html(html);
+    let selector = html_utils::create_selector(&format!(".{}", root_class))?;
+    let mut items: Vec<GenericMicroformat> = document
+        .select(&selector)
+        .map(|element| parse_item(&element, base_url, 0))
+        .collect::<Result<_>>()?;
+
+    let document_lang = meta::extract_from_doc(&document, base_url)?.effective_language();
+    if let Some(lang) = document_lang {
+        items.iter_mut().for_each(|item| item.inherit_lang(&lang));
+    }
+    Ok(items)
+}
+
+/// Whether a class names a microformats2 root (`h-` plus lowercase letters, digits, `-`)
+fn is_root_class(class: &str) -> bool {
+    class.strip_prefix("h-").is_some_and(|name| {
+        !name.is_empty()
+            && name.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    })
+}
+
+/// Split a property class into its prefix and name (`u-photo` → `("u", "photo")`)
+fn property_class(class: &str) -> Option<(&'static str, &str)> {
+    ["p", "u", "dt", "e"].into_iter().find_map(|prefix| {
+        let name = class.strip_prefix(prefix)?.strip_prefix('-')?;
+        (!name.is_empty()).then_some((prefix, name))
+    })
+}
+
+/// Parse one item, walking its subtree without entering nested items
+fn parse_item(
+    element: &ElementRef,
+    base_url: Option<&str>,
+    depth: usize,
+) -> Result<GenericMicroformat> {
+    let type_ = element.value().classes().filter(|c| is_root_class(c)).map(String::from).collect();
+    let mut properties: IndexMap<String, Vec<PropertyValue>> = IndexMap::new();
+    let mut children = Vec::new();
+
+    let mut stack: Vec<ElementRef> = element.children().filter_map(ElementRef::wrap).collect();
+    stack.reverse();
+    while let Some(el) = stack.pop() {
+        let is_root = el.value().classes().any(is_root_class);
+        let props: Vec<_> = el.value().classes().filter_map(property_class).collect();
+
+        if is_root {
+            if depth < MAX_NESTING_DEPTH {
+                let nested = parse_item(&el, base_url, depth + 1)?;
+                if props.is_empty() {
+                    children.push(nested);
+                } else {
+                    for (_, name) in props {
+                        properties
+                            .entry(name.to_string())
+                            .or_default()
+                            .push(PropertyValue::Nested(Box::new(nested.clone())));
+                    }
+                }
+            }
+            continue;
+        }
+
+        for (prefix, name) in props {
+            let value = parser::extract_property_value(&el, prefix, base_url)?;
+            properties.entry(name.to_string()).or_default().push(value);
+        }
+        let start = stack.len();
+        stack.extend(el.children().filter_map(ElementRef::wrap));
+        stack[start..].reverse();
+    }
+
+    Ok(GenericMicroformat {
+        type_,
+        properties,
+        children: (!children.is_empty()).then_some(children),
+        lang: parser::element_lang(element),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_custom_root_with_properties() {
+        let html = r#"
+            <div class="h-widget">
+                <span class="p-name">Sprocket</span>
+                <a class="u-url" href="/widgets/sprocket">Details</a>
+                <img class="u-photo" src="sprocket.png" alt="">
+                <time class="dt-released" datetime="2024-03-01">March</time>
+                <span class="p-color">Red</span><span class="p-color">Blue</span>
+            </div>
+        "#;
+        let widgets = extract_generic(html, Some("https://example.com/"), "h-widget").unwrap();
+        assert_eq!(widgets.len(), 1);
+
+        let widget = &widgets[0];
+        assert_eq!(widget.type_, vec!["h-widget"]);
+        assert_eq!(widget.properties["name"], vec![PropertyValue::Text("Sprocket".to_string())]);
+        assert_eq!(
+            widget.properties["url"],
+            vec![PropertyValue::Url("https://example.com/widgets/sprocket".to_string())]
+        );
+        assert_eq!(
+            widget.properties["photo"],
+            vec![PropertyValue::Url("https://example.com/sprocket.png".to_string())]
+        );
+        assert_eq!(widget.properties["released"], vec![PropertyValue::Text("2024-03-01".into())]);
+        assert_eq!(widget.properties["color"].len(), 2);
+        assert!(widget.children.is_none());
+    }
+
+    #[test]
+    fn test_extract_nested_microformats() {
+        let html = r#"
+            <div class="h-widget">
+                <span class="p-name">Sprocket</span>
+                <div class="p-maker h-card"><span class="p-name">Acme</span></div>
+                <div class="h-review-aggregate"><span class="p-count">12</span></div>
+            </div>
+        "#;
+        let widget = &extract_generic(html, None, "h-widget").unwrap()[0];
+
+        // Nested names stay with their own items
+        assert_eq!(widget.properties["name"], vec![PropertyValue::Text("Sprocket".to_string())]);
+        assert!(!widget.properties.contains_key("count"));
+
+        match &widget.properties["maker"][0] {
+            PropertyValue::Nested(card) => {
+                assert_eq!(card.type_, vec!["h-card"]);
+                assert_eq!(card.properties["name"], vec![PropertyValue::Text("Acme".into())]);
+            }
+            other => panic!("expected nested h-card, got {:?}", other),
+        }
+
+        let children = widget.children.as_ref().unwrap();
+        assert_eq!(children[0].type_, vec!["h-review-aggregate"]);
+        assert_eq!(children[0].properties["count"], vec![PropertyValue::Text("12".into())]);
+    }
+
+    #[test]
+    fn test_nested_items_fall_back_to_document_language() {
+        let html = r#"<head><meta property="og:locale" content="en_GB"></head>
+            <div class="h-widget">
+                <div class="p-maker h-card"><span class="p-name">Acme</span></div>
+                <div class="h-card" lang="FR_fr"><span class="p-name">Acmé</span></div>
+            </div>"#;
+        let widget = &extract_generic(html, None, "h-widget").unwrap()[0];
+
+        assert_eq!(widget.lang.as_deref(), Some("en-GB"));
+        match &widget.properties["maker"][0] {
+            PropertyValue::Nested(card) => assert_eq!(card.lang.as_deref(), Some("en-GB")),
+            other => panic!("expected nested h-card, got {:?}", other),
+        }
+        assert_eq!(widget.children.as_ref().unwrap()[0].lang.as_deref(), Some("fr-FR"));
+    }
+
+    #[test]
+    fn test_invalid_root_class_is_rejected() {
+        assert!(extract_generic("<div></div>", None, "p-name").is_err());
+        assert!(extract_generic("<div></div>", None, "h-").is_err());
+        assert!(extract_generic("<div></div>", None, "h-x,.y").is_err());
+        assert!(extract_generic("<div></div>", None, "h-widget").unwrap().is_empty());
+    }
+}
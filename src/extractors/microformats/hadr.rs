@@ -10,6 +10,7 @@ microformat_extractor! {
         region: text(".p-region"),
         postal_code: text(".p-postal-code"),
         country_name: text(".p-country-name"),
+        geo: nested_hgeo(".h-geo, .p-geo"),
     }
 }
 
@@ -83,4 +84,38 @@ mod tests {
         assert_eq!(addresses[0].locality, Some("New York".to_string()));
         assert_eq!(addresses[1].locality, Some("Los Angeles".to_string()));
     }
+
+    #[test]
+    fn test_hadr_with_nested_hgeo() {
+        let html = r#"
+            <div class="h-adr">
+                <span class="p-locality">San Francisco</span>
+                <span class="p-geo h-geo">
+                    <span class="p-latitude">37.7749</span>
+                    <span class="p-longitude">-122.4194</span>
+                </span>
+            </div>
+        "#;
+
+        let addresses = extract(html, None).unwrap();
+        assert_eq!(addresses.len(), 1);
+        let geo = addresses[0].geo.as_ref().unwrap();
+        assert_eq!(geo.latitude, Some(37.7749));
+        assert_eq!(geo.longitude, Some(-122.4194));
+    }
+
+    #[test]
+    fn test_hadr_with_plain_geo_value() {
+        let html = r#"<div class="h-adr"><span class="p-geo">51.5074;-0.1278</span></div>"#;
+        let addresses = extract(html, None).unwrap();
+        let geo = addresses[0].geo.as_ref().unwrap();
+        assert_eq!((geo.latitude, geo.longitude), (Some(51.5074), Some(-0.1278)));
+    }
+
+    #[test]
+    fn test_hadr_without_geo() {
+        let html = r#"<div class="h-adr"><span class="p-locality">Paris</span></div>"#;
+        let addresses = extract(html, None).unwrap();
+        assert!(addresses[0].geo.is_none());
+    }
 }
@@ -5,7 +5,7 @@ microformat_extractor! {
     HEntry, ".h-entry" {
         name: text(".p-name"),
         summary: text(".p-summary"),
-        content: html(".e-content"),
+        content: e_content(".e-content"),
         published: date(".dt-published"),
         updated: date(".dt-updated"),
         url: url(".u-url"),
@@ -127,12 +127,54 @@ mod tests {
         assert_eq!(entries.len(), 1);
         let entry = &entries[0];
         assert!(entry.content.is_some());
-        let content = entry.content.as_ref().unwrap();
+        let content = &entry.content.as_ref().unwrap().html;
         assert!(content.contains("<h2>"));
         assert!(content.contains("<strong>"));
         assert!(content.contains("<code>"));
     }
 
+    #[test]
+    fn test_hentry_content_html_and_text() {
+        let html = r#"
+            <article class="h-entry">
+                <div class="e-content">
+                    <p>Hello <strong>bold</strong>
+                       world</p>
+                    <ul><li>One</li> <li>Two</li></ul>
+                    <script>track()</script>
+                </div>
+            </article>
+        "#;
+        let entries = extract(html, None).unwrap();
+        let content = entries[0].content.as_ref().unwrap();
+        assert!(content.html.starts_with("<p>Hello <strong>bold</strong>"));
+        assert!(content.html.contains("<li>Two</li>"));
+        assert_eq!(content.text, "Hello bold world One Two");
+    }
+
+    #[test]
+    fn test_hentry_content_resolves_relative_urls() {
+        let html = r#"
+            <article class="h-entry">
+                <div class="e-content">
+                    <img src="/img/photo.jpg" alt="Photo">
+                    <a href="next.html">Next</a>
+                    <a href="https://other.example/">Other</a>
+                </div>
+            </article>
+        "#;
+        let entries = extract(html, Some("https://example.com/posts/1")).unwrap();
+        let content = entries[0].content.as_ref().unwrap();
+        assert!(content.html.contains(r#"src="https://example.com/img/photo.jpg""#));
+        assert!(content.html.contains(r#"href="https://example.com/posts/next.html""#));
+        assert!(content.html.contains(r#"href="https://other.example/""#));
+        assert_eq!(content.text, "Next Other");
+
+        // Without a base URL the HTML is kept as authored
+        let entries = extract(html, None).unwrap();
+        assert!(entries[0].content.as_ref().unwrap().html.contains(r#"src="/img/photo.jpg""#));
+    }
+
     #[test]
     fn test_hentry_minimal() {
         let html = r#"
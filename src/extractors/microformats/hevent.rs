@@ -1,18 +1,62 @@
-use crate::microformat_extractor;
+use crate::errors::Result;
+use crate::extractors::common::datetime_utils::{parse_datetime, parse_duration};
 use crate::types::HEvent;
 
-microformat_extractor! {
-    HEvent, ".h-event" {
-        name: text(".p-name"),
-        summary: text(".p-summary"),
-        start: date(".dt-start"),
-        end: date(".dt-end"),
-        location: text(".p-location"),
-        url: url(".u-url"),
-        description: text(".p-description, .e-description"),
+mod raw {
+    use crate::microformat_extractor;
+    use crate::types::HEvent;
+
+    microformat_extractor! {
+        HEvent, ".h-event" {
+            name: text(".p-name"),
+            summary: text(".p-summary"),
+            start: date(".dt-start"),
+            end: date(".dt-end"),
+            duration: date(".dt-duration"),
+            location: text(".p-location"),
+            location_card: nested_hcard(".p-location.h-card"),
+            location_adr: nested_hadr(".p-location.h-adr"),
+            url: url(".u-url"),
+            description: text(".p-description, .e-description"),
+        }
     }
 }
 
+/// Extract h-event items from HTML
+///
+/// `duration_seconds` is `end - start` when both parse as ISO 8601 date-times,
+/// otherwise the parsed `dt-duration`. `is_multi_day` is set when the event
+/// ends (at `dt-end`, or `dt-start` plus the duration) on a later calendar day
+/// than it starts.
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<HEvent>> {
+    let mut events = raw::extract(html, base_url)?;
+    for event in &mut events {
+        let start = event.start.as_deref().and_then(parse_datetime);
+        let end = event.end.as_deref().and_then(parse_datetime);
+        let duration = event.duration.as_deref().and_then(parse_duration);
+
+        event.duration_seconds = match (start, end) {
+            (Some(start), Some(end)) if end.timestamp() >= start.timestamp() => {
+                Some((end.timestamp() - start.timestamp()) as u64)
+            }
+            _ => duration,
+        };
+
+        event.is_multi_day = match (start, end, duration) {
+            (Some(start), Some(end), _) => end.date() > start.date(),
+            (Some(start), None, Some(duration)) => {
+                // Whole days elapsed from the start of the start date
+                let into_day = u64::from(start.hour) * 3_600
+                    + u64::from(start.minute) * 60
+                    + u64::from(start.second);
+                into_day.saturating_add(duration) > 86_400
+            }
+            _ => false,
+        };
+    }
+    Ok(events)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,7 +178,93 @@ mod tests {
         "#;
         let events = extract(html, None).unwrap();
         assert_eq!(events.len(), 1);
-        // Duration should be handled if implemented
+        assert_eq!(events[0].duration, Some("PT2H".to_string()));
+        assert_eq!(events[0].duration_seconds, Some(7_200));
+        assert!(!events[0].is_multi_day);
+    }
+
+    #[test]
+    fn test_hevent_only_duration() {
+        let html = r#"
+            <div class="h-event">
+                <span class="p-name">Open-ended retreat</span>
+                <span class="dt-duration">P3D</span>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        assert_eq!(events[0].start, None);
+        assert_eq!(events[0].duration_seconds, Some(3 * 86_400));
+        assert!(!events[0].is_multi_day);
+    }
+
+    #[test]
+    fn test_hevent_single_day() {
+        let html = r#"
+            <div class="h-event">
+                <time class="dt-start" datetime="2024-05-15T09:00">9 AM</time>
+                <time class="dt-end" datetime="2024-05-15T17:30">5:30 PM</time>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        assert!(!events[0].is_multi_day);
+        assert_eq!(events[0].duration_seconds, Some(8 * 3_600 + 30 * 60));
+    }
+
+    #[test]
+    fn test_hevent_multi_day() {
+        let html = r#"
+            <div class="h-event">
+                <time class="dt-start" datetime="2024-09-10T09:00:00-07:00">Sept 10</time>
+                <time class="dt-end" datetime="2024-09-12T17:00:00-07:00">Sept 12</time>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        assert!(events[0].is_multi_day);
+        assert_eq!(events[0].duration_seconds, Some(2 * 86_400 + 8 * 3_600));
+    }
+
+    #[test]
+    fn test_hevent_start_plus_duration_crosses_midnight() {
+        let html = r#"
+            <div class="h-event">
+                <time class="dt-start" datetime="2024-12-31T22:00">NYE</time>
+                <time class="dt-duration" datetime="PT4H">4 hours</time>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        assert!(events[0].is_multi_day);
+        assert_eq!(events[0].duration_seconds, Some(4 * 3_600));
+    }
+
+    #[test]
+    fn test_hevent_out_of_range_duration() {
+        let html = r#"
+            <div class="h-event">
+                <time class="dt-start" datetime="2024-12-31T22:00">NYE</time>
+                <time class="dt-duration" datetime="P99999999999999999999999Y">Forever</time>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        assert_eq!(events[0].duration_seconds, None);
+        assert!(!events[0].is_multi_day);
+    }
+
+    #[test]
+    fn test_hevent_location_hcard_and_hadr() {
+        let html = r#"
+            <div class="h-event">
+                <div class="p-location h-card"><span class="p-name">Venue Hall</span></div>
+            </div>
+            <div class="h-event">
+                <div class="p-location h-adr"><span class="p-locality">Oslo</span></div>
+            </div>
+        "#;
+        let events = extract(html, None).unwrap();
+        let card = events[0].location_card.as_ref().unwrap();
+        assert_eq!(card.name, Some("Venue Hall".to_string()));
+        assert!(events[0].location_adr.is_none());
+        let adr = events[1].location_adr.as_ref().unwrap();
+        assert_eq!(adr.locality, Some("Oslo".to_string()));
     }
 
     #[test]
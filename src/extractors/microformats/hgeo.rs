@@ -1,12 +1,55 @@
-use crate::microformat_extractor;
+use crate::errors::Result;
+use crate::extractors::common::html_utils;
+use crate::extractors::geo::parse_coordinates;
 use crate::types::HGeo;
+use scraper::ElementRef;
 
-microformat_extractor! {
-    HGeo, ".h-geo" {
-        latitude: f64_number(".p-latitude"),
-        longitude: f64_number(".p-longitude"),
-        altitude: f64_number(".p-altitude"),
+/// Extract all h-geo microformats
+pub fn extract(html: &str, _base_url: Option<&str>) -> Result<Vec<HGeo>> {
+    let document = html_utils::parse_html(html);
+    let selector = html_utils::create_selector(".h-geo")?;
+    Ok(document.select(&selector).map(|element| from_element(&element)).collect())
+}
+
+/// Read an h-geo, or a plain `p-geo` value, from its element
+///
+/// `p-latitude` and `p-longitude` are used when present. Otherwise a combined
+/// `"37.77;-122.41"` value is read from a `p-geo` child, the element's
+/// `title`, or its text. Latitudes outside -90..=90 and longitudes outside
+/// -180..=180 are dropped.
+pub(crate) fn from_element(element: &ElementRef) -> HGeo {
+    let number = |selector: &str| -> Option<f64> {
+        let selector = html_utils::create_selector(selector).ok()?;
+        let elem = element.select(&selector).next()?;
+        html_utils::extract_text(&elem)?.parse().ok()
+    };
+
+    let mut geo = HGeo {
+        latitude: number(".p-latitude").filter(|lat| (-90.0..=90.0).contains(lat)),
+        longitude: number(".p-longitude").filter(|lon| (-180.0..=180.0).contains(lon)),
+        altitude: number(".p-altitude"),
+        ..Default::default()
+    };
+
+    if geo.latitude.is_none() && geo.longitude.is_none() {
+        if let Some((latitude, longitude)) =
+            combined_value(element).and_then(|v| parse_coordinates(&v))
+        {
+            geo.latitude = Some(latitude);
+            geo.longitude = Some(longitude);
+        }
     }
+
+    geo
+}
+
+/// The combined `"lat;long"` value of an h-geo or p-geo element
+fn combined_value(element: &ElementRef) -> Option<String> {
+    let child = html_utils::create_selector(".p-geo")
+        .ok()
+        .and_then(|selector| element.select(&selector).next());
+    let source = child.as_ref().unwrap_or(element);
+    html_utils::get_attr(source, "title").or_else(|| html_utils::extract_text(source))
 }
 
 #[cfg(test)]
@@ -78,4 +121,38 @@ mod tests {
         assert!((geos[0].latitude.unwrap() - 51.5074).abs() < 0.0001);
         assert!((geos[1].latitude.unwrap() - 48.8566).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_hgeo_combined_value() {
+        let html = r#"<abbr class="h-geo" title="37.77;-122.41">San Francisco</abbr>"#;
+        let geos = extract(html, None).unwrap();
+        assert_eq!(geos[0].latitude, Some(37.77));
+        assert_eq!(geos[0].longitude, Some(-122.41));
+
+        let html = r#"<div class="h-geo"><span class="p-geo">51.5074; -0.1278</span></div>"#;
+        let geos = extract(html, None).unwrap();
+        assert_eq!(geos[0].latitude, Some(51.5074));
+        assert_eq!(geos[0].longitude, Some(-0.1278));
+    }
+
+    #[test]
+    fn test_hgeo_out_of_range_coordinates() {
+        let html = r#"
+            <div class="h-geo">
+                <span class="p-latitude">91.5</span>
+                <span class="p-longitude">-122.4194</span>
+            </div>
+            <div class="h-geo">
+                <span class="p-latitude">37.7749</span>
+                <span class="p-longitude">200</span>
+            </div>
+            <span class="h-geo">95;10</span>
+        "#;
+
+        let geos = extract(html, None).unwrap();
+        assert_eq!(geos.len(), 3);
+        assert_eq!((geos[0].latitude, geos[0].longitude), (None, Some(-122.4194)));
+        assert_eq!((geos[1].latitude, geos[1].longitude), (Some(37.7749), None));
+        assert_eq!((geos[2].latitude, geos[2].longitude), (None, None));
+    }
 }
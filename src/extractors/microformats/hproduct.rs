@@ -1,18 +1,40 @@
-use crate::microformat_extractor;
+use crate::errors::Result;
 use crate::types::HProduct;
 
-microformat_extractor! {
-    HProduct, ".h-product" {
-        name: text(".p-name"),
-        description: text(".p-description, .e-description"),
-        photo: url(".u-photo"),
-        price: text(".p-price"),
-        brand: text(".p-brand"),
-        category: multi_text(".p-category"),
-        rating: number(".p-rating"),
-        url: url(".u-url"),
-        identifier: text(".p-identifier"),
+mod raw {
+    use crate::microformat_extractor;
+    use crate::types::HProduct;
+
+    microformat_extractor! {
+        HProduct, ".h-product" {
+            name: text(".p-name"),
+            description: text(".p-description, .e-description"),
+            photo: url(".u-photo"),
+            price: text(".p-price"),
+            currency: text(".p-currency"),
+            brand: text(".p-brand"),
+            category: multi_text(".p-category"),
+            rating: number(".p-rating"),
+            url: url(".u-url"),
+            identifier: text(".p-identifier"),
+            review: multi_nested_hreview(".h-review"),
+        }
+    }
+}
+
+/// Extract h-product items from HTML
+///
+/// Besides the raw `p-price` text, each product gets a numeric `price_amount`
+/// and a `price_currency` derived from it (an explicit `p-currency` wins).
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<HProduct>> {
+    let mut products = raw::extract(html, base_url)?;
+    for product in &mut products {
+        let (amount, currency) =
+            product.price.as_deref().map(HProduct::parse_price).unwrap_or_default();
+        product.price_amount = amount;
+        product.price_currency = product.currency.clone().or(currency);
     }
+    Ok(products)
 }
 
 #[cfg(test)]
@@ -143,6 +165,104 @@ mod tests {
         assert_eq!(products[1].name, Some("Product 2".to_string()));
     }
 
+    #[test]
+    fn test_hproduct_dollar_prefixed_price() {
+        let html = r#"
+            <div class="h-product">
+                <span class="p-name">Mug</span>
+                <span class="p-price">$19.99</span>
+            </div>
+        "#;
+
+        let products = extract(html, None).unwrap();
+        assert_eq!(products[0].price, Some("$19.99".to_string()));
+        assert_eq!(products[0].price_amount, Some(19.99));
+        assert_eq!(products[0].price_currency, Some("USD".to_string()));
+    }
+
+    #[test]
+    fn test_hproduct_currency_suffixed_price() {
+        let html = r#"
+            <div class="h-product">
+                <span class="p-name">Lamp</span>
+                <span class="p-price">1,249.50 EUR</span>
+            </div>
+        "#;
+
+        let products = extract(html, None).unwrap();
+        assert_eq!(products[0].price_amount, Some(1249.5));
+        assert_eq!(products[0].price_currency, Some("EUR".to_string()));
+    }
+
+    #[test]
+    fn test_hproduct_explicit_currency_within_price() {
+        let html = r#"
+            <div class="h-product">
+                <span class="p-price"><span class="p-currency">CAD</span> 42</span>
+            </div>
+        "#;
+
+        let products = extract(html, None).unwrap();
+        assert_eq!(products[0].currency, Some("CAD".to_string()));
+        assert_eq!(products[0].price_amount, Some(42.0));
+        assert_eq!(products[0].price_currency, Some("CAD".to_string()));
+    }
+
+    #[test]
+    fn test_hproduct_offer_with_brand_categories_and_review() {
+        let html = r#"
+            <div class="h-product">
+                <a class="u-url p-name" href="/p/kettle">Kettle</a>
+                <span class="p-brand">Boilco</span>
+                <span class="p-category">Kitchen</span>
+                <span class="p-category">Appliances</span>
+                <span class="p-price">€34,90</span>
+                <div class="p-review h-review">
+                    <span class="p-name">Boils fast</span>
+                    <span class="p-rating">5</span>
+                </div>
+            </div>
+        "#;
+
+        let products = extract(html, Some("https://shop.example")).unwrap();
+        assert_eq!(products.len(), 1);
+        let product = &products[0];
+        assert_eq!(product.url, Some("https://shop.example/p/kettle".to_string()));
+        assert_eq!(product.brand, Some("Boilco".to_string()));
+        assert_eq!(product.category, vec!["Kitchen", "Appliances"]);
+        assert_eq!(product.price_amount, Some(34.9));
+        assert_eq!(product.price_currency, Some("EUR".to_string()));
+        assert_eq!(product.review.len(), 1);
+        assert_eq!(product.review[0].name, Some("Boils fast".to_string()));
+        assert_eq!(product.review[0].rating, Some(5.0));
+    }
+
+    #[test]
+    fn test_parse_price_without_currency() {
+        assert_eq!(HProduct::parse_price("12"), (Some(12.0), None));
+        assert_eq!(HProduct::parse_price("call for price"), (None, None));
+    }
+
+    #[test]
+    fn test_parse_price_ignores_words_that_are_not_currencies() {
+        assert_eq!(HProduct::parse_price("Now 20 EUR"), (Some(20.0), Some("EUR".to_string())));
+        assert_eq!(HProduct::parse_price("Was $25, now"), (Some(25.0), Some("USD".to_string())));
+        assert_eq!(HProduct::parse_price("Now 20"), (Some(20.0), None));
+        assert_eq!(HProduct::parse_price("20 eur"), (Some(20.0), None));
+    }
+
+    #[test]
+    fn test_parse_price_dot_thousands_grouping() {
+        let eur = Some("EUR".to_string());
+        assert_eq!(HProduct::parse_price("€1.000"), (Some(1000.0), eur.clone()));
+        assert_eq!(HProduct::parse_price("1.250.000 €"), (Some(1_250_000.0), eur.clone()));
+        assert_eq!(HProduct::parse_price("€1.299,00"), (Some(1299.0), eur));
+        // Anything but 3-digit groups keeps the dot as the decimal point
+        assert_eq!(HProduct::parse_price("$19.99"), (Some(19.99), Some("USD".to_string())));
+        assert_eq!(HProduct::parse_price("1.5"), (Some(1.5), None));
+        assert_eq!(HProduct::parse_price("1234.567"), (Some(1234.567), None));
+    }
+
     #[test]
     fn test_hproduct_empty() {
         let html = "<html><body><p>No products here</p></body></html>";
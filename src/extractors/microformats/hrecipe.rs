@@ -1,22 +1,46 @@
-use crate::microformat_extractor;
+use crate::errors::Result;
+use crate::extractors::common::datetime_utils::parse_duration;
 use crate::types::HRecipe;
 
-microformat_extractor! {
-    HRecipe, ".h-recipe" {
-        name: text(".p-name"),
-        summary: text(".p-summary"),
-        ingredient: multi_text(".p-ingredient"),
-        instructions: text(".e-instructions"),
-        duration: text(".p-duration"),
-        yield_: text(".p-yield"),
-        nutrition: text(".p-nutrition"),
-        photo: url(".u-photo"),
-        author: text(".p-author"),
-        published: date(".dt-published"),
-        category: multi_text(".p-category"),
+mod raw {
+    use crate::microformat_extractor;
+    use crate::types::HRecipe;
+
+    microformat_extractor! {
+        HRecipe, ".h-recipe" {
+            name: text(".p-name"),
+            summary: text(".p-summary"),
+            ingredient: multi_text(".p-ingredient"),
+            instructions: text(".e-instructions"),
+            duration: date(".dt-duration, .p-duration"),
+            yield_: text(".p-yield"),
+            nutrition: text(".p-nutrition"),
+            photo: url(".u-photo"),
+            author: text(".p-author"),
+            author_card: nested_hcard(".p-author.h-card"),
+            published: date(".dt-published"),
+            category: multi_text(".p-category"),
+        }
     }
 }
 
+/// Extract h-recipe items from HTML
+///
+/// `duration_seconds` is set when the duration is an ISO 8601 duration
+/// (`<time class="dt-duration" datetime="PT1H30M">`); free-text durations
+/// such as "about an hour" are kept in `duration` only. With a
+/// `p-author h-card`, `author` is the card's name rather than its full text.
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<HRecipe>> {
+    let mut recipes = raw::extract(html, base_url)?;
+    for recipe in &mut recipes {
+        recipe.duration_seconds = recipe.duration.as_deref().and_then(parse_duration);
+        if let Some(name) = recipe.author_card.as_ref().and_then(|card| card.name.clone()) {
+            recipe.author = Some(name);
+        }
+    }
+    Ok(recipes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,9 +94,42 @@ mod tests {
         let recipes = extract(html, None).unwrap();
         assert_eq!(recipes.len(), 1);
         assert_eq!(recipes[0].duration, Some("15 minutes".to_string()));
+        assert_eq!(recipes[0].duration_seconds, None);
         assert_eq!(recipes[0].yield_, Some("4 servings".to_string()));
     }
 
+    #[test]
+    fn test_hrecipe_with_ingredients_duration_yield_and_author_card() {
+        let html = r#"
+            <article class="h-recipe">
+                <h1 class="p-name">Pancakes</h1>
+                <ul>
+                    <li class="p-ingredient">1 cup flour</li>
+                    <li class="p-ingredient">1 egg</li>
+                    <li class="p-ingredient">1 cup milk</li>
+                </ul>
+                <time class="dt-duration" datetime="PT1H30M">an hour and a half</time>
+                <span class="p-yield">8 pancakes</span>
+                <span class="p-nutrition">Calories: 250, Fat: 12g</span>
+                <div class="p-author h-card">
+                    <span class="p-name">Jane Cook</span>
+                    <a class="u-url" href="/jane">Profile</a>
+                </div>
+            </article>
+        "#;
+
+        let recipe = &extract(html, Some("https://example.com/")).unwrap()[0];
+        assert_eq!(recipe.ingredient, vec!["1 cup flour", "1 egg", "1 cup milk"]);
+        assert_eq!(recipe.duration.as_deref(), Some("PT1H30M"));
+        assert_eq!(recipe.duration_seconds, Some(5_400));
+        assert_eq!(recipe.yield_.as_deref(), Some("8 pancakes"));
+        assert_eq!(recipe.nutrition.as_deref(), Some("Calories: 250, Fat: 12g"));
+
+        let card = recipe.author_card.as_ref().unwrap();
+        assert_eq!(card.url.as_deref(), Some("https://example.com/jane"));
+        assert_eq!(recipe.author.as_deref(), Some("Jane Cook"));
+    }
+
     #[test]
     fn test_hrecipe_with_photo() {
         let html = r#"
@@ -1,5 +1,7 @@
 use crate::microformat_extractor;
-use crate::types::HReview;
+use crate::types::jsonld::AggregateRating;
+use crate::types::{HReview, MicroformatItem, PropertyValue};
+use serde_json::Value;
 
 microformat_extractor! {
     HReview, ".h-review" {
@@ -19,6 +21,99 @@ microformat_extractor! {
     }
 }
 
+/// Summarize h-reviews as a Schema.org `AggregateRating`
+///
+/// `rating_value` is the mean of the reviews' ratings (clamped to each
+/// review's scale), `rating_count` the number of rated reviews and
+/// `review_count` the number of reviews. A missing best/worst falls back to
+/// the hReview 1–5 scale. If every rated review uses the same scale it is
+/// kept; otherwise each rating is rescaled to 1–5 before averaging. Returns
+/// `None` for an empty slice.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::microformats::hreview::{aggregate_rating, extract};
+///
+/// let html = r#"<div class="h-review"><span class="p-rating">4</span></div>
+///     <div class="h-review"><span class="p-rating">5</span></div>"#;
+/// let rating = aggregate_rating(&extract(html, None).unwrap()).unwrap();
+/// assert_eq!(rating.rating_value, Some(4.5));
+/// assert_eq!(rating.review_count, Some(2));
+/// ```
+pub fn aggregate_rating(reviews: &[HReview]) -> Option<AggregateRating> {
+    if reviews.is_empty() {
+        return None;
+    }
+
+    let rated: Vec<(f64, f64, f64)> = reviews
+        .iter()
+        .filter_map(|review| {
+            let rating = review.clamped_rating()?;
+            Some((rating as f64, review.worst_rating() as f64, review.best_rating() as f64))
+        })
+        .collect();
+
+    let (worst, best) = match rated.first() {
+        Some(&(_, worst, best)) if rated.iter().all(|&(_, w, b)| (w, b) == (worst, best)) => {
+            (worst, best)
+        }
+        _ => (HReview::DEFAULT_WORST as f64, HReview::DEFAULT_BEST as f64),
+    };
+    let rescale = |(rating, w, b): (f64, f64, f64)| {
+        if (w, b) == (worst, best) {
+            rating
+        } else if b == w {
+            best
+        } else {
+            worst + (rating - w) / (b - w) * (best - worst)
+        }
+    };
+
+    let mean = (!rated.is_empty())
+        .then(|| rated.iter().copied().map(rescale).sum::<f64>() / rated.len() as f64);
+    Some(AggregateRating {
+        rating_value: mean,
+        best_rating: Some(best),
+        worst_rating: Some(worst),
+        rating_count: Some(rated.len() as i32),
+        review_count: Some(reviews.len() as i32),
+        item_reviewed: None,
+    })
+}
+
+/// Read an `h-review-aggregate` item as a Schema.org `AggregateRating`
+///
+/// Maps `p-rating`, `p-best`, `p-worst`, `p-count` (reviews) and `p-votes`
+/// (ratings); a text `p-item` or the `p-name` of a nested item becomes
+/// `item_reviewed`. Missing best/worst fall back to the hReview 1–5 scale.
+/// Returns `None` if the item is not an `h-review-aggregate`. Such items come
+/// from [`extract_generic`](super::extract_generic) or the full microformats
+/// extraction.
+pub fn aggregate_rating_from_item(item: &MicroformatItem) -> Option<AggregateRating> {
+    if !item.type_.iter().any(|t| t == "h-review-aggregate") {
+        return None;
+    }
+
+    let text = |name: &str| match item.properties.get(name)?.first()? {
+        PropertyValue::Text(text) | PropertyValue::Url(text) => Some(text.trim().to_string()),
+        PropertyValue::Nested(nested) => match nested.properties.get("name")?.first()? {
+            PropertyValue::Text(name) => Some(name.trim().to_string()),
+            _ => None,
+        },
+    };
+    let number = |name: &str| text(name)?.parse::<f64>().ok().filter(|n| n.is_finite());
+    let count = |name: &str| text(name)?.parse::<i32>().ok();
+
+    Some(AggregateRating {
+        rating_value: number("rating"),
+        best_rating: Some(number("best").unwrap_or(HReview::DEFAULT_BEST as f64)),
+        worst_rating: Some(number("worst").unwrap_or(HReview::DEFAULT_WORST as f64)),
+        rating_count: count("votes"),
+        review_count: count("count"),
+        item_reviewed: text("item").map(Value::String),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,10 +223,174 @@ mod tests {
         assert_eq!(reviews[1].summary, Some("Review 2".to_string()));
     }
 
+    #[test]
+    fn test_hreview_rating_out_of_five() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-name">Solid laptop</span>
+                <span class="p-rating">4.5</span> out of <span class="p-best">5</span>
+            </div>
+        "#;
+
+        let reviews = extract(html, None).unwrap();
+        assert_eq!(reviews[0].rating, Some(4.5));
+        assert_eq!(reviews[0].best_rating(), 5.0);
+        assert_eq!(reviews[0].clamped_rating(), Some(4.5));
+        assert!(reviews[0].has_valid_rating());
+    }
+
+    #[test]
+    fn test_hreview_nested_hproduct_item() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-summary">Love it</span>
+                <div class="p-item h-product">
+                    <span class="p-name">Espresso Machine</span>
+                    <a class="u-url" href="/products/espresso">Details</a>
+                </div>
+                <span class="p-rating">5</span>
+            </div>
+        "#;
+
+        let reviews = extract(html, Some("https://shop.example.com")).unwrap();
+        assert_eq!(reviews.len(), 1);
+        assert!(reviews[0].item.is_none());
+        let product = reviews[0].item_product.as_ref().unwrap();
+        assert_eq!(product.name, Some("Espresso Machine".to_string()));
+        assert_eq!(product.url, Some("https://shop.example.com/products/espresso".to_string()));
+        assert_eq!(reviews[0].rating, Some(5.0));
+    }
+
+    #[test]
+    fn test_hreview_default_scale() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-summary">Decent</span>
+                <span class="p-rating">3</span>
+            </div>
+        "#;
+
+        let reviews = extract(html, None).unwrap();
+        assert_eq!(reviews[0].best, None);
+        assert_eq!(reviews[0].worst, None);
+        assert_eq!(reviews[0].best_rating(), HReview::DEFAULT_BEST);
+        assert_eq!(reviews[0].worst_rating(), HReview::DEFAULT_WORST);
+        assert_eq!(reviews[0].clamped_rating(), Some(3.0));
+    }
+
+    #[test]
+    fn test_hreview_rating_clamped_to_scale() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-rating">12</span>
+                <span class="p-best">10</span>
+                <span class="p-worst">0</span>
+            </div>
+        "#;
+
+        let reviews = extract(html, None).unwrap();
+        assert_eq!(reviews[0].clamped_rating(), Some(10.0));
+        assert!(!reviews[0].has_valid_rating());
+    }
+
+    #[test]
+    fn test_hreview_non_finite_scale_is_not_clamped() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-rating">4</span><span class="p-worst">NaN</span>
+            </div>
+            <div class="h-review">
+                <span class="p-rating">4</span><span class="p-best">inf</span>
+            </div>
+            <div class="h-review"><span class="p-rating">NaN</span></div>
+            <div class="h-review"><span class="p-rating">3</span></div>
+        "#;
+
+        let reviews = extract(html, None).unwrap();
+        assert!(reviews[..3].iter().all(|review| review.clamped_rating().is_none()));
+        assert!(!reviews[0].has_valid_rating());
+
+        // Only the finite review is rated
+        let rating = aggregate_rating(&reviews).unwrap();
+        assert_eq!(rating.rating_value, Some(3.0));
+        assert_eq!(rating.rating_count, Some(1));
+    }
+
     #[test]
     fn test_hreview_empty() {
         let html = "<html><body><p>No reviews here</p></body></html>";
         let reviews = extract(html, None).unwrap();
         assert_eq!(reviews.len(), 0);
     }
+
+    #[test]
+    fn test_aggregate_rating_averages_reviews() {
+        let html = r#"
+            <div class="h-review"><span class="p-rating">4</span></div>
+            <div class="h-review"><span class="p-rating">5</span></div>
+            <div class="h-review"><span class="p-rating">3</span></div>
+        "#;
+
+        let rating = aggregate_rating(&extract(html, None).unwrap()).unwrap();
+        assert_eq!(rating.rating_value, Some(4.0));
+        assert_eq!(rating.best_rating, Some(5.0));
+        assert_eq!(rating.worst_rating, Some(1.0));
+        assert_eq!(rating.rating_count, Some(3));
+        assert_eq!(rating.review_count, Some(3));
+    }
+
+    #[test]
+    fn test_aggregate_rating_single_review_keeps_its_scale() {
+        let html = r#"
+            <div class="h-review">
+                <span class="p-rating">8</span>
+                <span class="p-best">10</span>
+                <span class="p-worst">0</span>
+            </div>
+        "#;
+
+        let rating = aggregate_rating(&extract(html, None).unwrap()).unwrap();
+        assert_eq!(rating.rating_value, Some(8.0));
+        assert_eq!((rating.worst_rating, rating.best_rating), (Some(0.0), Some(10.0)));
+        assert_eq!(rating.review_count, Some(1));
+        assert!(aggregate_rating(&[]).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_rating_mixed_scales_and_unrated() {
+        let html = r#"
+            <div class="h-review"><span class="p-rating">5</span></div>
+            <div class="h-review">
+                <span class="p-rating">10</span><span class="p-best">10</span><span class="p-worst">0</span>
+            </div>
+            <div class="h-review"><span class="p-summary">No score</span></div>
+        "#;
+
+        // 10/10 rescales to 5 on the default scale
+        let rating = aggregate_rating(&extract(html, None).unwrap()).unwrap();
+        assert_eq!(rating.rating_value, Some(5.0));
+        assert_eq!(rating.best_rating, Some(5.0));
+        assert_eq!(rating.rating_count, Some(2));
+        assert_eq!(rating.review_count, Some(3));
+    }
+
+    #[test]
+    fn test_aggregate_rating_from_hreview_aggregate() {
+        let html = r#"
+            <div class="h-review-aggregate">
+                <span class="p-item">Espresso Machine</span>
+                <span class="p-rating">4.2</span> out of <span class="p-best">5</span>
+                based on <span class="p-count">87</span> reviews
+            </div>
+        "#;
+
+        let items =
+            crate::extractors::microformats::extract_generic(html, None, "h-review-aggregate");
+        let rating = aggregate_rating_from_item(&items.unwrap()[0]).unwrap();
+        assert_eq!(rating.rating_value, Some(4.2));
+        assert_eq!(rating.worst_rating, Some(1.0));
+        assert_eq!(rating.review_count, Some(87));
+        assert_eq!(rating.rating_count, None);
+        assert_eq!(rating.item_reviewed, Some(Value::from("Espresso Machine")));
+    }
 }
@@ -4,6 +4,8 @@
 //! While they have lower adoption (5-10%), they're important for the IndieWeb community
 //! and personal blogs.
 
+pub mod authorship;
+pub mod generic;
 pub mod hadr;
 pub mod hcard;
 pub mod hentry;
@@ -14,6 +16,33 @@ pub mod hproduct;
 pub mod hrecipe;
 pub mod hreview;
 
+use crate::errors::Result;
+use crate::types::MicroformatItem;
+use indexmap::IndexMap;
+use scraper::Html;
+
+pub use authorship::find_author;
+pub use generic::extract_generic;
 pub use hcard::extract as extract_hcard;
 pub use hentry::extract as extract_hentry;
 pub use hevent::extract as extract_hevent;
+
+/// Extract every microformats2 item, keyed by root type
+///
+/// The map is sorted by type name; items within a type keep document order.
+/// An item with several root classes (`h-entry h-as-article`) is listed under
+/// each of them.
+pub fn extract(
+    html: &str,
+    base_url: Option<&str>,
+) -> Result<IndexMap<String, Vec<MicroformatItem>>> {
+    crate::parser::parse_html(html, base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<IndexMap<String, Vec<MicroformatItem>>> {
+    crate::parser::parse_document(document, base_url)
+}
@@ -8,6 +8,12 @@
 //! - Phase 5: oEmbed (content embedding)
 //! - Phase 7: Microformats (5-10% adoption)
 //! - Phase 9: Dublin Core (archives and digital libraries)
+//!
+//! Each format module exposes `extract(html, base_url)`, so Rust callers that
+//! only need one format don't have to go through [`crate::extract_all`], and
+//! `extract_from_doc(&Html, base_url)` for a document the caller has already
+//! parsed (see [`crate::extract_all_from_doc`]). None of this depends on the
+//! `python` feature.
 
 pub mod common;
 
@@ -41,6 +47,24 @@ pub mod manifest;
 // rel-* link relationships
 pub mod rel_links;
 
+// Resource hints (preload, preconnect, dns-prefetch, ...)
+pub mod resource_hints;
+
+// Breadcrumb trails (JSON-LD, microdata, nav fallback)
+pub mod breadcrumbs;
+
+// Legacy geotagging meta tags (geo.position, ICBM, ...)
+pub mod geo;
+
+// OpenSearch description discovery (rel="search")
+pub mod opensearch;
+
+// Link / X-Robots-Tag / Content-Language response headers (non-HTML responses)
+pub mod headers;
+
+// Embedded app state (<script type="application/json">, e.g. __NEXT_DATA__)
+pub mod embedded_json;
+
 // Re-export microformats extractors for backward compatibility
 #[allow(unused_imports)]
 pub use microformats::{extract_hcard, extract_hentry, extract_hevent};
@@ -4,8 +4,10 @@
 //! oEmbed is used by platforms like YouTube, Vimeo, Twitter, etc.
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
 use crate::types::oembed::{OEmbedDiscovery, OEmbedEndpoint, OEmbedFormat};
+use scraper::Html;
 
 #[cfg(test)]
 mod tests;
@@ -19,12 +21,21 @@ mod tests;
 /// # Returns
 /// * `Result<OEmbedDiscovery>` - Discovered oEmbed endpoints or error
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<OEmbedDiscovery> {
-    let document = html_utils::parse_html(html);
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<OEmbedDiscovery> {
+    extract_in(Scope::document(document), base_url)
+}
+
+/// [`extract_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_in(scope: Scope, base_url: Option<&str>) -> Result<OEmbedDiscovery> {
     let mut discovery = OEmbedDiscovery::default();
 
     // Look for link tags with rel="alternate" and type containing "oembed"
     if let Ok(selector) = html_utils::create_selector("link[rel~=\"alternate\"][type][href]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(link_type), Some(href)) =
                 (html_utils::get_attr(&element, "type"), html_utils::get_attr(&element, "href"))
             {
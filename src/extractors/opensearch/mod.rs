@@ -0,0 +1,132 @@
+//! OpenSearch description discovery
+//!
+//! Finds the `<link rel="search" type="application/opensearchdescription+xml">`
+//! that lets browsers add a site's search engine, and parses the linked
+//! description document. Fetching the document is left to the caller.
+
+use crate::errors::{MicroformatError, Result};
+use crate::extractors::common::html_utils::{self, ParseMode};
+use crate::extractors::common::url_utils;
+use crate::types::opensearch::{OpenSearchDescription, OpenSearchLink, OpenSearchUrl};
+
+#[cfg(test)]
+mod tests;
+
+/// MIME type of an OpenSearch description document
+const OPENSEARCH_TYPE: &str = "application/opensearchdescription+xml";
+
+/// Discover the OpenSearch description link in HTML
+///
+/// Returns the first `<link rel="search">` whose `type` is
+/// `application/opensearchdescription+xml`, with its href resolved against
+/// `base_url`.
+///
+/// # Arguments
+/// * `html` - The HTML content to extract from
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Result<Option<OpenSearchLink>>` - The link, or `None` if the page has none
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::opensearch;
+///
+/// let html = r#"<link rel="search" type="application/opensearchdescription+xml"
+///                     href="/opensearch.xml" title="Example">"#;
+/// let link = opensearch::discover(html, Some("https://example.com")).unwrap().unwrap();
+/// assert_eq!(link.href, "https://example.com/opensearch.xml");
+/// assert_eq!(link.title.as_deref(), Some("Example"));
+/// ```
+pub fn discover(html: &str, base_url: Option<&str>) -> Result<Option<OpenSearchLink>> {
+    let doc = html_utils::parse_html(html);
+    let selector = html_utils::create_selector("link[rel~=search][type][href]")?;
+
+    let link = doc.select(&selector).find(|link| {
+        html_utils::get_attr(link, "type")
+            .is_some_and(|t| t.trim().eq_ignore_ascii_case(OPENSEARCH_TYPE))
+    });
+
+    let Some(link) = link else {
+        return Ok(None);
+    };
+    let href = html_utils::get_attr(&link, "href").unwrap_or_default();
+    let href = match base_url {
+        Some(base) => {
+            url_utils::resolve_url(Some(base), &href).map_err(MicroformatError::InvalidUrl)?
+        }
+        None => href,
+    };
+
+    Ok(Some(OpenSearchLink {
+        href,
+        title: html_utils::get_attr(&link, "title")
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty()),
+    }))
+}
+
+/// Parse an OpenSearch description document
+///
+/// Reads `ShortName`, `Description` and every `Url` template. URL templates
+/// are returned as written; `{searchTerms}` and the other parameters are not
+/// substituted.
+///
+/// # Arguments
+/// * `xml` - The OpenSearch description XML
+///
+/// # Returns
+/// * `Result<OpenSearchDescription>` - Parsed description, or an error if the
+///   document has no `<OpenSearchDescription>` root
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::opensearch;
+///
+/// let xml = r#"<OpenSearchDescription xmlns="http://a9.com/-/spec/opensearch/1.1/">
+///     <ShortName>Example</ShortName>
+///     <Url type="text/html" template="https://example.com/?q={searchTerms}"/>
+/// </OpenSearchDescription>"#;
+/// let description = opensearch::parse_opensearch(xml).unwrap();
+/// assert_eq!(description.short_name.as_deref(), Some("Example"));
+/// assert_eq!(description.urls[0].template, "https://example.com/?q={searchTerms}");
+/// ```
+pub fn parse_opensearch(xml: &str) -> Result<OpenSearchDescription> {
+    // The HTML parser lowercases element and attribute names, which also makes
+    // the lookups below case-insensitive
+    let doc = html_utils::parse_document(xml, ParseMode::Xml);
+
+    let root_selector = html_utils::create_selector("opensearchdescription")?;
+    let root = doc.select(&root_selector).next().ok_or_else(|| {
+        MicroformatError::ParseError(
+            "Invalid OpenSearch document: missing <OpenSearchDescription>".to_string(),
+        )
+    })?;
+
+    let child_text = |name: &str| -> Result<Option<String>> {
+        let selector = html_utils::create_selector(name)?;
+        Ok(root.select(&selector).next().and_then(|e| html_utils::extract_text(&e)))
+    };
+
+    let mut description = OpenSearchDescription {
+        short_name: child_text("shortname")?,
+        description: child_text("description")?,
+        urls: Vec::new(),
+    };
+
+    let url_selector = html_utils::create_selector("url[template]")?;
+    for url in root.select(&url_selector) {
+        let template = html_utils::get_attr(&url, "template").unwrap_or_default();
+        if template.trim().is_empty() {
+            continue;
+        }
+        description.urls.push(OpenSearchUrl {
+            template: template.trim().to_string(),
+            r#type: html_utils::get_attr(&url, "type"),
+            rel: html_utils::get_attr(&url, "rel"),
+            method: html_utils::get_attr(&url, "method"),
+        });
+    }
+
+    Ok(description)
+}
@@ -0,0 +1,84 @@
+//! Tests for OpenSearch discovery and description parsing
+
+use super::*;
+
+// Discovery tests
+
+#[test]
+fn test_discover_resolves_href_and_title() {
+    let html = r#"<html><head>
+        <link rel="search" type="application/opensearchdescription+xml"
+              href="/opensearch.xml" title="Example Search">
+    </head></html>"#;
+    let link = discover(html, Some("https://example.com/docs/")).unwrap().unwrap();
+    assert_eq!(link.href, "https://example.com/opensearch.xml");
+    assert_eq!(link.title.as_deref(), Some("Example Search"));
+}
+
+#[test]
+fn test_discover_ignores_other_search_links() {
+    let html = r#"
+        <link rel="search" href="/search.html">
+        <link rel="search" type="application/atom+xml" href="/search.atom">
+    "#;
+    assert!(discover(html, Some("https://example.com")).unwrap().is_none());
+}
+
+#[test]
+fn test_discover_multi_token_rel_and_case_insensitive_type() {
+    let html = r#"<link rel="nofollow search" type="Application/OpenSearchDescription+XML"
+                        href="https://cdn.example.com/os.xml">"#;
+    let link = discover(html, None).unwrap().unwrap();
+    assert_eq!(link.href, "https://cdn.example.com/os.xml");
+    assert!(link.title.is_none());
+}
+
+#[test]
+fn test_discover_none() {
+    let html = r#"<html><head><title>No search</title></head></html>"#;
+    assert!(discover(html, None).unwrap().is_none());
+}
+
+// Description parsing tests
+
+#[test]
+fn test_parse_minimal_description() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <OpenSearchDescription xmlns="http://a9.com/-/spec/opensearch/1.1/">
+            <ShortName>Example</ShortName>
+            <Description>Search example.com</Description>
+            <InputEncoding>UTF-8</InputEncoding>
+            <Url type="text/html" method="get" template="https://example.com/search?q={searchTerms}"/>
+            <Url type="application/x-suggestions+json" rel="suggestions"
+                 template="https://example.com/suggest?q={searchTerms}"/>
+        </OpenSearchDescription>"#;
+
+    let description = parse_opensearch(xml).unwrap();
+    assert_eq!(description.short_name.as_deref(), Some("Example"));
+    assert_eq!(description.description.as_deref(), Some("Search example.com"));
+    assert_eq!(description.urls.len(), 2);
+
+    assert_eq!(description.urls[0].template, "https://example.com/search?q={searchTerms}");
+    assert_eq!(description.urls[0].r#type.as_deref(), Some("text/html"));
+    assert_eq!(description.urls[0].method.as_deref(), Some("get"));
+    assert!(description.urls[0].rel.is_none());
+
+    assert_eq!(description.urls[1].rel.as_deref(), Some("suggestions"));
+}
+
+#[test]
+fn test_parse_skips_urls_without_template() {
+    let xml = r#"<OpenSearchDescription>
+        <ShortName>Example</ShortName>
+        <Url type="text/html" template=""/>
+    </OpenSearchDescription>"#;
+    let description = parse_opensearch(xml).unwrap();
+    assert!(description.urls.is_empty());
+    assert!(description.description.is_none());
+}
+
+#[test]
+fn test_parse_rejects_non_opensearch_document() {
+    let xml = r#"<rss version="2.0"><channel><title>Feed</title></channel></rss>"#;
+    assert!(parse_opensearch(xml).is_err());
+}
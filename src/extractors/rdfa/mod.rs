@@ -4,8 +4,10 @@
 //! RDFa is a W3C standard with 62% desktop adoption.
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use crate::types::rdfa::{RdfaItem, RdfaValue};
+use crate::extractors::common::html_utils::{self, ParseMode, Scope};
+use crate::extractors::common::{url_utils, MAX_NESTING_DEPTH};
+use crate::types::rdfa::{RdfaItem, RdfaValue, Triple};
+use indexmap::IndexMap;
 use scraper::{ElementRef, Html};
 use std::collections::HashMap;
 
@@ -100,7 +102,44 @@ impl PrefixContext {
 /// assert_eq!(items.len(), 1);
 /// ```
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<RdfaItem>> {
-    let doc = html_utils::parse_html(html);
+    extract_with_mode(html, base_url, ParseMode::Html5)
+}
+
+/// Extract all RDFa items, parsing the document in the given mode
+///
+/// Use [`ParseMode::Xml`] for XHTML served as XML: self-closing elements then
+/// keep their siblings, and `xml:lang` wins over `lang` on literals.
+///
+/// # Arguments
+/// * `html` - The HTML or XHTML content to extract from
+/// * `base_url` - Optional base URL for resolving relative URLs
+/// * `mode` - How to parse the markup
+///
+/// # Returns
+/// * `Result<Vec<RdfaItem>>` - List of extracted RDFa items or error
+pub fn extract_with_mode(
+    html: &str,
+    base_url: Option<&str>,
+    mode: ParseMode,
+) -> Result<Vec<RdfaItem>> {
+    extract_from_doc(&html_utils::parse_document(html, mode), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(doc: &Html, base_url: Option<&str>) -> Result<Vec<RdfaItem>> {
+    Ok(extract_limited_from_doc(Scope::document(doc), base_url, usize::MAX)?.0)
+}
+
+/// [`extract_from_doc`], stopping after `limit` root items
+///
+/// The flag is set when the document has more root items than `limit`. Only
+/// items within `scope` are read; prefixes declared anywhere in the document
+/// apply.
+pub(crate) fn extract_limited_from_doc(
+    scope: Scope,
+    base_url: Option<&str>,
+    limit: usize,
+) -> Result<(Vec<RdfaItem>, bool)> {
     let mut items = Vec::new();
 
     // Create prefix context with default prefixes
@@ -108,71 +147,169 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<Vec<RdfaItem>> {
 
     // Collect all prefix definitions from the document
     let prefix_selector = html_utils::create_selector("[prefix]")?;
-    for element in doc.select(&prefix_selector) {
+    for element in scope.html().select(&prefix_selector) {
         if let Some(prefix_attr) = html_utils::get_attr(&element, "prefix") {
             prefix_ctx.parse_prefix_attr(&prefix_attr);
         }
     }
 
     // Find all RDFa root elements (elements with typeof or vocab)
-    let roots = find_rdfa_roots(&doc)?;
-
-    for root in roots {
-        let item = extract_item_with_context(&root, base_url, &prefix_ctx)?;
+    // One root past the limit is enough to tell that the document has more
+    let roots = find_rdfa_roots(scope, limit.saturating_add(1))?;
+
+    let truncated = roots.len() > limit;
+    for root in roots.into_iter().take(limit) {
+        let inherited_vocab = ancestor_vocab(&root);
+        let item =
+            extract_item_with_context(&root, base_url, &prefix_ctx, inherited_vocab.as_deref(), 0)?;
         items.push(item);
     }
 
-    Ok(items)
+    Ok((items, truncated))
+}
+
+/// `rdf:type`, the predicate emitted for `typeof`
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// Extract RDFa as RDF triples
+///
+/// Subjects come from `about` (or `resource`, `href` or `src` on a `typeof`
+/// element); items without one get blank node identifiers (`_:b0`, `_:b1`,
+/// ...) numbered in document order. Predicates and types are expanded against the active `vocab`.
+///
+/// # Arguments
+/// * `html` - The HTML content to extract from
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Result<Vec<Triple>>` - Triples in document order
+pub fn extract_triples(html: &str, base_url: Option<&str>) -> Result<Vec<Triple>> {
+    Ok(triples(&extract(html, base_url)?))
+}
+
+/// Convert extracted RDFa items into RDF triples
+pub fn triples(items: &[RdfaItem]) -> Vec<Triple> {
+    let mut triples = Vec::new();
+    let mut blank_nodes = 0;
+    for item in items {
+        item_triples(item, None, &mut blank_nodes, &mut triples);
+    }
+    triples
+}
+
+/// Emit the triples of one item (and its nested items), returning its subject
+fn item_triples(
+    item: &RdfaItem,
+    inherited_vocab: Option<&str>,
+    blank_nodes: &mut usize,
+    triples: &mut Vec<Triple>,
+) -> String {
+    let vocab = item.vocab.as_deref().or(inherited_vocab);
+    let subject = match item.about {
+        Some(ref about) => about.clone(),
+        None => {
+            let id = format!("_:b{}", blank_nodes);
+            *blank_nodes += 1;
+            id
+        }
+    };
+
+    for type_name in item.type_of.iter().flatten() {
+        triples.push(Triple {
+            subject: subject.clone(),
+            predicate: RDF_TYPE.to_string(),
+            object: RdfaValue::Resource(expand_term(type_name, vocab)),
+        });
+    }
+
+    for (name, values) in &item.properties {
+        let predicate = expand_term(name, vocab);
+        for value in values {
+            let object = match value {
+                RdfaValue::Item(nested) => {
+                    RdfaValue::Resource(item_triples(nested, vocab, blank_nodes, triples))
+                }
+                other => other.clone(),
+            };
+            triples.push(Triple { subject: subject.clone(), predicate: predicate.clone(), object });
+        }
+    }
+
+    subject
+}
+
+/// Expand a term against the vocabulary unless it is already an IRI or CURIE
+fn expand_term(term: &str, vocab: Option<&str>) -> String {
+    match vocab {
+        Some(vocab) if !term.contains(':') => format!("{}{}", vocab, term),
+        _ => term.to_string(),
+    }
 }
 
-/// Find all RDFa root elements in the document
+/// Find up to `max` RDFa root elements in the document
 ///
 /// Root elements are those with `typeof` or `vocab` attributes
-fn find_rdfa_roots(doc: &Html) -> Result<Vec<ElementRef<'_>>> {
+fn find_rdfa_roots(scope: Scope, max: usize) -> Result<Vec<ElementRef>> {
     let mut roots = Vec::new();
 
     // Find elements with typeof attribute (type declaration)
     let typeof_selector = html_utils::create_selector("[typeof]")?;
-    for element in doc.select(&typeof_selector) {
-        // Only add if not nested within another typeof (we'll handle nesting later)
-        if !is_nested_typeof(&element) {
-            roots.push(element);
-        }
-    }
+    // Only add if not nested within another typeof (we'll handle nesting later)
+    let typed = scope.select(&typeof_selector).filter(|element| !is_nested_typeof(scope, *element));
 
-    // Find elements with vocab attribute that don't have typeof
+    // Then elements with vocab attribute that don't have typeof
     let vocab_selector = html_utils::create_selector("[vocab]:not([typeof])")?;
-    for element in doc.select(&vocab_selector) {
-        // Only add if not already in roots
-        if !roots.iter().any(|r| r.id() == element.id()) {
-            roots.push(element);
-        }
-    }
+    roots.extend(typed.chain(scope.select(&vocab_selector)).take(max));
 
     Ok(roots)
 }
 
-/// Check if an element is nested within another typeof element
-fn is_nested_typeof(element: &ElementRef) -> bool {
-    let mut current = element.parent();
-    while let Some(parent) = current {
-        if let Some(parent_elem) = parent.value().as_element() {
-            if parent_elem.attr("typeof").is_some() {
-                return true;
-            }
-        }
-        current = parent.parent();
+/// The `vocab` of the nearest ancestor declaring one
+///
+/// An empty `vocab=""` clears the default vocabulary for its subtree.
+fn ancestor_vocab(element: &ElementRef) -> Option<String> {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find_map(|ancestor| ancestor.value().attr("vocab"))
+        .map(str::trim)
+        .filter(|vocab| !vocab.is_empty())
+        .map(String::from)
+}
+
+/// Check if an element is nested within another typeof element in the scope
+fn is_nested_typeof(scope: Scope, element: ElementRef) -> bool {
+    scope.ancestors(element).any(|ancestor| ancestor.value().attr("typeof").is_some())
+}
+
+/// The vocabulary in effect on an element: its own `vocab`, else `inherited`
+///
+/// An empty `vocab=""` clears the default vocabulary.
+fn active_vocab<'a>(element: &ElementRef<'a>, inherited: Option<&'a str>) -> Option<&'a str> {
+    match element.value().attr("vocab") {
+        Some(vocab) => Some(vocab.trim()).filter(|vocab| !vocab.is_empty()),
+        None => inherited,
     }
-    false
 }
 
 /// Extract a single RDFa item from a root element with prefix context
+///
+/// Bare `typeof` and `property` terms are expanded against the vocabulary in
+/// effect where they appear, whether it is declared on the item, inside it or
+/// on an ancestor (`inherited_vocab`). An item declaring `vocab` also records
+/// it in [`RdfaItem::vocab`].
+///
+/// `depth` counts the enclosing items; nested items beyond [`MAX_NESTING_DEPTH`]
+/// are dropped.
 fn extract_item_with_context(
     element: &ElementRef,
     base_url: Option<&str>,
     prefix_ctx: &PrefixContext,
+    inherited_vocab: Option<&str>,
+    depth: usize,
 ) -> Result<RdfaItem> {
     let mut item = RdfaItem::new();
+    let vocab = active_vocab(element, inherited_vocab);
 
     // Extract vocab attribute
     if let Some(vocab) = html_utils::get_attr(element, "vocab") {
@@ -181,14 +318,23 @@ fn extract_item_with_context(
 
     // Extract typeof attribute (can be space-separated list of types with CURIEs)
     if let Some(type_attr) = html_utils::get_attr(element, "typeof") {
-        let types = prefix_ctx.expand_curie_list(&type_attr);
+        let types: Vec<String> = prefix_ctx
+            .expand_curie_list(&type_attr)
+            .iter()
+            .map(|type_name| expand_term(type_name, vocab))
+            .collect();
         if !types.is_empty() {
             item = item.with_type(types);
         }
     }
 
-    // Extract about attribute (subject URI, can be CURIE)
-    if let Some(about) = html_utils::get_attr(element, "about") {
+    // Extract about attribute (subject URI, can be CURIE); on a typeof element
+    // without about, resource (else href, else src) names the new subject instead
+    let about = html_utils::get_attr(element, "about").or_else(|| {
+        element.value().attr("typeof")?;
+        ["resource", "href", "src"].iter().find_map(|attr| html_utils::get_attr(element, attr))
+    });
+    if let Some(about) = about {
         // First expand CURIE if applicable
         let expanded = prefix_ctx.expand_curie(&about);
         // Then resolve URL if base_url is provided
@@ -201,47 +347,58 @@ fn extract_item_with_context(
     }
 
     // Extract properties from this element and descendants
-    let properties = extract_properties_with_context(element, base_url, prefix_ctx)?;
+    let properties = extract_properties_with_context(element, base_url, prefix_ctx, vocab, depth)?;
     item.properties = properties;
 
     Ok(item)
 }
 
-/// Extract all properties from an element and its descendants with prefix context
-fn extract_properties_with_context(
-    element: &ElementRef,
+/// Extract all properties from an element's descendants with prefix context
+///
+/// The element's own `property` is skipped: it describes the item's relation to
+/// its parent. Descendants are walked with an explicit stack so deeply nested
+/// markup cannot exhaust the call stack; the subtree of a nested item is left
+/// to that item. `vocab` is the vocabulary in effect on the element; a
+/// `vocab` on a descendant takes over for its subtree.
+fn extract_properties_with_context<'a>(
+    element: &ElementRef<'a>,
     base_url: Option<&str>,
     prefix_ctx: &PrefixContext,
-) -> Result<HashMap<String, Vec<RdfaValue>>> {
-    let mut properties: HashMap<String, Vec<RdfaValue>> = HashMap::new();
-
-    // Check if this element has a property attribute (can be CURIE)
-    if let Some(property_name) = html_utils::get_attr(element, "property") {
-        // Expand CURIE in property name
-        let expanded_name = prefix_ctx.expand_curie(&property_name);
-        let value = extract_property_value_with_context(element, base_url, prefix_ctx)?;
-        properties.entry(expanded_name).or_default().push(value);
-    }
-
-    // Recursively extract from children
-    for child in element.children() {
-        if let Some(child_element) = ElementRef::wrap(child) {
-            // Skip nested typeof elements - they will be extracted as separate items
-            if html_utils::get_attr(&child_element, "typeof").is_some() {
-                // This is a nested item
-                if html_utils::get_attr(element, "property").is_some() {
-                    // Parent has property, so this nested item is the value
-                    continue; // Will be handled by extract_property_value_with_context
-                }
+    vocab: Option<&'a str>,
+    depth: usize,
+) -> Result<IndexMap<String, Vec<RdfaValue>>> {
+    let mut properties: IndexMap<String, Vec<RdfaValue>> = IndexMap::new();
+
+    // Children are pushed in reverse so they are visited in document order,
+    // each with the vocabulary its parent has in effect
+    let mut stack: Vec<(ElementRef, Option<&str>)> =
+        element.children().rev().filter_map(ElementRef::wrap).map(|e| (e, vocab)).collect();
+
+    while let Some((current, parent_vocab)) = stack.pop() {
+        let vocab = active_vocab(&current, parent_vocab);
+        // Check if this element has a property attribute (can be CURIE)
+        if let Some(property_name) = html_utils::get_attr(&current, "property") {
+            // A typeof element is a nested item even with resource, href or
+            // src, which then name its subject; only content makes it a literal
+            let is_nested_item = html_utils::get_attr(&current, "typeof").is_some()
+                && current.value().attr("content").is_none();
+            if is_nested_item && depth >= MAX_NESTING_DEPTH {
+                continue;
             }
 
-            // Extract properties from child
-            let child_properties =
-                extract_properties_with_context(&child_element, base_url, prefix_ctx)?;
-            for (key, values) in child_properties {
-                properties.entry(key).or_default().extend(values);
+            // Expand CURIE in property name
+            let expanded_name = expand_term(&prefix_ctx.expand_curie(&property_name), vocab);
+            let value =
+                extract_property_value_with_context(&current, base_url, prefix_ctx, vocab, depth)?;
+            properties.entry(expanded_name).or_default().push(value);
+
+            // The nested item already holds the properties of its descendants
+            if is_nested_item {
+                continue;
             }
         }
+
+        stack.extend(current.children().rev().filter_map(ElementRef::wrap).map(|e| (e, vocab)));
     }
 
     Ok(properties)
@@ -252,11 +409,13 @@ fn extract_property_value_with_context(
     element: &ElementRef,
     base_url: Option<&str>,
     prefix_ctx: &PrefixContext,
+    inherited_vocab: Option<&str>,
+    depth: usize,
 ) -> Result<RdfaValue> {
     // Priority order for value extraction:
     // 1. content attribute (highest priority)
-    // 2. resource, href, src attributes (for URIs, can be CURIEs)
-    // 3. Check for nested typeof (nested item)
+    // 2. Check for nested typeof (nested item, named by its resource/href/src)
+    // 3. resource, href, src attributes (for URIs, can be CURIEs)
     // 4. Text content (lowest priority)
 
     // 1. Check for content attribute override
@@ -266,10 +425,17 @@ fn extract_property_value_with_context(
             let expanded_datatype = prefix_ctx.expand_curie(&datatype);
             return Ok(RdfaValue::TypedLiteral { value: content, datatype: expanded_datatype });
         }
-        return Ok(RdfaValue::Literal(content));
+        return Ok(literal(element, content));
+    }
+
+    // 2. Check for nested typeof (nested RDFa item)
+    if html_utils::get_attr(element, "typeof").is_some() {
+        let nested_item =
+            extract_item_with_context(element, base_url, prefix_ctx, inherited_vocab, depth + 1)?;
+        return Ok(RdfaValue::Item(Box::new(nested_item)));
     }
 
-    // 2. Check for resource/href/src attributes (URI values, can be CURIEs)
+    // 3. Check for resource/href/src attributes (URI values, can be CURIEs)
     for attr in &["resource", "href", "src"] {
         if let Some(uri) = html_utils::get_attr(element, attr) {
             // First expand CURIE if applicable
@@ -284,12 +450,6 @@ fn extract_property_value_with_context(
         }
     }
 
-    // 3. Check for nested typeof (nested RDFa item)
-    if html_utils::get_attr(element, "typeof").is_some() {
-        let nested_item = extract_item_with_context(element, base_url, prefix_ctx)?;
-        return Ok(RdfaValue::Item(Box::new(nested_item)));
-    }
-
     // 4. Extract text content
     if let Some(text) = html_utils::extract_text(element) {
         // Check if there's a datatype attribute (can be CURIE)
@@ -297,13 +457,27 @@ fn extract_property_value_with_context(
             let expanded_datatype = prefix_ctx.expand_curie(&datatype);
             return Ok(RdfaValue::TypedLiteral { value: text, datatype: expanded_datatype });
         }
-        return Ok(RdfaValue::Literal(text));
+        return Ok(literal(element, text));
     }
 
     // Fallback to empty literal
     Ok(RdfaValue::Literal(String::new()))
 }
 
+/// A plain literal, language-tagged when the property element declares `lang`
+///
+/// Only the element's own attribute is used; the document language inherited
+/// from ancestors is not applied, so untagged pages keep plain literals.
+fn literal(element: &ElementRef, value: String) -> RdfaValue {
+    let language = html_utils::get_attr(element, "lang")
+        .or_else(|| html_utils::get_attr(element, "xml:lang"))
+        .filter(|lang| !lang.trim().is_empty());
+    match language {
+        Some(language) => RdfaValue::LangLiteral { value, language },
+        None => RdfaValue::Literal(value),
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -329,11 +503,10 @@ mod unit_tests {
         let result = extract(html, None).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].vocab, Some("https://schema.org/".to_string()));
-        assert_eq!(result[0].type_of, Some(vec!["Person".to_string()]));
+        assert_eq!(result[0].type_of, Some(vec!["https://schema.org/Person".to_string()]));
     }
 
     #[test]
-    #[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
     fn test_extract_multiple_types() {
         let html = r#"<div typeof="Person Employee" property="name">Jane</div>"#;
         let result = extract(html, None).unwrap();
@@ -441,7 +614,6 @@ mod unit_tests {
     }
 
     #[test]
-    #[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
     fn test_extract_nested_typeof() {
         let html = r#"
             <div typeof="Person">
@@ -501,7 +673,7 @@ mod unit_tests {
         let doc = html_utils::parse_html(html);
         let selector = html_utils::create_selector("#nested").unwrap();
         let element = doc.select(&selector).next().unwrap();
-        assert!(is_nested_typeof(&element));
+        assert!(is_nested_typeof(Scope::document(&doc), element));
     }
 
     #[test]
@@ -510,6 +682,6 @@ mod unit_tests {
         let doc = html_utils::parse_html(html);
         let selector = html_utils::create_selector("#root").unwrap();
         let element = doc.select(&selector).next().unwrap();
-        assert!(!is_nested_typeof(&element));
+        assert!(!is_nested_typeof(Scope::document(&doc), element));
     }
 }
@@ -16,7 +16,7 @@ fn test_rdfa_basic_vocab_typeof_property() {
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].vocab, Some("https://schema.org/".to_string()));
-    assert_eq!(result[0].type_of, Some(vec!["Person".to_string()]));
+    assert_eq!(result[0].type_of, Some(vec!["https://schema.org/Person".to_string()]));
     assert_eq!(result[0].properties.len(), 2);
 }
 
@@ -32,9 +32,9 @@ fn test_rdfa_schema_org_person() {
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
     let item = &result[0];
-    assert!(item.properties.contains_key("name"));
-    assert!(item.properties.contains_key("email"));
-    assert!(item.properties.contains_key("url"));
+    assert!(item.properties.contains_key("https://schema.org/name"));
+    assert!(item.properties.contains_key("https://schema.org/email"));
+    assert!(item.properties.contains_key("https://schema.org/url"));
 }
 
 #[test]
@@ -48,7 +48,7 @@ fn test_rdfa_schema_org_product() {
     "#;
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
-    assert_eq!(result[0].type_of, Some(vec!["Product".to_string()]));
+    assert_eq!(result[0].type_of, Some(vec!["https://schema.org/Product".to_string()]));
     assert_eq!(result[0].properties.len(), 3);
 }
 
@@ -63,7 +63,7 @@ fn test_rdfa_schema_org_article() {
     "#;
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
-    assert_eq!(result[0].type_of, Some(vec!["Article".to_string()]));
+    assert_eq!(result[0].type_of, Some(vec!["https://schema.org/Article".to_string()]));
 }
 
 // Value type tests
@@ -148,7 +148,6 @@ fn test_rdfa_datatype_with_content() {
 // Nested item tests
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_nested_typeof() {
     let html = r#"
         <div typeof="Person">
@@ -172,7 +171,6 @@ fn test_rdfa_nested_typeof() {
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items - requires architectural changes to prevent infinite recursion
 fn test_rdfa_deeply_nested() {
     let html = r#"
         <div typeof="Organization">
@@ -238,7 +236,7 @@ fn test_rdfa_property_without_typeof() {
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
     // Should still extract the property even without typeof
-    assert!(result[0].properties.contains_key("name"));
+    assert!(result[0].properties.contains_key("https://schema.org/name"));
 }
 
 #[test]
@@ -332,7 +330,6 @@ fn test_rdfa_absolute_url_unchanged() {
 // Real-world example tests
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_real_world_person() {
     let html = r#"
         <div vocab="https://schema.org/" typeof="Person">
@@ -349,16 +346,15 @@ fn test_rdfa_real_world_person() {
     assert_eq!(result.len(), 1);
     let item = &result[0];
     assert_eq!(item.vocab, Some("https://schema.org/".to_string()));
-    assert_eq!(item.type_of, Some(vec!["Person".to_string()]));
-    assert!(item.properties.contains_key("name"));
-    assert!(item.properties.contains_key("jobTitle"));
-    assert!(item.properties.contains_key("worksFor"));
-    assert!(item.properties.contains_key("url"));
-    assert!(item.properties.contains_key("email"));
+    assert_eq!(item.type_of, Some(vec!["https://schema.org/Person".to_string()]));
+    assert!(item.properties.contains_key("https://schema.org/name"));
+    assert!(item.properties.contains_key("https://schema.org/jobTitle"));
+    assert!(item.properties.contains_key("https://schema.org/worksFor"));
+    assert!(item.properties.contains_key("https://schema.org/url"));
+    assert!(item.properties.contains_key("https://schema.org/email"));
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_real_world_event() {
     let html = r#"
         <div vocab="https://schema.org/" typeof="Event">
@@ -377,14 +373,13 @@ fn test_rdfa_real_world_event() {
     "#;
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
-    assert_eq!(result[0].type_of, Some(vec!["Event".to_string()]));
-    assert!(result[0].properties.contains_key("name"));
-    assert!(result[0].properties.contains_key("startDate"));
-    assert!(result[0].properties.contains_key("location"));
+    assert_eq!(result[0].type_of, Some(vec!["https://schema.org/Event".to_string()]));
+    assert!(result[0].properties.contains_key("https://schema.org/name"));
+    assert!(result[0].properties.contains_key("https://schema.org/startDate"));
+    assert!(result[0].properties.contains_key("https://schema.org/location"));
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_real_world_breadcrumb() {
     let html = r#"
         <ol vocab="https://schema.org/" typeof="BreadcrumbList">
@@ -400,8 +395,8 @@ fn test_rdfa_real_world_breadcrumb() {
     "#;
     let result = extract(html, None).unwrap();
     assert_eq!(result.len(), 1);
-    assert_eq!(result[0].type_of, Some(vec!["BreadcrumbList".to_string()]));
-    assert!(result[0].properties.contains_key("itemListElement"));
+    assert_eq!(result[0].type_of, Some(vec!["https://schema.org/BreadcrumbList".to_string()]));
+    assert!(result[0].properties.contains_key("https://schema.org/itemListElement"));
 }
 
 // Unicode and special character tests
@@ -465,7 +460,6 @@ fn test_rdfa_mixed_value_types() {
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_nested_with_vocab_inheritance() {
     let html = r#"
         <div vocab="https://schema.org/" typeof="Organization">
@@ -482,7 +476,7 @@ fn test_rdfa_nested_with_vocab_inheritance() {
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
+#[ignore] // TODO: the vocab-only container is reported as a third (empty) item
 fn test_rdfa_sibling_items() {
     let html = r#"
         <div vocab="https://schema.org/">
@@ -593,7 +587,6 @@ fn test_rdfa_about_with_prefix() {
 }
 
 #[test]
-#[ignore] // TODO: Fix stack overflow in deeply nested RDFa items
 fn test_rdfa_nested_with_prefixes() {
     let html = r#"
         <div prefix="ex: http://example.com/" typeof="ex:Organization">
@@ -621,3 +614,233 @@ fn test_rdfa_mixed_prefixes_and_full_uris() {
     assert!(result[0].properties.contains_key("https://schema.org/name"));
     assert!(result[0].properties.contains_key("https://schema.org/jobTitle"));
 }
+
+// Triple extraction tests
+
+#[test]
+fn test_triples_person_with_about() {
+    let html = r#"
+        <div vocab="https://schema.org/" typeof="Person" about="/people/jane">
+            <span property="name">Jane Doe</span>
+            <a property="url" href="https://jane.example/">Homepage</a>
+        </div>
+    "#;
+    let triples = extract_triples(html, Some("https://example.com/")).unwrap();
+    let subject = "https://example.com/people/jane".to_string();
+    assert_eq!(
+        triples,
+        vec![
+            Triple {
+                subject: subject.clone(),
+                predicate: RDF_TYPE.to_string(),
+                object: RdfaValue::Resource("https://schema.org/Person".to_string()),
+            },
+            Triple {
+                subject: subject.clone(),
+                predicate: "https://schema.org/name".to_string(),
+                object: RdfaValue::Literal("Jane Doe".to_string()),
+            },
+            Triple {
+                subject,
+                predicate: "https://schema.org/url".to_string(),
+                object: RdfaValue::Resource("https://jane.example/".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_triples_blank_node_subjects() {
+    let html = r#"
+        <div vocab="https://schema.org/" typeof="Person">
+            <span property="name">Jane Doe</span>
+        </div>
+        <div vocab="https://schema.org/" typeof="Person">
+            <span property="name">John Roe</span>
+        </div>
+    "#;
+    let triples = extract_triples(html, None).unwrap();
+    let subjects: Vec<&str> = triples.iter().map(|t| t.subject.as_str()).collect();
+    assert_eq!(subjects, vec!["_:b0", "_:b0", "_:b1", "_:b1"]);
+    assert_eq!(triples[3].object, RdfaValue::Literal("John Roe".to_string()));
+}
+
+#[test]
+fn test_triples_resource_on_typeof_is_subject() {
+    let html = r##"<div vocab="https://schema.org/" typeof="Book" resource="#book">
+        <span property="name">Dune</span></div>"##;
+    let triples = extract_triples(html, Some("https://example.com/books")).unwrap();
+    assert!(triples.iter().all(|t| t.subject == "https://example.com/books#book"));
+}
+
+#[test]
+fn test_nested_typeof_with_resource_is_an_item() {
+    let html = r#"<div vocab="https://schema.org/" typeof="Book">
+        <div property="author" typeof="Person" resource="/jane">
+            <span property="name">Jane</span>
+        </div>
+    </div>"#;
+    let items = extract(html, Some("https://example.com/")).unwrap();
+    let book = &items[0];
+    assert!(!book.properties.contains_key("https://schema.org/name"));
+    match &book.properties["https://schema.org/author"][0] {
+        RdfaValue::Item(author) => {
+            assert_eq!(author.about, Some("https://example.com/jane".to_string()));
+            assert!(author.properties.contains_key("https://schema.org/name"));
+        }
+        other => panic!("Expected nested item, got {:?}", other),
+    }
+
+    let triples = extract_triples(html, Some("https://example.com/")).unwrap();
+    let name = triples.iter().find(|t| t.predicate == "https://schema.org/name").unwrap();
+    assert_eq!(name.subject, "https://example.com/jane");
+}
+
+#[test]
+fn test_triples_keep_expanded_curies() {
+    let html = r#"<div typeof="foaf:Person"><span property="foaf:name">Jane</span></div>"#;
+    let triples = extract_triples(html, None).unwrap();
+    assert_eq!(
+        triples[0].object,
+        RdfaValue::Resource("http://xmlns.com/foaf/0.1/Person".to_string())
+    );
+    assert_eq!(triples[1].predicate, "http://xmlns.com/foaf/0.1/name");
+}
+
+#[test]
+fn test_xml_lang_html5_vs_xml_mode() {
+    let html = r#"<div vocab="https://schema.org/" typeof="Book">
+        <span property="name" lang="en" xml:lang="fr">Le Petit Prince</span>
+    </div>"#;
+
+    // In HTML syntax xml:lang has no effect, so lang wins
+    let items = extract(html, None).unwrap();
+    assert_eq!(
+        items[0].properties["https://schema.org/name"][0],
+        RdfaValue::LangLiteral { value: "Le Petit Prince".to_string(), language: "en".to_string() }
+    );
+
+    // In XML, xml:lang takes precedence
+    let items = extract_with_mode(html, None, ParseMode::Xml).unwrap();
+    assert_eq!(
+        items[0].properties["https://schema.org/name"][0],
+        RdfaValue::LangLiteral { value: "Le Petit Prince".to_string(), language: "fr".to_string() }
+    );
+}
+
+#[test]
+fn test_xml_mode_self_closing_property_keeps_siblings() {
+    let html = r#"<div vocab="https://schema.org/" typeof="Person">
+        <span property="jobTitle"/>
+        <span property="name" xml:lang="fr">Jeanne</span>
+    </div>"#;
+
+    // HTML5 nests the name span inside the "self-closed" jobTitle span
+    let items = extract(html, None).unwrap();
+    assert_eq!(
+        items[0].properties["https://schema.org/jobTitle"][0],
+        RdfaValue::Literal("Jeanne".to_string())
+    );
+
+    let items = extract_with_mode(html, None, ParseMode::Xml).unwrap();
+    assert_eq!(
+        items[0].properties["https://schema.org/jobTitle"][0],
+        RdfaValue::Literal(String::new())
+    );
+    assert_eq!(
+        items[0].properties["https://schema.org/name"][0],
+        RdfaValue::LangLiteral { value: "Jeanne".to_string(), language: "fr".to_string() }
+    );
+}
+
+#[test]
+fn test_xml_mode_uppercase_attributes() {
+    let xhtml = r#"<div VOCAB="https://schema.org/" TYPEOF="Person">
+        <span PROPERTY="name" xml:lang="en">Jane</span>
+        <a Property="url" HREF="https://example.com/jane"/>
+    </div>"#;
+    let items = extract_with_mode(xhtml, None, ParseMode::Xml).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].type_of, Some(vec!["https://schema.org/Person".to_string()]));
+    assert_eq!(
+        items[0].properties["https://schema.org/name"][0],
+        RdfaValue::LangLiteral { value: "Jane".to_string(), language: "en".to_string() }
+    );
+    assert_eq!(
+        items[0].properties["https://schema.org/url"][0],
+        RdfaValue::Resource("https://example.com/jane".to_string())
+    );
+}
+
+// Vocabulary inheritance
+
+#[test]
+fn test_inherited_vocab_expands_bare_terms() {
+    let html = r#"<body vocab="https://schema.org/">
+        <article>
+            <span typeof="Person"><span property="name">Jane Doe</span></span>
+        </article>
+    </body>"#;
+    let items = extract(html, None).unwrap();
+    let person = items.iter().find(|item| item.type_of.is_some()).unwrap();
+
+    assert_eq!(person.type_of, Some(vec!["https://schema.org/Person".to_string()]));
+    assert_eq!(person.vocab, None);
+    assert_eq!(
+        person.properties["https://schema.org/name"],
+        vec![RdfaValue::Literal("Jane Doe".to_string())]
+    );
+}
+
+#[test]
+fn test_nested_vocab_overrides_inherited_vocab() {
+    let html = r#"<div vocab="https://schema.org/">
+        <div vocab="http://xmlns.com/foaf/0.1/">
+            <span typeof="Person"><span property="name">Jane</span></span>
+        </div>
+        <div vocab=""><span typeof="Person">Bare</span></div>
+    </div>"#;
+    let items = extract(html, None).unwrap();
+    let types: Vec<_> = items.iter().filter_map(|item| item.type_of.clone()).collect();
+
+    assert_eq!(
+        types,
+        vec![vec!["http://xmlns.com/foaf/0.1/Person".to_string()], vec!["Person".to_string()]]
+    );
+    let foaf_person = items.iter().find(|item| item.type_of == Some(types[0].clone())).unwrap();
+    assert!(foaf_person.properties.contains_key("http://xmlns.com/foaf/0.1/name"));
+}
+
+#[test]
+fn test_vocab_inside_item_applies_to_its_properties() {
+    let html = r#"<div vocab="https://schema.org/">
+        <div typeof="Person">
+            <span property="name">Jane</span>
+            <div vocab="http://xmlns.com/foaf/0.1/"><span property="nick">jd</span></div>
+        </div>
+    </div>"#;
+    let items = extract(html, None).unwrap();
+    let person = items.iter().find(|item| item.type_of.is_some()).unwrap();
+
+    assert!(person.properties.contains_key("https://schema.org/name"));
+    assert_eq!(
+        person.properties["http://xmlns.com/foaf/0.1/nick"],
+        vec![RdfaValue::Literal("jd".to_string())]
+    );
+}
+
+#[test]
+fn test_own_and_inherited_vocab_expand_the_same() {
+    let own = r#"<div vocab="https://schema.org/" typeof="Person">
+        <span property="name">Jane</span>
+    </div>"#;
+    let inherited = r#"<div vocab="https://schema.org/"><div typeof="Person">
+        <span property="name">Jane</span>
+    </div></div>"#;
+    let own = extract(own, None).unwrap();
+    let inherited = extract(inherited, None).unwrap();
+    let inherited = inherited.iter().find(|item| item.type_of.is_some()).unwrap();
+
+    assert_eq!(own[0].type_of, inherited.type_of);
+    assert_eq!(own[0].properties, inherited.properties);
+}
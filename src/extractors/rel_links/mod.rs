@@ -17,27 +17,76 @@
 //! - rel-noopener: Security for external links
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use std::collections::HashMap;
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use indexmap::IndexMap;
+use scraper::Html;
 
 /// Extract rel-* link relationships from HTML
 ///
-/// Returns a HashMap mapping rel type to array of URLs
+/// Returns a map from rel type to array of URLs, with rel types in the order
+/// they first appear in the document
 ///
 /// # Arguments
 /// * `html` - HTML content to extract from
 /// * `base_url` - Optional base URL for resolving relative URLs
 ///
 /// # Returns
-/// * `Result<HashMap<String, Vec<String>>>` - Map of rel type to URLs
-pub fn extract(html: &str, base_url: Option<&str>) -> Result<HashMap<String, Vec<String>>> {
-    let document = html_utils::parse_html(html);
-    let mut rel_links: HashMap<String, Vec<String>> = HashMap::new();
+/// * `Result<IndexMap<String, Vec<String>>>` - Map of rel type to URLs
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<IndexMap<String, Vec<String>>> {
+    extract_with_options(html, base_url, None)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<IndexMap<String, Vec<String>>> {
+    extract_with_options_from_doc(document, base_url, None)
+}
+
+/// Extract rel-* link relationships, optionally normalizing and deduplicating URLs
+///
+/// With `normalize` set, each URL is passed through [`url_utils::normalize`]
+/// (dropping tracking parameters such as `utm_source`) and repeated URLs within
+/// a rel type are kept only once.
+///
+/// # Arguments
+/// * `html` - HTML content to extract from
+/// * `base_url` - Optional base URL for resolving relative URLs
+/// * `normalize` - Optional normalization applied before deduplication
+///
+/// # Returns
+/// * `Result<IndexMap<String, Vec<String>>>` - Map of rel type to URLs
+pub fn extract_with_options(
+    html: &str,
+    base_url: Option<&str>,
+    normalize: Option<&url_utils::NormalizeOptions>,
+) -> Result<IndexMap<String, Vec<String>>> {
+    extract_with_options_from_doc(&html_utils::parse_html(html), base_url, normalize)
+}
+
+/// [`extract_with_options`] on an already parsed document
+pub fn extract_with_options_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+    normalize: Option<&url_utils::NormalizeOptions>,
+) -> Result<IndexMap<String, Vec<String>>> {
+    extract_with_options_in(Scope::document(document), base_url, normalize)
+}
+
+/// [`extract_with_options_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_with_options_in(
+    scope: Scope,
+    base_url: Option<&str>,
+    normalize: Option<&url_utils::NormalizeOptions>,
+) -> Result<IndexMap<String, Vec<String>>> {
+    let mut rel_links: IndexMap<String, Vec<String>> = IndexMap::new();
 
     // Find all elements with rel and href attributes (link and a tags)
     let selector = html_utils::create_selector("[rel][href]")?;
 
-    for element in document.select(&selector) {
+    for element in scope.select(&selector) {
         if let (Some(rel), Some(href)) =
             (html_utils::get_attr(&element, "rel"), html_utils::get_attr(&element, "href"))
         {
@@ -46,8 +95,10 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<HashMap<String, Vec
                 continue;
             }
 
-            // Resolve URL if base_url is provided
-            let url = if let Some(base) = base_url {
+            // mailto:, tel:, xmpp:, did: ... identities are kept exactly as written
+            let url = if url_utils::is_non_http_uri(&href) {
+                href.trim().to_string()
+            } else if let Some(base) = base_url {
                 match url_utils::resolve_url(Some(base), &href) {
                     Ok(resolved) => resolved,
                     Err(_) => href.clone(), // Fall back to original if resolution fails
@@ -55,6 +106,12 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<HashMap<String, Vec
             } else {
                 href.clone()
             };
+            let url = match normalize {
+                Some(opts) if !url_utils::is_non_http_uri(&url) => {
+                    url_utils::normalize(&url, opts).unwrap_or(url)
+                }
+                _ => url,
+            };
 
             // Handle multiple space-separated rel values
             for rel_value in rel.split_whitespace() {
@@ -62,7 +119,11 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<HashMap<String, Vec
                     continue;
                 }
                 let rel_type = rel_value.to_lowercase();
-                rel_links.entry(rel_type).or_default().push(url.clone());
+                let urls = rel_links.entry(rel_type).or_default();
+                if normalize.is_some() && urls.contains(&url) {
+                    continue;
+                }
+                urls.push(url.clone());
             }
         }
     }
@@ -136,6 +197,27 @@ mod tests {
         assert!(links.is_empty());
     }
 
+    #[test]
+    fn test_normalized_dedup_strips_tracking_params() {
+        let html = r#"
+        <a rel="me" href="https://GitHub.com/user?utm_source=blog">GitHub</a>
+        <a rel="me" href="https://github.com/user">GitHub</a>
+        <a rel="me" href="https://example.com/profile?id=3&utm_medium=footer">Profile</a>
+        "#;
+        let opts = url_utils::NormalizeOptions::default();
+        let links = extract_with_options(html, None, Some(&opts)).unwrap();
+        assert_eq!(
+            links.get("me"),
+            Some(&vec![
+                "https://github.com/user".to_string(),
+                "https://example.com/profile?id=3".to_string(),
+            ])
+        );
+
+        // Without normalization both GitHub links are kept
+        assert_eq!(extract(html, None).unwrap().get("me").unwrap().len(), 3);
+    }
+
     #[test]
     fn test_multiple_same_rel() {
         let html = r#"
@@ -147,4 +229,30 @@ mod tests {
         assert!(links.get("me").unwrap().contains(&"https://twitter.com/user".to_string()));
         assert!(links.get("me").unwrap().contains(&"https://github.com/user".to_string()));
     }
+
+    #[test]
+    fn test_rel_me_non_http_identities_pass_through() {
+        let html = r#"
+        <link rel="me" href="mailto:Jane.Doe@Example.com">
+        <a rel="me" href="tel:+1 555 0100">Call</a>
+        <a rel="me" href="xmpp:jane@example.com">XMPP</a>
+        <a rel="me" href="did:web:example.com">DID</a>
+        <a rel="me" href="/about">About</a>
+        "#;
+        let links = extract(html, Some("https://example.com/blog/")).unwrap();
+        assert_eq!(
+            links.get("me"),
+            Some(&vec![
+                "mailto:Jane.Doe@Example.com".to_string(),
+                "tel:+1 555 0100".to_string(),
+                "xmpp:jane@example.com".to_string(),
+                "did:web:example.com".to_string(),
+                "https://example.com/about".to_string(),
+            ])
+        );
+
+        let opts = url_utils::NormalizeOptions::default();
+        let links = extract_with_options(html, None, Some(&opts)).unwrap();
+        assert_eq!(links["me"][1], "tel:+1 555 0100");
+    }
 }
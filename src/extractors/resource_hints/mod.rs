@@ -0,0 +1,121 @@
+//! Resource hints extractor
+//!
+//! Extracts `<link rel="preload|preconnect|dns-prefetch|prefetch|prerender">` tags
+//! so performance audits can see which origins and assets a page warms up, and
+//! `<link rel="stylesheet|modulepreload">` tags for asset inventories.
+
+use crate::errors::Result;
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use crate::types::resource_hints::{ImageCandidate, ResourceHint, ResourceHints};
+use scraper::Html;
+
+#[cfg(test)]
+mod tests;
+
+/// Extract resource hints from HTML
+///
+/// A hint repeating an earlier one of the same kind (same URL and CORS mode)
+/// is listed once, and `crossorigin` is normalized to `anonymous` or
+/// `use-credentials`.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+///
+/// # Returns
+/// * `Result<ResourceHints>` - Hints grouped by type
+pub fn extract(html: &str, base_url: Option<&str>) -> Result<ResourceHints> {
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<ResourceHints> {
+    extract_in(Scope::document(document), base_url)
+}
+
+/// [`extract_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_in(scope: Scope, base_url: Option<&str>) -> Result<ResourceHints> {
+    let mut hints = ResourceHints::default();
+
+    let selector = html_utils::create_selector("link[rel]")?;
+
+    for element in scope.select(&selector) {
+        if let Some(rel) = html_utils::get_attr(&element, "rel") {
+            let href = html_utils::get_attr(&element, "href").unwrap_or_default();
+            let href = href.trim();
+
+            // Responsive image preloads may carry only imagesrcset, with no href
+            let imagesrcset: Vec<ImageCandidate> = html_utils::get_attr(&element, "imagesrcset")
+                .map(|srcset| html_utils::parse_srcset(&srcset))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(url, descriptor)| ImageCandidate {
+                    url: url_utils::resolve_url(base_url, &url).unwrap_or(url),
+                    descriptor,
+                })
+                .collect();
+
+            if href.is_empty() && imagesrcset.is_empty() {
+                continue;
+            }
+
+            let hint = ResourceHint {
+                href: if href.is_empty() {
+                    String::new()
+                } else {
+                    url_utils::resolve_url(base_url, href).unwrap_or(href.to_string())
+                },
+                r#as: html_utils::get_attr(&element, "as"),
+                r#type: html_utils::get_attr(&element, "type"),
+                crossorigin: html_utils::get_attr(&element, "crossorigin")
+                    .map(|mode| normalize_crossorigin(&mode)),
+                integrity: html_utils::get_attr(&element, "integrity"),
+                media: html_utils::get_attr(&element, "media"),
+                imagesrcset,
+                imagesizes: html_utils::get_attr(&element, "imagesizes"),
+            };
+
+            // A single link may declare several hints (e.g. "preconnect dns-prefetch")
+            for rel_value in rel.split_whitespace() {
+                let bucket = match rel_value.to_lowercase().as_str() {
+                    "preload" => &mut hints.preload,
+                    "preconnect" => &mut hints.preconnect,
+                    "dns-prefetch" => &mut hints.dns_prefetch,
+                    "prefetch" => &mut hints.prefetch,
+                    "prerender" => &mut hints.prerender,
+                    "stylesheet" => &mut hints.stylesheet,
+                    "modulepreload" => &mut hints.modulepreload,
+                    _ => continue,
+                };
+                if !bucket.iter().any(|existing| is_duplicate(existing, &hint)) {
+                    bucket.push(hint.clone());
+                }
+            }
+        }
+    }
+
+    Ok(hints)
+}
+
+/// Normalize a `crossorigin` value to the CORS mode it selects
+///
+/// Per HTML, `use-credentials` (any case) selects credentialed requests and
+/// every other value, including the empty attribute, means `anonymous`.
+fn normalize_crossorigin(mode: &str) -> String {
+    if mode.trim().eq_ignore_ascii_case("use-credentials") {
+        "use-credentials".to_string()
+    } else {
+        "anonymous".to_string()
+    }
+}
+
+/// Whether two hints of the same kind request the same thing
+///
+/// The CORS mode is part of the identity: a preconnect with `crossorigin`
+/// opens a different connection than one without.
+fn is_duplicate(existing: &ResourceHint, hint: &ResourceHint) -> bool {
+    existing.href == hint.href
+        && existing.crossorigin == hint.crossorigin
+        && existing.imagesrcset == hint.imagesrcset
+}
@@ -0,0 +1,187 @@
+//! Tests for resource hint extraction
+
+use super::*;
+
+#[test]
+fn test_font_preload() {
+    let html =
+        r#"<link rel="preload" href="/fonts/inter.woff2" as="font" type="font/woff2" crossorigin>"#;
+    let hints = extract(html, Some("https://example.com")).unwrap();
+    assert_eq!(hints.preload.len(), 1);
+    let preload = &hints.preload[0];
+    assert_eq!(preload.href, "https://example.com/fonts/inter.woff2");
+    assert_eq!(preload.r#as, Some("font".to_string()));
+    assert_eq!(preload.r#type, Some("font/woff2".to_string()));
+    // A bare crossorigin attribute means anonymous
+    assert_eq!(preload.crossorigin, Some("anonymous".to_string()));
+}
+
+#[test]
+fn test_hero_image_preload_with_imagesrcset() {
+    let html = r#"
+        <link rel="preload" as="image" href="/hero-800.jpg"
+              imagesrcset="/hero-400.jpg 400w, /hero-800.jpg 800w, https://cdn.example.net/hero-1600.jpg 1600w"
+              imagesizes="100vw">
+    "#;
+    let hints = extract(html, Some("https://example.com/page")).unwrap();
+    let preload = &hints.preload[0];
+    assert_eq!(preload.r#as, Some("image".to_string()));
+    assert_eq!(preload.imagesizes, Some("100vw".to_string()));
+    let candidates: Vec<(&str, Option<&str>)> =
+        preload.imagesrcset.iter().map(|c| (c.url.as_str(), c.descriptor.as_deref())).collect();
+    assert_eq!(
+        candidates,
+        vec![
+            ("https://example.com/hero-400.jpg", Some("400w")),
+            ("https://example.com/hero-800.jpg", Some("800w")),
+            ("https://cdn.example.net/hero-1600.jpg", Some("1600w")),
+        ]
+    );
+}
+
+#[test]
+fn test_image_preload_without_href() {
+    let html = r#"<link rel="preload" as="image" imagesrcset="/a.jpg 1x, /a@2x.jpg 2x">"#;
+    let hints = extract(html, Some("https://example.com")).unwrap();
+    assert_eq!(hints.preload.len(), 1);
+    assert_eq!(hints.preload[0].href, "");
+    assert_eq!(hints.preload[0].imagesrcset.len(), 2);
+    assert_eq!(hints.preload[0].imagesrcset[1].url, "https://example.com/a@2x.jpg");
+}
+
+#[test]
+fn test_preconnect_to_cdn() {
+    let html = r#"<link rel="preconnect" href="https://cdn.example.net" crossorigin="anonymous">"#;
+    let hints = extract(html, None).unwrap();
+    assert_eq!(hints.preconnect.len(), 1);
+    assert_eq!(hints.preconnect[0].href, "https://cdn.example.net/");
+    assert_eq!(hints.preconnect[0].crossorigin, Some("anonymous".to_string()));
+    assert!(hints.preload.is_empty());
+}
+
+#[test]
+fn test_duplicate_preconnects_are_deduped() {
+    let html = r#"
+        <link rel="preconnect" href="https://cdn.example.net" crossorigin>
+        <link rel="preconnect" href="https://cdn.example.net/" crossorigin="anonymous">
+        <link rel="preconnect" href="https://cdn.example.net" crossorigin="USE-CREDENTIALS">
+        <link rel="preconnect" href="https://cdn.example.net">
+    "#;
+    let hints = extract(html, None).unwrap();
+    let modes: Vec<Option<&str>> =
+        hints.preconnect.iter().map(|hint| hint.crossorigin.as_deref()).collect();
+    // Different CORS modes open different connections, so they are kept
+    assert_eq!(modes, vec![Some("anonymous"), Some("use-credentials"), None]);
+}
+
+#[test]
+fn test_unique_origins_across_hint_kinds() {
+    let html = r#"
+        <link rel="preconnect" href="https://Fonts.gstatic.com" crossorigin>
+        <link rel="dns-prefetch" href="//fonts.gstatic.com">
+        <link rel="dns-prefetch" href="//analytics.example.net">
+        <link rel="preload" href="/hero.jpg" as="image">
+        <link rel="modulepreload" href="https://cdn.example.net/app.mjs">
+    "#;
+    let hints = extract(html, None).unwrap();
+    assert_eq!(
+        hints.unique_origins(),
+        vec!["fonts.gstatic.com", "analytics.example.net", "cdn.example.net"]
+    );
+
+    // With a base URL the preload resolves to the page's own host, listed first
+    // since preloads come first
+    let hints = extract(html, Some("https://example.com/")).unwrap();
+    assert_eq!(hints.unique_origins()[0], "example.com");
+}
+
+#[test]
+fn test_dns_prefetch() {
+    let html = r#"<link rel="dns-prefetch" href="//fonts.googleapis.com">"#;
+    let hints = extract(html, Some("https://example.com")).unwrap();
+    assert_eq!(hints.dns_prefetch.len(), 1);
+    assert_eq!(hints.dns_prefetch[0].href, "https://fonts.googleapis.com/");
+}
+
+#[test]
+fn test_hints_grouped_by_type() {
+    let html = r#"
+        <link rel="preload" href="/hero.jpg" as="image">
+        <link rel="preconnect" href="https://cdn.example.net">
+        <link rel="dns-prefetch" href="https://analytics.example.net">
+        <link rel="prefetch" href="/next-page.js">
+        <link rel="prerender" href="/checkout">
+        <link rel="stylesheet" href="/style.css">
+    "#;
+    let hints = extract(html, Some("https://example.com")).unwrap();
+    assert_eq!(hints.preload.len(), 1);
+    assert_eq!(hints.preload[0].r#as, Some("image".to_string()));
+    assert_eq!(hints.preconnect.len(), 1);
+    assert_eq!(hints.dns_prefetch.len(), 1);
+    assert_eq!(hints.prefetch[0].href, "https://example.com/next-page.js");
+    assert_eq!(hints.prerender[0].href, "https://example.com/checkout");
+}
+
+#[test]
+fn test_multiple_rel_values() {
+    let html = r#"<link rel="preconnect dns-prefetch" href="https://cdn.example.net">"#;
+    let hints = extract(html, None).unwrap();
+    assert_eq!(hints.preconnect.len(), 1);
+    assert_eq!(hints.dns_prefetch.len(), 1);
+}
+
+#[test]
+fn test_rel_case_insensitive() {
+    let html = r#"<link rel="PreConnect" href="https://cdn.example.net">"#;
+    let hints = extract(html, None).unwrap();
+    assert_eq!(hints.preconnect.len(), 1);
+}
+
+#[test]
+fn test_no_hints() {
+    let html = r#"<link rel="stylesheet" href="/style.css"><link rel="preload" href="">"#;
+    let hints = extract(html, None).unwrap();
+    assert!(!hints.has_hints());
+}
+
+#[test]
+fn test_stylesheet_with_integrity() {
+    let html = r#"<link rel="stylesheet" href="/css/site.css"
+        integrity="sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"
+        crossorigin="anonymous">"#;
+    let hints = extract(html, Some("https://example.com/blog/")).unwrap();
+    assert_eq!(hints.stylesheet.len(), 1);
+    let sheet = &hints.stylesheet[0];
+    assert_eq!(sheet.href, "https://example.com/css/site.css");
+    assert!(sheet.integrity.as_deref().unwrap().starts_with("sha384-"));
+    assert_eq!(sheet.crossorigin.as_deref(), Some("anonymous"));
+    assert_eq!(hints.assets().styles, vec![sheet.clone()]);
+}
+
+#[test]
+fn test_modulepreload() {
+    let html = r#"
+        <link rel="modulepreload" href="/js/app.mjs">
+        <link rel="preload" href="/js/vendor.js" as="script">
+    "#;
+    let hints = extract(html, Some("https://example.com")).unwrap();
+    assert_eq!(hints.modulepreload[0].href, "https://example.com/js/app.mjs");
+    assert!(hints.modulepreload[0].integrity.is_none());
+
+    let scripts: Vec<String> = hints.assets().scripts.into_iter().map(|s| s.href).collect();
+    assert_eq!(scripts, vec!["https://example.com/js/app.mjs", "https://example.com/js/vendor.js"]);
+}
+
+#[test]
+fn test_font_preload_in_asset_inventory() {
+    let html = r#"
+        <link rel="preload" href="/fonts/inter.woff2" as="font" crossorigin="anonymous">
+        <link rel="preload" href="/hero.jpg" as="image">
+    "#;
+    let assets = extract(html, Some("https://example.com")).unwrap().assets();
+    assert_eq!(assets.fonts.len(), 1);
+    assert_eq!(assets.fonts[0].href, "https://example.com/fonts/inter.woff2");
+    assert_eq!(assets.fonts[0].crossorigin.as_deref(), Some("anonymous"));
+    assert!(assets.styles.is_empty());
+    assert!(assets.scripts.is_empty());
+}
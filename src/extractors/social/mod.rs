@@ -5,16 +5,22 @@
 //!
 //! - **Open Graph Protocol**: 60%+ adoption (Facebook, LinkedIn, WhatsApp, Slack, Discord)
 //! - **Twitter Cards**: 45% adoption (Twitter/X)
+//! - **Pinterest**: Rich Pin opt-in and `pinterest:*` tags
 
 pub mod opengraph;
+pub mod pinterest;
 pub mod twitter;
 
 #[cfg(test)]
 mod opengraph_tests;
 #[cfg(test)]
+mod pinterest_tests;
+#[cfg(test)]
 mod twitter_tests;
 
 pub use opengraph::extract as extract_opengraph;
+pub use pinterest::extract as extract_pinterest;
 pub use twitter::{
     extract as extract_twitter, extract_with_fallback as extract_twitter_with_fallback,
+    extract_with_fallback_opts as extract_twitter_with_fallback_opts,
 };
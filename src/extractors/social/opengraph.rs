@@ -4,8 +4,13 @@
 //! Specification: https://ogp.me/
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use crate::types::social::{OgArticle, OgAudio, OgBook, OgImage, OgProfile, OgVideo, OpenGraph};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use crate::types::social::{
+    ImageUrlKind, OgActor, OgArticle, OgAudio, OgBook, OgImage, OgMusic, OgMusicRef, OgProfile,
+    OgVideo, OgVideoMetadata, OpenGraph,
+};
+use scraper::Html;
 
 /// Extract Open Graph metadata from HTML
 ///
@@ -16,7 +21,16 @@ use crate::types::social::{OgArticle, OgAudio, OgBook, OgImage, OgProfile, OgVid
 /// # Returns
 /// * `Result<OpenGraph>` - Extracted Open Graph data
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
-    let document = html_utils::parse_html(html);
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<OpenGraph> {
+    extract_in(Scope::document(document), base_url)
+}
+
+/// [`extract_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_in(scope: Scope, base_url: Option<&str>) -> Result<OpenGraph> {
     let mut og = OpenGraph::default();
 
     // Track current image/video/audio for structured properties
@@ -36,9 +50,15 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
     let mut profile_data = OgProfile::default();
     let mut has_profile_data = false;
 
+    // Music and video metadata accumulators, kept only for music.* and video.* types
+    let mut music_data = OgMusic::default();
+    let mut has_music_data = false;
+    let mut video_data = OgVideoMetadata::default();
+    let mut has_video_data = false;
+
     // Extract meta tags with property="og:*" or property="article:*" etc.
     if let Ok(selector) = html_utils::create_selector("meta[property]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(property), Some(content)) = (
                 html_utils::get_attr(&element, "property"),
                 html_utils::get_attr(&element, "content"),
@@ -53,37 +73,48 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
                     match prop {
                         "title" => og.title = Some(content),
                         "type" => og.r#type = Some(content),
-                        "url" => {
-                            og.url =
-                                Some(url_utils::resolve_url(base_url, &content).unwrap_or(content))
-                        }
-                        "image" => {
+                        "url" => og.url = Some(url_utils::resolve_or_keep(base_url, &content)),
+                        "image" | "image:url" => {
+                            let resolved_url = url_utils::resolve_or_keep(base_url, &content);
+
+                            // og:image:url is an alias for og:image; don't start a second
+                            // image when it just repeats the URL of the current one
+                            if prop == "image:url"
+                                && current_image.as_ref().is_some_and(|img| img.url == resolved_url)
+                            {
+                                continue;
+                            }
+
                             // Save previous image if exists
                             if let Some(img) = current_image.take() {
                                 og.images.push(img);
                             }
 
-                            let resolved_url = url_utils::resolve_url(base_url, &content)
-                                .unwrap_or(content.clone());
-
                             // First image becomes the primary image
                             if og.image.is_none() {
                                 og.image = Some(resolved_url.clone());
                             }
 
                             // Start new image
-                            current_image =
-                                Some(OgImage { url: resolved_url, ..Default::default() });
+                            current_image = Some(OgImage {
+                                url_kind: ImageUrlKind::classify(&resolved_url),
+                                url: resolved_url,
+                                ..Default::default()
+                            });
                         }
                         "description" => og.description = Some(content),
                         "site_name" => og.site_name = Some(content),
+                        "determiner" => og.determiner = Some(content.to_lowercase()),
                         "locale" => og.locale = Some(content),
 
                         // Handle nested properties
                         _ if prop.starts_with("image:") => {
                             if let Some(ref mut img) = current_image {
                                 match &prop[6..] {
-                                    "secure_url" => img.secure_url = Some(content),
+                                    "secure_url" => {
+                                        img.secure_url =
+                                            Some(url_utils::resolve_or_keep(base_url, &content))
+                                    }
                                     "type" => img.r#type = Some(content),
                                     "width" => img.width = content.parse().ok(),
                                     "height" => img.height = content.parse().ok(),
@@ -92,69 +123,99 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
                                 }
                             }
                         }
-                        _ if prop.starts_with("video:") => match &prop[6..] {
-                            "secure_url" => {
-                                if let Some(ref mut video) = current_video {
-                                    video.secure_url = Some(content);
+                        _ if prop.starts_with("video:") && prop != "video:url" => {
+                            match &prop[6..] {
+                                "secure_url" => {
+                                    if let Some(ref mut video) = current_video {
+                                        video.secure_url =
+                                            Some(url_utils::resolve_or_keep(base_url, &content));
+                                    }
                                 }
-                            }
-                            "type" => {
-                                if let Some(ref mut video) = current_video {
-                                    video.r#type = Some(content);
+                                "type" => {
+                                    if let Some(ref mut video) = current_video {
+                                        video.r#type = Some(content);
+                                    }
                                 }
-                            }
-                            "width" => {
-                                if let Some(ref mut video) = current_video {
-                                    video.width = content.parse().ok();
+                                "width" => {
+                                    if let Some(ref mut video) = current_video {
+                                        video.width = content.parse().ok();
+                                    }
                                 }
-                            }
-                            "height" => {
-                                if let Some(ref mut video) = current_video {
-                                    video.height = content.parse().ok();
+                                "height" => {
+                                    if let Some(ref mut video) = current_video {
+                                        video.height = content.parse().ok();
+                                    }
                                 }
+                                _ => {}
                             }
-                            _ => {}
-                        },
-                        _ if prop.starts_with("audio:") => match &prop[6..] {
-                            "secure_url" => {
-                                if let Some(ref mut audio) = current_audio {
-                                    audio.secure_url = Some(content);
+                        }
+                        _ if prop.starts_with("audio:") && prop != "audio:url" => {
+                            match &prop[6..] {
+                                "secure_url" => {
+                                    if let Some(ref mut audio) = current_audio {
+                                        audio.secure_url =
+                                            Some(url_utils::resolve_or_keep(base_url, &content));
+                                    }
+                                }
+                                "type" => {
+                                    if let Some(ref mut audio) = current_audio {
+                                        audio.r#type = Some(content);
+                                    }
                                 }
+                                _ => {}
                             }
-                            "type" => {
-                                if let Some(ref mut audio) = current_audio {
-                                    audio.r#type = Some(content);
+                        }
+                        _ if prop.starts_with("restrictions:") => {
+                            let restrictions = og.restrictions.get_or_insert_with(Default::default);
+                            match &prop[13..] {
+                                "age" => restrictions.age = Some(content),
+                                "country:allowed" => restrictions.country_allowed.push(content),
+                                "country:disallowed" => {
+                                    restrictions.country_disallowed.push(content)
                                 }
+                                "content" => restrictions.content.push(content),
+                                _ => {}
                             }
-                            _ => {}
-                        },
+                        }
                         _ if prop.starts_with("locale:") => {
                             if &prop[7..] == "alternate" {
                                 og.locale_alternate.push(content);
                             }
                         }
-                        "video" => {
+                        "video" | "video:url" => {
+                            let resolved_url = url_utils::resolve_or_keep(base_url, &content);
+
+                            // og:video:url is an alias for og:video
+                            if prop == "video:url"
+                                && current_video.as_ref().is_some_and(|v| v.url == resolved_url)
+                            {
+                                continue;
+                            }
+
                             // Save previous video if exists
                             if let Some(video) = current_video.take() {
                                 og.videos.push(video);
                             }
 
-                            let resolved_url =
-                                url_utils::resolve_url(base_url, &content).unwrap_or(content);
-
                             // Start new video
                             current_video =
                                 Some(OgVideo { url: resolved_url, ..Default::default() });
                         }
-                        "audio" => {
+                        "audio" | "audio:url" => {
+                            let resolved_url = url_utils::resolve_or_keep(base_url, &content);
+
+                            // og:audio:url is an alias for og:audio
+                            if prop == "audio:url"
+                                && current_audio.as_ref().is_some_and(|a| a.url == resolved_url)
+                            {
+                                continue;
+                            }
+
                             // Save previous audio if exists
                             if let Some(audio) = current_audio.take() {
                                 og.audios.push(audio);
                             }
 
-                            let resolved_url =
-                                url_utils::resolve_url(base_url, &content).unwrap_or(content);
-
                             // Start new audio
                             current_audio =
                                 Some(OgAudio { url: resolved_url, ..Default::default() });
@@ -190,6 +251,54 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
                         "gender" => profile_data.gender = Some(content),
                         _ => {}
                     }
+                } else if let Some(prop) = property.strip_prefix("music:") {
+                    has_music_data = true;
+                    match prop {
+                        "duration" => music_data.duration = content.parse().ok(),
+                        "album" => music_data.album.push(music_ref(content)),
+                        "album:disc" => {
+                            if let Some(album) = music_data.album.last_mut() {
+                                album.disc = content.parse().ok();
+                            }
+                        }
+                        "album:track" => {
+                            if let Some(album) = music_data.album.last_mut() {
+                                album.track = content.parse().ok();
+                            }
+                        }
+                        "musician" => music_data.musician.push(content),
+                        "song" => music_data.song.push(music_ref(content)),
+                        "song:disc" => {
+                            if let Some(song) = music_data.song.last_mut() {
+                                song.disc = content.parse().ok();
+                            }
+                        }
+                        "song:track" => {
+                            if let Some(song) = music_data.song.last_mut() {
+                                song.track = content.parse().ok();
+                            }
+                        }
+                        "release_date" => music_data.release_date = Some(content),
+                        "creator" => music_data.creator.push(content),
+                        _ => {}
+                    }
+                } else if let Some(prop) = property.strip_prefix("video:") {
+                    has_video_data = true;
+                    match prop {
+                        "actor" => video_data.actor.push(OgActor { url: content, role: None }),
+                        "actor:role" => {
+                            if let Some(actor) = video_data.actor.last_mut() {
+                                actor.role = Some(content);
+                            }
+                        }
+                        "director" => video_data.director.push(content),
+                        "writer" => video_data.writer.push(content),
+                        "duration" => video_data.duration = content.parse().ok(),
+                        "release_date" => video_data.release_date = Some(content),
+                        "tag" => video_data.tag.push(content),
+                        "series" => video_data.series = Some(content),
+                        _ => {}
+                    }
                 } else if let Some(prop) = property.strip_prefix("fb:") {
                     // Phase 6: Facebook platform integration
                     match prop {
@@ -223,10 +332,28 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<OpenGraph> {
     if has_profile_data {
         og.profile = Some(profile_data);
     }
+    if has_music_data && has_type_prefix(&og, "music.") {
+        og.music = Some(music_data);
+    }
+    if has_video_data && has_type_prefix(&og, "video.") {
+        og.video = Some(video_data);
+    }
 
     Ok(og)
 }
 
+/// Start a `music:album` or `music:song` reference; disc and track follow it
+fn music_ref(url: String) -> OgMusicRef {
+    OgMusicRef { url, ..Default::default() }
+}
+
+/// Whether `og:type` is in the given namespace (`music.song` for `music.`)
+fn has_type_prefix(og: &OpenGraph, prefix: &str) -> bool {
+    og.r#type.as_deref().is_some_and(|t| {
+        t.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
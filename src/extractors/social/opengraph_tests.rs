@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use crate::extractors::social::opengraph::extract;
+    use crate::types::social::ImageUrlKind;
 
     #[test]
     fn test_basic_opengraph() {
@@ -68,6 +69,28 @@ mod tests {
         assert_eq!(img.alt, Some("Test image".to_string()));
     }
 
+    #[test]
+    fn test_opengraph_relative_secure_urls_are_resolved() {
+        let html = r#"
+            <meta property="og:image" content="/image.jpg">
+            <meta property="og:image:secure_url" content="/secure.jpg">
+            <meta property="og:video" content="/video.mp4">
+            <meta property="og:video:secure_url" content="/secure-video.mp4">
+            <meta property="og:audio" content="/audio.mp3">
+            <meta property="og:audio:secure_url" content="/secure-audio.mp3">
+        "#;
+        let og = extract(html, Some("https://example.com/page")).unwrap();
+        assert_eq!(og.images[0].secure_url.as_deref(), Some("https://example.com/secure.jpg"));
+        assert_eq!(
+            og.videos[0].secure_url.as_deref(),
+            Some("https://example.com/secure-video.mp4")
+        );
+        assert_eq!(
+            og.audios[0].secure_url.as_deref(),
+            Some("https://example.com/secure-audio.mp3")
+        );
+    }
+
     #[test]
     fn test_opengraph_multiple_images() {
         let html = r#"
@@ -163,6 +186,35 @@ mod tests {
         assert_eq!(video.r#type, Some("video/mp4".to_string()));
         assert_eq!(video.width, Some(1280));
         assert_eq!(video.height, Some(720));
+        assert_eq!(video.best_url(), "https://example.com/secure-video.mp4");
+    }
+
+    #[test]
+    fn test_opengraph_video_url_alias() {
+        let html = r#"
+            <meta property="og:video" content="http://example.com/clip.mp4">
+            <meta property="og:video:url" content="http://example.com/clip.mp4">
+            <meta property="og:video:width" content="640">
+        "#;
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.videos.len(), 1);
+        assert_eq!(og.videos[0].width, Some(640));
+        assert_eq!(og.videos[0].best_url(), "http://example.com/clip.mp4");
+    }
+
+    #[test]
+    fn test_opengraph_audio_with_type_only() {
+        let html = r#"
+            <meta property="og:audio" content="/media/episode.mp3">
+            <meta property="og:audio:type" content="audio/mpeg">
+        "#;
+        let og = extract(html, Some("https://example.com/podcast")).unwrap();
+        assert_eq!(og.audios.len(), 1);
+        let audio = &og.audios[0];
+        assert_eq!(audio.url, "https://example.com/media/episode.mp3");
+        assert_eq!(audio.secure_url, None);
+        assert_eq!(audio.r#type, Some("audio/mpeg".to_string()));
+        assert_eq!(audio.best_url(), "https://example.com/media/episode.mp3");
     }
 
     #[test]
@@ -594,4 +646,195 @@ mod tests {
         assert!(og.url.is_some());
         assert!(og.url.unwrap().contains("foo=bar"));
     }
+
+    #[test]
+    fn test_opengraph_repeated_images_accumulate() {
+        let html = r#"
+            <meta property="og:title" content="Gallery">
+            <meta property="og:image" content="/one.jpg">
+            <meta property="og:image" content="/two.jpg">
+            <meta property="og:image" content="/three.jpg">
+        "#;
+        let og = extract(html, Some("https://example.com")).unwrap();
+        assert_eq!(og.images.len(), 3);
+        assert_eq!(og.title, Some("Gallery".to_string()));
+        assert_eq!(og.image, Some("https://example.com/one.jpg".to_string()));
+        assert_eq!(og.primary_image().unwrap().url, "https://example.com/one.jpg");
+    }
+
+    #[test]
+    fn test_opengraph_primary_image_none() {
+        let html = r#"<meta property="og:title" content="No images">"#;
+        let og = extract(html, None).unwrap();
+        assert!(og.primary_image().is_none());
+    }
+
+    #[test]
+    fn test_opengraph_repeated_videos_and_tags() {
+        let html = r#"
+            <meta property="og:video" content="https://example.com/a.mp4">
+            <meta property="og:video" content="https://example.com/b.mp4">
+            <meta property="article:tag" content="rust">
+            <meta property="article:tag" content="html">
+        "#;
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.videos.len(), 2);
+        assert_eq!(og.article.unwrap().tag, vec!["rust".to_string(), "html".to_string()]);
+    }
+
+    #[test]
+    fn test_opengraph_image_url_alias() {
+        let html = r#"
+            <meta property="og:image" content="https://example.com/a.jpg">
+            <meta property="og:image:url" content="https://example.com/a.jpg">
+            <meta property="og:image:width" content="800">
+            <meta property="og:image:url" content="https://example.com/b.jpg">
+        "#;
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.images.len(), 2);
+        assert_eq!(og.images[0].width, Some(800));
+        assert_eq!(og.images[1].url, "https://example.com/b.jpg");
+    }
+
+    #[test]
+    fn test_og_determiner_and_site_name() {
+        let html = r#"
+            <meta property="og:title" content="Rock">
+            <meta property="og:determiner" content="The">
+            <meta property="og:site_name" content="IMDb">
+        "#;
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.determiner.as_deref(), Some("the"));
+        assert_eq!(og.site_name.as_deref(), Some("IMDb"));
+        assert_eq!(og.headline().as_deref(), Some("the Rock"));
+    }
+
+    #[test]
+    fn test_og_without_determiner_or_site_name() {
+        let html = r#"<meta property="og:title" content="Rock">"#;
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.determiner, None);
+        assert_eq!(og.site_name, None);
+        assert_eq!(og.headline().as_deref(), Some("Rock"));
+
+        let html = r#"<meta property="og:title" content="Rock"><meta property="og:determiner" content="auto">"#;
+        assert_eq!(extract(html, None).unwrap().headline().as_deref(), Some("Rock"));
+        assert_eq!(extract("", None).unwrap().headline(), None);
+    }
+
+    #[test]
+    fn test_og_restrictions() {
+        let html = r#"
+            <meta property="og:restrictions:age" content="21+">
+            <meta property="og:restrictions:country:allowed" content="US">
+            <meta property="og:restrictions:country:allowed" content="CA">
+            <meta property="og:restrictions:content" content="alcohol">
+        "#;
+        let restrictions = extract(html, None).unwrap().restrictions.unwrap();
+        assert_eq!(restrictions.age.as_deref(), Some("21+"));
+        assert_eq!(restrictions.country_allowed, vec!["US", "CA"]);
+        assert!(restrictions.country_disallowed.is_empty());
+        assert_eq!(restrictions.content, vec!["alcohol"]);
+
+        assert!(extract(r#"<meta property="og:title" content="T">"#, None)
+            .unwrap()
+            .restrictions
+            .is_none());
+    }
+
+    #[test]
+    fn test_og_image_http_url_kind() {
+        let html = r#"<meta property="og:image" content="/img/cover.png">"#;
+        let og = extract(html, Some("https://example.com/post")).unwrap();
+        assert_eq!(og.image.as_deref(), Some("https://example.com/img/cover.png"));
+        assert_eq!(og.images[0].url_kind, ImageUrlKind::Http);
+
+        // Unresolvable without a base URL
+        let og = extract(html, None).unwrap();
+        assert_eq!(og.images[0].url_kind, ImageUrlKind::Invalid);
+    }
+
+    #[test]
+    fn test_og_image_data_uri_left_untouched() {
+        let data_uri = "data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg'>a b</svg>";
+        let html = format!(r#"<meta property="og:image" content="{}">"#, data_uri);
+        let og = extract(&html, Some("https://example.com/post")).unwrap();
+        assert_eq!(og.image.as_deref(), Some(data_uri));
+        assert_eq!(og.images[0].url, data_uri);
+        assert_eq!(og.images[0].url_kind, ImageUrlKind::Data);
+
+        let html = r#"<meta name="twitter:image" content="data:image/png;base64,iVBORw0KGgo=">"#;
+        let card = crate::extractors::social::twitter::extract(html, Some("https://example.com/"));
+        assert_eq!(card.unwrap().image.as_deref(), Some("data:image/png;base64,iVBORw0KGgo="));
+    }
+
+    #[test]
+    fn test_og_image_blank_dropped() {
+        let html = r#"
+            <meta property="og:image" content="   ">
+            <meta property="og:image" content="javascript:void(0)">
+        "#;
+        let og = extract(html, Some("https://example.com/")).unwrap();
+        assert_eq!(og.images.len(), 1);
+        assert_eq!(og.images[0].url_kind, ImageUrlKind::Invalid);
+    }
+
+    #[test]
+    fn test_og_music_song_metadata() {
+        let html = r#"
+            <meta property="og:type" content="music.song">
+            <meta property="music:duration" content="245">
+            <meta property="music:album" content="https://example.com/albums/blue">
+            <meta property="music:album:disc" content="1">
+            <meta property="music:album:track" content="4">
+            <meta property="music:musician" content="https://example.com/artists/ana">
+        "#;
+        let og = extract(html, None).unwrap();
+        let music = og.music.unwrap();
+        assert_eq!(music.duration, Some(245));
+        assert_eq!(music.album.len(), 1);
+        assert_eq!(music.album[0].url, "https://example.com/albums/blue");
+        assert_eq!(music.album[0].disc, Some(1));
+        assert_eq!(music.album[0].track, Some(4));
+        assert_eq!(music.musician, vec!["https://example.com/artists/ana"]);
+        assert!(og.video.is_none());
+    }
+
+    #[test]
+    fn test_og_video_movie_with_actors() {
+        let html = r#"
+            <meta property="og:type" content="video.movie">
+            <meta property="og:video" content="https://example.com/trailer.mp4">
+            <meta property="video:duration" content="7260">
+            <meta property="video:actor" content="https://example.com/people/lee">
+            <meta property="video:actor:role" content="Captain">
+            <meta property="video:actor" content="https://example.com/people/kim">
+            <meta property="video:director" content="https://example.com/people/park">
+            <meta property="video:release_date" content="2024-05-01">
+        "#;
+        let og = extract(html, None).unwrap();
+        let video = og.video.unwrap();
+        assert_eq!(video.duration, Some(7260));
+        assert_eq!(video.actor.len(), 2);
+        assert_eq!(video.actor[0].url, "https://example.com/people/lee");
+        assert_eq!(video.actor[0].role.as_deref(), Some("Captain"));
+        assert_eq!(video.actor[1].url, "https://example.com/people/kim");
+        assert_eq!(video.actor[1].role, None);
+        assert_eq!(video.director, vec!["https://example.com/people/park"]);
+        assert_eq!(video.release_date.as_deref(), Some("2024-05-01"));
+        // og:video media files are unaffected
+        assert_eq!(og.videos.len(), 1);
+    }
+
+    #[test]
+    fn test_og_music_and_video_need_matching_type() {
+        let html = r#"
+            <meta property="og:type" content="article">
+            <meta property="music:duration" content="245">
+            <meta property="video:series" content="https://example.com/shows/x">
+        "#;
+        let og = extract(html, None).unwrap();
+        assert!(og.music.is_none());
+        assert!(og.video.is_none());
+    }
 }
@@ -0,0 +1,74 @@
+//! Pinterest meta tag extractor
+//!
+//! Pinterest builds Rich Pins from Open Graph and Schema.org data, so the
+//! platform-specific tags only opt in (`pinterest-rich-pin`), opt out
+//! (`<meta name="pinterest" content="nopin">`) or carry `pinterest:*` extras.
+
+use crate::errors::Result;
+use crate::extractors::common::html_utils;
+use crate::types::social::Pinterest;
+
+/// Extract Pinterest meta tags from HTML
+///
+/// `pinterest:*` tags are read from both `name` and `property` attributes;
+/// when a key repeats, the first value wins.
+///
+/// # Arguments
+/// * `html` - HTML content to parse
+///
+/// # Returns
+/// * `Result<Pinterest>` - Extracted Pinterest tags
+pub fn extract(html: &str) -> Result<Pinterest> {
+    let document = html_utils::parse_html(html);
+    let mut pinterest = Pinterest::default();
+
+    let selector = html_utils::create_selector("meta[content]")?;
+    for element in document.select(&selector) {
+        let Some(key) = html_utils::get_attr(&element, "name")
+            .or_else(|| html_utils::get_attr(&element, "property"))
+        else {
+            continue;
+        };
+        let content = html_utils::get_attr(&element, "content").unwrap_or_default();
+        let content = content.trim();
+        if content.is_empty() {
+            continue;
+        }
+
+        let key = key.trim().to_lowercase();
+        match key.as_str() {
+            "pinterest-rich-pin" => {
+                if pinterest.rich_pin.is_none() {
+                    pinterest.rich_pin = parse_bool(content);
+                }
+            }
+            "pinterest" if content.eq_ignore_ascii_case("nopin") => {
+                pinterest.nopin = true;
+                pinterest.nopin_description = html_utils::get_attr(&element, "description")
+                    .map(|d| d.trim().to_string())
+                    .filter(|d| !d.is_empty());
+            }
+            _ => {
+                if let Some(name) = key.strip_prefix("pinterest:").filter(|n| !n.is_empty()) {
+                    pinterest
+                        .properties
+                        .entry(name.to_string())
+                        .or_insert_with(|| content.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(pinterest)
+}
+
+/// Parse a `true`/`false` tag value, ignoring case
+fn parse_bool(value: &str) -> Option<bool> {
+    if value.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
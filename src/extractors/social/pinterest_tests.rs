@@ -0,0 +1,58 @@
+//! Tests for Pinterest meta tag extraction
+
+#[cfg(test)]
+mod tests {
+    use crate::extractors::social::pinterest::extract;
+
+    #[test]
+    fn test_rich_pin_opt_in() {
+        let html = r#"<meta name="pinterest-rich-pin" content="true">"#;
+        let pinterest = extract(html).unwrap();
+        assert_eq!(pinterest.rich_pin, Some(true));
+        assert!(!pinterest.nopin);
+    }
+
+    #[test]
+    fn test_rich_pin_opt_out_and_invalid_value() {
+        let pinterest = extract(r#"<meta name="pinterest-rich-pin" content="FALSE">"#).unwrap();
+        assert_eq!(pinterest.rich_pin, Some(false));
+
+        let pinterest = extract(r#"<meta name="pinterest-rich-pin" content="yes">"#).unwrap();
+        assert_eq!(pinterest.rich_pin, None);
+    }
+
+    #[test]
+    fn test_pinterest_properties_map() {
+        let html = r#"
+            <meta property="pinterest:description" content="Pin this recipe">
+            <meta name="pinterest:media" content="https://example.com/pin.jpg">
+            <meta property="pinterest:description" content="Ignored duplicate">
+        "#;
+        let pinterest = extract(html).unwrap();
+        assert_eq!(
+            pinterest.properties.get("description").map(String::as_str),
+            Some("Pin this recipe")
+        );
+        assert_eq!(
+            pinterest.properties.get("media").map(String::as_str),
+            Some("https://example.com/pin.jpg")
+        );
+        let keys: Vec<&str> = pinterest.properties.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["description", "media"]);
+    }
+
+    #[test]
+    fn test_nopin() {
+        let html = r#"<meta name="pinterest" content="nopin" description="Sorry, no pinning">"#;
+        let pinterest = extract(html).unwrap();
+        assert!(pinterest.nopin);
+        assert_eq!(pinterest.nopin_description.as_deref(), Some("Sorry, no pinning"));
+    }
+
+    #[test]
+    fn test_no_pinterest_tags() {
+        let html = r#"<meta property="og:title" content="Title">"#;
+        let pinterest = extract(html).unwrap();
+        assert_eq!(pinterest, Default::default());
+    }
+}
@@ -4,8 +4,16 @@
 //! Specification: https://developer.twitter.com/en/docs/twitter-for-websites/cards/overview/abouts-cards
 
 use crate::errors::Result;
-use crate::extractors::common::{html_utils, url_utils};
-use crate::types::social::{TwitterApp, TwitterCard, TwitterPlayer};
+use crate::extractors::common::html_utils::{self, Scope};
+use crate::extractors::common::url_utils;
+use crate::types::jsonld::JsonLdObject;
+use crate::types::meta::MetaTags;
+use crate::types::social::{
+    FallbackConfig, FallbackSource, OpenGraph, TwitterApp, TwitterCard, TwitterCardKind,
+    TwitterPlayer,
+};
+use scraper::Html;
+use serde_json::Value;
 
 /// Extract Twitter Card metadata from HTML
 ///
@@ -16,7 +24,16 @@ use crate::types::social::{TwitterApp, TwitterCard, TwitterPlayer};
 /// # Returns
 /// * `Result<TwitterCard>` - Extracted Twitter Card data
 pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
-    let document = html_utils::parse_html(html);
+    extract_from_doc(&html_utils::parse_html(html), base_url)
+}
+
+/// [`extract`] on an already parsed document
+pub fn extract_from_doc(document: &Html, base_url: Option<&str>) -> Result<TwitterCard> {
+    extract_in(Scope::document(document), base_url)
+}
+
+/// [`extract_from_doc`] on the part of a document within `scope`
+pub(crate) fn extract_in(scope: Scope, base_url: Option<&str>) -> Result<TwitterCard> {
     let mut card = TwitterCard::default();
 
     // Track player/app metadata
@@ -30,7 +47,7 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
 
     // Extract meta tags with name="twitter:*"
     if let Ok(selector) = html_utils::create_selector("meta[name]") {
-        for element in document.select(&selector) {
+        for element in scope.select(&selector) {
             if let (Some(name), Some(content)) =
                 (html_utils::get_attr(&element, "name"), html_utils::get_attr(&element, "content"))
             {
@@ -46,8 +63,7 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
                         "title" => card.title = Some(content),
                         "description" => card.description = Some(content),
                         "image" => {
-                            card.image =
-                                Some(url_utils::resolve_url(base_url, &content).unwrap_or(content))
+                            card.image = Some(url_utils::resolve_or_keep(base_url, &content))
                         }
                         "site" => card.site = Some(content),
                         "creator" => card.creator = Some(content),
@@ -70,23 +86,23 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
                         }
                         _ if prop.starts_with("player") => {
                             if prop == "player" {
-                                player_url = Some(
-                                    url_utils::resolve_url(base_url, &content).unwrap_or(content),
-                                );
+                                player_url = Some(url_utils::resolve_or_keep(base_url, &content));
                             } else if let Some(subprop) = prop.strip_prefix("player:") {
                                 match subprop {
                                     "width" => player_width = content.parse().ok(),
                                     "height" => player_height = content.parse().ok(),
                                     "stream" => {
-                                        player_stream = Some(
-                                            url_utils::resolve_url(base_url, &content)
-                                                .unwrap_or(content),
-                                        )
+                                        player_stream =
+                                            Some(url_utils::resolve_or_keep(base_url, &content))
                                     }
                                     _ => {}
                                 }
                             }
                         }
+                        "dnt" => card.dnt = parse_on_off(&content),
+                        _ if prop.starts_with("widgets:") => {
+                            card.widgets.entry(prop[8..].to_string()).or_insert(content);
+                        }
                         _ if prop.starts_with("app:") => {
                             has_app_data = true;
                             let subprop = &prop[4..];
@@ -141,10 +157,21 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
     Ok(card)
 }
 
+/// Parse an `on`/`off` flag such as `twitter:dnt`, also accepting `true`/`false`
+fn parse_on_off(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "on" | "true" => Some(true),
+        "off" | "false" => Some(false),
+        _ => None,
+    }
+}
+
 /// Extract Twitter Card with fallback to Open Graph
 ///
 /// Twitter recommends: If twitter:* tags are missing, fall back to og:* tags.
-/// This function implements that recommendation.
+/// This function implements that recommendation. A page with `twitter:*` tags
+/// but no `twitter:card` is rendered as a `summary` card, so `card` defaults
+/// to `"summary"` in that case.
 ///
 /// # Arguments
 /// * `html` - HTML content to parse
@@ -153,26 +180,187 @@ pub fn extract(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
 /// # Returns
 /// * `Result<TwitterCard>` - Extracted Twitter Card data with OG fallback
 pub fn extract_with_fallback(html: &str, base_url: Option<&str>) -> Result<TwitterCard> {
-    let mut card = extract(html, base_url)?;
+    extract_with_fallback_from_doc(&html_utils::parse_html(html), base_url)
+}
 
-    // If critical Twitter fields are missing, try Open Graph
-    if card.title.is_none() || card.description.is_none() || card.image.is_none() {
-        let og = super::opengraph::extract(html, base_url)?;
+/// [`extract_with_fallback`] on an already parsed document
+pub fn extract_with_fallback_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<TwitterCard> {
+    extract_with_fallback_opts_from_doc(document, base_url, &FallbackConfig::default())
+}
 
-        if card.title.is_none() {
-            card.title = og.title;
-        }
-        if card.description.is_none() {
-            card.description = og.description;
-        }
-        if card.image.is_none() {
-            card.image = og.image;
+/// Extract Twitter Card with configurable fallback sources
+///
+/// Like [`extract_with_fallback`], but each of `title`, `description` and
+/// `image` is filled from the sources `config` lists for it, tried in order,
+/// when the page has no `twitter:*` value. Sources are only extracted if a
+/// field needs them.
+///
+/// # Example
+/// ```rust
+/// use meta_oxide::extractors::social::twitter::extract_with_fallback_opts;
+/// use meta_oxide::social::{FallbackConfig, FallbackSource};
+///
+/// let html = "<title>Page title</title>";
+/// let config = FallbackConfig {
+///     title: vec![FallbackSource::OpenGraph, FallbackSource::Meta],
+///     ..Default::default()
+/// };
+/// let card = extract_with_fallback_opts(html, None, &config).unwrap();
+/// assert_eq!(card.title.as_deref(), Some("Page title"));
+/// ```
+pub fn extract_with_fallback_opts(
+    html: &str,
+    base_url: Option<&str>,
+    config: &FallbackConfig,
+) -> Result<TwitterCard> {
+    extract_with_fallback_opts_from_doc(&html_utils::parse_html(html), base_url, config)
+}
+
+/// [`extract_with_fallback_opts`] on an already parsed document
+pub fn extract_with_fallback_opts_from_doc(
+    document: &Html,
+    base_url: Option<&str>,
+    config: &FallbackConfig,
+) -> Result<TwitterCard> {
+    extract_with_fallback_opts_in(Scope::document(document), base_url, config)
+}
+
+/// [`extract_with_fallback_opts_from_doc`] on the part of a document within
+/// `scope`; the fallback sources are read from the same part
+pub(crate) fn extract_with_fallback_opts_in(
+    scope: Scope,
+    base_url: Option<&str>,
+    config: &FallbackConfig,
+) -> Result<TwitterCard> {
+    let mut card = extract_in(scope, base_url)?;
+
+    // Privacy flags alone don't make a card
+    let privacy_only =
+        TwitterCard { dnt: card.dnt, widgets: card.widgets.clone(), ..Default::default() };
+    if card.card.is_none() && card != privacy_only {
+        card.card = Some(TwitterCardKind::Summary.to_string());
+    }
+
+    let mut sources = Sources { scope, base_url, og: None, jsonld: None, meta: None };
+    let fields = [
+        (&mut card.title, &config.title, Field::Title),
+        (&mut card.description, &config.description, Field::Description),
+        (&mut card.image, &config.image, Field::Image),
+    ];
+    for (value, chain, field) in fields {
+        for &source in chain {
+            if value.is_some() {
+                break;
+            }
+            *value = sources.get(source, field)?;
         }
     }
 
     Ok(card)
 }
 
+/// A Twitter Card field that can fall back to another source
+#[derive(Clone, Copy)]
+enum Field {
+    Title,
+    Description,
+    Image,
+}
+
+/// Fallback sources, each extracted on first use
+struct Sources<'a> {
+    scope: Scope<'a>,
+    base_url: Option<&'a str>,
+    og: Option<OpenGraph>,
+    jsonld: Option<Vec<JsonLdObject>>,
+    meta: Option<MetaTags>,
+}
+
+impl Sources<'_> {
+    fn get(&mut self, source: FallbackSource, field: Field) -> Result<Option<String>> {
+        let (scope, base_url) = (self.scope, self.base_url);
+        Ok(match source {
+            FallbackSource::OpenGraph => {
+                let og = match self.og {
+                    Some(ref og) => og,
+                    None => self.og.insert(super::opengraph::extract_in(scope, base_url)?),
+                };
+                match field {
+                    Field::Title => og.title.clone(),
+                    Field::Description => og.description.clone(),
+                    Field::Image => og.image.clone(),
+                }
+            }
+            FallbackSource::JsonLd => {
+                let objects = match self.jsonld {
+                    Some(ref objects) => objects,
+                    None => self.jsonld.insert(
+                        crate::extractors::jsonld::extract_limited_from_doc(
+                            scope,
+                            base_url,
+                            usize::MAX,
+                        )?
+                        .0,
+                    ),
+                };
+                match field {
+                    Field::Title => {
+                        jsonld_text(objects, "headline").or_else(|| jsonld_text(objects, "name"))
+                    }
+                    Field::Description => jsonld_text(objects, "description"),
+                    Field::Image => objects
+                        .iter()
+                        .find_map(jsonld_image)
+                        .map(|url| url_utils::resolve_or_keep(base_url, url)),
+                }
+            }
+            FallbackSource::Meta => match field {
+                Field::Image => {
+                    let selector = html_utils::create_selector("link[rel~='image_src'][href]")?;
+                    let href = scope
+                        .select(&selector)
+                        .find_map(|link| html_utils::get_attr(&link, "href"))
+                        .filter(|href| !href.trim().is_empty());
+                    href.map(|href| url_utils::resolve_or_keep(base_url, href.trim()))
+                }
+                Field::Title | Field::Description => {
+                    let meta = match self.meta {
+                        Some(ref meta) => meta,
+                        None => {
+                            self.meta.insert(crate::extractors::meta::extract_in(scope, base_url)?)
+                        }
+                    };
+                    match field {
+                        Field::Title => meta.title.clone(),
+                        _ => meta.description.clone(),
+                    }
+                }
+            },
+        })
+    }
+}
+
+/// The first non-empty string `property` across the JSON-LD objects
+fn jsonld_text(objects: &[JsonLdObject], property: &str) -> Option<String> {
+    objects.iter().find_map(|object| {
+        let value = object.borrowed_path(property)?;
+        let text = value.as_str().or_else(|| value.get(0)?.as_str())?.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+/// URL of a JSON-LD `image`: a string, an `ImageObject`, or the first of a list
+fn jsonld_image(object: &JsonLdObject) -> Option<&str> {
+    let url = match object.borrowed_path("image.0")? {
+        Value::String(url) => url,
+        image => image.get("url").or_else(|| image.get("contentUrl"))?.as_str()?,
+    };
+    Some(url.trim()).filter(|url| !url.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,7 +2,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::extractors::social::twitter::{extract, extract_with_fallback};
+    use crate::extractors::social::twitter::{
+        extract, extract_with_fallback, extract_with_fallback_opts,
+    };
+    use crate::types::social::{FallbackConfig, FallbackSource, TwitterCardKind};
 
     #[test]
     fn test_basic_twitter_card() {
@@ -491,4 +494,113 @@ mod tests {
         // Should handle malformed URL gracefully
         assert!(card.player.is_some());
     }
+
+    #[test]
+    fn test_twitter_card_kind_known_values() {
+        let cases = [
+            ("summary", TwitterCardKind::Summary),
+            ("summary_large_image", TwitterCardKind::SummaryLargeImage),
+            ("app", TwitterCardKind::App),
+            ("player", TwitterCardKind::Player),
+            ("Summary_Large_Image", TwitterCardKind::SummaryLargeImage),
+        ];
+        for (value, kind) in cases {
+            let html = format!(r#"<meta name="twitter:card" content="{}">"#, value);
+            let card = extract(&html, None).unwrap();
+            assert_eq!(card.card.as_deref(), Some(value));
+            assert_eq!(card.kind(), Some(kind.clone()));
+            assert!(kind.is_known());
+        }
+    }
+
+    #[test]
+    fn test_twitter_card_kind_unknown_value() {
+        let html = r#"<meta name="twitter:card" content="summary_card">"#;
+        let kind = extract(html, None).unwrap().kind().unwrap();
+        assert_eq!(kind, TwitterCardKind::Custom("summary_card".to_string()));
+        assert!(!kind.is_known());
+        assert_eq!(kind.to_string(), "summary_card");
+    }
+
+    #[test]
+    fn test_twitter_card_missing_defaults_to_summary_in_fallback() {
+        let html = r#"<meta name="twitter:title" content="Title">"#;
+        assert_eq!(extract(html, None).unwrap().kind(), None);
+
+        let card = extract_with_fallback(html, None).unwrap();
+        assert_eq!(card.card.as_deref(), Some("summary"));
+        assert_eq!(card.kind(), Some(TwitterCardKind::Summary));
+
+        // Without any twitter:* tags there is no card to default
+        let html = r#"<meta property="og:title" content="OG only">"#;
+        assert_eq!(extract_with_fallback(html, None).unwrap().card, None);
+    }
+
+    #[test]
+    fn test_twitter_dnt_and_widgets() {
+        let html = r#"
+            <meta name="twitter:dnt" content="on">
+            <meta name="twitter:widgets:csp" content="on">
+            <meta name="twitter:widgets:theme" content="dark">
+        "#;
+        let card = extract(html, None).unwrap();
+        assert_eq!(card.dnt, Some(true));
+        assert_eq!(card.widgets.get("csp").map(String::as_str), Some("on"));
+        assert_eq!(card.widgets.get("theme").map(String::as_str), Some("dark"));
+
+        // Privacy flags alone don't default the card type
+        assert_eq!(extract_with_fallback(html, None).unwrap().card, None);
+
+        let card = extract(r#"<meta name="twitter:dnt" content="OFF">"#, None).unwrap();
+        assert_eq!(card.dnt, Some(false));
+        let card = extract(r#"<meta name="twitter:dnt" content="maybe">"#, None).unwrap();
+        assert_eq!(card.dnt, None);
+    }
+
+    // ========== CONFIGURABLE FALLBACK ==========
+
+    #[test]
+    fn test_image_fallback_to_jsonld() {
+        let html = r#"
+            <meta name="twitter:title" content="Tweet title">
+            <script type="application/ld+json">
+                {"@type": "Article", "headline": "Headline",
+                 "image": {"@type": "ImageObject", "url": "/images/hero.jpg"}}
+            </script>
+        "#;
+        let config = FallbackConfig {
+            image: vec![FallbackSource::OpenGraph, FallbackSource::JsonLd],
+            ..Default::default()
+        };
+        let card =
+            extract_with_fallback_opts(html, Some("https://example.com/post"), &config).unwrap();
+        assert_eq!(card.image.as_deref(), Some("https://example.com/images/hero.jpg"));
+        assert_eq!(card.title.as_deref(), Some("Tweet title"));
+
+        // The default config only falls back to Open Graph
+        let card = extract_with_fallback(html, None).unwrap();
+        assert_eq!(card.image, None);
+    }
+
+    #[test]
+    fn test_fallback_order_is_respected() {
+        let html = r#"
+            <title>Meta title</title>
+            <meta property="og:title" content="OG title">
+            <meta property="og:image" content="https://example.com/og.jpg">
+            <script type="application/ld+json">{"@type": "Product", "image": ["https://example.com/ld.jpg"]}</script>
+        "#;
+        let config = FallbackConfig {
+            title: vec![FallbackSource::Meta, FallbackSource::OpenGraph],
+            description: Vec::new(),
+            image: vec![FallbackSource::JsonLd, FallbackSource::OpenGraph],
+        };
+        let card = extract_with_fallback_opts(html, None, &config).unwrap();
+        assert_eq!(card.title.as_deref(), Some("Meta title"));
+        assert_eq!(card.image.as_deref(), Some("https://example.com/ld.jpg"));
+
+        let card = extract_with_fallback_opts(html, None, &FallbackConfig::none()).unwrap();
+        assert_eq!(card.title, None);
+        assert_eq!(card.image, None);
+    }
 }
@@ -25,7 +25,11 @@ use std::os::raw::{c_char, c_int};
 use std::ptr;
 
 use crate::extractors;
+use crate::extractors::common::prescan::MarkupScan;
 use crate::parser;
+use crate::types::dublin_core::DublinCore;
+use crate::types::social::{OpenGraph, TwitterCard};
+use crate::types::HasData;
 
 /// Error codes returned by FFI functions
 #[repr(C)]
@@ -68,8 +72,9 @@ fn clear_last_error() {
 
 /// Result structure containing all extracted metadata
 ///
-/// Each field is a JSON string or NULL if no data was found.
-/// The caller must free this struct using `meta_oxide_result_free()`.
+/// Each field is a JSON string or NULL if no data was found; `meta`,
+/// `open_graph`, `twitter` and `dublin_core` are always set, as empty objects
+/// when there is nothing to report. The caller must free this struct using `meta_oxide_result_free()`.
 #[repr(C)]
 pub struct MetaOxideResult {
     /// Standard HTML meta tags (JSON object)
@@ -206,77 +211,112 @@ pub unsafe extern "C" fn meta_oxide_extract_all(
     });
 
     let result = Box::into_raw(result);
+    fill_result(result, html_str, base_url_str, &MarkupScan::scan(html_str));
 
+    result
+}
+
+/// Populate an allocated result, skipping extractors the pre-scan ruled out
+unsafe fn fill_result(
+    result: *mut MetaOxideResult,
+    html_str: &str,
+    base_url_str: Option<&str>,
+    scan: &MarkupScan,
+) {
     // Extract meta tags
     if let Ok(meta) = extractors::meta::extract(html_str, base_url_str) {
         (*result).meta = to_json_c_string(&meta);
     }
 
-    // Extract Open Graph
-    if let Ok(og) = extractors::social::extract_opengraph(html_str, base_url_str) {
+    // Extract Open Graph (always reported, empty when the scan rules it out)
+    let og = if scan.opengraph {
+        extractors::social::extract_opengraph(html_str, base_url_str)
+    } else {
+        Ok(OpenGraph::default())
+    };
+    if let Ok(og) = og {
         (*result).open_graph = to_json_c_string(&og);
     }
 
     // Extract Twitter Cards
-    if let Ok(twitter) = extractors::social::extract_twitter_with_fallback(html_str, base_url_str) {
+    let twitter = if scan.twitter {
+        extractors::social::extract_twitter_with_fallback(html_str, base_url_str)
+    } else {
+        Ok(TwitterCard::default())
+    };
+    if let Ok(twitter) = twitter {
         (*result).twitter = to_json_c_string(&twitter);
     }
 
     // Extract JSON-LD
-    if let Ok(json_ld) = extractors::jsonld::extract(html_str, base_url_str) {
-        if !json_ld.is_empty() {
-            (*result).json_ld = to_json_c_string(&json_ld);
+    if scan.jsonld {
+        if let Ok(json_ld) = extractors::jsonld::extract(html_str, base_url_str) {
+            if json_ld.has_data() {
+                (*result).json_ld = to_json_c_string(&json_ld);
+            }
         }
     }
 
     // Extract Microdata
-    if let Ok(microdata) = extractors::microdata::extract(html_str, base_url_str) {
-        if !microdata.is_empty() {
-            (*result).microdata = to_json_c_string(&microdata);
+    if scan.microdata {
+        if let Ok(microdata) = extractors::microdata::extract(html_str, base_url_str) {
+            if microdata.has_data() {
+                (*result).microdata = to_json_c_string(&microdata);
+            }
         }
     }
 
     // Extract Microformats (all 9 types)
-    if let Ok(microformats) = parser::parse_html(html_str, base_url_str) {
-        if !microformats.is_empty() {
-            (*result).microformats = to_json_c_string(&microformats);
+    if scan.microformats {
+        if let Ok(microformats) = parser::parse_html(html_str, base_url_str) {
+            if microformats.has_data() {
+                (*result).microformats = to_json_c_string(&microformats);
+            }
         }
     }
 
     // Extract RDFa
-    if let Ok(rdfa) = extractors::rdfa::extract(html_str, base_url_str) {
-        if !rdfa.is_empty() {
-            (*result).rdfa = to_json_c_string(&rdfa);
+    if scan.rdfa {
+        if let Ok(rdfa) = extractors::rdfa::extract(html_str, base_url_str) {
+            if rdfa.has_data() {
+                (*result).rdfa = to_json_c_string(&rdfa);
+            }
         }
     }
 
     // Extract Dublin Core
-    if let Ok(dc) = extractors::dublin_core::extract(html_str) {
+    let dc = if scan.dublin_core {
+        extractors::dublin_core::extract(html_str)
+    } else {
+        Ok(DublinCore::default())
+    };
+    if let Ok(dc) = dc {
         (*result).dublin_core = to_json_c_string(&dc);
     }
 
-    // Extract Web App Manifest
-    if let Ok(manifest) = extractors::manifest::extract(html_str, base_url_str) {
-        if manifest.href.is_some() {
-            (*result).manifest = to_json_c_string(&manifest);
+    // Link-based extractors all need a rel attribute
+    if scan.links {
+        // Extract Web App Manifest
+        if let Ok(manifest) = extractors::manifest::extract(html_str, base_url_str) {
+            if manifest.has_data() {
+                (*result).manifest = to_json_c_string(&manifest);
+            }
         }
-    }
 
-    // Extract oEmbed
-    if let Ok(oembed) = extractors::oembed::extract(html_str, base_url_str) {
-        if oembed.has_endpoints() {
-            (*result).oembed = to_json_c_string(&oembed);
+        // Extract oEmbed
+        if let Ok(oembed) = extractors::oembed::extract(html_str, base_url_str) {
+            if oembed.has_data() {
+                (*result).oembed = to_json_c_string(&oembed);
+            }
         }
-    }
 
-    // Extract rel-* links
-    if let Ok(rel_links) = extractors::rel_links::extract(html_str, base_url_str) {
-        if !rel_links.is_empty() {
-            (*result).rel_links = to_json_c_string(&rel_links);
+        // Extract rel-* links
+        if let Ok(rel_links) = extractors::rel_links::extract(html_str, base_url_str) {
+            if rel_links.has_data() {
+                (*result).rel_links = to_json_c_string(&rel_links);
+            }
         }
     }
-
-    result
 }
 
 /// Extract standard HTML meta tags
@@ -461,6 +501,36 @@ pub unsafe extern "C" fn meta_oxide_extract_rdfa(
     }
 }
 
+/// Extract RDFa as RDF triples
+///
+/// # Returns
+/// JSON array string or NULL on error
+///
+/// # Safety
+/// - `html` must be a valid null-terminated C string
+/// - `base_url` may be NULL or a valid null-terminated C string
+#[no_mangle]
+pub unsafe extern "C" fn meta_oxide_extract_rdfa_triples(
+    html: *const c_char,
+    base_url: *const c_char,
+) -> *mut c_char {
+    clear_last_error();
+
+    let html_str = match from_c_string(html) {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    let base_url_str = from_c_string_opt(base_url);
+
+    match extractors::rdfa::extract_triples(html_str, base_url_str) {
+        Ok(triples) => to_json_c_string(&triples),
+        Err(e) => {
+            set_last_error(MetaOxideError::ParseError, Some(e.to_string()));
+            ptr::null_mut()
+        }
+    }
+}
+
 /// Extract Dublin Core metadata
 ///
 /// # Returns
@@ -587,6 +657,91 @@ pub unsafe extern "C" fn meta_oxide_extract_rel_links(
     }
 }
 
+/// Extract resource hints (preload, preconnect, dns-prefetch, prefetch, prerender)
+///
+/// # Returns
+/// JSON object string or NULL on error
+///
+/// # Safety
+/// - `html` must be a valid null-terminated C string
+/// - `base_url` may be NULL or a valid null-terminated C string
+#[no_mangle]
+pub unsafe extern "C" fn meta_oxide_extract_resource_hints(
+    html: *const c_char,
+    base_url: *const c_char,
+) -> *mut c_char {
+    clear_last_error();
+
+    let html_str = match from_c_string(html) {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    let base_url_str = from_c_string_opt(base_url);
+
+    match extractors::resource_hints::extract(html_str, base_url_str) {
+        Ok(hints) => to_json_c_string(&hints),
+        Err(e) => {
+            set_last_error(MetaOxideError::ParseError, Some(e.to_string()));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Extract the breadcrumb trail (JSON-LD, microdata, or nav fallback)
+///
+/// # Returns
+/// JSON array string or NULL on error
+///
+/// # Safety
+/// - `html` must be a valid null-terminated C string
+/// - `base_url` may be NULL or a valid null-terminated C string
+#[no_mangle]
+pub unsafe extern "C" fn meta_oxide_extract_breadcrumbs(
+    html: *const c_char,
+    base_url: *const c_char,
+) -> *mut c_char {
+    clear_last_error();
+
+    let html_str = match from_c_string(html) {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    let base_url_str = from_c_string_opt(base_url);
+
+    match extractors::breadcrumbs::extract(html_str, base_url_str) {
+        Ok(trail) => to_json_c_string(&trail),
+        Err(e) => {
+            set_last_error(MetaOxideError::ParseError, Some(e.to_string()));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Extract legacy geotagging meta tags (geo.position, ICBM, geo.region, geo.placename)
+///
+/// # Returns
+/// JSON object string or NULL on error
+///
+/// # Safety
+/// `html` must be a valid null-terminated C string
+#[no_mangle]
+pub unsafe extern "C" fn meta_oxide_extract_geo(html: *const c_char) -> *mut c_char {
+    clear_last_error();
+
+    let html_str = match from_c_string(html) {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match extractors::geo::extract(html_str) {
+        Ok(geo) => to_json_c_string(&geo),
+        Err(e) => {
+            set_last_error(MetaOxideError::ParseError, Some(e.to_string()));
+            ptr::null_mut()
+        }
+    }
+}
+
 /// Get the last error code
 ///
 /// Returns MetaOxideError::Ok (0) if no error occurred
@@ -723,6 +878,14 @@ pub extern "C" fn meta_oxide_version() -> *const c_char {
     concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
 }
 
+/// Get the version of the JSON output shape
+///
+/// Bumped only on breaking output changes, not on every release.
+#[no_mangle]
+pub extern "C" fn meta_oxide_schema_version() -> u32 {
+    crate::SCHEMA_VERSION
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -750,6 +913,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_all_always_sets_metadata_fields() {
+        let html = CString::new("<p>plain</p>").unwrap();
+
+        unsafe {
+            let result = meta_oxide_extract_all(html.as_ptr(), ptr::null());
+            assert!(!result.is_null());
+            assert!(!(*result).meta.is_null());
+            assert!(!(*result).open_graph.is_null());
+            assert!(!(*result).twitter.is_null());
+            assert!(!(*result).dublin_core.is_null());
+            assert!((*result).json_ld.is_null());
+            meta_oxide_result_free(result);
+        }
+    }
+
     #[test]
     fn test_extract_meta() {
         let html = CString::new(
@@ -790,4 +969,75 @@ mod tests {
             assert!(!version_str.is_empty());
         }
     }
+
+    /// Run extract_all with the given scan and collect every field as parsed JSON
+    unsafe fn extract_all_fields(html: &str, scan: &MarkupScan) -> Vec<Option<serde_json::Value>> {
+        let result = Box::into_raw(Box::new(MetaOxideResult {
+            meta: ptr::null_mut(),
+            open_graph: ptr::null_mut(),
+            twitter: ptr::null_mut(),
+            json_ld: ptr::null_mut(),
+            microdata: ptr::null_mut(),
+            microformats: ptr::null_mut(),
+            rdfa: ptr::null_mut(),
+            dublin_core: ptr::null_mut(),
+            manifest: ptr::null_mut(),
+            oembed: ptr::null_mut(),
+            rel_links: ptr::null_mut(),
+        }));
+        fill_result(result, html, Some("https://example.com/"), scan);
+
+        let r = &*result;
+        let fields = [
+            r.meta,
+            r.open_graph,
+            r.twitter,
+            r.json_ld,
+            r.microdata,
+            r.microformats,
+            r.rdfa,
+            r.dublin_core,
+            r.manifest,
+            r.oembed,
+            r.rel_links,
+        ];
+        let owned = fields
+            .iter()
+            .map(|p| {
+                (!p.is_null())
+                    .then(|| serde_json::from_slice(CStr::from_ptr(*p).to_bytes()).unwrap())
+            })
+            .collect();
+        meta_oxide_result_free(result);
+        owned
+    }
+
+    #[test]
+    fn test_extract_all_prescan_matches_full_extraction() {
+        let fixtures = [
+            "",
+            "<html><head><title>Plain</title></head><body><p>No markup</p></body></html>",
+            "<p>No head at all</p>",
+            r#"<script type="application/ld+json">{"@type": "Person", "name": "Jane"}</script>"#,
+            r#"<div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Jane</span></div>"#,
+            r#"<div vocab="https://schema.org/" typeof="Person"><span property="name">Jane</span></div>"#,
+            r#"<meta property="og:title" content="OG"><meta property="og:image" content="/a.png">"#,
+            r#"<meta name="twitter:card" content="summary"><meta name="twitter:title" content="T">"#,
+            r#"<div class="h-card"><span class="p-name">Jane</span></div>"#,
+            r#"<div class="h-entry"><h1 class="p-name">Post</h1></div>"#,
+            r#"<meta name="DC.title" content="Archive"><meta name="dcterms.creator" content="Jane">"#,
+            r#"<link rel="manifest" href="/manifest.json"><link rel="me" href="https://social.example/@jane">"#,
+            r#"<link rel="alternate" type="application/json+oembed" href="/oembed?url=x">"#,
+            r#"<META PROPERTY="og:title" CONTENT="Upper"><DIV CLASS="h-card"><SPAN CLASS="p-name">J</SPAN></DIV>"#,
+            r#"<meta name="twitter&#58;title" content="Encoded"><div class="h&#45;card">x</div>"#,
+        ];
+
+        for html in fixtures {
+            unsafe {
+                let fast = extract_all_fields(html, &MarkupScan::scan(html));
+                let full = extract_all_fields(html, &MarkupScan::full());
+                assert_eq!(fast, full, "fast path diverged for fixture: {}", html);
+            }
+        }
+    }
 }
@@ -1,54 +1,143 @@
 // PyO3 macro expansions can trigger false positive clippy warnings
 #![allow(clippy::useless_conversion)]
 
+#[cfg(feature = "python")]
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::types::{PyDict, PyList};
-#[cfg(feature = "python")]
-use std::collections::HashMap;
 
+pub mod analysis;
+#[cfg(feature = "cache")]
+mod cache;
 mod errors;
-mod extractors;
+mod extract;
+pub mod extractors;
 pub mod ffi;
 #[macro_use]
 mod macros;
 mod parser;
+pub mod prelude;
+pub mod serialize;
 mod types;
 
-pub use errors::{MicroformatError, Result};
+#[cfg(feature = "cache")]
+pub use cache::{extract_all_cached, extract_all_cached_with_options, DocumentCache};
+pub use errors::{ErrorKind, MicroformatError, Result};
+pub use extract::{
+    extract_all, extract_all_batch, extract_all_from_doc, extract_all_from_doc_with_options,
+    extract_all_json, extract_all_with_options, extract_within, ExtractOptions, UrlResolution,
+    SCHEMA_VERSION,
+};
+pub use extractors::common::html_utils::ParseMode;
+/// Normalize an absolute URL for deduplication; see [`UrlNormalizeOptions`]
+/// for what is stripped
+pub use extractors::common::url_utils::normalize as normalize_url;
+pub use extractors::common::url_utils::NormalizeOptions as UrlNormalizeOptions;
+/// The parsed document type taken by `extract_all_from_doc` and the
+/// extractors' `extract_from_doc` functions
+///
+/// Re-exported from `scraper` 0.20; a document parsed with another major
+/// version of `scraper` is a different type and will not be accepted.
+pub use scraper::Html;
+pub use types::extracted::ExtractedData;
 pub use types::*;
 
-// Re-export utilities needed by macros (required for macro expansion, not Python-specific)
-#[doc(hidden)]
-pub use extractors::common::{html_utils, url_utils};
+/// HTML parsing and text helpers shared by the extractors
+///
+/// [`html_utils::text_content`] and [`html_utils::extract_text`] apply the
+/// whitespace policy every extractor uses, so callers reading their own
+/// elements get the same text.
+pub use extractors::common::html_utils;
+/// URL resolution and normalization helpers shared by the extractors
+pub use extractors::common::url_utils;
+
+#[cfg(feature = "python")]
+use extractors::common::prescan::MarkupScan;
+#[cfg(feature = "python")]
+use types::{
+    dublin_core::DublinCore,
+    social::{OpenGraph, TwitterCard},
+};
 
 #[cfg(feature = "python")]
 /// Extract microformats data from HTML content
 #[cfg(feature = "python")]
 #[pyfunction]
 #[pyo3(signature = (html, base_url=None))]
-fn extract_microformats(
-    html: &str,
-    base_url: Option<&str>,
-) -> PyResult<HashMap<String, Vec<PyObject>>> {
+fn extract_microformats(html: &str, base_url: Option<&str>) -> PyResult<Py<PyDict>> {
     Python::with_gil(|py| {
         let result = parser::parse_html(html, base_url)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
 
-        let mut py_result = HashMap::new();
-
-        // Convert Rust data structures to Python objects
+        // Convert Rust data structures to Python objects, keeping the sorted key order
+        let py_result = PyDict::new_bound(py);
         for (format_type, items) in result.iter() {
             let py_items: Vec<PyObject> =
                 items.iter().map(|item| item.to_py_dict(py).into()).collect();
-            py_result.insert(format_type.clone(), py_items);
+            py_result.set_item(format_type, py_items)?;
         }
 
-        Ok(py_result)
+        Ok(py_result.into())
     })
 }
 
+/// Extract a microformat with any `h-*` root class
+///
+/// Uses the generic microformats2 rules, so custom vocabularies such as
+/// `h-review-aggregate` work without a dedicated extractor.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     root_class (str): Root class to look for, e.g. "h-widget"
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     list: One dict per root element with "type", "properties" and,
+///         for nested roots without a property class, "children"
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> widgets = meta_oxide.extract_microformat(html, "h-widget")
+///     >>> print(widgets[0]['properties']['name'])
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, root_class, base_url=None))]
+fn extract_microformat(
+    py: Python,
+    html: &str,
+    root_class: &str,
+    base_url: Option<&str>,
+) -> PyResult<Vec<Py<PyDict>>> {
+    let items = extractors::microformats::extract_generic(html, base_url, root_class)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(items.iter().map(|item| item.to_py_dict(py)).collect())
+}
+
+/// Find the author of a post with the IndieWeb authorship rules
+///
+/// Prefers the h-entry's nested p-author h-card, then the page's rel="author"
+/// link (as a URL-only card unless an h-card on the page has that URL).
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     dict | None: The author h-card, or None if no author was found
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> author = meta_oxide.find_author(html, "https://example.com/post")
+///     >>> print(author.get('name'), author.get('url'))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn find_author(py: Python, html: &str, base_url: Option<&str>) -> Option<Py<PyDict>> {
+    extractors::microformats::find_author(html, base_url).map(|card| card.to_py_dict(py))
+}
+
 #[cfg(feature = "python")]
 py_extractor_binding!(extract_hcard, hcard, HCard);
 
@@ -90,6 +179,7 @@ py_extractor_binding!(extract_hgeo, hgeo, HGeo);
 ///         - viewport: Viewport meta tag
 ///         - charset: Character encoding
 ///         - language: Page language
+///         - color_scheme: List of supported color schemes
 ///         - robots: Robots directives
 ///         - alternate: List of alternate links
 ///         - feeds: List of feed links
@@ -181,6 +271,28 @@ fn extract_twitter_with_fallback(
     Ok(card.to_py_dict(py))
 }
 
+/// Extract Pinterest meta tags
+///
+/// Args:
+///     html (str): HTML content to extract from
+///
+/// Returns:
+///     dict: Dictionary with optional 'rich_pin' (bool), 'nopin',
+///         'nopin_description' and 'properties' (the pinterest:* tags)
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> pinterest = meta_oxide.extract_pinterest(html)
+///     >>> print(pinterest.get('rich_pin'))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html))]
+fn extract_pinterest(py: Python, html: &str) -> PyResult<Py<PyDict>> {
+    let pinterest = extractors::social::extract_pinterest(html)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(pinterest.to_py_dict(py))
+}
+
 /// Extract JSON-LD structured data
 ///
 /// Args:
@@ -241,6 +353,30 @@ fn extract_microdata(py: Python, html: &str, base_url: Option<&str>) -> PyResult
     Ok(list.unbind())
 }
 
+/// Extract RDFa and microdata as an N-Triples document
+///
+/// Each statement is one line, ready to load into an RDF store. RDFa blank
+/// nodes are labelled `_:b0`, ..., microdata blank nodes `_:m0`, ...
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     str: N-Triples document
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> nt = meta_oxide.extract_ntriples(html, "https://example.com")
+///     >>> print(nt.count("\n"), "statements")
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn extract_ntriples(html: &str, base_url: Option<&str>) -> PyResult<String> {
+    serialize::extract_ntriples(html, base_url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+}
+
 /// Extract Dublin Core metadata (Phase 9)
 ///
 /// Extracts Dublin Core metadata elements commonly used in digital libraries and archives.
@@ -273,6 +409,8 @@ fn extract_dublin_core(py: Python, html: &str) -> PyResult<Py<PyDict>> {
 /// Args:
 ///     html (str): HTML content to extract from
 ///     base_url (str, optional): Base URL for resolving relative URLs
+///     dedupe (bool, optional): Strip tracking parameters (utm_*, fbclid, gclid)
+///         and drop repeated URLs. Defaults to False.
 ///
 /// Returns:
 ///     dict: Dictionary mapping rel type to list of URLs
@@ -284,11 +422,170 @@ fn extract_dublin_core(py: Python, html: &str) -> PyResult<Py<PyDict>> {
 ///     >>> print(links.get('me'))  # ['https://twitter.com/user', 'https://github.com/user']
 #[cfg(feature = "python")]
 #[pyfunction]
-#[pyo3(signature = (html, base_url=None))]
-fn extract_rel_links(html: &str, base_url: Option<&str>) -> PyResult<HashMap<String, Vec<String>>> {
-    let links = extractors::rel_links::extract(html, base_url)
+#[pyo3(signature = (html, base_url=None, dedupe=false))]
+fn extract_rel_links(
+    py: Python,
+    html: &str,
+    base_url: Option<&str>,
+    dedupe: bool,
+) -> PyResult<Py<PyDict>> {
+    let opts = dedupe.then(url_utils::NormalizeOptions::default);
+    let links = extractors::rel_links::extract_with_options(html, base_url, opts.as_ref())
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-    Ok(links)
+    rel_links_to_py_dict(py, &links)
+}
+
+/// Convert rel links to a Python dict, keeping document order
+#[cfg(feature = "python")]
+fn rel_links_to_py_dict(py: Python, links: &IndexMap<String, Vec<String>>) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new_bound(py);
+    for (rel, urls) in links {
+        dict.set_item(rel, urls)?;
+    }
+    Ok(dict.unbind())
+}
+
+/// Extract resource hints from HTML
+///
+/// Collects `<link>` tags declaring `preload`, `preconnect`, `dns-prefetch`,
+/// `prefetch`, `prerender`, `stylesheet` and `modulepreload` hints, grouped
+/// by hint type. Keys use underscores, so `dns-prefetch` hints are under
+/// 'dns_prefetch'; hint types with no links are omitted.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     dict: Dictionary mapping hint type to a list of hints, each with
+///         'href' and optional 'as', 'type', 'crossorigin', 'media'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> hints = meta_oxide.extract_resource_hints(html, "https://example.com")
+///     >>> for hint in hints.get('preload', []):
+///     ...     print(hint['href'], hint.get('as'))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn extract_resource_hints(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<PyDict>> {
+    let hints = extractors::resource_hints::extract(html, base_url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(hints.to_py_dict(py))
+}
+
+/// Extract the page's breadcrumb trail
+///
+/// Reads JSON-LD and microdata `BreadcrumbList` markup, falling back to
+/// `<nav aria-label="breadcrumb">` link lists.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     list: Breadcrumb steps ordered by position, each with 'position'
+///         and optional 'name' and 'url'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> trail = meta_oxide.extract_breadcrumbs(html, "https://example.com")
+///     >>> print(" > ".join(step.get('name', '') for step in trail))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn extract_breadcrumbs(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<PyList>> {
+    let trail = extractors::breadcrumbs::extract(html, base_url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+    let list = PyList::empty_bound(py);
+    for crumb in trail {
+        list.append(crumb.to_py_dict(py)).unwrap();
+    }
+    Ok(list.unbind())
+}
+
+/// Extract legacy geotagging meta tags
+///
+/// Reads `geo.position` / `ICBM` coordinates (preferring `geo.position`),
+/// `geo.region` and `geo.placename`.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///
+/// Returns:
+///     dict: Dictionary with optional 'latitude', 'longitude', 'region'
+///         and 'placename'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> geo = meta_oxide.extract_geo(html)
+///     >>> print(geo.get('latitude'), geo.get('longitude'))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html))]
+fn extract_geo(py: Python, html: &str) -> PyResult<Py<PyDict>> {
+    let geo = extractors::geo::extract(html)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(geo.to_py_dict(py))
+}
+
+/// Extract JSON state embedded in `<script type="application/json">` blocks
+///
+/// Captures framework state such as Next.js `__NEXT_DATA__`. JSON-LD scripts
+/// are not included, and blocks that are not valid JSON are skipped.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///
+/// Returns:
+///     list: Dicts with an optional 'id' and the parsed 'data'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> blocks = meta_oxide.extract_embedded_json(html)
+///     >>> next_data = next(b for b in blocks if b.get('id') == '__NEXT_DATA__')
+///     >>> print(next_data['data']['props'])
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html))]
+fn extract_embedded_json(py: Python, html: &str) -> PyResult<Py<PyList>> {
+    let blocks = extractors::embedded_json::extract(html)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let list = PyList::empty_bound(py);
+    for block in blocks {
+        list.append(block.to_py_dict(py)).unwrap();
+    }
+    Ok(list.unbind())
+}
+
+/// Extract metadata from HTTP response headers
+///
+/// For PDFs and other non-HTML responses, reads the canonical and alternate
+/// links from `Link`, robots directives from `X-Robots-Tag` and languages
+/// from `Content-Language`.
+///
+/// Args:
+///     headers (list[tuple[str, str]]): Response headers as (name, value) pairs
+///     base_url (str, optional): Base URL for resolving relative link targets
+///
+/// Returns:
+///     dict: Dictionary with optional 'canonical', 'alternate', 'rel_links',
+///         'robots', 'bot_robots' and 'content_language'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> meta = meta_oxide.extract_from_headers(list(response.headers.items()), response.url)
+///     >>> print(meta.get('canonical'))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (headers, base_url=None))]
+fn extract_from_headers(
+    py: Python,
+    headers: Vec<(String, String)>,
+    base_url: Option<&str>,
+) -> PyResult<Py<PyDict>> {
+    Ok(extractors::headers::extract_from_headers(&headers, base_url).to_py_dict(py))
 }
 
 /// Discover oEmbed endpoints (Phase 5)
@@ -304,6 +601,8 @@ fn extract_rel_links(html: &str, base_url: Option<&str>) -> PyResult<HashMap<Str
 ///     dict: Dictionary containing discovered oEmbed endpoints:
 ///         - json_endpoints: List of JSON oEmbed endpoints
 ///         - xml_endpoints: List of XML oEmbed endpoints
+///         Each endpoint has 'href', 'format' ("json" or "xml") and, when
+///         the link tag has one, 'title'
 ///
 /// Example:
 ///     >>> import meta_oxide
@@ -351,6 +650,36 @@ fn extract_rdfa(py: Python, html: &str, base_url: Option<&str>) -> PyResult<PyOb
     Ok(list.to_object(py))
 }
 
+/// Extract RDFa as RDF triples
+///
+/// Subjects come from `about` (or blank nodes such as `_:b0`), predicates are
+/// expanded against the active vocabulary, and `typeof` becomes an `rdf:type` triple.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     list: List of dicts with 'subject', 'predicate' and 'object'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> for t in meta_oxide.extract_rdfa_triples(html, "https://example.com"):
+///     ...     print(t['subject'], t['predicate'], t['object'])
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn extract_rdfa_triples(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<PyList>> {
+    let triples = extractors::rdfa::extract_triples(html, base_url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+    let list = PyList::empty_bound(py);
+    for triple in triples {
+        list.append(triple.to_py_dict(py)).unwrap();
+    }
+    Ok(list.unbind())
+}
+
 /// Extract Web App Manifest link from HTML
 ///
 /// Finds and resolves the manifest link from <link rel="manifest"> tags.
@@ -402,6 +731,57 @@ fn parse_manifest(py: Python, json: &str, base_url: Option<&str>) -> PyResult<Py
     Ok(manifest.to_py_dict(py))
 }
 
+/// Discover the OpenSearch description link
+///
+/// Finds <link rel="search" type="application/opensearchdescription+xml">,
+/// which browsers use to add a site's search engine.
+///
+/// Args:
+///     html (str): HTML content to extract from
+///     base_url (str, optional): Base URL for resolving relative URLs
+///
+/// Returns:
+///     dict or None: Dictionary with 'href' and optional 'title'
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> link = meta_oxide.discover_opensearch(html, "https://example.com")
+///     >>> print(link['href'])
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (html, base_url=None))]
+fn discover_opensearch(
+    py: Python,
+    html: &str,
+    base_url: Option<&str>,
+) -> PyResult<Option<Py<PyDict>>> {
+    let link = extractors::opensearch::discover(html, base_url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(link.map(|link| link.to_py_dict(py)))
+}
+
+/// Parse an OpenSearch description document
+///
+/// Args:
+///     xml (str): OpenSearch description XML
+///
+/// Returns:
+///     dict: Dictionary with optional 'short_name', 'description' and 'urls'
+///         (each with 'template' and optional 'type', 'rel', 'method')
+///
+/// Example:
+///     >>> import meta_oxide
+///     >>> description = meta_oxide.parse_opensearch(xml)
+///     >>> print(description['urls'][0]['template'])
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (xml))]
+fn parse_opensearch(py: Python, xml: &str) -> PyResult<Py<PyDict>> {
+    let description = extractors::opensearch::parse_opensearch(xml)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(description.to_py_dict(py))
+}
+
 /// Extract ALL supported structured data from HTML (Phases 1-4)
 ///
 /// This is the main convenience function that extracts:
@@ -414,15 +794,27 @@ fn parse_manifest(py: Python, json: &str, base_url: Option<&str>) -> PyResult<Py
 /// Args:
 ///     html (str): HTML content to extract from
 ///     base_url (str, optional): Base URL for resolving relative URLs
+///     ignore_hidden (bool, optional): Skip text inside hidden elements
+///         (hidden, aria-hidden="true", inline display:none) when reading
+///         microdata and microformats text values. Defaults to False.
+///     xml (bool, optional): Parse the input as XHTML served as XML, so
+///         self-closing elements keep their siblings and xml:lang wins over
+///         lang. Defaults to False.
 ///
 /// Returns:
 ///     dict: Dictionary containing all extracted data with keys:
+///         - schema_version: Version of this dict's shape (meta_oxide.SCHEMA_VERSION),
+///           bumped only on breaking changes
 ///         - meta: Standard HTML meta tags (title, description, etc.)
 ///         - opengraph: Open Graph Protocol data
 ///         - twitter: Twitter Card data
 ///         - jsonld: JSON-LD / Schema.org structured data (list of objects)
 ///         - microformats: Microformats data (h-card, h-entry, h-event)
 ///         - rel_links: HTML link relationships (rel-author, rel-me, etc.)
+///         - document_base: <base> href and target
+///
+///     meta, opengraph, twitter and dublin_core are always present (empty
+///     when the page has none); the other keys only when something was found.
 ///
 /// Example:
 ///     >>> import meta_oxide
@@ -435,13 +827,38 @@ fn parse_manifest(py: Python, json: &str, base_url: Option<&str>) -> PyResult<Py
 #[cfg(feature = "python")]
 #[cfg(feature = "python")]
 #[pyfunction]
-#[pyo3(signature = (html, base_url=None))]
-fn extract_all(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<PyDict>> {
+#[pyo3(name = "extract_all", signature = (html, base_url=None, ignore_hidden=false, xml=false))]
+fn py_extract_all(
+    py: Python,
+    html: &str,
+    base_url: Option<&str>,
+    ignore_hidden: bool,
+    xml: bool,
+) -> PyResult<Py<PyDict>> {
+    let mode = if xml { ParseMode::Xml } else { ParseMode::Html5 };
+    let markup = html_utils::html5_markup(html, mode);
+    let html = markup.as_ref();
+
     let dict = PyDict::new_bound(py);
+    dict.set_item("schema_version", SCHEMA_VERSION)?;
+
+    // Cheap pre-scan so pages without structured markup skip the heavier extractors
+    let scan = MarkupScan::scan(html);
+
+    // Microdata and microformats read element text, so they see the filtered document
+    let text_html = if ignore_hidden && (scan.microdata || scan.microformats) {
+        std::borrow::Cow::Owned(
+            html_utils::without_hidden_text(&html_utils::parse_html(html)).html(),
+        )
+    } else {
+        std::borrow::Cow::Borrowed(html)
+    };
 
     // Extract Phase 1: Standard Meta Tags
+    let mut document_lang = None;
     match extractors::meta::extract(html, base_url) {
         Ok(meta_tags) => {
+            document_lang = meta_tags.effective_language();
             dict.set_item("meta", meta_tags.to_py_dict(py))?;
         }
         Err(e) => {
@@ -449,9 +866,19 @@ fn extract_all(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<Py
             eprintln!("Meta extraction warning: {}", e);
         }
     }
+    if scan.base {
+        if let Ok(Some(base)) = extractors::meta::extract_document_base(html, base_url) {
+            dict.set_item("document_base", base.to_py_dict(py))?;
+        }
+    }
 
-    // Extract Phase 2: Open Graph
-    match extractors::social::extract_opengraph(html, base_url) {
+    // Extract Phase 2: Open Graph (always reported, empty when the scan rules it out)
+    let og = if scan.opengraph {
+        extractors::social::extract_opengraph(html, base_url)
+    } else {
+        Ok(OpenGraph::default())
+    };
+    match og {
         Ok(og) => {
             dict.set_item("opengraph", og.to_py_dict(py))?;
         }
@@ -461,7 +888,12 @@ fn extract_all(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<Py
     }
 
     // Extract Phase 2: Twitter Cards (with fallback to OG)
-    match extractors::social::extract_twitter_with_fallback(html, base_url) {
+    let twitter = if scan.twitter {
+        extractors::social::extract_twitter_with_fallback(html, base_url)
+    } else {
+        Ok(TwitterCard::default())
+    };
+    match twitter {
         Ok(twitter) => {
             dict.set_item("twitter", twitter.to_py_dict(py))?;
         }
@@ -471,142 +903,161 @@ fn extract_all(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<Py
     }
 
     // Extract Phase 3: JSON-LD (41% adoption, HIGHEST IMPACT)
-    match extractors::jsonld::extract(html, base_url) {
-        Ok(objects) => {
-            if !objects.is_empty() {
-                let list = PyList::empty_bound(py);
-                for obj in objects {
-                    list.append(obj.to_py_dict(py)).unwrap();
+    if scan.jsonld {
+        match extractors::jsonld::extract(html, base_url) {
+            Ok(objects) => {
+                if objects.has_data() {
+                    let list = PyList::empty_bound(py);
+                    for obj in objects {
+                        list.append(obj.to_py_dict(py)).unwrap();
+                    }
+                    dict.set_item("jsonld", list)?;
                 }
-                dict.set_item("jsonld", list)?;
             }
-        }
-        Err(e) => {
-            eprintln!("JSON-LD extraction warning: {}", e);
+            Err(e) => {
+                eprintln!("JSON-LD extraction warning: {}", e);
+            }
         }
     }
 
     // Extract Phase 4: Microdata (26% adoption)
-    match extractors::microdata::extract(html, base_url) {
-        Ok(items) => {
-            if !items.is_empty() {
-                let list = PyList::empty_bound(py);
-                for item in items {
-                    list.append(item.to_py_dict(py)).unwrap();
+    if scan.microdata {
+        match extractors::microdata::extract(&text_html, base_url) {
+            Ok(items) => {
+                if items.has_data() {
+                    let list = PyList::empty_bound(py);
+                    for item in items {
+                        list.append(item.to_py_dict(py)).unwrap();
+                    }
+                    dict.set_item("microdata", list)?;
                 }
-                dict.set_item("microdata", list)?;
             }
-        }
-        Err(e) => {
-            eprintln!("Microdata extraction warning: {}", e);
+            Err(e) => {
+                eprintln!("Microdata extraction warning: {}", e);
+            }
         }
     }
 
     // Extract Phase 7: Microformats (already implemented)
-    let mf_dict = PyDict::new_bound(py);
-    let mut has_microformats = false;
-
-    // Extract h-card
-    if let Ok(hcards) = extractors::microformats::hcard::extract(html, base_url) {
-        if !hcards.is_empty() {
-            let cards: Vec<_> = hcards.iter().map(|card| card.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-card", cards)?;
-            has_microformats = true;
+    if scan.microformats {
+        let mf_dict = PyDict::new_bound(py);
+        let mut has_microformats = false;
+
+        // Extract h-card
+        if let Ok(hcards) = extractors::microformats::hcard::extract(&text_html, base_url) {
+            if hcards.has_data() {
+                let cards: Vec<_> =
+                    hcards.iter().map(|card| card.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-card", cards)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-entry
-    if let Ok(entries) = extractors::microformats::hentry::extract(html, base_url) {
-        if !entries.is_empty() {
-            let entries_py: Vec<_> = entries.iter().map(|e| e.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-entry", entries_py)?;
-            has_microformats = true;
+        // Extract h-entry
+        if let Ok(entries) = extractors::microformats::hentry::extract(&text_html, base_url) {
+            if entries.has_data() {
+                let entries_py: Vec<_> =
+                    entries.iter().map(|e| e.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-entry", entries_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-event
-    if let Ok(events) = extractors::microformats::hevent::extract(html, base_url) {
-        if !events.is_empty() {
-            let events_py: Vec<_> = events.iter().map(|e| e.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-event", events_py)?;
-            has_microformats = true;
+        // Extract h-event
+        if let Ok(events) = extractors::microformats::hevent::extract(&text_html, base_url) {
+            if events.has_data() {
+                let events_py: Vec<_> =
+                    events.iter().map(|e| e.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-event", events_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-review
-    if let Ok(reviews) = extractors::microformats::hreview::extract(html, base_url) {
-        if !reviews.is_empty() {
-            let reviews_py: Vec<_> = reviews.iter().map(|r| r.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-review", reviews_py)?;
-            has_microformats = true;
+        // Extract h-review
+        if let Ok(reviews) = extractors::microformats::hreview::extract(&text_html, base_url) {
+            if reviews.has_data() {
+                let reviews_py: Vec<_> =
+                    reviews.iter().map(|r| r.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-review", reviews_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-recipe
-    if let Ok(recipes) = extractors::microformats::hrecipe::extract(html, base_url) {
-        if !recipes.is_empty() {
-            let recipes_py: Vec<_> = recipes.iter().map(|r| r.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-recipe", recipes_py)?;
-            has_microformats = true;
+        // Extract h-recipe
+        if let Ok(recipes) = extractors::microformats::hrecipe::extract(&text_html, base_url) {
+            if recipes.has_data() {
+                let recipes_py: Vec<_> =
+                    recipes.iter().map(|r| r.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-recipe", recipes_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-product
-    if let Ok(products) = extractors::microformats::hproduct::extract(html, base_url) {
-        if !products.is_empty() {
-            let products_py: Vec<_> =
-                products.iter().map(|p| p.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-product", products_py)?;
-            has_microformats = true;
+        // Extract h-product
+        if let Ok(products) = extractors::microformats::hproduct::extract(&text_html, base_url) {
+            if products.has_data() {
+                let products_py: Vec<_> =
+                    products.iter().map(|p| p.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-product", products_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-feed
-    if let Ok(feeds) = extractors::microformats::hfeed::extract(html, base_url) {
-        if !feeds.is_empty() {
-            let feeds_py: Vec<_> = feeds.iter().map(|f| f.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-feed", feeds_py)?;
-            has_microformats = true;
+        // Extract h-feed
+        if let Ok(feeds) = extractors::microformats::hfeed::extract(&text_html, base_url) {
+            if feeds.has_data() {
+                let feeds_py: Vec<_> = feeds.iter().map(|f| f.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-feed", feeds_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-adr
-    if let Ok(addresses) = extractors::microformats::hadr::extract(html, base_url) {
-        if !addresses.is_empty() {
-            let addresses_py: Vec<_> =
-                addresses.iter().map(|a| a.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-adr", addresses_py)?;
-            has_microformats = true;
+        // Extract h-adr
+        if let Ok(addresses) = extractors::microformats::hadr::extract(&text_html, base_url) {
+            if addresses.has_data() {
+                let addresses_py: Vec<_> =
+                    addresses.iter().map(|a| a.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-adr", addresses_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    // Extract h-geo
-    if let Ok(geos) = extractors::microformats::hgeo::extract(html, base_url) {
-        if !geos.is_empty() {
-            let geos_py: Vec<_> = geos.iter().map(|g| g.to_py_dict(py).into_py(py)).collect();
-            mf_dict.set_item("h-geo", geos_py)?;
-            has_microformats = true;
+        // Extract h-geo
+        if let Ok(geos) = extractors::microformats::hgeo::extract(&text_html, base_url) {
+            if geos.has_data() {
+                let geos_py: Vec<_> = geos.iter().map(|g| g.to_py_dict(py).into_py(py)).collect();
+                mf_dict.set_item("h-geo", geos_py)?;
+                has_microformats = true;
+            }
         }
-    }
 
-    if has_microformats {
-        dict.set_item("microformats", mf_dict)?;
+        if has_microformats {
+            add_microformat_langs(&mf_dict, &text_html, document_lang.as_deref())?;
+            dict.set_item("microformats", mf_dict)?;
+        }
     }
 
     // Extract Phase 5: oEmbed endpoint discovery
-    match extractors::oembed::extract(html, base_url) {
-        Ok(oembed) => {
-            if oembed.has_endpoints() {
-                dict.set_item("oembed", oembed.to_py_dict(py))?;
+    if scan.links {
+        match extractors::oembed::extract(html, base_url) {
+            Ok(oembed) => {
+                if oembed.has_data() {
+                    dict.set_item("oembed", oembed.to_py_dict(py))?;
+                }
+            }
+            Err(e) => {
+                eprintln!("oEmbed extraction warning: {}", e);
             }
-        }
-        Err(e) => {
-            eprintln!("oEmbed extraction warning: {}", e);
         }
     }
 
     // Extract Phase 9: Dublin Core metadata
-    match extractors::dublin_core::extract(html) {
+    let dc = if scan.dublin_core {
+        extractors::dublin_core::extract(html)
+    } else {
+        Ok(DublinCore::default())
+    };
+    match dc {
         Ok(dc) => {
             dict.set_item("dublin_core", dc.to_py_dict(py))?;
         }
@@ -616,48 +1067,80 @@ fn extract_all(py: Python, html: &str, base_url: Option<&str>) -> PyResult<Py<Py
     }
 
     // Extract rel-* link relationships
-    match extractors::rel_links::extract(html, base_url) {
-        Ok(rel_links) => {
-            if !rel_links.is_empty() {
-                dict.set_item("rel_links", rel_links)?;
+    if scan.links {
+        match extractors::rel_links::extract(html, base_url) {
+            Ok(rel_links) => {
+                if rel_links.has_data() {
+                    dict.set_item("rel_links", rel_links_to_py_dict(py, &rel_links)?)?;
+                }
+            }
+            Err(e) => {
+                eprintln!("rel_links extraction warning: {}", e);
             }
-        }
-        Err(e) => {
-            eprintln!("rel_links extraction warning: {}", e);
         }
     }
 
     // Extract RDFa (W3C standard with 62% adoption)
-    match extractors::rdfa::extract(html, base_url) {
-        Ok(rdfa_items) => {
-            if !rdfa_items.is_empty() {
-                let list = PyList::empty_bound(py);
-                for item in rdfa_items {
-                    list.append(item.to_py_dict(py)).unwrap();
+    if scan.rdfa {
+        match extractors::rdfa::extract(html, base_url) {
+            Ok(rdfa_items) => {
+                if rdfa_items.has_data() {
+                    let list = PyList::empty_bound(py);
+                    for item in rdfa_items {
+                        list.append(item.to_py_dict(py)).unwrap();
+                    }
+                    dict.set_item("rdfa", list)?;
                 }
-                dict.set_item("rdfa", list)?;
             }
-        }
-        Err(e) => {
-            eprintln!("RDFa extraction warning: {}", e);
+            Err(e) => {
+                eprintln!("RDFa extraction warning: {}", e);
+            }
         }
     }
 
     // Extract Web App Manifest link
-    match extractors::manifest::extract(html, base_url) {
-        Ok(discovery) => {
-            if discovery.href.is_some() {
-                dict.set_item("manifest", discovery.to_py_dict(py))?;
+    if scan.links {
+        match extractors::manifest::extract(html, base_url) {
+            Ok(discovery) => {
+                if discovery.has_data() {
+                    dict.set_item("manifest", discovery.to_py_dict(py))?;
+                }
+            }
+            Err(e) => {
+                eprintln!("Manifest extraction warning: {}", e);
             }
-        }
-        Err(e) => {
-            eprintln!("Manifest extraction warning: {}", e);
         }
     }
 
     Ok(dict.unbind())
 }
 
+/// Add the `lang` of each typed microformat to its dict, as `extract_all` records it
+///
+/// The typed extractors emit one item per root element in document order, so
+/// each list lines up with the elements carrying its root class.
+#[cfg(feature = "python")]
+fn add_microformat_langs(
+    mf_dict: &Bound<'_, PyDict>,
+    html: &str,
+    document_lang: Option<&str>,
+) -> PyResult<()> {
+    let document = html_utils::parse_html(html);
+    for (root, items) in mf_dict.iter() {
+        let root: String = root.extract()?;
+        let Ok(selector) = html_utils::create_selector(&format!(".{}", root)) else {
+            continue;
+        };
+        for (element, item) in document.select(&selector).zip(items.iter()?) {
+            let lang = parser::element_lang(&element).or_else(|| document_lang.map(String::from));
+            if let Some(lang) = lang {
+                item?.set_item("lang", lang)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(feature = "python")]
 /// MetaOxide: A fast Rust library for extracting structured data
 #[pymodule]
@@ -667,6 +1150,7 @@ fn meta_oxide(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Phase 2: Social Media
     m.add_function(wrap_pyfunction!(extract_opengraph, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_pinterest, m)?)?;
     m.add_function(wrap_pyfunction!(extract_twitter, m)?)?;
     m.add_function(wrap_pyfunction!(extract_twitter_with_fallback, m)?)?;
 
@@ -684,6 +1168,8 @@ fn meta_oxide(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // RDFa
     m.add_function(wrap_pyfunction!(extract_rdfa, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_rdfa_triples, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_ntriples, m)?)?;
 
     // Web App Manifest
     m.add_function(wrap_pyfunction!(extract_manifest, m)?)?;
@@ -692,8 +1178,25 @@ fn meta_oxide(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // rel-* link relationships
     m.add_function(wrap_pyfunction!(extract_rel_links, m)?)?;
 
+    // Resource hints
+    m.add_function(wrap_pyfunction!(extract_resource_hints, m)?)?;
+
+    // Breadcrumbs
+    m.add_function(wrap_pyfunction!(extract_breadcrumbs, m)?)?;
+
+    // Geotagging meta tags
+    m.add_function(wrap_pyfunction!(extract_geo, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_embedded_json, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_from_headers, m)?)?;
+
+    // OpenSearch
+    m.add_function(wrap_pyfunction!(discover_opensearch, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_opensearch, m)?)?;
+
     // Phase 7: Microformats
     m.add_function(wrap_pyfunction!(extract_microformats, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_microformat, m)?)?;
+    m.add_function(wrap_pyfunction!(find_author, m)?)?;
     m.add_function(wrap_pyfunction!(extract_hcard, m)?)?;
     m.add_function(wrap_pyfunction!(extract_hentry, m)?)?;
     m.add_function(wrap_pyfunction!(extract_hevent, m)?)?;
@@ -705,10 +1208,11 @@ fn meta_oxide(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(extract_hgeo, m)?)?;
 
     // Main convenience function
-    m.add_function(wrap_pyfunction!(extract_all, m)?)?;
+    m.add_function(wrap_pyfunction!(py_extract_all, m)?)?;
 
     // Add version
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("SCHEMA_VERSION", SCHEMA_VERSION)?;
 
     Ok(())
 }
@@ -729,7 +1233,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -746,7 +1250,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -764,7 +1268,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -782,7 +1286,7 @@ mod integration_tests {
                 </body>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -796,11 +1300,28 @@ mod integration_tests {
                 <span class="p-name">Jane</span>
             </div>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
 
+    #[test]
+    #[cfg(feature = "python")]
+    fn test_extract_all_microformats_lang() {
+        Python::with_gil(|py| {
+            let html = r#"<meta property="og:locale" content="en_GB">
+                <div class="h-card"><span class="p-name">Jane</span></div>
+                <div class="h-card" lang="fr"><span class="p-name">Jeanne</span></div>"#;
+            let result = py_extract_all(py, html, None, false, false).unwrap();
+            let cards = result.bind(py).get_item("microformats").unwrap().unwrap();
+            let cards = cards.get_item("h-card").unwrap();
+            let langs: Vec<String> = (0..2)
+                .map(|i| cards.get_item(i).unwrap().get_item("lang").unwrap().extract().unwrap())
+                .collect();
+            assert_eq!(langs, vec!["en-GB", "fr"]);
+        });
+    }
+
     #[test]
     #[cfg(feature = "python")]
     fn test_extract_all_with_base_url() {
@@ -812,7 +1333,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, Some("https://example.com"));
+            let result = py_extract_all(py, html, Some("https://example.com"), false, false);
             assert!(result.is_ok());
         });
     }
@@ -843,7 +1364,7 @@ mod integration_tests {
                 </body>
             </html>
             "#;
-            let result = extract_all(py, html, Some("https://example.com"));
+            let result = py_extract_all(py, html, Some("https://example.com"), false, false);
             assert!(result.is_ok());
         });
     }
@@ -853,7 +1374,7 @@ mod integration_tests {
     fn test_extract_all_empty_html() {
         Python::with_gil(|py| {
             let html = "<html><head></head></html>";
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -872,7 +1393,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             // Should still succeed, just with no JSON-LD
             assert!(result.is_ok());
         });
@@ -889,7 +1410,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -906,7 +1427,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -923,7 +1444,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, Some("https://example.com"));
+            let result = py_extract_all(py, html, Some("https://example.com"), false, false);
             assert!(result.is_ok());
         });
     }
@@ -951,7 +1472,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -968,7 +1489,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -985,7 +1506,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -1003,7 +1524,7 @@ mod integration_tests {
                 </head>
             </html>
             "#;
-            let result = extract_all(py, html, None);
+            let result = py_extract_all(py, html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -1022,7 +1543,7 @@ mod integration_tests {
             }
             html.push_str("</body></html>");
 
-            let result = extract_all(py, &html, None);
+            let result = py_extract_all(py, &html, None, false, false);
             assert!(result.is_ok());
         });
     }
@@ -1040,7 +1561,7 @@ mod integration_tests {
             }
             html.push_str("</body></html>");
 
-            let result = extract_all(py, &html, None);
+            let result = py_extract_all(py, &html, None, false, false);
             assert!(result.is_ok());
         });
     }
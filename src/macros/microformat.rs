@@ -39,11 +39,14 @@
 /// - `text(selector)` - Extract text content → `Option<String>`
 /// - `url(selector)` - Extract URL from href/src attribute → `Option<String>`
 /// - `html(selector)` - Extract inner HTML content → `Option<String>`
+/// - `e_content(selector)` - Extract inner HTML (URLs resolved) and text → `Option<EntryContent>`
 /// - `date(selector)` - Extract datetime attribute or text → `Option<String>`
 /// - `multi_text(selector)` - Extract multiple text values → `Vec<String>`
 /// - `multi_url(selector)` - Extract multiple URLs → `Vec<String>`
 /// - `number(selector)` - Parse as f32 → `Option<f32>`
 /// - `f64_number(selector)` - Parse as f64 → `Option<f64>`
+/// - `nested_hgeo(selector)` - Extract a nested h-geo or `p-geo` value → `Option<Box<HGeo>>`
+/// - `multi_nested_hreview(selector)` - Extract nested h-reviews → `Vec<HReview>`
 ///
 /// # Generated Code
 ///
@@ -194,6 +197,22 @@ macro_rules! microformat_extractor {
         }
     };
 
+    // Extract e-content as HTML with resolved URLs plus its plain text
+    (@extract_property $element:ident, $item:ident, $field:ident, e_content, $selector:expr, $base_url:ident) => {
+        if let Ok(sel) = $crate::html_utils::create_selector($selector) {
+            if let Some(elem) = $element.select(&sel).next() {
+                let html =
+                    $crate::html_utils::resolve_fragment_urls(elem.inner_html().trim(), $base_url);
+                if !html.is_empty() {
+                    $item.$field = Some($crate::EntryContent {
+                        html,
+                        text: $crate::html_utils::text_content(&elem),
+                    });
+                }
+            }
+        }
+    };
+
     // Extract datetime (from datetime attribute or text)
     (@extract_property $element:ident, $item:ident, $field:ident, date, $selector:expr, $base_url:ident) => {
         if let Ok(sel) = $crate::html_utils::create_selector($selector) {
@@ -304,6 +323,46 @@ macro_rules! microformat_extractor {
         }
     };
 
+    // Extract nested h-adr microformat (Option<Box<HAdr>>)
+    (@extract_property $element:ident, $item:ident, $field:ident, nested_hadr, $selector:expr, $base_url:ident) => {
+        if let Ok(sel) = $crate::html_utils::create_selector($selector) {
+            if let Some(elem) = $element.select(&sel).next() {
+                let nested_html = elem.html();
+                if let Ok(items) = $crate::extractors::microformats::hadr::extract(&nested_html, $base_url) {
+                    if let Some(item) = items.first() {
+                        $item.$field = Some(Box::new(item.clone()));
+                    }
+                }
+            }
+        }
+    };
+
+    // Extract nested h-geo, or a plain p-geo value (Option<Box<HGeo>>)
+    (@extract_property $element:ident, $item:ident, $field:ident, nested_hgeo, $selector:expr, $base_url:ident) => {
+        if let Ok(sel) = $crate::html_utils::create_selector($selector) {
+            if let Some(elem) = $element.select(&sel).next() {
+                let geo = $crate::extractors::microformats::hgeo::from_element(&elem);
+                if geo.latitude.is_some() || geo.longitude.is_some() {
+                    $item.$field = Some(Box::new(geo));
+                }
+            }
+        }
+    };
+
+    // Extract all nested h-review microformats (Vec<HReview>)
+    (@extract_property $element:ident, $item:ident, $field:ident, multi_nested_hreview, $selector:expr, $base_url:ident) => {
+        if let Ok(sel) = $crate::html_utils::create_selector($selector) {
+            for elem in $element.select(&sel) {
+                let nested_html = elem.html();
+                if let Ok(items) = $crate::extractors::microformats::hreview::extract(&nested_html, $base_url) {
+                    if let Some(item) = items.into_iter().next() {
+                        $item.$field.push(item);
+                    }
+                }
+            }
+        }
+    };
+
     // Extract nested h-card with text fallback (for dual-field patterns)
     // Tries nested h-card first, if not found falls back to text extraction
     (@extract_dual_property $element:ident, $item:ident, $text_field:ident, $nested_field:ident,
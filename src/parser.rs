@@ -1,29 +1,70 @@
 use crate::errors::{MicroformatError, Result};
+use crate::extractors::common::html_utils::{self, Scope};
 use crate::extractors::common::url_utils;
+use crate::types::meta::normalize_language_tag;
 use crate::types::{MicroformatItem, PropertyValue};
+use indexmap::IndexMap;
 use scraper::{Html, Selector};
-use std::collections::HashMap;
 
 /// Parse HTML and extract all microformats
+///
+/// The returned map is keyed by microformat type and sorted by type name, so
+/// iteration (and the JSON/Python output) is deterministic. Items within a
+/// type, and properties within an item, keep document order.
 pub fn parse_html(
     html: &str,
     base_url: Option<&str>,
-) -> Result<HashMap<String, Vec<MicroformatItem>>> {
-    let document = Html::parse_document(html);
-    let mut results: HashMap<String, Vec<MicroformatItem>> = HashMap::new();
+) -> Result<IndexMap<String, Vec<MicroformatItem>>> {
+    parse_document(&Html::parse_document(html), base_url)
+}
+
+/// [`parse_html`] on an already parsed document
+pub(crate) fn parse_document(
+    document: &Html,
+    base_url: Option<&str>,
+) -> Result<IndexMap<String, Vec<MicroformatItem>>> {
+    Ok(parse_document_limited(Scope::document(document), base_url, None, usize::MAX, false)?.0)
+}
+
+/// [`parse_document`], stopping after `limit` `h-*` elements
+///
+/// Every `h-*` element is reported as an item, so nested ones count too; an
+/// element with several `h-*` classes counts once. The flag is set when the
+/// document has more `h-*` elements than `limit`. `document_lang` is the
+/// `lang` of items with no `lang` attribute on themselves or an ancestor.
+/// With `skip_empty_text`, `p-*` properties with no text are left out (for a
+/// document whose hidden text was removed). Only roots within `scope` are read.
+pub(crate) fn parse_document_limited(
+    scope: Scope,
+    base_url: Option<&str>,
+    document_lang: Option<&str>,
+    limit: usize,
+    skip_empty_text: bool,
+) -> Result<(IndexMap<String, Vec<MicroformatItem>>, bool)> {
+    let mut parsed = 0;
+    let mut truncated = false;
+    let mut results: IndexMap<String, Vec<MicroformatItem>> = IndexMap::new();
 
     // Find all elements with microformat classes (h-*, p-*, u-*, dt-*, e-*)
     let mf_selector = Selector::parse("[class*='h-']")
-        .map_err(|e| MicroformatError::ParseError(e.to_string()))?;
+        .map_err(|e| MicroformatError::InvalidSelector(e.to_string()))?;
 
-    for element in document.select(&mf_selector) {
+    for element in scope.select(&mf_selector) {
         if let Some(classes) = element.value().attr("class") {
             // Check for root microformat classes (h-*)
             let h_classes: Vec<&str> =
                 classes.split_whitespace().filter(|c| c.starts_with("h-")).collect();
 
             if !h_classes.is_empty() {
-                let item = parse_microformat_item(&element, base_url)?;
+                if parsed == limit {
+                    truncated = true;
+                    break;
+                }
+                parsed += 1;
+                let mut item = parse_microformat_item(&element, base_url, skip_empty_text)?;
+                if let Some(lang) = document_lang {
+                    item.inherit_lang(lang);
+                }
 
                 for h_class in h_classes {
                     results.entry(h_class.to_string()).or_default().push(item.clone());
@@ -32,15 +73,17 @@ pub fn parse_html(
         }
     }
 
-    Ok(results)
+    results.sort_keys();
+    Ok((results, truncated))
 }
 
 /// Parse a single microformat item
 fn parse_microformat_item(
     element: &scraper::ElementRef,
     base_url: Option<&str>,
+    skip_empty_text: bool,
 ) -> Result<MicroformatItem> {
-    let mut properties: HashMap<String, Vec<PropertyValue>> = HashMap::new();
+    let mut properties: IndexMap<String, Vec<PropertyValue>> = IndexMap::new();
     let mut type_classes = Vec::new();
 
     // Extract type classes (h-*)
@@ -50,16 +93,35 @@ fn parse_microformat_item(
     }
 
     // Extract properties
-    extract_properties(element, &mut properties, base_url)?;
+    extract_properties(element, &mut properties, base_url, skip_empty_text)?;
+
+    Ok(MicroformatItem {
+        type_: type_classes,
+        properties,
+        children: None,
+        lang: element_lang(element),
+    })
+}
 
-    Ok(MicroformatItem { type_: type_classes, properties, children: None })
+/// The `lang` in effect on an element: its own, else the nearest ancestor's
+///
+/// Normalized the same way as the document language, so `en_gb` on an
+/// element and `en-GB` from `og:locale` compare equal.
+pub(crate) fn element_lang(element: &scraper::ElementRef) -> Option<String> {
+    std::iter::once(**element)
+        .chain(element.ancestors())
+        .filter_map(|node| node.value().as_element()?.attr("lang"))
+        .map(str::trim)
+        .find(|lang| !lang.is_empty())
+        .map(normalize_language_tag)
 }
 
 /// Extract properties from a microformat element
 fn extract_properties(
     element: &scraper::ElementRef,
-    properties: &mut HashMap<String, Vec<PropertyValue>>,
+    properties: &mut IndexMap<String, Vec<PropertyValue>>,
     base_url: Option<&str>,
+    skip_empty_text: bool,
 ) -> Result<()> {
     // Find all property elements (p-*, u-*, dt-*, e-*)
     for child in element.descendants() {
@@ -79,6 +141,12 @@ fn extract_properties(
                     };
 
                     let value = extract_property_value(&child_element, prefix, base_url)?;
+                    if skip_empty_text
+                        && prefix == "p"
+                        && value == PropertyValue::Text(String::new())
+                    {
+                        continue;
+                    }
                     properties.entry(name.to_string()).or_default().push(value);
                 }
             }
@@ -89,7 +157,7 @@ fn extract_properties(
 }
 
 /// Extract a property value based on its type
-fn extract_property_value(
+pub(crate) fn extract_property_value(
     element: &scraper::ElementRef,
     prefix: &str,
     base_url: Option<&str>,
@@ -97,7 +165,7 @@ fn extract_property_value(
     match prefix {
         "p" => {
             // Plain text
-            let text = element.text().collect::<String>().trim().to_string();
+            let text = html_utils::text_content(element);
             Ok(PropertyValue::Text(text))
         }
         "u" => {
@@ -107,10 +175,13 @@ fn extract_property_value(
                 .attr("href")
                 .or_else(|| element.value().attr("src"))
                 .map(String::from)
-                .unwrap_or_else(|| element.text().collect::<String>().trim().to_string());
+                .unwrap_or_else(|| html_utils::text_content(element));
 
-            let absolute_url =
-                if let Some(base) = base_url { resolve_url(base, &url)? } else { url };
+            // Like the other extractors, keep the URL as written if it cannot be resolved
+            let absolute_url = match base_url {
+                Some(base) => resolve_url(base, &url).unwrap_or(url),
+                None => url,
+            };
 
             Ok(PropertyValue::Url(absolute_url))
         }
@@ -121,7 +192,7 @@ fn extract_property_value(
                 .attr("datetime")
                 .or_else(|| element.value().attr("value"))
                 .map(String::from)
-                .unwrap_or_else(|| element.text().collect::<String>().trim().to_string());
+                .unwrap_or_else(|| html_utils::text_content(element));
 
             Ok(PropertyValue::Text(datetime))
         }
@@ -150,6 +221,23 @@ mod tests {
         assert!(doc.root_element().html().contains("test"));
     }
 
+    #[test]
+    fn test_item_lang_inherited_and_overridden() {
+        let html = r#"<html lang="en"><body>
+            <article class="h-entry">
+                <h1 class="p-name">Hello</h1>
+                <div class="p-author h-card" lang="fr"><span class="p-name">Amélie</span></div>
+            </article>
+        </body></html>"#;
+        let items = parse_html(html, None).unwrap();
+        assert_eq!(items["h-entry"][0].lang.as_deref(), Some("en"));
+        assert_eq!(items["h-card"][0].lang.as_deref(), Some("fr"));
+
+        // Without any lang attribute there is nothing to inherit
+        let items = parse_html(r#"<div class="h-card"><span class="p-name">Jo</span></div>"#, None);
+        assert_eq!(items.unwrap()["h-card"][0].lang, None);
+    }
+
     #[test]
     fn test_extract_property_value_p_prefix() {
         // Test plain text extraction (p- prefix)
@@ -375,6 +463,28 @@ mod tests {
         assert!(items.contains_key("h-card"));
     }
 
+    #[test]
+    fn test_parse_html_key_order_is_deterministic() {
+        let html = r#"
+            <div class="h-entry"><span class="p-name">Post</span><a class="u-url" href="/p">x</a></div>
+            <div class="h-card"><span class="p-name">Jane</span><span class="p-org">Acme</span></div>
+            <div class="h-adr"><span class="p-locality">Berlin</span></div>
+            <div class="h-event"><span class="p-name">Launch</span></div>
+        "#;
+
+        let first = parse_html(html, None).unwrap();
+        let second = parse_html(html, None).unwrap();
+
+        let keys: Vec<&String> = first.keys().collect();
+        assert_eq!(keys, ["h-adr", "h-card", "h-entry", "h-event"]);
+        assert_eq!(keys, second.keys().collect::<Vec<_>>());
+        assert_eq!(serde_json::to_string(&first).unwrap(), serde_json::to_string(&second).unwrap());
+
+        // Properties keep document order
+        let card_props: Vec<&String> = first["h-card"][0].properties.keys().collect();
+        assert_eq!(card_props, ["name", "org"]);
+    }
+
     #[test]
     fn test_parse_html_with_doctype() {
         let html = "<!DOCTYPE html><html><body><p>Content</p></body></html>";
@@ -0,0 +1,24 @@
+//! Convenience re-exports of the most commonly used items
+//!
+//! ```rust
+//! use meta_oxide::prelude::*;
+//!
+//! fn title(html: &str) -> Result<Option<String>> {
+//!     let data: ExtractedData = extract_all(html, None)?;
+//!     Ok(data.meta.title)
+//! }
+//!
+//! assert_eq!(title("<title>Hi</title>").unwrap().as_deref(), Some("Hi"));
+//! ```
+//!
+//! Every item is listed by name, so a glob import brings in exactly these and
+//! nothing else. Note that [`Result`] shadows `std::result::Result`; write
+//! `std::result::Result` explicitly where a different error type is needed.
+
+pub use crate::errors::{MicroformatError, Result};
+pub use crate::extract::{extract_all, extract_all_with_options, ExtractOptions, UrlResolution};
+pub use crate::types::extracted::ExtractedData;
+pub use crate::types::jsonld::JsonLdObject;
+pub use crate::types::meta::MetaTags;
+pub use crate::types::microdata::MicrodataItem;
+pub use crate::types::rdfa::RdfaItem;
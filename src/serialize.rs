@@ -0,0 +1,262 @@
+//! Serialization of extracted structured data into RDF formats
+
+use crate::errors::Result;
+use crate::extractors::{microdata, rdfa};
+use crate::types::rdfa::{RdfaValue, Triple};
+use std::fmt::Write;
+
+/// Extract the RDFa and microdata of a page as an N-Triples document
+///
+/// RDFa blank nodes are labelled `_:b0`, `_:b1`, ... and microdata blank nodes
+/// `_:m0`, `_:m1`, ..., so statements from the two formats never share a node.
+///
+/// # Arguments
+/// * `html` - The HTML content
+/// * `base_url` - Optional base URL for resolving relative URLs
+pub fn extract_ntriples(html: &str, base_url: Option<&str>) -> Result<String> {
+    let mut triples = rdfa::extract_triples(html, base_url)?;
+    triples.extend(microdata::extract_triples(html, base_url)?);
+    Ok(to_ntriples(&triples))
+}
+
+/// Serialize triples as N-Triples, one statement per line
+///
+/// Subjects and resource objects starting with `_:` are written as blank
+/// nodes; everything else is written as an IRI. Literals keep their datatype
+/// (`"30"^^<http://www.w3.org/2001/XMLSchema#integer>`) or language tag
+/// (`"hi"@en`). Quotes, backslashes and line breaks in literals are escaped.
+///
+/// Only valid statements are written: a triple whose subject, predicate or
+/// resource object is not an absolute IRI (such as the bare `title` of RDFa
+/// without a `vocab`) is skipped, and a malformed language tag or a relative
+/// datatype leaves a plain literal.
+pub fn to_ntriples(triples: &[Triple]) -> String {
+    let mut out = String::new();
+    for triple in triples {
+        let object = match &triple.object {
+            RdfaValue::Resource(iri) => term(iri),
+            RdfaValue::Literal(value) => Some(literal(value)),
+            RdfaValue::TypedLiteral { value, datatype } => match iri(datatype) {
+                Some(datatype) => Some(format!("{}^^{}", literal(value), datatype)),
+                None => Some(literal(value)),
+            },
+            RdfaValue::LangLiteral { value, language } => {
+                let language = language.trim();
+                if is_language_tag(language) {
+                    Some(format!("{}@{}", literal(value), language))
+                } else {
+                    Some(literal(value))
+                }
+            }
+            // Triples reference nested items by subject, so this only happens
+            // for hand-built input; the nested item has no identity to point at
+            RdfaValue::Item(_) => None,
+        };
+        let (Some(subject), Some(predicate), Some(object)) =
+            (term(&triple.subject), iri(&triple.predicate), object)
+        else {
+            continue;
+        };
+        let _ = writeln!(out, "{} {} {} .", subject, predicate, object);
+    }
+    out
+}
+
+/// A subject or object: blank node label or absolute IRI
+fn term(value: &str) -> Option<String> {
+    match value.strip_prefix("_:") {
+        Some(label) => {
+            let label: String =
+                label.chars().filter(|c| c.is_alphanumeric() || matches!(c, '_' | '-')).collect();
+            (!label.is_empty()).then(|| format!("_:{}", label))
+        }
+        None => iri(value),
+    }
+}
+
+/// An absolute IRI reference, escaping characters N-Triples does not allow inside `<>`
+///
+/// Returns `None` for a relative reference, which N-Triples cannot express.
+fn iri(value: &str) -> Option<String> {
+    if !is_absolute_iri(value) {
+        return None;
+    }
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('<');
+    for c in value.chars() {
+        match c {
+            '\u{0}'..='\u{20}' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => {
+                let _ = write!(out, "\\u{:04X}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('>');
+    Some(out)
+}
+
+/// Whether `value` starts with a scheme (`ALPHA *(ALPHA / DIGIT / "+" / "-" / ".") ":"`)
+fn is_absolute_iri(value: &str) -> bool {
+    let Some((scheme, _)) = value.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Whether `tag` matches the N-Triples `LANGTAG` production: `[a-zA-Z]+(-[a-zA-Z0-9]+)*`
+fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    subtags.next().is_some_and(|primary| {
+        !primary.is_empty() && primary.chars().all(|c| c.is_ascii_alphabetic())
+    }) && subtags
+        .all(|subtag| !subtag.is_empty() && subtag.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// A quoted literal with N-Triples string escapes
+fn literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triple(subject: &str, predicate: &str, object: RdfaValue) -> Triple {
+        Triple { subject: subject.to_string(), predicate: predicate.to_string(), object }
+    }
+
+    #[test]
+    fn test_to_ntriples_terms() {
+        let triples = vec![
+            triple(
+                "https://example.com/jane",
+                "https://schema.org/knows",
+                RdfaValue::Resource("_:b0".to_string()),
+            ),
+            triple(
+                "_:b0",
+                "https://schema.org/age",
+                RdfaValue::TypedLiteral {
+                    value: "30".to_string(),
+                    datatype: "http://www.w3.org/2001/XMLSchema#integer".to_string(),
+                },
+            ),
+            triple(
+                "_:b0",
+                "https://schema.org/greeting",
+                RdfaValue::LangLiteral { value: "hi".to_string(), language: "en".to_string() },
+            ),
+        ];
+        assert_eq!(
+            to_ntriples(&triples),
+            "<https://example.com/jane> <https://schema.org/knows> _:b0 .\n\
+             _:b0 <https://schema.org/age> \"30\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n\
+             _:b0 <https://schema.org/greeting> \"hi\"@en .\n"
+        );
+    }
+
+    #[test]
+    fn test_to_ntriples_escapes_literals_and_iris() {
+        let triples = vec![
+            triple(
+                "https://example.com/a b",
+                "https://schema.org/description",
+                RdfaValue::Literal("She said \"hi\"\nthen left \\ again".to_string()),
+            ),
+            triple("_:b1", "https://schema.org/name", RdfaValue::Literal("Plain".to_string())),
+        ];
+        let output = to_ntriples(&triples);
+
+        // Parse it back: one statement per line, each terminated by " ."
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.ends_with(" .")));
+        assert_eq!(
+            lines[0],
+            r#"<https://example.com/a\u0020b> <https://schema.org/description> "She said \"hi\"\nthen left \\ again" ."#
+        );
+    }
+
+    #[test]
+    fn test_to_ntriples_falls_back_to_plain_literal_for_bad_language_tags() {
+        let lang = |language: &str| RdfaValue::LangLiteral {
+            value: "Bob".to_string(),
+            language: language.to_string(),
+        };
+        let triples = vec![
+            triple("_:b0", "https://schema.org/name", lang("en gb")),
+            triple("_:b0", "https://schema.org/name", lang("en-")),
+            triple("_:b0", "https://schema.org/name", lang("en-GB")),
+            triple(
+                "_:b0",
+                "https://schema.org/age",
+                RdfaValue::TypedLiteral {
+                    value: "30".to_string(),
+                    datatype: "integer".to_string(),
+                },
+            ),
+        ];
+        assert_eq!(
+            to_ntriples(&triples),
+            "_:b0 <https://schema.org/name> \"Bob\" .\n\
+             _:b0 <https://schema.org/name> \"Bob\" .\n\
+             _:b0 <https://schema.org/name> \"Bob\"@en-GB .\n\
+             _:b0 <https://schema.org/age> \"30\" .\n"
+        );
+    }
+
+    #[test]
+    fn test_to_ntriples_skips_relative_iris() {
+        let html = r#"
+            <div typeof="Thing"><span property="title">no vocab</span></div>
+            <div vocab="https://schema.org/" typeof="Person"><span property="name">Jane</span></div>
+        "#;
+        let output = extract_ntriples(html, None).unwrap();
+        assert_eq!(
+            output,
+            "_:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://schema.org/Person> .\n\
+             _:b1 <https://schema.org/name> \"Jane\" .\n"
+        );
+
+        let relative_object = vec![triple(
+            "https://example.com/a",
+            "https://schema.org/url",
+            RdfaValue::Resource("/b".to_string()),
+        )];
+        assert!(to_ntriples(&relative_object).is_empty());
+    }
+
+    #[test]
+    fn test_to_ntriples_from_extracted_rdfa_and_microdata() {
+        let html = r#"
+            <div vocab="https://schema.org/" typeof="Person">
+                <span property="name" lang="en">Jane</span>
+            </div>
+            <div itemscope itemtype="https://schema.org/Book">
+                <span itemprop="name">Dune</span>
+                <a itemprop="url" href="https://example.com/dune">Dune</a>
+            </div>
+        "#;
+        let output = extract_ntriples(html, None).unwrap();
+        assert_eq!(output.lines().count(), 5);
+        assert!(output.contains(r#"_:b0 <https://schema.org/name> "Jane"@en ."#));
+        assert!(output.contains(
+            "_:m0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://schema.org/Book> ."
+        ));
+        assert!(output.contains("_:m0 <https://schema.org/url> <https://example.com/dune> ."));
+    }
+}
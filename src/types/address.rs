@@ -0,0 +1,43 @@
+//! Types for postal addresses reconciled across metadata formats
+
+use serde::{Deserialize, Serialize};
+
+/// Where a postal address was declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressSource {
+    /// JSON-LD `PostalAddress` object (usually a LocalBusiness `address`)
+    JsonLd,
+    /// Microdata `PostalAddress` item
+    Microdata,
+    /// RDFa `PostalAddress` item
+    Rdfa,
+    /// Microformats h-adr, or address properties directly on an h-card
+    Microformats,
+}
+
+/// A postal address, whichever format declared it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostalAddress {
+    /// Street address (`streetAddress`, `p-street-address`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+
+    /// City or town (`addressLocality`, `p-locality`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+
+    /// State or province (`addressRegion`, `p-region`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Postal code (`postalCode`, `p-postal-code`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// Country name or code (`addressCountry`, `p-country-name`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// The first source that declared this address
+    pub source: AddressSource,
+}
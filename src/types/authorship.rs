@@ -0,0 +1,29 @@
+//! Types for authorship reconciliation across metadata formats
+
+use serde::{Deserialize, Serialize};
+
+/// Where an author candidate was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthorSource {
+    /// JSON-LD `author` property
+    JsonLd,
+    /// Microformats h-entry `p-author` / h-card
+    HCard,
+    /// `<meta name="author">`
+    Meta,
+    /// `<link rel="author">` / `<a rel="author">`
+    RelAuthor,
+}
+
+/// An author of the document, reconciled from one or more sources
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Author {
+    /// Display name, if the source provided one
+    pub name: Option<String>,
+
+    /// Profile or homepage URL, if known
+    pub url: Option<String>,
+
+    /// The most structured source that described this author
+    pub source: AuthorSource,
+}
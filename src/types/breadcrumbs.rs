@@ -0,0 +1,41 @@
+//! Types for breadcrumb navigation trails
+//!
+//! Breadcrumbs describe a page's position in the site hierarchy. They can be
+//! declared as Schema.org `BreadcrumbList` (JSON-LD or microdata) or as a plain
+//! `<nav aria-label="breadcrumb">` list.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+
+/// One step of a breadcrumb trail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Breadcrumb {
+    /// 1-based position in the trail (root first)
+    pub position: u32,
+
+    /// Display name of the step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// URL of the step (resolved against the base URL); usually absent for the current page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[cfg(feature = "python")]
+impl Breadcrumb {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("position", self.position).unwrap();
+        if let Some(ref v) = self.name {
+            dict.set_item("name", v).unwrap();
+        }
+        if let Some(ref v) = self.url {
+            dict.set_item("url", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
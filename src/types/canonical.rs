@@ -0,0 +1,36 @@
+//! Types for canonical URL consistency checks
+
+use serde::{Deserialize, Serialize};
+
+/// The canonical URL each format declares, normalized for comparison
+///
+/// URLs are normalized by treating `http` and `https` as the same scheme and
+/// dropping trailing slashes and fragments, so only meaningful disagreements
+/// count as conflicts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CanonicalReport {
+    /// `<link rel="canonical">`
+    pub link_canonical: Option<String>,
+
+    /// `og:url`
+    pub og_url: Option<String>,
+
+    /// JSON-LD `url` of the page (or of the entity that is its main subject)
+    pub jsonld_url: Option<String>,
+
+    /// JSON-LD `mainEntityOfPage`
+    pub jsonld_main_entity_of_page: Option<String>,
+
+    /// Whether two or more of the declared URLs disagree
+    pub conflicts: bool,
+}
+
+impl CanonicalReport {
+    /// All declared URLs, in precedence order
+    pub fn urls(&self) -> Vec<&str> {
+        [&self.link_canonical, &self.og_url, &self.jsonld_url, &self.jsonld_main_entity_of_page]
+            .into_iter()
+            .filter_map(|url| url.as_deref())
+            .collect()
+    }
+}
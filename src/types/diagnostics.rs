@@ -0,0 +1,72 @@
+//! Types for extraction diagnostics
+//!
+//! Diagnostics describe problems with the input document that did not stop
+//! extraction but likely affect the quality of the result, and optionally
+//! how long each extractor took.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Warnings collected while extracting a document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Diagnostics {
+    /// Warnings in the order they were raised
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+
+    /// Time spent in each extractor that ran, keyed by extractor name
+    /// (`meta`, `jsonld`, ...) in the order they ran
+    ///
+    /// Only filled when
+    /// [`ExtractOptions::collect_timings`](crate::ExtractOptions::collect_timings)
+    /// is set.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub timings: IndexMap<String, Duration>,
+}
+
+impl Diagnostics {
+    /// Whether no warnings were raised
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Whether a warning of the given kind was raised
+    pub fn has(&self, kind: WarningKind) -> bool {
+        self.warnings.iter().any(|w| w.kind == kind)
+    }
+}
+
+/// A single diagnostic warning
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Warning {
+    /// Category of the problem
+    pub kind: WarningKind,
+
+    /// Human-readable explanation
+    pub message: String,
+}
+
+/// Category of a diagnostic warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// The text contains many U+FFFD replacement characters, which usually
+    /// means the bytes were decoded with the wrong charset
+    Mojibake,
+
+    /// An alternate link's `hreflang` is not a well-formed BCP 47 language tag
+    InvalidHreflang,
+
+    /// An extractor panicked and its result was left empty
+    ExtractorPanicked,
+
+    /// A `<script>` is never closed, so the markup after it was parsed as
+    /// script text and any structured data there was missed
+    UnclosedScript,
+
+    /// A format had more items than
+    /// [`ExtractOptions::max_items_per_format`](crate::ExtractOptions::max_items_per_format)
+    /// and the rest were not extracted
+    ItemsTruncated,
+}
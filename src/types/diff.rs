@@ -0,0 +1,86 @@
+//! Types for comparing structured data between two versions of a page
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The part of [`ExtractedData`](crate::ExtractedData) a change belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataFormat {
+    Meta,
+    OpenGraph,
+    Twitter,
+    JsonLd,
+    Microdata,
+    Microformats,
+    DublinCore,
+    Rdfa,
+    OEmbed,
+    Manifest,
+    RelLinks,
+    ResourceHints,
+    DocumentBase,
+}
+
+/// How an entry differs between the old and new data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Only present in the new data
+    Added,
+    /// Only present in the old data
+    Removed,
+    /// Present in both with different values
+    Changed,
+}
+
+/// A single added, removed or changed entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    /// Format the entry belongs to
+    pub format: DataFormat,
+
+    /// What changed within the format
+    ///
+    /// A field name for single-record formats (`canonical`, `image`), the
+    /// rel value for rel links, and an item key for item formats: the item's
+    /// `@id`/`itemid`/`about`/`uid` when it has one (with ` #2`, ` #3`, ...
+    /// on later items repeating it), otherwise its type and position among
+    /// items of that type (`Product #1`).
+    pub key: String,
+
+    /// Kind of change
+    pub kind: ChangeKind,
+
+    /// Old value, absent for additions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+
+    /// New value, absent for removals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+/// Differences between the structured data of two versions of a page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DataDiff {
+    /// Changes grouped by format, in [`DataFormat`] order
+    pub changes: Vec<Change>,
+}
+
+impl DataDiff {
+    /// Whether the two versions carry the same structured data
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Changes within one format
+    pub fn for_format(&self, format: DataFormat) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(move |c| c.format == format)
+    }
+
+    /// The change to a specific entry, if it changed
+    pub fn get(&self, format: DataFormat, key: &str) -> Option<&Change> {
+        self.for_format(format).find(|c| c.key == key)
+    }
+}
@@ -0,0 +1,46 @@
+//! Types for URL discovery across metadata formats
+
+use serde::{Deserialize, Serialize};
+
+/// Which extractor a discovered URL came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlSource {
+    /// `<link rel="canonical">`
+    Canonical,
+    /// Other meta-level links: icons, feeds, alternates, prev/next, shortlink
+    Meta,
+    /// `og:*` properties
+    OpenGraph,
+    /// `twitter:*` properties
+    Twitter,
+    /// JSON-LD string values
+    JsonLd,
+    /// Microdata `itemid` and URL-valued properties
+    Microdata,
+    /// Microformats `u-*` properties
+    Microformats,
+    /// RDFa `about` and resource values
+    Rdfa,
+    /// `rel-*` links from `<link>` and `<a>`
+    RelLink,
+    /// oEmbed endpoints
+    OEmbed,
+    /// Web App Manifest link
+    Manifest,
+    /// Resource hints (preload, preconnect, ...)
+    ResourceHint,
+}
+
+/// A URL found in the document, with where it was found
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscoveredUrl {
+    /// Absolute URL
+    pub url: String,
+
+    /// Extractor that reported it
+    pub source: UrlSource,
+
+    /// The property or relation within that source, e.g. `og:image` or `rel=me`
+    pub context: String,
+}
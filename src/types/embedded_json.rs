@@ -0,0 +1,36 @@
+//! Types for JSON state embedded in `<script type="application/json">` blocks
+//!
+//! Single-page app frameworks serialize their initial state into the page
+//! (Next.js `__NEXT_DATA__`, Nuxt `__NUXT_DATA__`), which often carries more
+//! structured data than the markup itself.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One parsed `<script type="application/json">` block
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedJson {
+    /// The script's `id` attribute, e.g. `__NEXT_DATA__`
+    pub id: Option<String>,
+
+    /// The parsed script content
+    pub data: Value,
+}
+
+#[cfg(feature = "python")]
+impl EmbeddedJson {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        if let Some(ref v) = self.id {
+            dict.set_item("id", v).unwrap();
+        }
+        dict.set_item("data", super::jsonld::json_value_to_py(py, &self.data)).unwrap();
+
+        dict.into()
+    }
+}
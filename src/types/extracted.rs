@@ -0,0 +1,509 @@
+//! Combined result of running every extractor over a document
+
+use crate::types::diagnostics::Diagnostics;
+use crate::types::discovered_url::{DiscoveredUrl, UrlSource};
+use crate::types::dublin_core::DublinCore;
+use crate::types::jsonld::JsonLdObject;
+use crate::types::manifest::ManifestDiscovery;
+use crate::types::meta::{DocumentBase, MetaTags};
+use crate::types::microdata::MicrodataItem;
+use crate::types::oembed::OEmbedDiscovery;
+use crate::types::rdfa::{RdfaItem, RdfaValue};
+use crate::types::resource_hints::ResourceHints;
+use crate::types::setters::with_setters;
+use crate::types::social::{OpenGraph, TwitterCard};
+use crate::types::MicroformatItem;
+use crate::types::{microdata, PropertyValue};
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// All structured data extracted from a single HTML document
+///
+/// Produced by [`crate::extract_all`]. Extractors that found nothing leave
+/// their field at its default (empty) value.
+///
+/// Serialization is deterministic: no field is backed by a `HashMap`, so the
+/// same document always serializes to the same JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
+pub struct ExtractedData {
+    /// Standard HTML meta tags (Phase 1)
+    pub meta: MetaTags,
+
+    /// Open Graph metadata (Phase 2)
+    pub opengraph: OpenGraph,
+
+    /// Twitter Card metadata, with Open Graph fallback (Phase 2)
+    pub twitter: TwitterCard,
+
+    /// JSON-LD objects (Phase 3)
+    pub jsonld: Vec<JsonLdObject>,
+
+    /// Microdata items (Phase 4)
+    pub microdata: Vec<MicrodataItem>,
+
+    /// oEmbed endpoint discovery (Phase 5)
+    pub oembed: OEmbedDiscovery,
+
+    /// Microformats keyed by root class (`h-card`, `h-entry`, ...) (Phase 7)
+    pub microformats: IndexMap<String, Vec<MicroformatItem>>,
+
+    /// Dublin Core metadata (Phase 9)
+    pub dublin_core: DublinCore,
+
+    /// RDFa items
+    pub rdfa: Vec<RdfaItem>,
+
+    /// Web App Manifest link
+    pub manifest: ManifestDiscovery,
+
+    /// rel-* link relationships keyed by rel value
+    pub rel_links: IndexMap<String, Vec<String>>,
+
+    /// Resource hints (preload, preconnect, ...)
+    pub resource_hints: ResourceHints,
+
+    /// `<base href>` and `<base target>` link defaults
+    pub document_base: Option<DocumentBase>,
+
+    /// The base URL the extractors resolved against, used by [`Self::all_urls`]
+    #[serde(skip)]
+    pub base_url: Option<String>,
+
+    /// Problems noticed during extraction: invalid `hreflang` values, plus
+    /// mojibake and caught extractor panics when enabled in [`crate::ExtractOptions`]
+    #[serde(default, skip_serializing_if = "Diagnostics::is_empty")]
+    pub diagnostics: Diagnostics,
+}
+
+with_setters!(ExtractedData {
+    with_meta => meta: MetaTags,
+    with_opengraph => opengraph: OpenGraph,
+    with_twitter => twitter: TwitterCard,
+    with_jsonld => jsonld: Vec<JsonLdObject>,
+    with_microdata => microdata: Vec<MicrodataItem>,
+    with_oembed => oembed: OEmbedDiscovery,
+    with_microformats => microformats: IndexMap<String, Vec<MicroformatItem>>,
+    with_dublin_core => dublin_core: DublinCore,
+    with_rdfa => rdfa: Vec<RdfaItem>,
+    with_manifest => manifest: ManifestDiscovery,
+    with_rel_links => rel_links: IndexMap<String, Vec<String>>,
+    with_resource_hints => resource_hints: ResourceHints,
+    with_document_base => document_base: Option<DocumentBase>,
+    with_base_url => base_url: Option<String>,
+    with_diagnostics => diagnostics: Diagnostics,
+});
+
+impl ExtractedData {
+    /// Social preview images paired with their alt text, as `(image_url, alt)`
+    ///
+    /// Open Graph images come first in document order, each with its own
+    /// `og:image:alt`. The Twitter image is added unless it repeats an Open Graph
+    /// image, in which case its `twitter:image:alt` fills a missing OG alt.
+    pub fn social_image_alts(&self) -> Vec<(String, Option<String>)> {
+        let mut alts: Vec<(String, Option<String>)> =
+            self.opengraph.images.iter().map(|img| (img.url.clone(), img.alt.clone())).collect();
+
+        if let Some(ref image) = self.twitter.image {
+            match alts.iter_mut().find(|(url, _)| url == image) {
+                Some((_, alt)) => {
+                    if alt.is_none() {
+                        *alt = self.twitter.image_alt.clone();
+                    }
+                }
+                None => alts.push((image.clone(), self.twitter.image_alt.clone())),
+            }
+        }
+
+        alts
+    }
+
+    /// Every URL the document declares, tagged with where it was found
+    ///
+    /// Covers link-level meta tags, Open Graph, Twitter Cards, JSON-LD, microdata,
+    /// microformats, RDFa, rel-* links, oEmbed, the manifest link and resource
+    /// hints, in that order. Relative URLs are resolved against the base URL
+    /// given to [`crate::extract_all`] (most extractors already did so);
+    /// relative URLs that can't be resolved, and non-http(s) URLs, are left
+    /// out. A URL reported twice by the same source is listed once, with its
+    /// first context.
+    pub fn all_urls(&self) -> Vec<DiscoveredUrl> {
+        let mut urls = UrlCollector {
+            base_url: self.base_url.as_deref(),
+            seen: IndexSet::new(),
+            urls: Vec::new(),
+        };
+
+        // Meta-level links
+        let meta = &self.meta;
+        urls.push(UrlSource::Canonical, "link rel=canonical", meta.canonical.as_deref());
+        let links = [
+            ("link rel=shortlink", &meta.shortlink),
+            ("link rel=icon", &meta.icon),
+            ("link rel=apple-touch-icon", &meta.apple_touch_icon),
+            ("link rel=prev", &meta.prev),
+            ("link rel=next", &meta.next),
+        ];
+        for (context, url) in links {
+            urls.push(UrlSource::Meta, context, url.as_deref());
+        }
+        for feed in &meta.feeds {
+            urls.push(UrlSource::Meta, "link rel=alternate feed", Some(&feed.href));
+        }
+        for alternate in &meta.alternate {
+            urls.push(UrlSource::Meta, "link rel=alternate", Some(&alternate.href));
+        }
+
+        // Open Graph
+        let og = &self.opengraph;
+        urls.push(UrlSource::OpenGraph, "og:url", og.url.as_deref());
+        urls.push(UrlSource::OpenGraph, "og:image", og.image.as_deref());
+        for image in &og.images {
+            urls.push(UrlSource::OpenGraph, "og:image", Some(&image.url));
+            urls.push(UrlSource::OpenGraph, "og:image:secure_url", image.secure_url.as_deref());
+        }
+        for video in &og.videos {
+            urls.push(UrlSource::OpenGraph, "og:video", Some(&video.url));
+            urls.push(UrlSource::OpenGraph, "og:video:secure_url", video.secure_url.as_deref());
+        }
+        for audio in &og.audios {
+            urls.push(UrlSource::OpenGraph, "og:audio", Some(&audio.url));
+            urls.push(UrlSource::OpenGraph, "og:audio:secure_url", audio.secure_url.as_deref());
+        }
+
+        // Twitter Card
+        let twitter = &self.twitter;
+        urls.push(UrlSource::Twitter, "twitter:image", twitter.image.as_deref());
+        if let Some(ref player) = twitter.player {
+            urls.push(UrlSource::Twitter, "twitter:player", Some(&player.url));
+            urls.push(UrlSource::Twitter, "twitter:player:stream", player.stream.as_deref());
+        }
+
+        // JSON-LD: every string value that is (or, under a URL-ish key, resolves to) a URL
+        for object in &self.jsonld {
+            urls.push_jsonld_object(object);
+        }
+
+        // Microdata
+        for item in &self.microdata {
+            urls.push_microdata_item(item);
+        }
+
+        // Microformats `u-*` properties
+        for (root, items) in &self.microformats {
+            for item in items {
+                urls.push_microformat_item(root, item);
+            }
+        }
+
+        // RDFa
+        for item in &self.rdfa {
+            urls.push_rdfa_item(item);
+        }
+
+        // rel-* links, by rel name so the order is stable
+        let mut rel_links: Vec<_> = self.rel_links.iter().collect();
+        rel_links.sort_by_key(|(rel, _)| *rel);
+        for (rel, hrefs) in rel_links {
+            let context = format!("rel={}", rel);
+            for href in hrefs {
+                urls.push(UrlSource::RelLink, &context, Some(href));
+            }
+        }
+
+        // oEmbed, manifest and resource hints
+        for endpoint in &self.oembed.json_endpoints {
+            urls.push(UrlSource::OEmbed, "oembed json", Some(&endpoint.href));
+        }
+        for endpoint in &self.oembed.xml_endpoints {
+            urls.push(UrlSource::OEmbed, "oembed xml", Some(&endpoint.href));
+        }
+        urls.push(UrlSource::Manifest, "link rel=manifest", self.manifest.href.as_deref());
+        let hints = &self.resource_hints;
+        let groups = [
+            ("preload", &hints.preload),
+            ("preconnect", &hints.preconnect),
+            ("dns-prefetch", &hints.dns_prefetch),
+            ("prefetch", &hints.prefetch),
+            ("prerender", &hints.prerender),
+        ];
+        for (rel, group) in groups {
+            for hint in group {
+                urls.push(UrlSource::ResourceHint, rel, Some(&hint.href));
+                for candidate in &hint.imagesrcset {
+                    urls.push(UrlSource::ResourceHint, rel, Some(&candidate.url));
+                }
+            }
+        }
+
+        urls.urls
+    }
+}
+
+/// JSON-LD keys and microdata property names whose relative string values are URLs
+const URL_KEYS: &[&str] =
+    &["url", "image", "logo", "sameAs", "contentUrl", "embedUrl", "thumbnailUrl", "@id"];
+
+/// Accumulates [`DiscoveredUrl`]s, resolving and deduplicating as it goes
+struct UrlCollector<'a> {
+    base_url: Option<&'a str>,
+    seen: IndexSet<(UrlSource, String)>,
+    urls: Vec<DiscoveredUrl>,
+}
+
+impl UrlCollector<'_> {
+    fn push(&mut self, source: UrlSource, context: &str, url: Option<&str>) {
+        let Some(url) = url.map(str::trim).filter(|url| !url.is_empty()) else {
+            return;
+        };
+        let Some(url) = self.absolute(url) else {
+            return;
+        };
+        if self.seen.insert((source, url.clone())) {
+            self.urls.push(DiscoveredUrl { url, source, context: context.to_string() });
+        }
+    }
+
+    /// Resolve against the base; only http(s) URLs are kept
+    fn absolute(&self, url: &str) -> Option<String> {
+        let parsed = match url::Url::parse(url) {
+            Ok(parsed) => parsed,
+            Err(url::ParseError::RelativeUrlWithoutBase) => {
+                url::Url::parse(self.base_url?).ok()?.join(url).ok()?
+            }
+            Err(_) => return None,
+        };
+        matches!(parsed.scheme(), "http" | "https").then(|| parsed.to_string())
+    }
+
+    fn is_absolute(url: &str) -> bool {
+        url::Url::parse(url).is_ok_and(|u| matches!(u.scheme(), "http" | "https"))
+    }
+
+    fn push_jsonld_object(&mut self, object: &JsonLdObject) {
+        let type_name = match object.type_ {
+            Some(Value::String(ref t)) => t.as_str(),
+            Some(Value::Array(ref types)) => types.first().and_then(Value::as_str).unwrap_or(""),
+            _ => "",
+        };
+        if let Some(ref id) = object.id {
+            self.push(UrlSource::JsonLd, &format!("jsonld {}.@id", type_name), Some(id));
+        }
+        for (key, value) in &object.properties {
+            self.push_jsonld_value(&format!("jsonld {}.{}", type_name, key), key, value);
+        }
+        for member in object.graph.iter().flatten() {
+            self.push_jsonld_object(member);
+        }
+    }
+
+    fn push_jsonld_value(&mut self, context: &str, key: &str, value: &Value) {
+        match value {
+            Value::String(s) if URL_KEYS.contains(&key) || Self::is_absolute(s) => {
+                self.push(UrlSource::JsonLd, context, Some(s));
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.push_jsonld_value(context, key, value);
+                }
+            }
+            Value::Object(map) => {
+                let type_name = map.get("@type").and_then(Value::as_str).unwrap_or("");
+                for (key, value) in map {
+                    self.push_jsonld_value(&format!("jsonld {}.{}", type_name, key), key, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_microdata_item(&mut self, item: &MicrodataItem) {
+        let type_name = item
+            .item_type
+            .as_ref()
+            .and_then(|types| types.first())
+            .map(|t| t.rsplit(['/', '#']).next().unwrap_or(t))
+            .unwrap_or("");
+        if let Some(ref id) = item.id {
+            self.push(UrlSource::Microdata, &format!("microdata {}.itemid", type_name), Some(id));
+        }
+        for (name, values) in &item.properties {
+            for value in values {
+                match value {
+                    microdata::PropertyValue::Text(text)
+                        if URL_KEYS.contains(&name.as_str()) || Self::is_absolute(text) =>
+                    {
+                        let context = format!("microdata {}.{}", type_name, name);
+                        self.push(UrlSource::Microdata, &context, Some(text));
+                    }
+                    microdata::PropertyValue::Item(nested) => self.push_microdata_item(nested),
+                    microdata::PropertyValue::Text(_) => {}
+                }
+            }
+        }
+    }
+
+    fn push_microformat_item(&mut self, root: &str, item: &MicroformatItem) {
+        for (name, values) in &item.properties {
+            for value in values {
+                match value {
+                    PropertyValue::Url(url) => {
+                        self.push(
+                            UrlSource::Microformats,
+                            &format!("{}.{}", root, name),
+                            Some(url),
+                        );
+                    }
+                    PropertyValue::Nested(nested) => {
+                        let nested_root = nested.type_.first().map_or(root, String::as_str);
+                        self.push_microformat_item(nested_root, nested);
+                    }
+                    PropertyValue::Text(_) => {}
+                }
+            }
+        }
+    }
+
+    fn push_rdfa_item(&mut self, item: &RdfaItem) {
+        let type_name = item.type_of.as_ref().and_then(|types| types.first()).map_or("", |t| t);
+        if let Some(ref about) = item.about {
+            self.push(UrlSource::Rdfa, &format!("rdfa {}.about", type_name), Some(about));
+        }
+        for (property, values) in &item.properties {
+            for value in values {
+                match value {
+                    RdfaValue::Resource(url) => {
+                        let context = format!("rdfa {}.{}", type_name, property);
+                        self.push(UrlSource::Rdfa, &context, Some(url));
+                    }
+                    RdfaValue::Item(nested) => self.push_rdfa_item(nested),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extract_all;
+    use crate::types::discovered_url::UrlSource;
+
+    #[test]
+    fn test_social_image_alts_og_with_alt() {
+        let html = r#"
+            <meta property="og:image" content="https://example.com/a.jpg">
+            <meta property="og:image:alt" content="First image">
+            <meta property="og:image" content="https://example.com/b.jpg">
+            <meta property="og:image:alt" content="Second image">
+        "#;
+        let data = extract_all(html, None).unwrap();
+        assert_eq!(
+            data.social_image_alts(),
+            vec![
+                ("https://example.com/a.jpg".to_string(), Some("First image".to_string())),
+                ("https://example.com/b.jpg".to_string(), Some("Second image".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_social_image_alts_twitter_with_alt() {
+        let html = r#"
+            <meta name="twitter:image" content="/card.png">
+            <meta name="twitter:image:alt" content="Card image">
+        "#;
+        let data = extract_all(html, Some("https://example.com")).unwrap();
+        assert_eq!(
+            data.social_image_alts(),
+            vec![("https://example.com/card.png".to_string(), Some("Card image".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_social_image_alts_missing_alt() {
+        let html = r#"<meta property="og:image" content="https://example.com/a.jpg">"#;
+        let data = extract_all(html, None).unwrap();
+        // The Twitter fallback reuses the OG image, so it isn't listed twice
+        assert_eq!(data.social_image_alts(), vec![("https://example.com/a.jpg".to_string(), None)]);
+    }
+
+    #[test]
+    fn test_social_image_alts_twitter_alt_fills_og_image() {
+        let html = r#"
+            <meta property="og:image" content="https://example.com/a.jpg">
+            <meta name="twitter:image" content="https://example.com/a.jpg">
+            <meta name="twitter:image:alt" content="From Twitter">
+        "#;
+        let data = extract_all(html, None).unwrap();
+        assert_eq!(
+            data.social_image_alts(),
+            vec![("https://example.com/a.jpg".to_string(), Some("From Twitter".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_all_urls_canonical_og_image_and_rel_me() {
+        let html = r#"
+            <link rel="canonical" href="/article">
+            <meta property="og:image" content="/img/hero.jpg">
+            <a rel="me" href="https://github.com/jane">GitHub</a>
+        "#;
+        let base = Some("https://example.com/");
+        let data = extract_all(html, base).unwrap();
+        let urls = data.all_urls();
+
+        let find = |source: UrlSource| urls.iter().filter(move |u| u.source == source);
+        let canonical: Vec<_> = find(UrlSource::Canonical).collect();
+        assert_eq!(canonical.len(), 1);
+        assert_eq!(canonical[0].url, "https://example.com/article");
+        assert_eq!(canonical[0].context, "link rel=canonical");
+
+        let og: Vec<_> = find(UrlSource::OpenGraph).collect();
+        assert_eq!(og.len(), 1);
+        assert_eq!(og[0].url, "https://example.com/img/hero.jpg");
+        assert_eq!(og[0].context, "og:image");
+
+        let rel_me = find(UrlSource::RelLink).find(|u| u.context == "rel=me").unwrap();
+        assert_eq!(rel_me.url, "https://github.com/jane");
+    }
+
+    #[test]
+    fn test_all_urls_structured_data_resolved_and_deduplicated() {
+        let html = r#"
+            <script type="application/ld+json">
+            {"@type": "Article", "url": "/post", "image": ["/a.png", "/a.png"], "headline": "Hi"}
+            </script>
+            <div itemscope itemtype="https://schema.org/Person">
+                <meta itemprop="url" content="/jane">
+                <span itemprop="name">Jane</span>
+            </div>
+            <div class="h-card"><a class="u-url" href="/sam">Sam</a></div>
+        "#;
+        let data = extract_all(html, Some("https://example.com")).unwrap();
+        let urls = data.all_urls();
+
+        let jsonld: Vec<(&str, &str)> = urls
+            .iter()
+            .filter(|u| u.source == UrlSource::JsonLd)
+            .map(|u| (u.url.as_str(), u.context.as_str()))
+            .collect();
+        assert!(jsonld.contains(&("https://example.com/post", "jsonld Article.url")));
+        assert!(jsonld.contains(&("https://example.com/a.png", "jsonld Article.image")));
+        assert_eq!(jsonld.len(), 2);
+
+        let hcard = urls.iter().find(|u| u.source == UrlSource::Microformats).unwrap();
+        assert_eq!(hcard.url, "https://example.com/sam");
+        assert_eq!(hcard.context, "h-card.url");
+
+        let microdata: Vec<_> = urls.iter().filter(|u| u.source == UrlSource::Microdata).collect();
+        assert_eq!(microdata.len(), 1);
+        assert_eq!(microdata[0].url, "https://example.com/jane");
+        assert_eq!(microdata[0].context, "microdata Person.url");
+
+        // Without a base, relative URLs can't be reported
+        let data = extract_all(html, None).unwrap();
+        assert!(data.all_urls().iter().all(|u| u.url.starts_with("https://")));
+    }
+}
@@ -0,0 +1,48 @@
+//! Types for legacy geotagging meta tags
+//!
+//! `geo.position`, `ICBM`, `geo.region` and `geo.placename` predate Schema.org
+//! and are still found on local business and travel sites.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+
+/// Location declared through `geo.*` and `ICBM` meta tags
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct GeoMeta {
+    /// Latitude in decimal degrees, from `geo.position` or else `ICBM`
+    pub latitude: Option<f64>,
+
+    /// Longitude in decimal degrees, from `geo.position` or else `ICBM`
+    pub longitude: Option<f64>,
+
+    /// ISO 3166 region code, e.g. `US-NY` (`geo.region`)
+    pub region: Option<String>,
+
+    /// Human-readable place name (`geo.placename`)
+    pub placename: Option<String>,
+}
+
+#[cfg(feature = "python")]
+impl GeoMeta {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        if let Some(v) = self.latitude {
+            dict.set_item("latitude", v).unwrap();
+        }
+        if let Some(v) = self.longitude {
+            dict.set_item("longitude", v).unwrap();
+        }
+        if let Some(ref v) = self.region {
+            dict.set_item("region", v).unwrap();
+        }
+        if let Some(ref v) = self.placename {
+            dict.set_item("placename", v).unwrap();
+        }
+
+        dict.into()
+    }
+}
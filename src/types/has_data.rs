@@ -0,0 +1,109 @@
+//! Emptiness predicate shared by every extracted type
+
+use crate::types::dublin_core::DublinCore;
+use crate::types::extracted::ExtractedData;
+use crate::types::geo::GeoMeta;
+use crate::types::manifest::{ManifestDiscovery, WebAppManifest};
+use crate::types::meta::MetaTags;
+use crate::types::oembed::OEmbedDiscovery;
+use crate::types::opensearch::OpenSearchDescription;
+use crate::types::resource_hints::ResourceHints;
+use crate::types::social::{OpenGraph, Pinterest, TwitterCard};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Whether an extraction result carries any data
+///
+/// `has_data()` is `false` when the value is entirely default/empty, which is
+/// what an extractor returns for a document without that kind of markup.
+pub trait HasData {
+    /// Returns `true` if anything was extracted
+    fn has_data(&self) -> bool;
+}
+
+/// Structs whose `Default` value means "nothing found"
+macro_rules! has_data_unless_default {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl HasData for $type {
+                fn has_data(&self) -> bool {
+                    *self != <$type>::default()
+                }
+            }
+        )*
+    };
+}
+
+has_data_unless_default!(
+    MetaTags,
+    OpenGraph,
+    TwitterCard,
+    Pinterest,
+    DublinCore,
+    GeoMeta,
+    OEmbedDiscovery,
+    ManifestDiscovery,
+    WebAppManifest,
+    OpenSearchDescription,
+    ResourceHints,
+    ExtractedData,
+);
+
+impl<T> HasData for Vec<T> {
+    fn has_data(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<K, V> HasData for HashMap<K, V> {
+    fn has_data(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<K, V> HasData for IndexMap<K, V> {
+    fn has_data(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_meta_tags_has_no_data() {
+        assert!(!MetaTags::default().has_data());
+    }
+
+    #[test]
+    fn test_title_only_meta_tags_has_data() {
+        let meta = MetaTags::default().with_title("Title");
+        assert!(meta.has_data());
+    }
+
+    #[test]
+    fn test_default_structs_have_no_data() {
+        assert!(!OpenGraph::default().has_data());
+        assert!(!TwitterCard::default().has_data());
+        assert!(!DublinCore::default().has_data());
+        assert!(!OEmbedDiscovery::default().has_data());
+        assert!(!ManifestDiscovery::default().has_data());
+        assert!(!ResourceHints::default().has_data());
+        assert!(!ExtractedData::default().has_data());
+    }
+
+    #[test]
+    fn test_collections() {
+        assert!(!Vec::<String>::new().has_data());
+        assert!(vec![1].has_data());
+        assert!(!HashMap::<String, Vec<String>>::new().has_data());
+    }
+
+    #[test]
+    fn test_manifest_discovery_with_href() {
+        let discovery =
+            ManifestDiscovery { href: Some("/manifest.json".to_string()), manifest: None };
+        assert!(discovery.has_data());
+    }
+}
@@ -0,0 +1,73 @@
+//! Types for metadata carried in HTTP response headers
+//!
+//! PDFs, images and other non-HTML responses cannot declare a canonical URL
+//! or robots policy in markup, so servers send them as `Link` and
+//! `X-Robots-Tag` headers instead.
+
+use super::meta::{AlternateLink, RobotsDirective};
+use indexmap::IndexMap;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+
+/// Metadata read from the `Link`, `X-Robots-Tag` and `Content-Language` headers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct HeaderMetadata {
+    /// First `rel="canonical"` target of the `Link` header
+    pub canonical: Option<String>,
+
+    /// `rel="alternate"` targets of the `Link` header (translations, other formats)
+    pub alternate: Vec<AlternateLink>,
+
+    /// Every `Link` header target keyed by lowercase rel value, like
+    /// [`ExtractedData::rel_links`](crate::ExtractedData::rel_links)
+    pub rel_links: IndexMap<String, Vec<String>>,
+
+    /// Directives of `X-Robots-Tag` values that do not name a crawler
+    pub robots: Option<RobotsDirective>,
+
+    /// Directives of `X-Robots-Tag: <crawler>: ...` values, keyed by lowercase crawler name
+    pub bot_robots: IndexMap<String, RobotsDirective>,
+
+    /// Language tags from `Content-Language`, in header order
+    pub content_language: Vec<String>,
+}
+
+#[cfg(feature = "python")]
+impl HeaderMetadata {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        if let Some(ref v) = self.canonical {
+            dict.set_item("canonical", v).unwrap();
+        }
+        if !self.alternate.is_empty() {
+            let alternates: Vec<_> = self.alternate.iter().map(|a| a.to_py_dict(py)).collect();
+            dict.set_item("alternate", alternates).unwrap();
+        }
+        if !self.rel_links.is_empty() {
+            let rel_links = PyDict::new_bound(py);
+            for (rel, urls) in &self.rel_links {
+                rel_links.set_item(rel, urls).unwrap();
+            }
+            dict.set_item("rel_links", rel_links).unwrap();
+        }
+        if let Some(ref robots) = self.robots {
+            dict.set_item("robots", robots.to_py_dict(py)).unwrap();
+        }
+        if !self.bot_robots.is_empty() {
+            let bots = PyDict::new_bound(py);
+            for (bot, directive) in &self.bot_robots {
+                bots.set_item(bot, directive.to_py_dict(py)).unwrap();
+            }
+            dict.set_item("bot_robots", bots).unwrap();
+        }
+        if !self.content_language.is_empty() {
+            dict.set_item("content_language", &self.content_language).unwrap();
+        }
+
+        dict.unbind()
+    }
+}
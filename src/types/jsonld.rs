@@ -3,12 +3,15 @@
 //! JSON-LD is the fastest-growing format (41% adoption) that enables
 //! Google Rich Results, AI/LLM training, and rich metadata extraction.
 
+use crate::types::setters::with_setters;
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::types::PyDict;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Helper module for deserializing numeric values that might be strings or numbers
@@ -65,7 +68,8 @@ mod string_or_int {
 ///
 /// JSON-LD objects can be of any Schema.org type (Article, Product, Person, etc.)
 /// and may contain nested objects and arrays.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub struct JsonLdObject {
     /// @context - usually "https://schema.org" or similar
     #[serde(rename = "@context")]
@@ -85,7 +89,148 @@ pub struct JsonLdObject {
 
     /// All other properties as a flat map
     #[serde(flatten)]
-    pub properties: HashMap<String, Value>,
+    pub properties: IndexMap<String, Value>,
+}
+
+with_setters!(JsonLdObject {
+    with_context => context: Option<Value>,
+    with_type => type_: Option<Value>,
+    with_id => id: Option<String>,
+    with_graph => graph: Option<Vec<JsonLdObject>>,
+    with_properties => properties: IndexMap<String, Value>,
+});
+
+impl JsonLdObject {
+    /// Set a single property, replacing any earlier value
+    pub fn with_property(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.properties.insert(name.into(), value.into());
+        self
+    }
+
+    /// Resolve the vocabulary used to interpret short property names
+    ///
+    /// Handles the three local `@context` forms:
+    /// - string: `"https://schema.org"` (normalized to end with `/`)
+    /// - object: `{"@vocab": "https://schema.org/"}`
+    /// - array: the first entry that yields a vocabulary
+    ///
+    /// Remote contexts are never fetched; a string context is taken as the vocabulary.
+    pub fn context_vocab(&self) -> Option<String> {
+        self.context.as_ref().and_then(vocab_from_context)
+    }
+
+    /// Look up a nested value by a dotted path such as `author.name` or `offers.0.price`
+    ///
+    /// Numeric segments index into arrays. Since JSON-LD treats a single value
+    /// like a one-element array, index `0` of a non-array value is the value
+    /// itself, so `offers.0.price` works whether `offers` is an object or a
+    /// list. `@`-prefixed keys are matched like any other, including the
+    /// top-level `@id`, `@type`, `@context` and `@graph`. `@id` and `@graph`
+    /// are not stored as [`Value`]s, so paths starting there return an owned
+    /// copy; every other path borrows from the object.
+    ///
+    /// # Example
+    /// ```rust
+    /// use meta_oxide::jsonld::JsonLdObject;
+    ///
+    /// let obj: JsonLdObject = serde_json::from_str(
+    ///     r##"{"@id": "#widget", "@type": "Product", "offers": [{"price": "9.99"}]}"##,
+    /// ).unwrap();
+    /// assert_eq!(*obj.get_path("offers.0.price").unwrap(), "9.99");
+    /// assert_eq!(*obj.get_path("@id").unwrap(), "#widget");
+    /// assert!(obj.get_path("offers.1.price").is_none());
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<Cow<'_, Value>> {
+        let (first, rest) = match path.split_once('.') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (path, None),
+        };
+        let owned = match first {
+            "@id" => Value::from(self.id.as_deref()?),
+            "@graph" => serde_json::to_value(self.graph.as_ref()?).ok()?,
+            _ => return self.borrowed_path(path).map(Cow::Borrowed),
+        };
+        match rest {
+            Some(rest) => walk_path(&owned, rest.split('.')).cloned().map(Cow::Owned),
+            None => Some(Cow::Owned(owned)),
+        }
+    }
+
+    /// [`get_path`](Self::get_path) for paths that do not start at `@id` or
+    /// `@graph`, borrowing the result
+    pub(crate) fn borrowed_path(&self, path: &str) -> Option<&Value> {
+        let mut segments = path.split('.');
+        let value = match segments.next()? {
+            "@type" => self.type_.as_ref()?,
+            "@context" => self.context.as_ref()?,
+            key => self.properties.get(key)?,
+        };
+        walk_path(value, segments)
+    }
+}
+
+/// Follow dotted-path `segments` down from `value` (see [`JsonLdObject::get_path`])
+fn walk_path<'v, 's>(
+    mut value: &'v Value,
+    segments: impl Iterator<Item = &'s str>,
+) -> Option<&'v Value> {
+    for segment in segments {
+        value = match value {
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            Value::Object(map) if map.contains_key(segment) => &map[segment],
+            _ if segment == "0" => value,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Extract the effective vocabulary from a single `@context` value
+fn vocab_from_context(context: &Value) -> Option<String> {
+    match context {
+        Value::String(s) => {
+            let s = s.trim();
+            if s.is_empty() {
+                None
+            } else if s.ends_with('/') || s.ends_with('#') {
+                Some(s.to_string())
+            } else {
+                Some(format!("{}/", s))
+            }
+        }
+        Value::Object(map) => map
+            .get("@vocab")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(String::from),
+        Value::Array(entries) => entries.iter().find_map(vocab_from_context),
+        _ => None,
+    }
+}
+
+/// An `@id` shared by JSON-LD objects that describe different things
+///
+/// See [`validate_ids`](crate::extractors::jsonld::validate_ids).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdCollision {
+    /// The shared `@id`
+    pub id: String,
+    /// Positions of the objects using it, in the order they were passed
+    pub indices: Vec<usize>,
+}
+
+/// A WebSite's sitelinks search box (`potentialAction` of type `SearchAction`)
+///
+/// See [`search_action`](crate::extractors::jsonld::search_action).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchAction {
+    /// URL template of the search results page, e.g.
+    /// `https://example.com/search?q={search_term_string}`
+    pub target: String,
+    /// Name of the template parameter that receives the query, e.g.
+    /// `search_term_string`
+    pub query_param: Option<String>,
 }
 
 /// Article type (most common JSON-LD type)
@@ -345,6 +490,45 @@ pub struct Recipe {
     pub aggregate_rating: Option<Value>, // AggregateRating object
 }
 
+impl Recipe {
+    /// `recipeInstructions` as an ordered list of step texts
+    ///
+    /// Handles a plain string (one step per non-empty line), an array of
+    /// strings, and arrays of `HowToStep` objects (their `text`, or `name`
+    /// without one). `HowToSection`s are flattened into their
+    /// `itemListElement` steps, in order.
+    pub fn steps_text(&self) -> Vec<String> {
+        let mut steps = Vec::new();
+        if let Some(ref instructions) = self.recipe_instructions {
+            collect_steps(instructions, &mut steps);
+        }
+        steps
+    }
+}
+
+/// Append the step texts in a `recipeInstructions`/`step` value
+fn collect_steps(value: &Value, steps: &mut Vec<String>) {
+    match value {
+        Value::String(text) => steps
+            .extend(text.lines().map(str::trim).filter(|line| !line.is_empty()).map(String::from)),
+        Value::Array(items) => items.iter().for_each(|item| collect_steps(item, steps)),
+        Value::Object(step) => collect_step(step, steps),
+        _ => {}
+    }
+}
+
+/// Append the text of one `HowToStep`, or the steps of a `HowToSection`
+fn collect_step(step: &serde_json::Map<String, Value>, steps: &mut Vec<String>) {
+    let text = |key| step.get(key).and_then(Value::as_str).map(str::trim).filter(|t| !t.is_empty());
+    if let Some(text) = text("text") {
+        steps.push(text.to_string());
+    } else if let Some(items) = step.get("itemListElement") {
+        collect_steps(items, steps);
+    } else if let Some(name) = text("name") {
+        steps.push(name.to_string());
+    }
+}
+
 /// BreadcrumbList type (Schema.org BreadcrumbList)
 /// Represents hierarchical navigation breadcrumbs
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -641,7 +825,7 @@ pub struct HowTo {
     pub supply: Option<Value>, // HowToSupply array or single object
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step: Option<Vec<HashMap<String, Value>>>, // Array of HowToStep objects
+    pub step: Option<Value>, // HowToStep/HowToSection array, strings or text
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -654,6 +838,20 @@ pub struct HowTo {
     pub date_published: Option<String>, // ISO 8601 date
 }
 
+impl HowTo {
+    /// `step` as an ordered list of step texts
+    ///
+    /// Same flattening as [`Recipe::steps_text`]: plain text, arrays of
+    /// strings, `HowToStep` objects and `HowToSection`s are all accepted.
+    pub fn steps_text(&self) -> Vec<String> {
+        let mut steps = Vec::new();
+        if let Some(ref step) = self.step {
+            collect_steps(step, &mut steps);
+        }
+        steps
+    }
+}
+
 /// WebSite type (Schema.org WebSite)
 ///
 /// Represents a website with metadata like name, description, search capabilities, etc.
@@ -824,7 +1022,7 @@ pub struct AggregateRating {
 
 /// Helper function to convert serde_json::Value to Python objects recursively
 #[cfg(feature = "python")]
-fn json_value_to_py(py: Python, value: &Value) -> PyObject {
+pub(crate) fn json_value_to_py(py: Python, value: &Value) -> PyObject {
     match value {
         Value::String(s) => s.to_object(py),
         Value::Number(n) => {
@@ -913,6 +1111,103 @@ mod tests {
         assert_eq!(obj.properties.get("headline").unwrap().as_str(), Some("Test Article"));
     }
 
+    #[test]
+    fn test_get_path() {
+        let json = r##"{
+            "@context": "https://schema.org",
+            "@type": "Product",
+            "author": {"@type": "Person", "@id": "#jane", "name": "Jane"},
+            "offers": [{"price": "9.99"}, {"price": "12.50"}],
+            "brand": {"name": "Acme"}
+        }"##;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+
+        assert_eq!(*obj.get_path("author.name").unwrap(), "Jane");
+        assert_eq!(*obj.get_path("author.@id").unwrap(), "#jane");
+        assert_eq!(*obj.get_path("@type").unwrap(), "Product");
+        assert_eq!(*obj.get_path("offers.0.price").unwrap(), "9.99");
+        assert_eq!(*obj.get_path("offers.1.price").unwrap(), "12.50");
+        // A single value acts as a one-element array
+        assert_eq!(*obj.get_path("brand.0.name").unwrap(), "Acme");
+    }
+
+    #[test]
+    fn test_get_path_top_level_id_and_graph() {
+        let json = r##"{
+            "@id": "https://example.com/#site",
+            "@graph": [
+                {"@type": "Organization", "name": "Acme"},
+                {"@type": "WebSite", "@id": "#web", "publisher": {"name": "Acme"}}
+            ]
+        }"##;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+
+        assert_eq!(*obj.get_path("@id").unwrap(), "https://example.com/#site");
+        assert!(matches!(obj.get_path("@id"), Some(Cow::Owned(_))));
+        assert_eq!(obj.get_path("@graph").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(*obj.get_path("@graph.0.name").unwrap(), "Acme");
+        assert_eq!(*obj.get_path("@graph.1.@id").unwrap(), "#web");
+        assert_eq!(*obj.get_path("@graph.1.publisher.name").unwrap(), "Acme");
+        assert!(obj.get_path("@graph.2").is_none());
+
+        let plain: JsonLdObject = serde_json::from_str(r#"{"name": "x"}"#).unwrap();
+        assert!(plain.get_path("@id").is_none());
+        assert!(plain.get_path("@graph.0").is_none());
+        assert!(matches!(plain.get_path("name"), Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_get_path_missing() {
+        let json = r#"{"@type": "Product", "offers": [{"price": "9.99"}], "name": "Widget"}"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+
+        assert!(obj.get_path("author.name").is_none());
+        assert!(obj.get_path("offers.2.price").is_none());
+        assert!(obj.get_path("offers.0.currency").is_none());
+        assert!(obj.get_path("name.first").is_none());
+        assert!(obj.get_path("name.1").is_none());
+        assert!(obj.get_path("").is_none());
+    }
+
+    #[test]
+    fn test_context_vocab_string() {
+        let json = r#"{"@context": "https://schema.org", "@type": "Article"}"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+        assert_eq!(obj.context_vocab(), Some("https://schema.org/".to_string()));
+    }
+
+    #[test]
+    fn test_context_vocab_object() {
+        let json = r#"{
+            "@context": {"@vocab": "http://example.org/vocab#", "name": "http://xmlns.com/foaf/0.1/name"},
+            "name": "Jane"
+        }"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+        assert_eq!(obj.context_vocab(), Some("http://example.org/vocab#".to_string()));
+    }
+
+    #[test]
+    fn test_context_vocab_array() {
+        let json = r#"{
+            "@context": ["https://schema.org/", {"@vocab": "http://example.org/"}],
+            "@type": "Person"
+        }"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+        assert_eq!(obj.context_vocab(), Some("https://schema.org/".to_string()));
+
+        let json =
+            r#"{"@context": [{"ex": "http://example.org/"}, {"@vocab": "http://example.org/"}]}"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+        assert_eq!(obj.context_vocab(), Some("http://example.org/".to_string()));
+    }
+
+    #[test]
+    fn test_context_vocab_missing() {
+        let json = r#"{"@type": "Thing"}"#;
+        let obj: JsonLdObject = serde_json::from_str(json).unwrap();
+        assert_eq!(obj.context_vocab(), None);
+    }
+
     #[test]
     fn test_jsonld_object_with_graph() {
         let json = r#"{
@@ -1380,6 +1675,70 @@ mod tests {
         assert!(recipe.recipe_instructions.as_ref().unwrap().is_array());
     }
 
+    #[test]
+    fn test_recipe_steps_text_from_string() {
+        let recipe = Recipe {
+            recipe_instructions: Some(Value::from("Preheat oven.\n\n  Bake 20 minutes.\n")),
+            ..Default::default()
+        };
+        assert_eq!(recipe.steps_text(), vec!["Preheat oven.", "Bake 20 minutes."]);
+    }
+
+    #[test]
+    fn test_recipe_steps_text_from_array_of_strings() {
+        let json = r#"{"recipeInstructions": ["Mix dry ingredients", " ", "Cook on griddle"]}"#;
+        let recipe: Recipe = serde_json::from_str(json).unwrap();
+        assert_eq!(recipe.steps_text(), vec!["Mix dry ingredients", "Cook on griddle"]);
+    }
+
+    #[test]
+    fn test_recipe_steps_text_from_howto_steps_and_sections() {
+        let json = r#"{"recipeInstructions": [
+            {"@type": "HowToStep", "text": "Make the dough."},
+            {"@type": "HowToSection", "name": "Filling", "itemListElement": [
+                {"@type": "HowToStep", "text": "Chop the apples."},
+                {"@type": "HowToStep", "name": "Add cinnamon."}
+            ]},
+            {"@type": "HowToStep", "text": "Bake."}
+        ]}"#;
+        let recipe: Recipe = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            recipe.steps_text(),
+            vec!["Make the dough.", "Chop the apples.", "Add cinnamon.", "Bake."]
+        );
+        assert!(Recipe::default().steps_text().is_empty());
+    }
+
+    #[test]
+    fn test_howto_steps_text() {
+        let json = r#"{"name": "Fix a tire", "step": [
+            {"@type": "HowToStep", "text": "Remove the wheel."},
+            {"@type": "HowToSection", "name": "Patch", "itemListElement": [
+                {"@type": "HowToStep", "text": "Find the hole."},
+                {"@type": "HowToStep", "text": "Apply the patch."}
+            ]}
+        ]}"#;
+        let howto: HowTo = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            howto.steps_text(),
+            vec!["Remove the wheel.", "Find the hole.", "Apply the patch."]
+        );
+    }
+
+    #[test]
+    fn test_howto_steps_text_from_string() {
+        let json = r#"{"name": "Fix a tire", "step": "Remove the wheel.\nPatch the hole."}"#;
+        let howto: HowTo = serde_json::from_str(json).unwrap();
+        assert_eq!(howto.steps_text(), vec!["Remove the wheel.", "Patch the hole."]);
+    }
+
+    #[test]
+    fn test_howto_steps_text_from_array_of_strings() {
+        let json = r#"{"name": "Fix a tire", "step": ["Remove the wheel.", "Patch the hole."]}"#;
+        let howto: HowTo = serde_json::from_str(json).unwrap();
+        assert_eq!(howto.steps_text(), vec!["Remove the wheel.", "Patch the hole."]);
+    }
+
     #[test]
     fn test_recipe_with_metadata() {
         let json = r#"{
@@ -3032,7 +3391,7 @@ mod tests {
         let howto: HowTo = serde_json::from_str(json).unwrap();
         assert_eq!(howto.name, Some("How to Make Coffee".to_string()));
         assert!(howto.step.is_some());
-        assert_eq!(howto.step.as_ref().unwrap().len(), 2);
+        assert_eq!(howto.step.as_ref().unwrap().as_array().unwrap().len(), 2);
     }
 
     #[test]
@@ -0,0 +1,25 @@
+//! Types for copyright and licensing reconciliation
+
+use serde::{Deserialize, Serialize};
+
+/// Copyright and license statements gathered from every format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct LicenseInfo {
+    /// Free-text copyright statement, e.g. "© 2024 Acme Corp"
+    pub copyright_text: Option<String>,
+
+    /// License URLs, deduplicated, in the order first declared
+    pub license_urls: Vec<String>,
+
+    /// Name of the copyright holder
+    pub copyright_holder: Option<String>,
+}
+
+impl LicenseInfo {
+    /// Whether the page declares neither copyright nor a license
+    pub fn is_empty(&self) -> bool {
+        self.copyright_text.is_none()
+            && self.license_urls.is_empty()
+            && self.copyright_holder.is_none()
+    }
+}
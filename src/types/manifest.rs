@@ -34,6 +34,11 @@ pub struct WebAppManifest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<String>,
 
+    /// Ordered display mode fallbacks tried before `display`
+    /// (e.g. `["window-controls-overlay", "standalone"]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub display_override: Vec<String>,
+
     /// Screen orientation preference
     #[serde(skip_serializing_if = "Option::is_none")]
     pub orientation: Option<String>,
@@ -143,10 +148,12 @@ pub struct ManifestImage {
 /// Shortcut definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ManifestShortcut {
-    /// Shortcut name
+    /// Shortcut name (required by the spec; empty when missing)
+    #[serde(default)]
     pub name: String,
 
-    /// Shortcut URL
+    /// Shortcut URL (required by the spec; empty when missing)
+    #[serde(default)]
     pub url: String,
 
     /// Short name for the shortcut
@@ -176,6 +183,51 @@ pub struct ManifestDiscovery {
     pub manifest: Option<WebAppManifest>,
 }
 
+/// Display modes accepted in `display` and `display_override`
+const KNOWN_DISPLAY_MODES: &[&str] = &[
+    "fullscreen",
+    "standalone",
+    "minimal-ui",
+    "browser",
+    "window-controls-overlay",
+    "tabbed",
+    "borderless",
+    "picture-in-picture",
+];
+
+impl WebAppManifest {
+    /// Check the manifest for spec problems
+    ///
+    /// Unknown display values are kept when parsing (browsers skip them), so this
+    /// reports them instead, along with shortcuts missing their required
+    /// `name` or `url`. Returns an empty list for a valid manifest.
+    pub fn validate(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        if let Some(ref display) = self.display {
+            if !KNOWN_DISPLAY_MODES.contains(&display.as_str()) {
+                issues.push(format!("unknown display mode '{}'", display));
+            }
+        }
+        for mode in &self.display_override {
+            if !KNOWN_DISPLAY_MODES.contains(&mode.as_str()) {
+                issues.push(format!("unknown display_override mode '{}'", mode));
+            }
+        }
+
+        for (i, shortcut) in self.shortcuts.iter().enumerate() {
+            if shortcut.name.trim().is_empty() {
+                issues.push(format!("shortcut {} is missing a name", i));
+            }
+            if shortcut.url.trim().is_empty() {
+                issues.push(format!("shortcut {} is missing a url", i));
+            }
+        }
+
+        issues
+    }
+}
+
 #[cfg(feature = "python")]
 impl WebAppManifest {
     /// Convert to Python dictionary
@@ -197,6 +249,9 @@ impl WebAppManifest {
         if let Some(ref display) = self.display {
             dict.set_item("display", display).unwrap();
         }
+        if !self.display_override.is_empty() {
+            dict.set_item("display_override", self.display_override.clone()).unwrap();
+        }
         if let Some(ref orientation) = self.orientation {
             dict.set_item("orientation", orientation).unwrap();
         }
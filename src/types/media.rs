@@ -0,0 +1,45 @@
+//! Types for the media gallery collected across metadata formats
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a gallery entry is an image or a video
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaKind {
+    Image,
+    Video,
+}
+
+/// Where a gallery entry was first declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaSource {
+    /// `og:image` / `og:video` and their structured properties
+    OpenGraph,
+    /// `twitter:image`
+    Twitter,
+    /// JSON-LD `ImageObject`, `VideoObject` or a plain `image` URL
+    JsonLd,
+}
+
+/// An image or video declared by the page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaItem {
+    /// Absolute URL of the media file (or a `data:` URI)
+    pub url: String,
+
+    /// Image or video
+    pub kind: MediaKind,
+
+    /// Width in pixels
+    pub width: Option<u32>,
+
+    /// Height in pixels
+    pub height: Option<u32>,
+
+    /// Alternative text or caption
+    pub alt: Option<String>,
+
+    /// The first source that declared this URL
+    pub source: MediaSource,
+}
@@ -1,15 +1,42 @@
 //! Types for standard HTML meta tags (Phase 1)
 
+use crate::extractors::common::url_utils;
+use crate::types::setters::with_setters;
+use crate::types::social::TwitterCard;
+use crate::UrlNormalizeOptions;
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::types::PyDict;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Normalize the case of a BCP 47 tag: `en`, `zh-Hant`, `en-US`, `es-419`
+pub(crate) fn normalize_language_tag(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .enumerate()
+        .map(|(i, subtag)| match subtag.len() {
+            _ if i == 0 => subtag.to_ascii_lowercase(),
+            // Only all-letter subtags can be regions or scripts
+            _ if !subtag.bytes().all(|b| b.is_ascii_alphabetic()) => subtag.to_ascii_lowercase(),
+            2 => subtag.to_ascii_uppercase(),
+            4 => {
+                let mut script = subtag.to_ascii_lowercase();
+                script[..1].make_ascii_uppercase();
+                script
+            }
+            _ => subtag.to_ascii_lowercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
 
 /// Standard HTML meta tags extracted from a web page
 ///
 /// These are the foundation tags that virtually 100% of websites use.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub struct MetaTags {
     // Basic meta tags
     pub title: Option<String>,
@@ -17,6 +44,7 @@ pub struct MetaTags {
     pub keywords: Option<Vec<String>>,
     pub author: Option<String>,
     pub generator: Option<String>,
+    pub copyright: Option<String>, // <meta name="copyright">
 
     // Links
     pub canonical: Option<String>,
@@ -34,19 +62,37 @@ pub struct MetaTags {
     // Robots directives
     pub robots: Option<RobotsDirective>,
     pub googlebot: Option<RobotsDirective>,
+    pub bot_robots: IndexMap<String, RobotsDirective>, // Other crawlers keyed by lowercase name
 
     // Viewport and mobile
     pub viewport: Option<String>,
     pub theme_color: Option<String>,
+    #[serde(default)]
+    pub theme_colors: Vec<ThemeColor>, // Every theme-color, with its media query
+    pub color_scheme: Option<Vec<String>>, // Lowercase tokens: light, dark, normal, only
 
     // Language and charset
     pub charset: Option<String>,
+    pub charset_source: Option<String>, // "bom", "meta", or "http-equiv"
     pub language: Option<String>,
+    pub xml_lang: Option<String>, // <html xml:lang>, used when lang is absent
+    pub og_locale: Option<String>, // og:locale, a language fallback
+    pub dc_language: Option<String>, // <meta name="DC.language"> or DCTERMS.language
+    #[serde(default)]
+    pub text_direction: Option<String>, // <html dir> ("ltr", "rtl", "auto"), else inferred from the language
 
     // Additional common meta
     pub application_name: Option<String>,
     pub referrer: Option<String>,
 
+    // Security headers set through <meta http-equiv>, keyed by lowercase header name
+    #[serde(default)]
+    pub security_headers: IndexMap<String, String>,
+
+    // Content rating
+    pub content_rating: Option<String>, // <meta name="rating"> or http-equiv="PICS-Label"
+    pub is_adult: bool,                 // Rating classified as adult/mature content
+
     // Site verification (Phase 6)
     pub google_site_verification: Option<String>,
     pub google_signin_client_id: Option<String>,
@@ -54,6 +100,9 @@ pub struct MetaTags {
     pub yandex_verification: Option<String>,
     pub p_domain_verify: Option<String>, // Pinterest
     pub facebook_domain_verification: Option<String>,
+    pub norton_safeweb_site_verification: Option<String>, // Norton Safe Web
+    pub alexa_verify_id: Option<String>,                  // Alexa (alexaVerifyID)
+    pub baidu_site_verification: Option<String>,
 
     // Analytics (Phase 6)
     pub google_analytics: Option<String>,
@@ -70,8 +119,14 @@ pub struct MetaTags {
 
     // Mobile App Links (Phase 8)
     pub apple_itunes_app: Option<String>, // app-id=123456789, affiliate-data=...
+    #[serde(default)]
+    pub apple_itunes_app_banner: Option<AppleItunesApp>, // apple_itunes_app, parsed
     pub google_play_app: Option<String>,  // app-id=com.example.android
+    #[serde(default)]
+    pub google_play_app_banner: Option<GooglePlayApp>, // google_play_app, parsed
     pub format_detection: Option<String>, // telephone=no, email=no, address=no
+    #[serde(default)]
+    pub format_detection_flags: Option<FormatDetection>, // format_detection, parsed
 
     // Microsoft/Windows meta (Phase 8)
     pub msapplication_tile_color: Option<String>, // Tile color for Windows 8+ start screen
@@ -79,6 +134,68 @@ pub struct MetaTags {
     pub msapplication_config: Option<String>,     // browserconfig.xml URL
 }
 
+with_setters!(MetaTags {
+    with_title => title: Option<String>,
+    with_description => description: Option<String>,
+    with_keywords => keywords: Option<Vec<String>>,
+    with_author => author: Option<String>,
+    with_generator => generator: Option<String>,
+    with_copyright => copyright: Option<String>,
+    with_canonical => canonical: Option<String>,
+    with_alternate => alternate: Vec<AlternateLink>,
+    with_feeds => feeds: Vec<FeedLink>,
+    with_shortlink => shortlink: Option<String>,
+    with_icon => icon: Option<String>,
+    with_apple_touch_icon => apple_touch_icon: Option<String>,
+    with_manifest => manifest: Option<String>,
+    with_prev => prev: Option<String>,
+    with_next => next: Option<String>,
+    with_robots => robots: Option<RobotsDirective>,
+    with_googlebot => googlebot: Option<RobotsDirective>,
+    with_bot_robots => bot_robots: IndexMap<String, RobotsDirective>,
+    with_viewport => viewport: Option<String>,
+    with_theme_color => theme_color: Option<String>,
+    with_theme_colors => theme_colors: Vec<ThemeColor>,
+    with_color_scheme => color_scheme: Option<Vec<String>>,
+    with_charset => charset: Option<String>,
+    with_charset_source => charset_source: Option<String>,
+    with_language => language: Option<String>,
+    with_xml_lang => xml_lang: Option<String>,
+    with_og_locale => og_locale: Option<String>,
+    with_dc_language => dc_language: Option<String>,
+    with_text_direction => text_direction: Option<String>,
+    with_application_name => application_name: Option<String>,
+    with_referrer => referrer: Option<String>,
+    with_security_headers => security_headers: IndexMap<String, String>,
+    with_content_rating => content_rating: Option<String>,
+    with_is_adult => is_adult: bool,
+    with_google_site_verification => google_site_verification: Option<String>,
+    with_google_signin_client_id => google_signin_client_id: Option<String>,
+    with_msvalidate_01 => msvalidate_01: Option<String>,
+    with_yandex_verification => yandex_verification: Option<String>,
+    with_p_domain_verify => p_domain_verify: Option<String>,
+    with_facebook_domain_verification => facebook_domain_verification: Option<String>,
+    with_norton_safeweb_site_verification => norton_safeweb_site_verification: Option<String>,
+    with_alexa_verify_id => alexa_verify_id: Option<String>,
+    with_baidu_site_verification => baidu_site_verification: Option<String>,
+    with_google_analytics => google_analytics: Option<String>,
+    with_fb_app_id => fb_app_id: Option<String>,
+    with_fb_pages => fb_pages: Option<String>,
+    with_mobile_web_app_capable => mobile_web_app_capable: Option<String>,
+    with_apple_mobile_web_app_capable => apple_mobile_web_app_capable: Option<String>,
+    with_apple_mobile_web_app_status_bar_style => apple_mobile_web_app_status_bar_style: Option<String>,
+    with_apple_mobile_web_app_title => apple_mobile_web_app_title: Option<String>,
+    with_apple_itunes_app => apple_itunes_app: Option<String>,
+    with_apple_itunes_app_banner => apple_itunes_app_banner: Option<AppleItunesApp>,
+    with_google_play_app => google_play_app: Option<String>,
+    with_google_play_app_banner => google_play_app_banner: Option<GooglePlayApp>,
+    with_format_detection => format_detection: Option<String>,
+    with_format_detection_flags => format_detection_flags: Option<FormatDetection>,
+    with_msapplication_tile_color => msapplication_tile_color: Option<String>,
+    with_msapplication_tile_image => msapplication_tile_image: Option<String>,
+    with_msapplication_config => msapplication_config: Option<String>,
+});
+
 /// Alternate link (for translations, mobile versions, etc.)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AlternateLink {
@@ -88,6 +205,15 @@ pub struct AlternateLink {
     pub r#type: Option<String>,
 }
 
+/// A `<meta name="theme-color">` value, optionally scoped by a media query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeColor {
+    /// The color as declared, e.g. `#1a1a1a`
+    pub color: String,
+    /// Media query the color applies to, e.g. `(prefers-color-scheme: dark)`
+    pub media: Option<String>,
+}
+
 /// Feed link (RSS, Atom)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FeedLink {
@@ -96,6 +222,185 @@ pub struct FeedLink {
     pub r#type: String, // "application/rss+xml" or "application/atom+xml"
 }
 
+/// Document-wide link defaults from the `<base>` element
+///
+/// Per HTML, `href` comes from the first `<base>` with an `href` and `target`
+/// from the first `<base>` with a `target`, which may be different elements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DocumentBase {
+    /// Base URL for relative links (resolved against the document URL)
+    pub href: Option<String>,
+    /// Default browsing context for links and forms, e.g. `_blank`
+    pub target: Option<String>,
+}
+
+/// Social media handles declared in meta tags
+///
+/// Twitter handles are normalized to the `@handle` form whether the page wrote
+/// `handle`, `@handle` or a profile URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SocialHandles {
+    /// `twitter:site` - the website's account
+    pub twitter_site: Option<String>,
+    /// `twitter:creator` - the content author's account
+    pub twitter_creator: Option<String>,
+    /// `fb:pages` - Facebook page IDs (comma-separated in the tag)
+    pub fb_pages: Vec<String>,
+    /// `fb:app_id` - Facebook application ID
+    pub fb_app_id: Option<String>,
+}
+
+/// Security policies a page declares in its markup
+///
+/// See [`MetaTags::security_meta`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SecurityMeta {
+    /// Content Security Policy directives mapped to their sources
+    /// (`script-src` → `['self', https://cdn.example.com]`)
+    pub csp: IndexMap<String, Vec<String>>,
+    /// Referrer policy, e.g. `strict-origin-when-cross-origin`
+    pub referrer_policy: Option<String>,
+    /// Every security header set through `<meta http-equiv>`, raw
+    pub headers: IndexMap<String, String>,
+}
+
+/// Parse a Content Security Policy into directives and their sources
+///
+/// Directive names are lowercased; sources keep their case (`'self'`,
+/// `'nonce-…'`). A repeated directive is ignored, as browsers do.
+pub fn parse_csp(policy: &str) -> IndexMap<String, Vec<String>> {
+    let mut directives = IndexMap::new();
+    for directive in policy.split(';') {
+        let mut tokens = directive.split_ascii_whitespace();
+        if let Some(name) = tokens.next() {
+            directives
+                .entry(name.to_ascii_lowercase())
+                .or_insert_with(|| tokens.map(String::from).collect());
+        }
+    }
+    directives
+}
+
+/// Parsed `<meta name="format-detection">`
+///
+/// Each flag says whether mobile browsers may turn matching text into a
+/// link; `None` means the page left it at the browser default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct FormatDetection {
+    pub telephone: Option<bool>,
+    pub address: Option<bool>,
+    pub email: Option<bool>,
+    pub date: Option<bool>,
+}
+
+impl FormatDetection {
+    /// Parse `telephone=no, address=no` style content
+    ///
+    /// Pairs may be separated by commas or semicolons; keys and values are
+    /// case-insensitive. `yes`/`no` (and `true`/`false`, `1`/`0`) are read as
+    /// flags, and unknown keys or values are ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut flags = FormatDetection::default();
+        for pair in content.split([',', ';']) {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = match value.trim().to_ascii_lowercase().as_str() {
+                "yes" | "true" | "1" => true,
+                "no" | "false" | "0" => false,
+                _ => continue,
+            };
+            match key.trim().to_ascii_lowercase().as_str() {
+                "telephone" => flags.telephone = Some(value),
+                "address" => flags.address = Some(value),
+                "email" => flags.email = Some(value),
+                "date" => flags.date = Some(value),
+                _ => {}
+            }
+        }
+        flags
+    }
+}
+
+/// Parsed `<meta name="apple-itunes-app">` (Safari Smart App Banner)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppleItunesApp {
+    /// App Store ID, e.g. `284882215`
+    pub app_id: String,
+    /// URL passed to the app when opened from the banner
+    pub app_argument: Option<String>,
+    /// Affiliate token for the App Store link
+    pub affiliate_data: Option<String>,
+}
+
+impl AppleItunesApp {
+    /// Parse `app-id=123, app-argument=myapp://...` style content
+    ///
+    /// Returns `None` without an `app-id`, since the banner needs it.
+    pub fn parse(content: &str) -> Option<Self> {
+        let fields = parse_banner_fields(content, &["app-id", "app-argument", "affiliate-data"]);
+        Some(AppleItunesApp {
+            app_id: fields.get("app-id")?.clone(),
+            app_argument: fields.get("app-argument").cloned(),
+            affiliate_data: fields.get("affiliate-data").cloned(),
+        })
+    }
+}
+
+/// Parsed `<meta name="google-play-app">` (Android smart banner)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GooglePlayApp {
+    /// Play Store package name, e.g. `com.example.android`
+    pub app_id: String,
+    /// URL passed to the app when opened from the banner
+    pub app_argument: Option<String>,
+}
+
+impl GooglePlayApp {
+    /// Parse `app-id=com.example.android` style content
+    ///
+    /// Returns `None` without an `app-id`, since the banner needs it.
+    pub fn parse(content: &str) -> Option<Self> {
+        let fields = parse_banner_fields(content, &["app-id", "app-argument"]);
+        Some(GooglePlayApp {
+            app_id: fields.get("app-id")?.clone(),
+            app_argument: fields.get("app-argument").cloned(),
+        })
+    }
+}
+
+/// Split smart banner content into its `key=value` fields
+///
+/// Only `keys` start a new field (case-insensitively), so a comma inside a
+/// value such as an `app-argument` URL stays part of that value. Empty
+/// values are dropped and the first occurrence of a key wins.
+fn parse_banner_fields(content: &str, keys: &[&str]) -> IndexMap<String, String> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for part in content.split(',') {
+        let field = part.split_once('=').and_then(|(key, value)| {
+            let key = key.trim().to_ascii_lowercase();
+            keys.contains(&key.as_str()).then(|| (key, value.to_string()))
+        });
+        match (field, fields.last_mut()) {
+            (Some(field), _) => fields.push(field),
+            (None, Some((_, value))) => {
+                value.push(',');
+                value.push_str(part);
+            }
+            (None, None) => {}
+        }
+    }
+
+    let mut map = IndexMap::new();
+    for (key, value) in fields {
+        let value = value.trim();
+        if !value.is_empty() {
+            map.entry(key).or_insert_with(|| value.to_string());
+        }
+    }
+    map
+}
+
 /// Robots directive
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct RobotsDirective {
@@ -144,6 +449,173 @@ impl RobotsDirective {
 
         directive
     }
+
+    /// Merge bot-specific directives over this (generic) directive
+    ///
+    /// Values set in `overrides` win; unset values are inherited from `self`.
+    /// `raw` is the generic content followed by the override content, which
+    /// parses back to the merged result.
+    pub fn merge(&self, overrides: &RobotsDirective) -> RobotsDirective {
+        let raw = [self.raw.as_str(), overrides.raw.as_str()]
+            .iter()
+            .filter(|s| !s.trim().is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        RobotsDirective {
+            index: overrides.index.or(self.index),
+            follow: overrides.follow.or(self.follow),
+            archive: overrides.archive.or(self.archive),
+            snippet: overrides.snippet.or(self.snippet),
+            translate: overrides.translate.or(self.translate),
+            imageindex: overrides.imageindex.or(self.imageindex),
+            raw,
+        }
+    }
+}
+
+impl MetaTags {
+    /// Effective robots policy for a named crawler (e.g. "googlebot", "bingbot")
+    ///
+    /// The bot-specific directive overrides the generic `robots` one on conflicts
+    /// and inherits anything it leaves unspecified. Bot names are case-insensitive.
+    pub fn effective_robots(&self, bot: &str) -> RobotsDirective {
+        let bot = bot.to_lowercase();
+        let specific =
+            if bot == "googlebot" { self.googlebot.as_ref() } else { self.bot_robots.get(&bot) };
+
+        match (&self.robots, specific) {
+            (Some(generic), Some(specific)) => generic.merge(specific),
+            (Some(generic), None) => generic.clone(),
+            (None, Some(specific)) => specific.clone(),
+            (None, None) => RobotsDirective::default(),
+        }
+    }
+
+    /// The page language as a normalized BCP 47 tag
+    ///
+    /// Precedence: `<html lang>`, `<html xml:lang>`, `og:locale`, then
+    /// `DC.language`; empty values are skipped. The tag is normalized to a
+    /// lowercase language, title-case script and uppercase region, with `_`
+    /// read as `-` (so `EN-us` and `en_US` both become `en-US`).
+    pub fn effective_language(&self) -> Option<String> {
+        [&self.language, &self.xml_lang, &self.og_locale, &self.dc_language]
+            .into_iter()
+            .flatten()
+            .map(|tag| tag.trim())
+            .find(|tag| !tag.is_empty())
+            .map(normalize_language_tag)
+    }
+
+    /// The canonical URL in normalized form, for comparing canonicals
+    ///
+    /// Tracking parameters named in `opts` are removed, the host is lowercased
+    /// and default ports are dropped. The raw [`canonical`](Self::canonical)
+    /// field is left as declared. `None` when there is no canonical or it is
+    /// not an absolute URL.
+    pub fn canonical_normalized(&self, opts: &UrlNormalizeOptions) -> Option<String> {
+        url_utils::normalize(self.canonical.as_deref()?, opts).ok()
+    }
+
+    /// Collect all site-verification tokens keyed by provider name
+    ///
+    /// Providers: `google`, `bing`, `yandex`, `pinterest`, `facebook`,
+    /// `norton`, `alexa`, `baidu`. Providers without a token are omitted.
+    pub fn verification_tokens(&self) -> HashMap<String, String> {
+        let providers = [
+            ("google", &self.google_site_verification),
+            ("bing", &self.msvalidate_01),
+            ("yandex", &self.yandex_verification),
+            ("pinterest", &self.p_domain_verify),
+            ("facebook", &self.facebook_domain_verification),
+            ("norton", &self.norton_safeweb_site_verification),
+            ("alexa", &self.alexa_verify_id),
+            ("baidu", &self.baidu_site_verification),
+        ];
+
+        providers
+            .into_iter()
+            .filter_map(|(provider, token)| {
+                token.as_ref().map(|token| (provider.to_string(), token.clone()))
+            })
+            .collect()
+    }
+
+    /// Security policies declared through meta tags
+    ///
+    /// The CSP comes from `<meta http-equiv="Content-Security-Policy">`; the
+    /// referrer policy from `<meta name="referrer">`, falling back to
+    /// `<meta http-equiv="Referrer-Policy">`. `headers` also carries
+    /// non-standard ones such as `X-Frame-Options`, which browsers ignore in
+    /// markup but scanners still report.
+    pub fn security_meta(&self) -> SecurityMeta {
+        SecurityMeta {
+            csp: self
+                .security_headers
+                .get("content-security-policy")
+                .map(|policy| parse_csp(policy))
+                .unwrap_or_default(),
+            referrer_policy: self
+                .referrer
+                .clone()
+                .or_else(|| self.security_headers.get("referrer-policy").cloned()),
+            headers: self.security_headers.clone(),
+        }
+    }
+
+    /// Collect the site's social handles from these tags and the Twitter Card
+    ///
+    /// `twitter:site` / `twitter:creator` come from the Twitter Card extractor;
+    /// `fb:pages` and `fb:app_id` from this struct.
+    pub fn social_handles(&self, twitter: &TwitterCard) -> SocialHandles {
+        SocialHandles {
+            twitter_site: twitter.site.as_deref().and_then(normalize_twitter_handle),
+            twitter_creator: twitter.creator.as_deref().and_then(normalize_twitter_handle),
+            fb_pages: self
+                .fb_pages
+                .iter()
+                .flat_map(|pages| pages.split(','))
+                .map(str::trim)
+                .filter(|page| !page.is_empty())
+                .map(str::to_string)
+                .collect(),
+            fb_app_id: self.fb_app_id.clone(),
+        }
+    }
+}
+
+/// Normalize `handle`, `@handle` or a twitter.com / x.com profile URL to `@handle`
+///
+/// A URL on any other host is not a handle and gives `None`.
+fn normalize_twitter_handle(value: &str) -> Option<String> {
+    let value = value.trim();
+    // "twitter.com/jane" is a URL without its scheme; a bare handle has no '/'
+    let url = match url::Url::parse(value) {
+        Ok(url) => Some(url),
+        Err(_) if value.contains('/') => url::Url::parse(&format!("https://{}", value)).ok(),
+        Err(_) => None,
+    };
+    let handle = match url {
+        Some(url) => {
+            let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+            let is_twitter = ["twitter.com", "x.com"]
+                .iter()
+                .any(|known| host == *known || host.ends_with(&format!(".{}", known)));
+            if !is_twitter {
+                return None;
+            }
+            url.path_segments().and_then(|mut s| s.next()).unwrap_or_default().to_string()
+        }
+        None => value.to_string(),
+    };
+    let handle = handle.trim_start_matches('@');
+
+    if handle.is_empty() {
+        None
+    } else {
+        Some(format!("@{}", handle))
+    }
 }
 
 // Python conversion implementations
@@ -173,21 +645,53 @@ impl MetaTags {
         if let Some(ref v) = self.charset {
             dict.set_item("charset", v).unwrap();
         }
+        if let Some(ref v) = self.charset_source {
+            dict.set_item("charset_source", v).unwrap();
+        }
         if let Some(ref v) = self.language {
             dict.set_item("language", v).unwrap();
         }
+        if let Some(ref v) = self.xml_lang {
+            dict.set_item("xml_lang", v).unwrap();
+        }
+        if let Some(ref v) = self.text_direction {
+            dict.set_item("text_direction", v).unwrap();
+        }
+        if let Some(ref v) = self.og_locale {
+            dict.set_item("og_locale", v).unwrap();
+        }
+        if let Some(ref v) = self.dc_language {
+            dict.set_item("dc_language", v).unwrap();
+        }
+        if let Some(v) = self.effective_language() {
+            dict.set_item("effective_language", v).unwrap();
+        }
         if let Some(ref v) = self.theme_color {
             dict.set_item("theme_color", v).unwrap();
         }
+        if !self.theme_colors.is_empty() {
+            let colors: Vec<_> = self.theme_colors.iter().map(|c| c.to_py_dict(py)).collect();
+            dict.set_item("theme_colors", colors).unwrap();
+        }
+        if let Some(ref v) = self.color_scheme {
+            dict.set_item("color_scheme", v.clone()).unwrap();
+        }
         if let Some(ref v) = self.generator {
             dict.set_item("generator", v).unwrap();
         }
+        if let Some(ref v) = self.copyright {
+            dict.set_item("copyright", v).unwrap();
+        }
         if let Some(ref v) = self.application_name {
             dict.set_item("application_name", v).unwrap();
         }
         if let Some(ref v) = self.referrer {
             dict.set_item("referrer", v).unwrap();
         }
+        if let Some(ref v) = self.content_rating {
+            dict.set_item("content_rating", v).unwrap();
+        }
+        dict.set_item("is_adult", self.is_adult).unwrap();
         if let Some(ref v) = self.shortlink {
             dict.set_item("shortlink", v).unwrap();
         }
@@ -228,6 +732,15 @@ impl MetaTags {
         if let Some(ref v) = self.facebook_domain_verification {
             dict.set_item("facebook_domain_verification", v).unwrap();
         }
+        if let Some(ref v) = self.norton_safeweb_site_verification {
+            dict.set_item("norton_safeweb_site_verification", v).unwrap();
+        }
+        if let Some(ref v) = self.alexa_verify_id {
+            dict.set_item("alexa_verify_id", v).unwrap();
+        }
+        if let Some(ref v) = self.baidu_site_verification {
+            dict.set_item("baidu_site_verification", v).unwrap();
+        }
 
         // Analytics
         if let Some(ref v) = self.google_analytics {
@@ -260,12 +773,21 @@ impl MetaTags {
         if let Some(ref v) = self.apple_itunes_app {
             dict.set_item("apple_itunes_app", v).unwrap();
         }
+        if let Some(ref banner) = self.apple_itunes_app_banner {
+            dict.set_item("apple_itunes_app_banner", banner.to_py_dict(py)).unwrap();
+        }
         if let Some(ref v) = self.google_play_app {
             dict.set_item("google_play_app", v).unwrap();
         }
+        if let Some(ref banner) = self.google_play_app_banner {
+            dict.set_item("google_play_app_banner", banner.to_py_dict(py)).unwrap();
+        }
         if let Some(ref v) = self.format_detection {
             dict.set_item("format_detection", v).unwrap();
         }
+        if let Some(ref flags) = self.format_detection_flags {
+            dict.set_item("format_detection_flags", flags.to_py_dict(py)).unwrap();
+        }
 
         // Microsoft/Windows
         if let Some(ref v) = self.msapplication_tile_color {
@@ -285,6 +807,20 @@ impl MetaTags {
         if let Some(ref googlebot) = self.googlebot {
             dict.set_item("googlebot", googlebot.to_py_dict(py)).unwrap();
         }
+        if !self.security_headers.is_empty() {
+            let headers = PyDict::new_bound(py);
+            for (name, value) in &self.security_headers {
+                headers.set_item(name, value).unwrap();
+            }
+            dict.set_item("security_headers", headers).unwrap();
+        }
+        if !self.bot_robots.is_empty() {
+            let bots = PyDict::new_bound(py);
+            for (bot, directive) in &self.bot_robots {
+                bots.set_item(bot, directive.to_py_dict(py)).unwrap();
+            }
+            dict.set_item("bot_robots", bots).unwrap();
+        }
 
         // Lists
         if !self.alternate.is_empty() {
@@ -347,6 +883,64 @@ impl AlternateLink {
     }
 }
 
+#[cfg(feature = "python")]
+impl FormatDetection {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        let flags = [
+            ("telephone", self.telephone),
+            ("address", self.address),
+            ("email", self.email),
+            ("date", self.date),
+        ];
+        for (key, flag) in flags {
+            if let Some(flag) = flag {
+                dict.set_item(key, flag).unwrap();
+            }
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl AppleItunesApp {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("app_id", &self.app_id).unwrap();
+        if let Some(ref v) = self.app_argument {
+            dict.set_item("app_argument", v).unwrap();
+        }
+        if let Some(ref v) = self.affiliate_data {
+            dict.set_item("affiliate_data", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl GooglePlayApp {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("app_id", &self.app_id).unwrap();
+        if let Some(ref v) = self.app_argument {
+            dict.set_item("app_argument", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl ThemeColor {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("color", &self.color).unwrap();
+        if let Some(ref v) = self.media {
+            dict.set_item("media", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
 #[cfg(feature = "python")]
 impl FeedLink {
     pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
@@ -360,10 +954,74 @@ impl FeedLink {
     }
 }
 
+#[cfg(feature = "python")]
+impl DocumentBase {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        if let Some(ref v) = self.href {
+            dict.set_item("href", v).unwrap();
+        }
+        if let Some(ref v) = self.target {
+            dict.set_item("target", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl SocialHandles {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        if let Some(ref v) = self.twitter_site {
+            dict.set_item("twitter_site", v).unwrap();
+        }
+        if let Some(ref v) = self.twitter_creator {
+            dict.set_item("twitter_creator", v).unwrap();
+        }
+        if !self.fb_pages.is_empty() {
+            dict.set_item("fb_pages", self.fb_pages.clone()).unwrap();
+        }
+        if let Some(ref v) = self.fb_app_id {
+            dict.set_item("fb_app_id", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_canonical_normalized() {
+        let meta = MetaTags::default().with_canonical(
+            "https://WWW.Example.com:443/shoes?color=red&utm_source=x&utm_medium=y",
+        );
+        let opts = UrlNormalizeOptions::default();
+        assert_eq!(
+            meta.canonical_normalized(&opts).as_deref(),
+            Some("https://www.example.com/shoes?color=red")
+        );
+        // The raw value is kept as declared
+        assert_eq!(
+            meta.canonical.as_deref(),
+            Some("https://WWW.Example.com:443/shoes?color=red&utm_source=x&utm_medium=y")
+        );
+
+        let keep_all = UrlNormalizeOptions { strip_params: Vec::new(), ..Default::default() };
+        assert_eq!(
+            meta.canonical_normalized(&keep_all).as_deref(),
+            Some("https://www.example.com/shoes?color=red&utm_source=x&utm_medium=y")
+        );
+    }
+
+    #[test]
+    fn test_canonical_normalized_missing_or_relative() {
+        let opts = UrlNormalizeOptions::default();
+        assert_eq!(MetaTags::default().canonical_normalized(&opts), None);
+        assert_eq!(MetaTags::default().with_canonical("/shoes").canonical_normalized(&opts), None);
+    }
+
     #[test]
     fn test_robots_directive_parse_index() {
         let directive = RobotsDirective::parse("index, follow");
@@ -399,4 +1057,61 @@ mod tests {
         assert_eq!(directive.follow, Some(true));
         assert_eq!(directive.snippet, Some(false));
     }
+
+    #[test]
+    fn test_robots_directive_merge() {
+        let generic = RobotsDirective::parse("index, follow");
+        let bot = RobotsDirective::parse("noindex");
+        let merged = generic.merge(&bot);
+        assert_eq!(merged.index, Some(false));
+        assert_eq!(merged.follow, Some(true));
+        assert_eq!(merged.raw, "index, follow, noindex");
+        assert_eq!(RobotsDirective::parse(&merged.raw), merged);
+    }
+
+    #[test]
+    fn test_social_handles_normalize_twitter_site() {
+        let meta = MetaTags::default();
+        for site in ["@example", "example", " @example ", "https://twitter.com/example?lang=en"] {
+            let twitter = TwitterCard::default().with_site(site);
+            assert_eq!(meta.social_handles(&twitter).twitter_site, Some("@example".to_string()));
+        }
+
+        let twitter = TwitterCard::default().with_site("@");
+        assert_eq!(meta.social_handles(&twitter).twitter_site, None);
+    }
+
+    #[test]
+    fn test_social_handles_twitter_host_matched_exactly() {
+        let meta = MetaTags::default();
+        for site in ["https://x.com/example", "mobile.twitter.com/example/status/1"] {
+            let twitter = TwitterCard::default().with_site(site);
+            assert_eq!(meta.social_handles(&twitter).twitter_site, Some("@example".to_string()));
+        }
+        for site in ["https://www.dropbox.com/example", "https://netflix.com/example"] {
+            let twitter = TwitterCard::default().with_site(site);
+            assert_eq!(meta.social_handles(&twitter).twitter_site, None);
+        }
+    }
+
+    #[test]
+    fn test_social_handles_site_and_creator() {
+        let html = r#"
+            <meta name="twitter:site" content="@newsroom">
+            <meta name="twitter:creator" content="jdoe">
+            <meta property="fb:pages" content="12345, 67890">
+            <meta property="fb:app_id" content="424242">
+        "#;
+        let data = crate::extract_all(html, None).unwrap();
+        let handles = data.meta.social_handles(&data.twitter);
+        assert_eq!(
+            handles,
+            SocialHandles {
+                twitter_site: Some("@newsroom".to_string()),
+                twitter_creator: Some("@jdoe".to_string()),
+                fb_pages: vec!["12345".to_string(), "67890".to_string()],
+                fb_app_id: Some("424242".to_string()),
+            }
+        );
+    }
 }
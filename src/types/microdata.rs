@@ -3,18 +3,22 @@
 //! Microdata is an HTML specification for embedding structured data using
 //! itemscope, itemtype, and itemprop attributes with Schema.org vocabulary.
 
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::types::{PyDict, PyList};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{Map, Value};
+
+use super::jsonld::JsonLdObject;
 
 /// A microdata item with properties
 ///
 /// Corresponds to an element with `itemscope` attribute.
 /// Can contain nested items and multiple property values.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct MicrodataItem {
     /// Schema.org type(s) from itemtype attribute
     /// Can be multiple types separated by spaces
@@ -29,7 +33,7 @@ pub struct MicrodataItem {
     /// Properties extracted from itemprop attributes
     /// Key: property name, Value: array of property values
     #[serde(flatten)]
-    pub properties: HashMap<String, Vec<PropertyValue>>,
+    pub properties: IndexMap<String, Vec<PropertyValue>>,
 }
 
 /// Value of a microdata property
@@ -48,7 +52,7 @@ pub enum PropertyValue {
 impl MicrodataItem {
     /// Create a new empty microdata item
     pub fn new() -> Self {
-        Self { item_type: None, id: None, properties: HashMap::new() }
+        Self { item_type: None, id: None, properties: IndexMap::new() }
     }
 
     /// Set the item type(s)
@@ -74,6 +78,83 @@ impl MicrodataItem {
     }
 }
 
+impl MicrodataItem {
+    /// Convert to an equivalent JSON-LD object
+    ///
+    /// `itemtype` becomes `@type` (an array when there are several) and
+    /// `itemid` becomes `@id`. The vocabulary of the first type is used as
+    /// `@context`, so `https://schema.org/Person` is written as `Person`; types
+    /// from another vocabulary keep their full IRI. Properties with one value
+    /// map to that value, repeated properties to an array, and nested items
+    /// to nested objects.
+    pub fn to_jsonld(&self) -> JsonLdObject {
+        let vocab =
+            self.item_type.as_ref().and_then(|types| types.first()).and_then(|t| vocabulary_of(t));
+
+        JsonLdObject {
+            context: vocab.map(|v| Value::String(v.to_string())),
+            type_: self.jsonld_type(vocab),
+            id: self.id.clone(),
+            graph: None,
+            properties: self.jsonld_properties(vocab).into_iter().collect(),
+        }
+    }
+
+    /// The `@type` value, with types compacted against `vocab`
+    fn jsonld_type(&self, vocab: Option<&str>) -> Option<Value> {
+        let types: Vec<Value> = self
+            .item_type
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|t| {
+                let compact = vocab.and_then(|v| t.strip_prefix(v)).filter(|t| !t.is_empty());
+                Value::String(compact.unwrap_or(t).to_string())
+            })
+            .collect();
+
+        match types.len() {
+            0 => None,
+            1 => types.into_iter().next(),
+            _ => Some(Value::Array(types)),
+        }
+    }
+
+    fn jsonld_properties(&self, vocab: Option<&str>) -> Map<String, Value> {
+        let mut map = Map::new();
+        for (name, values) in &self.properties {
+            let mut converted: Vec<Value> = values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::Text(text) => Value::String(text.clone()),
+                    PropertyValue::Item(item) => {
+                        let mut object = Map::new();
+                        if let Some(item_type) = item.jsonld_type(vocab) {
+                            object.insert("@type".to_string(), item_type);
+                        }
+                        if let Some(ref id) = item.id {
+                            object.insert("@id".to_string(), Value::String(id.clone()));
+                        }
+                        object.extend(item.jsonld_properties(vocab));
+                        Value::Object(object)
+                    }
+                })
+                .collect();
+
+            let value =
+                if converted.len() == 1 { converted.remove(0) } else { Value::Array(converted) };
+            map.insert(name.clone(), value);
+        }
+        map
+    }
+}
+
+/// The vocabulary IRI of an item type: everything up to its last `/` or `#`
+pub(crate) fn vocabulary_of(item_type: &str) -> Option<&str> {
+    let end = item_type.rfind(['/', '#'])?;
+    item_type.contains("://").then(|| &item_type[..=end])
+}
+
 #[cfg(feature = "python")]
 impl MicrodataItem {
     /// Convert to Python dictionary
@@ -294,4 +375,98 @@ mod tests {
 
         assert_eq!(item, deserialized);
     }
+
+    #[test]
+    fn test_properties_preserve_insertion_order() {
+        let mut item = MicrodataItem::new();
+        item.add_text_property("position".to_string(), "1".to_string());
+        item.add_text_property("name".to_string(), "Home".to_string());
+        item.add_text_property("item".to_string(), "https://example.com/".to_string());
+
+        let keys: Vec<&str> = item.properties.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["position", "name", "item"]);
+
+        let json = serde_json::to_string(&item).unwrap();
+        assert_eq!(json, r#"{"position":["1"],"name":["Home"],"item":["https://example.com/"]}"#);
+    }
+
+    #[test]
+    fn test_to_jsonld_person_with_nested_address() {
+        let mut address =
+            MicrodataItem::new().with_type(vec!["https://schema.org/PostalAddress".to_string()]);
+        address.add_text_property("streetAddress".to_string(), "123 Main St".to_string());
+        address.add_text_property("addressLocality".to_string(), "Springfield".to_string());
+
+        let mut person = MicrodataItem::new()
+            .with_type(vec!["https://schema.org/Person".to_string()])
+            .with_id("https://example.com/#jane".to_string());
+        person.add_text_property("name".to_string(), "Jane Doe".to_string());
+        person.add_text_property("telephone".to_string(), "555-1234".to_string());
+        person.add_text_property("telephone".to_string(), "555-5678".to_string());
+        person.add_item_property("address".to_string(), address);
+
+        let jsonld = person.to_jsonld();
+        assert_eq!(jsonld.context, Some(serde_json::json!("https://schema.org/")));
+        assert_eq!(jsonld.type_, Some(serde_json::json!("Person")));
+        assert_eq!(jsonld.id.as_deref(), Some("https://example.com/#jane"));
+        assert_eq!(jsonld.properties["name"], serde_json::json!("Jane Doe"));
+        assert_eq!(jsonld.properties["telephone"], serde_json::json!(["555-1234", "555-5678"]));
+        assert_eq!(
+            jsonld.properties["address"],
+            serde_json::json!({
+                "@type": "PostalAddress",
+                "streetAddress": "123 Main St",
+                "addressLocality": "Springfield"
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_jsonld_multiple_types() {
+        let item = MicrodataItem::new().with_type(vec![
+            "https://schema.org/Person".to_string(),
+            "https://schema.org/Patient".to_string(),
+            "http://xmlns.com/foaf/0.1/Person".to_string(),
+        ]);
+
+        let jsonld = item.to_jsonld();
+        assert_eq!(
+            jsonld.type_,
+            Some(serde_json::json!(["Person", "Patient", "http://xmlns.com/foaf/0.1/Person"]))
+        );
+        assert!(jsonld.id.is_none());
+        assert!(jsonld.properties.is_empty());
+    }
+
+    #[test]
+    fn test_to_jsonld_untyped_item() {
+        let mut item = MicrodataItem::new();
+        item.add_text_property("name".to_string(), "Untyped".to_string());
+
+        let jsonld = item.to_jsonld();
+        assert!(jsonld.context.is_none());
+        assert!(jsonld.type_.is_none());
+        assert_eq!(jsonld.properties["name"], serde_json::json!("Untyped"));
+    }
+
+    #[test]
+    fn test_repeated_property_keeps_sequence() {
+        let mut item = MicrodataItem::new();
+        item.add_text_property("step".to_string(), "Preheat".to_string());
+        item.add_text_property("name".to_string(), "Cake".to_string());
+        item.add_text_property("step".to_string(), "Mix".to_string());
+        item.add_text_property("step".to_string(), "Bake".to_string());
+
+        let steps = &item.properties["step"];
+        assert_eq!(
+            steps,
+            &vec![
+                PropertyValue::Text("Preheat".to_string()),
+                PropertyValue::Text("Mix".to_string()),
+                PropertyValue::Text("Bake".to_string()),
+            ]
+        );
+        let keys: Vec<&str> = item.properties.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["step", "name"]);
+    }
 }
@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
@@ -6,13 +7,39 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Represents a microformat item with properties and type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MicroformatItem {
     pub type_: Vec<String>,
-    pub properties: HashMap<String, Vec<PropertyValue>>,
+    pub properties: IndexMap<String, Vec<PropertyValue>>,
     pub children: Option<Vec<MicroformatItem>>,
+    /// Language of the item: the `lang` of its root element or nearest
+    /// ancestor, else the document language when extracted with
+    /// [`extract_all`](crate::extract_all)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
 }
 
+impl MicroformatItem {
+    /// Set `lang` on this item, its nested property items and its children
+    /// wherever none was found in the markup
+    pub(crate) fn inherit_lang(&mut self, lang: &str) {
+        self.lang.get_or_insert_with(|| lang.to_string());
+        for value in self.properties.values_mut().flatten() {
+            if let PropertyValue::Nested(nested) = value {
+                nested.inherit_lang(lang);
+            }
+        }
+        for child in self.children.iter_mut().flatten() {
+            child.inherit_lang(lang);
+        }
+    }
+}
+
+/// A microformat with any `h-*` root, read with the generic microformats2 rules
+///
+/// Produced by [`crate::extractors::microformats::generic::extract_generic`].
+pub type GenericMicroformat = MicroformatItem;
+
 #[cfg(feature = "python")]
 impl MicroformatItem {
     pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
@@ -33,13 +60,16 @@ impl MicroformatItem {
                 children.iter().map(|child| child.to_py_dict(py).into()).collect();
             dict.set_item("children", py_children).unwrap();
         }
+        if let Some(lang) = &self.lang {
+            dict.set_item("lang", lang).unwrap();
+        }
 
         dict.into()
     }
 }
 
 /// Represents different types of property values
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum PropertyValue {
     Text(String),
@@ -105,12 +135,21 @@ impl HCard {
     }
 }
 
+/// `e-content` of an h-entry in both renderings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct EntryContent {
+    /// Inner HTML, with relative `href`/`src` URLs resolved against the base URL
+    pub html: String,
+    /// Plain-text rendering (see [`crate::html_utils::text_content`])
+    pub text: String,
+}
+
 /// h-entry microformat representation
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct HEntry {
     pub name: Option<String>,
     pub summary: Option<String>,
-    pub content: Option<String>,
+    pub content: Option<EntryContent>,
     pub published: Option<String>,
     pub updated: Option<String>,
     pub author: Option<Box<HCard>>,
@@ -131,7 +170,10 @@ impl HEntry {
             dict.set_item("summary", summary).unwrap();
         }
         if let Some(content) = &self.content {
-            dict.set_item("content", content).unwrap();
+            let content_dict = PyDict::new_bound(py);
+            content_dict.set_item("html", &content.html).unwrap();
+            content_dict.set_item("text", &content.text).unwrap();
+            dict.set_item("content", content_dict).unwrap();
         }
         if let Some(published) = &self.published {
             dict.set_item("published", published).unwrap();
@@ -164,9 +206,17 @@ pub struct HEvent {
     pub summary: Option<String>,
     pub start: Option<String>,
     pub end: Option<String>,
+    pub duration: Option<String>, // Raw dt-duration, e.g. "PT2H"
     pub location: Option<String>,
+    pub location_card: Option<Box<HCard>>, // p-location h-card
+    pub location_adr: Option<Box<HAdr>>,   // p-location h-adr
     pub url: Option<String>,
     pub description: Option<String>,
+
+    // Derived from start/end (or start + duration)
+    pub is_multi_day: bool,
+    pub duration_seconds: Option<u64>,
+
     pub additional_properties: HashMap<String, Vec<String>>,
 }
 
@@ -187,9 +237,22 @@ impl HEvent {
         if let Some(end) = &self.end {
             dict.set_item("end", end).unwrap();
         }
+        if let Some(duration) = &self.duration {
+            dict.set_item("duration", duration).unwrap();
+        }
         if let Some(location) = &self.location {
             dict.set_item("location", location).unwrap();
         }
+        if let Some(card) = &self.location_card {
+            dict.set_item("location_card", card.to_py_dict(py)).unwrap();
+        }
+        if let Some(adr) = &self.location_adr {
+            dict.set_item("location_adr", adr.to_py_dict(py)).unwrap();
+        }
+        dict.set_item("is_multi_day", self.is_multi_day).unwrap();
+        if let Some(seconds) = self.duration_seconds {
+            dict.set_item("duration_seconds", seconds).unwrap();
+        }
         if let Some(url) = &self.url {
             dict.set_item("url", url).unwrap();
         }
@@ -235,6 +298,46 @@ pub struct HReview {
     pub additional_properties: HashMap<String, Vec<String>>,
 }
 
+impl HReview {
+    /// Best rating assumed when `p-best` is absent (hReview default scale)
+    pub(crate) const DEFAULT_BEST: f32 = 5.0;
+
+    /// Worst rating assumed when `p-worst` is absent (hReview default scale)
+    pub(crate) const DEFAULT_WORST: f32 = 1.0;
+
+    /// Upper bound of the rating scale, falling back to the hReview default
+    pub fn best_rating(&self) -> f32 {
+        self.best.unwrap_or(Self::DEFAULT_BEST)
+    }
+
+    /// Lower bound of the rating scale, falling back to the hReview default
+    pub fn worst_rating(&self) -> f32 {
+        self.worst.unwrap_or(Self::DEFAULT_WORST)
+    }
+
+    /// Rating clamped to the `[worst, best]` scale
+    ///
+    /// Returns `None` when no rating was found, when any of rating, worst or
+    /// best is not a finite number (`NaN`, `inf`), or when the declared scale
+    /// is inverted (worst greater than best) and therefore cannot be trusted.
+    pub fn clamped_rating(&self) -> Option<f32> {
+        let rating = self.rating?;
+        let (worst, best) = (self.worst_rating(), self.best_rating());
+        if ![rating, worst, best].iter().all(|n| n.is_finite()) || worst > best {
+            return None;
+        }
+        Some(rating.clamp(worst, best))
+    }
+
+    /// Whether the extracted rating lies within the `[worst, best]` scale
+    pub fn has_valid_rating(&self) -> bool {
+        match (self.rating, self.clamped_rating()) {
+            (Some(rating), Some(clamped)) => rating == clamped,
+            _ => false,
+        }
+    }
+}
+
 #[cfg(feature = "python")]
 impl HReview {
     pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
@@ -308,13 +411,18 @@ pub struct HRecipe {
     pub summary: Option<String>,
     pub ingredient: Vec<String>,
     pub instructions: Option<String>,
-    pub duration: Option<String>,
+    pub duration: Option<String>, // dt-duration (e.g. "PT1H30M") or p-duration text
     pub yield_: Option<String>,
     pub nutrition: Option<String>,
     pub photo: Option<String>,
-    pub author: Option<String>,
+    pub author: Option<String>, // Name, from the author h-card when there is one
+    pub author_card: Option<Box<HCard>>, // p-author h-card
     pub published: Option<String>,
     pub category: Vec<String>,
+
+    // Derived from a machine-readable (ISO 8601) duration
+    pub duration_seconds: Option<u64>,
+
     pub additional_properties: HashMap<String, Vec<String>>,
 }
 
@@ -350,12 +458,18 @@ impl HRecipe {
         if let Some(author) = &self.author {
             dict.set_item("author", author).unwrap();
         }
+        if let Some(card) = &self.author_card {
+            dict.set_item("author_card", card.to_py_dict(py)).unwrap();
+        }
         if let Some(published) = &self.published {
             dict.set_item("published", published).unwrap();
         }
         if !self.category.is_empty() {
             dict.set_item("category", self.category.clone()).unwrap();
         }
+        if let Some(seconds) = self.duration_seconds {
+            dict.set_item("duration_seconds", seconds).unwrap();
+        }
 
         for (key, values) in &self.additional_properties {
             dict.set_item(key, values.clone()).unwrap();
@@ -371,15 +485,94 @@ pub struct HProduct {
     pub name: Option<String>,
     pub description: Option<String>,
     pub photo: Option<String>,
-    pub price: Option<String>,
+    pub price: Option<String>, // Raw price text, e.g. "$19.99"
+    pub currency: Option<String>,
+    pub price_amount: Option<f64>, // Numeric amount parsed from `price`
+    pub price_currency: Option<String>, // `p-currency`, else the currency in `price`
     pub brand: Option<String>,
     pub category: Vec<String>,
     pub rating: Option<f32>,
     pub url: Option<String>,
     pub identifier: Option<String>,
+    pub review: Vec<HReview>,
     pub additional_properties: HashMap<String, Vec<String>>,
 }
 
+/// Currency symbols and the ISO 4217 code they stand for
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("US$", "USD"),
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+    ("₹", "INR"),
+    ("₩", "KRW"),
+    ("₽", "RUB"),
+];
+
+impl HProduct {
+    /// Split a price string such as `"$19.99"` or `"19.99 USD"` into amount and currency
+    ///
+    /// The currency is an uppercase ISO 4217 code or a currency symbol written
+    /// next to the number; other words, like `Now` in `"Now 20 EUR"`, are
+    /// ignored. Thousands separators are ignored; a comma followed by exactly
+    /// two digits is read as a decimal comma (`"19,99 €"`), and dots that each
+    /// start a group of exactly three digits, with no comma, group thousands
+    /// (`"€1.000"` is one thousand).
+    pub fn parse_price(price: &str) -> (Option<f64>, Option<String>) {
+        let is_digit = |c: char| c.is_ascii_digit();
+        let (Some(start), Some(end)) = (price.find(is_digit), price.rfind(is_digit)) else {
+            return (None, None);
+        };
+        let before = price[..start].split_whitespace().next_back();
+        let after = price[end + 1..].split_whitespace().next();
+
+        let symbol = |word: &str| {
+            CURRENCY_SYMBOLS
+                .iter()
+                .find(|(symbol, _)| *symbol == word)
+                .map(|(_, code)| code.to_string())
+        };
+        let code = |word: &str| {
+            (word.len() == 3 && word.bytes().all(|b| b.is_ascii_uppercase()))
+                .then(|| word.to_string())
+        };
+        let currency = before
+            .and_then(symbol)
+            .or_else(|| after.and_then(symbol))
+            .or_else(|| before.and_then(code))
+            .or_else(|| after.and_then(code));
+
+        (Self::parse_amount(&price[start..=end]), currency)
+    }
+
+    fn parse_amount(text: &str) -> Option<f64> {
+        let number: String =
+            text.chars().filter(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-')).collect();
+        let normalized = match (number.rfind('.'), number.rfind(',')) {
+            // "1.299,00": dots group thousands, the comma is the decimal point
+            (Some(dot), Some(comma)) if comma > dot => number.replace('.', "").replace(',', "."),
+            (None, Some(comma))
+                if number.len() - comma == 3 && number.matches(',').count() == 1 =>
+            {
+                number.replace(',', ".")
+            }
+            // "1.000" or "1.250.000": European thousands grouping, no decimal part
+            (Some(_), None) if Self::is_dot_grouped(&number) => number.replace('.', ""),
+            _ => number.replace(',', ""),
+        };
+        normalized.parse().ok()
+    }
+
+    /// Whether dots split `number` into a 1–3 digit lead and 3-digit groups
+    fn is_dot_grouped(number: &str) -> bool {
+        let mut groups = number.trim_start_matches('-').split('.');
+        let is_digits = |group: &str| group.bytes().all(|b| b.is_ascii_digit());
+        groups.next().is_some_and(|lead| (1..=3).contains(&lead.len()) && is_digits(lead))
+            && groups.all(|group| group.len() == 3 && is_digits(group))
+    }
+}
+
 #[cfg(feature = "python")]
 impl HProduct {
     pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
@@ -397,6 +590,15 @@ impl HProduct {
         if let Some(price) = &self.price {
             dict.set_item("price", price).unwrap();
         }
+        if let Some(currency) = &self.currency {
+            dict.set_item("currency", currency).unwrap();
+        }
+        if let Some(price_amount) = self.price_amount {
+            dict.set_item("price_amount", price_amount).unwrap();
+        }
+        if let Some(price_currency) = &self.price_currency {
+            dict.set_item("price_currency", price_currency).unwrap();
+        }
         if let Some(brand) = &self.brand {
             dict.set_item("brand", brand).unwrap();
         }
@@ -412,6 +614,10 @@ impl HProduct {
         if let Some(identifier) = &self.identifier {
             dict.set_item("identifier", identifier).unwrap();
         }
+        if !self.review.is_empty() {
+            let reviews: Vec<Py<PyDict>> = self.review.iter().map(|r| r.to_py_dict(py)).collect();
+            dict.set_item("review", reviews).unwrap();
+        }
 
         for (key, values) in &self.additional_properties {
             dict.set_item(key, values.clone()).unwrap();
@@ -467,6 +673,8 @@ pub struct HAdr {
     pub region: Option<String>,
     pub postal_code: Option<String>,
     pub country_name: Option<String>,
+    /// Location from a nested h-geo or `p-geo` value
+    pub geo: Option<Box<HGeo>>,
     pub additional_properties: HashMap<String, Vec<String>>,
 }
 
@@ -496,6 +704,9 @@ impl HAdr {
         if let Some(country_name) = &self.country_name {
             dict.set_item("country_name", country_name).unwrap();
         }
+        if let Some(geo) = &self.geo {
+            dict.set_item("geo", geo.to_py_dict(py)).unwrap();
+        }
 
         for (key, values) in &self.additional_properties {
             dict.set_item(key, values.clone()).unwrap();
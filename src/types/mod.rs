@@ -1,14 +1,36 @@
 //! Type definitions for metadata extraction
 
+pub mod address;
+pub mod authorship;
+pub mod breadcrumbs;
+pub mod canonical;
+pub mod diagnostics;
+pub mod diff;
+pub mod discovered_url;
 pub mod dublin_core;
+pub mod embedded_json;
+pub mod extracted;
+pub mod geo;
+pub mod has_data;
+pub mod headers;
 pub mod jsonld;
+pub mod license;
 pub mod manifest;
+pub mod media;
 pub mod meta;
 pub mod microdata;
 pub mod microformats;
 pub mod oembed;
+pub mod opensearch;
 pub mod rdfa;
+pub mod resource_hints;
+mod setters;
 pub mod social;
+pub mod social_preview;
+pub mod social_profiles;
+pub mod theme_color;
+
+pub use has_data::HasData;
 
 // Re-export microformat types for backward compatibility
 pub use microformats::*;
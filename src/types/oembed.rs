@@ -101,3 +101,42 @@ impl OEmbedDiscovery {
         dict.unbind()
     }
 }
+
+#[cfg(all(test, feature = "python"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_py_dict_keeps_xml_endpoints_and_titles() {
+        Python::with_gil(|py| {
+            let discovery = OEmbedDiscovery {
+                json_endpoints: vec![OEmbedEndpoint {
+                    href: "https://example.com/oembed?format=json".to_string(),
+                    format: OEmbedFormat::Json,
+                    title: Some("JSON title".to_string()),
+                }],
+                xml_endpoints: vec![OEmbedEndpoint {
+                    href: "https://example.com/oembed?format=xml".to_string(),
+                    format: OEmbedFormat::Xml,
+                    title: Some("XML title".to_string()),
+                }],
+            };
+
+            let py_dict = discovery.to_py_dict(py);
+            let dict = py_dict.bind(py);
+
+            for (key, format, title) in
+                [("json_endpoints", "json", "JSON title"), ("xml_endpoints", "xml", "XML title")]
+            {
+                let endpoints = dict.get_item(key).unwrap().unwrap();
+                let endpoint = endpoints.get_item(0).unwrap();
+                assert_eq!(
+                    endpoint.get_item("format").unwrap().extract::<String>().unwrap(),
+                    format
+                );
+                assert_eq!(endpoint.get_item("title").unwrap().extract::<String>().unwrap(), title);
+                assert!(endpoint.get_item("href").is_ok());
+            }
+        });
+    }
+}
@@ -0,0 +1,107 @@
+//! Types for OpenSearch description discovery
+//!
+//! Sites advertise their search engine with
+//! `<link rel="search" type="application/opensearchdescription+xml">`, which
+//! points to a small XML document describing the search URL templates.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::{PyDict, PyList};
+use serde::{Deserialize, Serialize};
+
+/// An OpenSearch description link found in HTML
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OpenSearchLink {
+    /// URL of the OpenSearch description document (resolved)
+    pub href: String,
+
+    /// Human-readable name from the link's `title` attribute
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// A parsed OpenSearch description document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OpenSearchDescription {
+    /// Short name of the search engine (`<ShortName>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+
+    /// Description of the search engine (`<Description>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Search URL templates (`<Url>`), in document order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<OpenSearchUrl>,
+}
+
+/// A `<Url>` template from an OpenSearch description
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OpenSearchUrl {
+    /// URL template, e.g. `https://example.com/search?q={searchTerms}`
+    pub template: String,
+
+    /// MIME type of the search results, e.g. `text/html`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// Role of the template (`results`, `suggestions`, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rel: Option<String>,
+
+    /// HTTP method, e.g. `GET` or `POST`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+#[cfg(feature = "python")]
+impl OpenSearchLink {
+    /// Convert to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        dict.set_item("href", &self.href).unwrap();
+        if let Some(ref v) = self.title {
+            dict.set_item("title", v).unwrap();
+        }
+
+        dict.into()
+    }
+}
+
+#[cfg(feature = "python")]
+impl OpenSearchDescription {
+    /// Convert to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        if let Some(ref v) = self.short_name {
+            dict.set_item("short_name", v).unwrap();
+        }
+        if let Some(ref v) = self.description {
+            dict.set_item("description", v).unwrap();
+        }
+        if !self.urls.is_empty() {
+            let list = PyList::empty_bound(py);
+            for url in &self.urls {
+                let url_dict = PyDict::new_bound(py);
+                url_dict.set_item("template", &url.template).unwrap();
+                if let Some(ref v) = url.r#type {
+                    url_dict.set_item("type", v).unwrap();
+                }
+                if let Some(ref v) = url.rel {
+                    url_dict.set_item("rel", v).unwrap();
+                }
+                if let Some(ref v) = url.method {
+                    url_dict.set_item("method", v).unwrap();
+                }
+                list.append(url_dict).unwrap();
+            }
+            dict.set_item("urls", list).unwrap();
+        }
+
+        dict.into()
+    }
+}
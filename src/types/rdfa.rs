@@ -3,18 +3,22 @@
 //! RDFa is a W3C standard for embedding structured data in HTML using attributes.
 //! It provides semantic markup for web content with 62% desktop adoption.
 
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::types::{PyDict, PyList};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{json, Map, Value};
+
+use super::jsonld::JsonLdObject;
 
 /// RDFa item representing a resource with properties
 ///
 /// Corresponds to an element with `typeof` or `vocab` attribute.
 /// Can contain nested items and multiple property values.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub struct RdfaItem {
     /// The resource type(s) from typeof attribute
     #[serde(rename = "type")]
@@ -32,7 +36,7 @@ pub struct RdfaItem {
     /// Properties extracted from property attributes
     /// Key: property name, Value: array of property values
     #[serde(flatten)]
-    pub properties: HashMap<String, Vec<RdfaValue>>,
+    pub properties: IndexMap<String, Vec<RdfaValue>>,
 }
 
 /// Value of an RDFa property
@@ -40,6 +44,7 @@ pub struct RdfaItem {
 /// Can be a literal text, URI reference, nested item, or typed literal
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum RdfaValue {
     /// Nested RDFa item (must come first for untagged serde)
     Item(Box<RdfaItem>),
@@ -47,6 +52,9 @@ pub enum RdfaValue {
     /// Typed literal with datatype
     TypedLiteral { value: String, datatype: String },
 
+    /// Literal tagged with a language (`lang` / `xml:lang` on the property element)
+    LangLiteral { value: String, language: String },
+
     /// Literal text value (before Resource since both accept strings)
     Literal(String),
 
@@ -54,10 +62,28 @@ pub enum RdfaValue {
     Resource(String),
 }
 
+/// An RDF triple produced from RDFa markup
+///
+/// The subject is an IRI or a blank node identifier (`_:b0`). The object is a
+/// literal ([`RdfaValue::Literal`], [`RdfaValue::TypedLiteral`], [`RdfaValue::LangLiteral`])
+/// or a [`RdfaValue::Resource`];
+/// nested items are referenced by their subject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Triple {
+    /// Subject IRI or blank node identifier
+    pub subject: String,
+
+    /// Fully expanded predicate IRI
+    pub predicate: String,
+
+    /// Object value
+    pub object: RdfaValue,
+}
+
 impl RdfaItem {
     /// Create a new empty RDFa item
     pub fn new() -> Self {
-        Self { type_of: None, about: None, vocab: None, properties: HashMap::new() }
+        Self { type_of: None, about: None, vocab: None, properties: IndexMap::new() }
     }
 
     /// Set the item type(s)
@@ -84,6 +110,77 @@ impl RdfaItem {
     }
 }
 
+impl RdfaItem {
+    /// Convert to an equivalent JSON-LD object
+    ///
+    /// `typeof` becomes `@type` (an array when there are several), `about`
+    /// becomes `@id` and `vocab` becomes `{"@vocab": ...}` in `@context`.
+    /// Resources map to `{"@id": ...}`, typed and language-tagged literals to
+    /// `{"@value": ..., "@type"/"@language": ...}`, and nested items to nested
+    /// objects, which only carry their own `@context` when their vocabulary
+    /// differs from the enclosing one.
+    pub fn to_jsonld(&self) -> JsonLdObject {
+        JsonLdObject {
+            context: self.vocab.as_ref().map(|vocab| json!({ "@vocab": vocab })),
+            type_: self.jsonld_type(),
+            id: self.about.clone(),
+            graph: None,
+            properties: self.jsonld_properties(self.vocab.as_deref()).into_iter().collect(),
+        }
+    }
+
+    fn jsonld_type(&self) -> Option<Value> {
+        match self.type_of.as_deref().unwrap_or_default() {
+            [] => None,
+            [single] => Some(Value::String(single.clone())),
+            types => Some(json!(types)),
+        }
+    }
+
+    fn jsonld_properties(&self, vocab: Option<&str>) -> Map<String, Value> {
+        let mut map = Map::new();
+        for (name, values) in &self.properties {
+            let mut converted: Vec<Value> =
+                values.iter().map(|value| value.to_jsonld(vocab)).collect();
+            let value =
+                if converted.len() == 1 { converted.remove(0) } else { Value::Array(converted) };
+            map.insert(name.clone(), value);
+        }
+        map
+    }
+}
+
+impl RdfaValue {
+    /// Convert to a JSON-LD value, given the vocabulary in effect
+    fn to_jsonld(&self, vocab: Option<&str>) -> Value {
+        match self {
+            RdfaValue::Literal(text) => Value::String(text.clone()),
+            RdfaValue::Resource(iri) => json!({ "@id": iri }),
+            RdfaValue::TypedLiteral { value, datatype } => {
+                json!({ "@value": value, "@type": datatype })
+            }
+            RdfaValue::LangLiteral { value, language } => {
+                json!({ "@value": value, "@language": language })
+            }
+            RdfaValue::Item(item) => {
+                let item_vocab = item.vocab.as_deref().or(vocab);
+                let mut object = Map::new();
+                if let Some(nested) = item.vocab.as_deref().filter(|v| Some(*v) != vocab) {
+                    object.insert("@context".to_string(), json!({ "@vocab": nested }));
+                }
+                if let Some(item_type) = item.jsonld_type() {
+                    object.insert("@type".to_string(), item_type);
+                }
+                if let Some(ref about) = item.about {
+                    object.insert("@id".to_string(), Value::String(about.clone()));
+                }
+                object.extend(item.jsonld_properties(item_vocab));
+                Value::Object(object)
+            }
+        }
+    }
+}
+
 #[cfg(feature = "python")]
 impl RdfaItem {
     /// Convert to Python dictionary
@@ -124,6 +221,18 @@ impl RdfaItem {
     }
 }
 
+#[cfg(feature = "python")]
+impl Triple {
+    /// Convert to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("subject", &self.subject).unwrap();
+        dict.set_item("predicate", &self.predicate).unwrap();
+        dict.set_item("object", self.object.to_py_value(py)).unwrap();
+        dict.unbind()
+    }
+}
+
 #[cfg(feature = "python")]
 impl RdfaValue {
     /// Convert to Python value
@@ -138,6 +247,12 @@ impl RdfaValue {
                 dict.set_item("datatype", datatype).unwrap();
                 dict.to_object(py)
             }
+            RdfaValue::LangLiteral { value, language } => {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("value", value).unwrap();
+                dict.set_item("language", language).unwrap();
+                dict.to_object(py)
+            }
         }
     }
 }
@@ -431,4 +546,94 @@ mod tests {
             assert!(py_dict.contains("type").unwrap());
         });
     }
+
+    #[test]
+    fn test_properties_preserve_insertion_order() {
+        let mut item = RdfaItem::new();
+        item.add_property("name".to_string(), RdfaValue::Literal("Jane".to_string()));
+        item.add_property("jobTitle".to_string(), RdfaValue::Literal("Editor".to_string()));
+        item.add_property("email".to_string(), RdfaValue::Literal("jane@example.com".to_string()));
+
+        let keys: Vec<&str> = item.properties.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["name", "jobTitle", "email"]);
+
+        let json = serde_json::to_string(&item).unwrap();
+        assert_eq!(json, r#"{"name":["Jane"],"jobTitle":["Editor"],"email":["jane@example.com"]}"#);
+    }
+
+    #[test]
+    fn test_to_jsonld_person_with_nested_address_and_typed_age() {
+        let mut address = RdfaItem::new().with_type(vec!["PostalAddress".to_string()]);
+        address.add_property(
+            "streetAddress".to_string(),
+            RdfaValue::Literal("123 Main St".to_string()),
+        );
+
+        let mut person = RdfaItem::new()
+            .with_type(vec!["Person".to_string()])
+            .with_vocab("https://schema.org/".to_string())
+            .with_about("https://example.com/#jane".to_string());
+        person.add_property("name".to_string(), RdfaValue::Literal("Jane Doe".to_string()));
+        person.add_property(
+            "age".to_string(),
+            RdfaValue::TypedLiteral {
+                value: "42".to_string(),
+                datatype: "http://www.w3.org/2001/XMLSchema#integer".to_string(),
+            },
+        );
+        person.add_property(
+            "sameAs".to_string(),
+            RdfaValue::Resource("https://social.example/@jane".to_string()),
+        );
+        person.add_property("address".to_string(), RdfaValue::Item(Box::new(address)));
+
+        let jsonld = person.to_jsonld();
+        assert_eq!(jsonld.context, Some(json!({ "@vocab": "https://schema.org/" })));
+        assert_eq!(jsonld.type_, Some(json!("Person")));
+        assert_eq!(jsonld.id.as_deref(), Some("https://example.com/#jane"));
+        assert_eq!(jsonld.properties["name"], json!("Jane Doe"));
+        assert_eq!(
+            jsonld.properties["age"],
+            json!({ "@value": "42", "@type": "http://www.w3.org/2001/XMLSchema#integer" })
+        );
+        assert_eq!(jsonld.properties["sameAs"], json!({ "@id": "https://social.example/@jane" }));
+        assert_eq!(
+            jsonld.properties["address"],
+            json!({ "@type": "PostalAddress", "streetAddress": "123 Main St" })
+        );
+        assert_eq!(jsonld.context_vocab().as_deref(), Some("https://schema.org/"));
+    }
+
+    #[test]
+    fn test_to_jsonld_lang_literal_and_nested_vocab() {
+        let mut creator = RdfaItem::new()
+            .with_type(vec!["Agent".to_string()])
+            .with_vocab("http://xmlns.com/foaf/0.1/".to_string());
+        creator.add_property("name".to_string(), RdfaValue::Literal("Ada".to_string()));
+
+        let mut item = RdfaItem::new().with_vocab("https://schema.org/".to_string());
+        item.add_property(
+            "headline".to_string(),
+            RdfaValue::LangLiteral { value: "Bonjour".to_string(), language: "fr".to_string() },
+        );
+        item.add_property("keywords".to_string(), RdfaValue::Literal("a".to_string()));
+        item.add_property("keywords".to_string(), RdfaValue::Literal("b".to_string()));
+        item.add_property("creator".to_string(), RdfaValue::Item(Box::new(creator)));
+
+        let jsonld = item.to_jsonld();
+        assert!(jsonld.type_.is_none());
+        assert_eq!(
+            jsonld.properties["headline"],
+            json!({ "@value": "Bonjour", "@language": "fr" })
+        );
+        assert_eq!(jsonld.properties["keywords"], json!(["a", "b"]));
+        assert_eq!(
+            jsonld.properties["creator"],
+            json!({
+                "@context": { "@vocab": "http://xmlns.com/foaf/0.1/" },
+                "@type": "Agent",
+                "name": "Ada"
+            })
+        );
+    }
 }
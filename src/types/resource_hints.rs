@@ -0,0 +1,242 @@
+//! Types for resource hints
+//!
+//! Resource hints (`preload`, `preconnect`, `dns-prefetch`, `prefetch`, `prerender`)
+//! tell the browser which origins and assets a page needs ahead of time.
+//! Specification: https://www.w3.org/TR/resource-hints/
+//!
+//! Stylesheet and `modulepreload` links are collected alongside them, so the
+//! declared assets of a page can be audited in one place (see
+//! [`ResourceHints::assets`]).
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+
+/// A single resource hint declared by a `<link>` tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ResourceHint {
+    /// Target URL (resolved against the base URL when possible)
+    ///
+    /// Empty for image preloads that only declare `imagesrcset`.
+    pub href: String,
+
+    /// Destination of a preload (`font`, `image`, `script`, `style`, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#as: Option<String>,
+
+    /// MIME type of the hinted resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// CORS mode, normalized to `anonymous` (also for a bare `crossorigin`)
+    /// or `use-credentials`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crossorigin: Option<String>,
+
+    /// Subresource Integrity metadata (`sha384-...`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<String>,
+
+    /// Media query restricting when the hint applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+
+    /// Responsive image candidates of an `as="image"` preload (`imagesrcset`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imagesrcset: Vec<ImageCandidate>,
+
+    /// Sizes attribute paired with `imagesrcset` (`imagesizes`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imagesizes: Option<String>,
+}
+
+/// One candidate of a responsive image source set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ImageCandidate {
+    /// Candidate URL (resolved against the base URL when possible)
+    pub url: String,
+
+    /// Width (`480w`) or pixel density (`2x`) descriptor, if given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor: Option<String>,
+}
+
+/// All resource hints declared by a page, grouped by hint type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ResourceHints {
+    /// `<link rel="preload">` - fetch a resource needed for the current page
+    pub preload: Vec<ResourceHint>,
+
+    /// `<link rel="preconnect">` - open a connection to an origin early
+    pub preconnect: Vec<ResourceHint>,
+
+    /// `<link rel="dns-prefetch">` - resolve an origin's DNS early
+    pub dns_prefetch: Vec<ResourceHint>,
+
+    /// `<link rel="prefetch">` - fetch a resource likely needed for a future navigation
+    pub prefetch: Vec<ResourceHint>,
+
+    /// `<link rel="prerender">` - render a likely next page in the background
+    pub prerender: Vec<ResourceHint>,
+
+    /// `<link rel="stylesheet">` - a stylesheet the page applies
+    #[serde(default)]
+    pub stylesheet: Vec<ResourceHint>,
+
+    /// `<link rel="modulepreload">` - fetch and compile a JavaScript module early
+    #[serde(default)]
+    pub modulepreload: Vec<ResourceHint>,
+}
+
+impl ResourceHints {
+    /// Check if any resource hints were found
+    ///
+    /// Stylesheets are not hints and do not count.
+    pub fn has_hints(&self) -> bool {
+        !self.preload.is_empty()
+            || !self.preconnect.is_empty()
+            || !self.dns_prefetch.is_empty()
+            || !self.prefetch.is_empty()
+            || !self.prerender.is_empty()
+            || !self.modulepreload.is_empty()
+    }
+
+    /// Distinct hosts the page's hints point at, lowercased, in first-seen order
+    ///
+    /// Covers every hint kind, in field order (preload, preconnect,
+    /// dns-prefetch, ...), including stylesheets. Protocol-relative URLs
+    /// (`//cdn.example.net`) count; relative URLs left unresolved do not.
+    pub fn unique_origins(&self) -> Vec<String> {
+        let all = [
+            &self.preload,
+            &self.preconnect,
+            &self.dns_prefetch,
+            &self.prefetch,
+            &self.prerender,
+            &self.stylesheet,
+            &self.modulepreload,
+        ];
+
+        let mut hosts: Vec<String> = Vec::new();
+        for hint in all.into_iter().flatten() {
+            let href = hint.href.trim();
+            let absolute = match href.strip_prefix("//") {
+                Some(rest) => format!("https://{}", rest),
+                None => href.to_string(),
+            };
+            let host = url::Url::parse(&absolute)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_ascii_lowercase));
+            if let Some(host) = host {
+                if !hosts.contains(&host) {
+                    hosts.push(host);
+                }
+            }
+        }
+        hosts
+    }
+
+    /// Styles, scripts and fonts the page declares, for asset inventories
+    ///
+    /// Stylesheets and `preload as="style"` are styles, `modulepreload` and
+    /// `preload as="script"` are scripts, and `preload as="font"` are fonts.
+    pub fn assets(&self) -> AssetInventory {
+        let preloads = |kind: &str| {
+            self.preload
+                .iter()
+                .filter(|hint| hint.r#as.as_deref().is_some_and(|a| a.eq_ignore_ascii_case(kind)))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        AssetInventory {
+            styles: self.stylesheet.iter().cloned().chain(preloads("style")).collect(),
+            scripts: self.modulepreload.iter().cloned().chain(preloads("script")).collect(),
+            fonts: preloads("font"),
+        }
+    }
+}
+
+/// Declared page assets grouped by kind, from [`ResourceHints::assets`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct AssetInventory {
+    /// Stylesheets and style preloads
+    pub styles: Vec<ResourceHint>,
+
+    /// Module preloads and script preloads
+    pub scripts: Vec<ResourceHint>,
+
+    /// Font preloads
+    pub fonts: Vec<ResourceHint>,
+}
+
+// Python conversion implementations
+#[cfg(feature = "python")]
+impl ResourceHint {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("href", &self.href).unwrap();
+        if let Some(ref v) = self.r#as {
+            dict.set_item("as", v).unwrap();
+        }
+        if let Some(ref v) = self.r#type {
+            dict.set_item("type", v).unwrap();
+        }
+        if let Some(ref v) = self.crossorigin {
+            dict.set_item("crossorigin", v).unwrap();
+        }
+        if let Some(ref v) = self.integrity {
+            dict.set_item("integrity", v).unwrap();
+        }
+        if let Some(ref v) = self.media {
+            dict.set_item("media", v).unwrap();
+        }
+        if !self.imagesrcset.is_empty() {
+            let candidates: Vec<_> = self.imagesrcset.iter().map(|c| c.to_py_dict(py)).collect();
+            dict.set_item("imagesrcset", candidates).unwrap();
+        }
+        if let Some(ref v) = self.imagesizes {
+            dict.set_item("imagesizes", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl ImageCandidate {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("url", &self.url).unwrap();
+        if let Some(ref v) = self.descriptor {
+            dict.set_item("descriptor", v).unwrap();
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl ResourceHints {
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        let groups = [
+            ("preload", &self.preload),
+            ("preconnect", &self.preconnect),
+            ("dns_prefetch", &self.dns_prefetch),
+            ("prefetch", &self.prefetch),
+            ("prerender", &self.prerender),
+            ("stylesheet", &self.stylesheet),
+            ("modulepreload", &self.modulepreload),
+        ];
+        for (key, hints) in groups {
+            if !hints.is_empty() {
+                let list: Vec<_> = hints.iter().map(|h| h.to_py_dict(py)).collect();
+                dict.set_item(key, list).unwrap();
+            }
+        }
+
+        dict.unbind()
+    }
+}
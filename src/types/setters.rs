@@ -0,0 +1,36 @@
+//! Consuming `with_*` setters for the `#[non_exhaustive]` output types
+//!
+//! Outside this crate those types can't be built with struct literals, so
+//! code that needs one (tests, fixtures, custom pipelines) starts from
+//! `default()` and chains setters instead.
+
+/// Generate `with_*` setters: `with_title => title: Option<String>`
+///
+/// `Option` fields take the inner value (anything `Into` it) and wrap it in
+/// `Some`; other fields take their own type.
+macro_rules! with_setters {
+    ($type:ty { $($fields:tt)* }) => {
+        impl $type {
+            with_setters!(@fields $($fields)*);
+        }
+    };
+    (@fields) => {};
+    (@fields $setter:ident => $field:ident: Option<$inner:ty>, $($rest:tt)*) => {
+        #[doc = concat!("Set `", stringify!($field), "`")]
+        pub fn $setter(mut self, value: impl Into<$inner>) -> Self {
+            self.$field = Some(value.into());
+            self
+        }
+        with_setters!(@fields $($rest)*);
+    };
+    (@fields $setter:ident => $field:ident: $value:ty, $($rest:tt)*) => {
+        #[doc = concat!("Set `", stringify!($field), "`")]
+        pub fn $setter(mut self, value: $value) -> Self {
+            self.$field = value;
+            self
+        }
+        with_setters!(@fields $($rest)*);
+    };
+}
+
+pub(crate) use with_setters;
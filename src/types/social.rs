@@ -5,7 +5,10 @@
 //!
 //! - **Open Graph**: Used by Facebook, LinkedIn, WhatsApp, Slack, Discord (60%+ adoption)
 //! - **Twitter Cards**: Used by Twitter/X for link previews (45% adoption)
+//! - **Pinterest**: Rich Pin opt-in and `pinterest:*` tags (Slack and Discord read Open Graph)
 
+use crate::types::setters::with_setters;
+use indexmap::IndexMap;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
@@ -17,6 +20,7 @@ use serde::{Deserialize, Serialize};
 /// 60%+ of websites use Open Graph to control link preview appearance.
 /// Specification: https://ogp.me/
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub struct OpenGraph {
     // Basic metadata (required by spec)
     /// The title of the object as it should appear in the graph
@@ -33,6 +37,9 @@ pub struct OpenGraph {
     pub description: Option<String>,
     /// The name of the overall site (e.g., "IMDb", "Wikipedia")
     pub site_name: Option<String>,
+    /// Word that precedes the title in a sentence: "a", "an", "the" or "auto"
+    /// (lowercase; absent means no determiner, the spec default)
+    pub determiner: Option<String>,
     /// The locale tag (e.g., "en_US", "es_ES")
     pub locale: Option<String>,
     /// Array of alternate locales this page is available in
@@ -57,6 +64,17 @@ pub struct OpenGraph {
     pub book: Option<OgBook>,
     /// Profile-specific metadata (when type="profile")
     pub profile: Option<OgProfile>,
+    /// Music-specific metadata (when type is `music.*`)
+    #[serde(default)]
+    pub music: Option<OgMusic>,
+    /// Video-specific metadata (`video:*`, when type is `video.*`)
+    ///
+    /// Distinct from [`videos`](Self::videos), which holds the `og:video`
+    /// media files attached to any type.
+    #[serde(default)]
+    pub video: Option<OgVideoMetadata>,
+    /// Audience restrictions (`og:restrictions:*`)
+    pub restrictions: Option<OgRestrictions>,
 
     // Platform integration (Phase 6)
     /// Facebook App ID for platform integration
@@ -65,6 +83,49 @@ pub struct OpenGraph {
     pub fb_admins: Option<String>,
 }
 
+with_setters!(OpenGraph {
+    with_title => title: Option<String>,
+    with_type => r#type: Option<String>,
+    with_url => url: Option<String>,
+    with_image => image: Option<String>,
+    with_description => description: Option<String>,
+    with_site_name => site_name: Option<String>,
+    with_determiner => determiner: Option<String>,
+    with_locale => locale: Option<String>,
+    with_locale_alternate => locale_alternate: Vec<String>,
+    with_images => images: Vec<OgImage>,
+    with_videos => videos: Vec<OgVideo>,
+    with_audios => audios: Vec<OgAudio>,
+    with_article => article: Option<OgArticle>,
+    with_book => book: Option<OgBook>,
+    with_profile => profile: Option<OgProfile>,
+    with_music => music: Option<OgMusic>,
+    with_video => video: Option<OgVideoMetadata>,
+    with_restrictions => restrictions: Option<OgRestrictions>,
+    with_fb_app_id => fb_app_id: Option<String>,
+    with_fb_admins => fb_admins: Option<String>,
+});
+
+impl OpenGraph {
+    /// The first `og:image` declared on the page, with its structured metadata
+    pub fn primary_image(&self) -> Option<&OgImage> {
+        self.images.first()
+    }
+
+    /// The title preceded by its determiner, for use in a sentence
+    ///
+    /// `og:determiner` "the" and `og:title` "Rock" give "the Rock". Only
+    /// "a", "an" and "the" are prepended; "auto" leaves the choice to the
+    /// consumer, so the bare title is returned. `None` without a title.
+    pub fn headline(&self) -> Option<String> {
+        let title = self.title.as_deref()?;
+        match self.determiner.as_deref() {
+            Some(determiner @ ("a" | "an" | "the")) => Some(format!("{} {}", determiner, title)),
+            _ => Some(title.to_string()),
+        }
+    }
+}
+
 /// Open Graph Image with full metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct OgImage {
@@ -80,6 +141,48 @@ pub struct OgImage {
     pub height: Option<u32>,
     /// Alt text for accessibility
     pub alt: Option<String>,
+    /// Whether `url` is a fetchable http(s) URL, a `data:` URI or neither
+    #[serde(default)]
+    pub url_kind: ImageUrlKind,
+}
+
+/// Kind of value in an image URL field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageUrlKind {
+    /// Absolute `http` or `https` URL with a host
+    Http,
+    /// Inline `data:` URI
+    Data,
+    /// Anything else: unresolved relative paths, other schemes, malformed values
+    #[default]
+    Invalid,
+}
+
+impl ImageUrlKind {
+    /// Classify a (resolved) image URL
+    pub fn classify(url: &str) -> Self {
+        let url = url.trim();
+        if url.get(..5).is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:")) {
+            // data:[<mediatype>][;base64],<data>
+            return if url.contains(',') { ImageUrlKind::Data } else { ImageUrlKind::Invalid };
+        }
+        match url::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => {
+                ImageUrlKind::Http
+            }
+            _ => ImageUrlKind::Invalid,
+        }
+    }
+
+    /// Lowercase name, as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageUrlKind::Http => "http",
+            ImageUrlKind::Data => "data",
+            ImageUrlKind::Invalid => "invalid",
+        }
+    }
 }
 
 /// Open Graph Video metadata
@@ -108,6 +211,20 @@ pub struct OgAudio {
     pub r#type: Option<String>,
 }
 
+impl OgVideo {
+    /// URL to embed: the HTTPS `secure_url` when declared, otherwise `url`
+    pub fn best_url(&self) -> &str {
+        self.secure_url.as_deref().unwrap_or(&self.url)
+    }
+}
+
+impl OgAudio {
+    /// URL to embed: the HTTPS `secure_url` when declared, otherwise `url`
+    pub fn best_url(&self) -> &str {
+        self.secure_url.as_deref().unwrap_or(&self.url)
+    }
+}
+
 /// Article-specific Open Graph metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct OgArticle {
@@ -151,12 +268,82 @@ pub struct OgProfile {
     pub gender: Option<String>,
 }
 
+/// Music-specific Open Graph metadata (`music.song`, `music.album`, ...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OgMusic {
+    /// Song length in seconds
+    pub duration: Option<u32>,
+    /// Albums the song is on
+    pub album: Vec<OgMusicRef>,
+    /// URLs to musician profile pages
+    pub musician: Vec<String>,
+    /// Songs on the album or playlist
+    pub song: Vec<OgMusicRef>,
+    /// Date the album was released
+    pub release_date: Option<String>,
+    /// URLs to the playlist or radio station creator's profile pages
+    pub creator: Vec<String>,
+}
+
+/// A `music:album` or `music:song` reference with its disc and track numbers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OgMusicRef {
+    /// URL of the album or song
+    pub url: String,
+    /// Disc number (`music:album:disc`, `music:song:disc`)
+    pub disc: Option<u32>,
+    /// Track number (`music:album:track`, `music:song:track`)
+    pub track: Option<u32>,
+}
+
+/// Video-specific Open Graph metadata (`video.movie`, `video.episode`, ...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OgVideoMetadata {
+    /// Actors, with the role they play
+    pub actor: Vec<OgActor>,
+    /// URLs to director profile pages
+    pub director: Vec<String>,
+    /// URLs to writer profile pages
+    pub writer: Vec<String>,
+    /// Length in seconds
+    pub duration: Option<u32>,
+    /// Date the video was released
+    pub release_date: Option<String>,
+    /// Keywords/tags associated with the video
+    pub tag: Vec<String>,
+    /// URL of the series an episode belongs to (`video.episode`)
+    pub series: Option<String>,
+}
+
+/// A `video:actor` reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OgActor {
+    /// URL to the actor's profile page
+    pub url: String,
+    /// Role the actor plays (`video:actor:role`)
+    pub role: Option<String>,
+}
+
+/// Open Graph audience restrictions (`og:restrictions:*`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OgRestrictions {
+    /// Minimum age, e.g. "18+" or "21+" (`og:restrictions:age`)
+    pub age: Option<String>,
+    /// Countries the content is limited to (`og:restrictions:country:allowed`)
+    pub country_allowed: Vec<String>,
+    /// Countries the content is blocked in (`og:restrictions:country:disallowed`)
+    pub country_disallowed: Vec<String>,
+    /// Regulated content types, e.g. "alcohol" (`og:restrictions:content`)
+    pub content: Vec<String>,
+}
+
 /// Twitter Card data (45% adoption)
 ///
 /// Twitter Cards control how links appear on Twitter/X.
 /// Falls back to Open Graph when Twitter-specific tags are missing.
 /// Specification: https://developer.twitter.com/en/docs/twitter-for-websites/cards/overview/abouts-cards
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[non_exhaustive]
 pub struct TwitterCard {
     /// Card type: "summary", "summary_large_image", "app", or "player"
     pub card: Option<String>,
@@ -186,6 +373,89 @@ pub struct TwitterCard {
     pub app: Option<TwitterApp>,
     /// Player card specific metadata (video/audio)
     pub player: Option<TwitterPlayer>,
+
+    // Privacy
+    /// Opt out of Twitter/X personalization tracking (`twitter:dnt`)
+    pub dnt: Option<bool>,
+    /// Embedded widget settings keyed by the name after `twitter:widgets:`
+    /// (`csp`, `theme`, `link-color`, ...)
+    pub widgets: IndexMap<String, String>,
+}
+
+with_setters!(TwitterCard {
+    with_card => card: Option<String>,
+    with_title => title: Option<String>,
+    with_description => description: Option<String>,
+    with_image => image: Option<String>,
+    with_image_alt => image_alt: Option<String>,
+    with_site => site: Option<String>,
+    with_site_id => site_id: Option<String>,
+    with_creator => creator: Option<String>,
+    with_creator_id => creator_id: Option<String>,
+    with_app => app: Option<TwitterApp>,
+    with_player => player: Option<TwitterPlayer>,
+    with_dnt => dnt: Option<bool>,
+    with_widgets => widgets: IndexMap<String, String>,
+});
+
+impl TwitterCard {
+    /// The card type, normalized against the known kinds
+    ///
+    /// `None` when no `twitter:card` tag was found.
+    pub fn kind(&self) -> Option<TwitterCardKind> {
+        self.card.as_deref().map(TwitterCardKind::parse)
+    }
+}
+
+/// Normalized `twitter:card` type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TwitterCardKind {
+    /// `summary`: title, description and thumbnail
+    Summary,
+    /// `summary_large_image`: summary with a prominent image
+    SummaryLargeImage,
+    /// `app`: direct download link for a mobile app
+    App,
+    /// `player`: inline video or audio
+    Player,
+    /// Any other value, trimmed but otherwise as authored (e.g. `summary_card`)
+    Custom(String),
+}
+
+impl TwitterCardKind {
+    /// Parse a `twitter:card` value, ignoring surrounding whitespace and case
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        match value.to_ascii_lowercase().as_str() {
+            "summary" => Self::Summary,
+            "summary_large_image" => Self::SummaryLargeImage,
+            "app" => Self::App,
+            "player" => Self::Player,
+            _ => Self::Custom(value.to_string()),
+        }
+    }
+
+    /// The canonical `twitter:card` value
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Summary => "summary",
+            Self::SummaryLargeImage => "summary_large_image",
+            Self::App => "app",
+            Self::Player => "player",
+            Self::Custom(value) => value,
+        }
+    }
+
+    /// Whether this is one of the card types Twitter/X renders
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Custom(_))
+    }
+}
+
+impl std::fmt::Display for TwitterCardKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Twitter App card metadata
@@ -232,6 +502,63 @@ pub struct TwitterPlayer {
     pub stream: Option<String>,
 }
 
+/// Where a missing Twitter Card field may be filled from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackSource {
+    /// `og:title`, `og:description`, `og:image`
+    OpenGraph,
+    /// The first JSON-LD `headline` (or `name`), `description` and `image`
+    JsonLd,
+    /// `<title>`, `<meta name="description">` and `<link rel="image_src">`
+    Meta,
+}
+
+/// Per-field fallback sources for Twitter Card extraction
+///
+/// Each field lists the sources to try, in order, when the page has no
+/// `twitter:*` value for it. The default falls back to Open Graph only,
+/// as Twitter itself does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FallbackConfig {
+    pub title: Vec<FallbackSource>,
+    pub description: Vec<FallbackSource>,
+    pub image: Vec<FallbackSource>,
+}
+
+impl Default for FallbackConfig {
+    fn default() -> Self {
+        FallbackConfig {
+            title: vec![FallbackSource::OpenGraph],
+            description: vec![FallbackSource::OpenGraph],
+            image: vec![FallbackSource::OpenGraph],
+        }
+    }
+}
+
+impl FallbackConfig {
+    /// No fallback at all: only `twitter:*` tags are read
+    pub fn none() -> Self {
+        FallbackConfig { title: Vec::new(), description: Vec::new(), image: Vec::new() }
+    }
+}
+
+/// Pinterest-specific meta tags
+///
+/// Rich Pins are built from Open Graph and Schema.org data; these tags only
+/// control opt-in and opt-out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Pinterest {
+    /// Rich Pin opt-in from `<meta name="pinterest-rich-pin" content="true|false">`
+    pub rich_pin: Option<bool>,
+    /// Pinning disabled by `<meta name="pinterest" content="nopin">`
+    pub nopin: bool,
+    /// Message shown when pinning is disabled (`description` on the nopin tag)
+    pub nopin_description: Option<String>,
+    /// Every `pinterest:*` tag, keyed by the part after the prefix, in document order
+    pub properties: IndexMap<String, String>,
+}
+
 // Python conversion implementations
 
 #[cfg(feature = "python")]
@@ -259,6 +586,9 @@ impl OpenGraph {
         if let Some(ref v) = self.site_name {
             let _ = dict.set_item("site_name", v);
         }
+        if let Some(ref v) = self.determiner {
+            let _ = dict.set_item("determiner", v);
+        }
         if let Some(ref v) = self.locale {
             let _ = dict.set_item("locale", v);
         }
@@ -288,6 +618,15 @@ impl OpenGraph {
         if let Some(ref profile) = self.profile {
             let _ = dict.set_item("profile", profile.to_py_dict(py));
         }
+        if let Some(ref music) = self.music {
+            let _ = dict.set_item("music", music.to_py_dict(py));
+        }
+        if let Some(ref video) = self.video {
+            let _ = dict.set_item("video", video.to_py_dict(py));
+        }
+        if let Some(ref restrictions) = self.restrictions {
+            let _ = dict.set_item("restrictions", restrictions.to_py_dict(py));
+        }
 
         // Platform integration (Phase 6)
         if let Some(ref v) = self.fb_app_id {
@@ -322,6 +661,7 @@ impl OgImage {
         if let Some(ref v) = self.alt {
             let _ = dict.set_item("alt", v);
         }
+        let _ = dict.set_item("url_kind", self.url_kind.as_str());
         dict.unbind()
     }
 }
@@ -433,6 +773,116 @@ impl OgProfile {
     }
 }
 
+#[cfg(feature = "python")]
+impl OgMusic {
+    /// Convert OgMusic to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        if let Some(v) = self.duration {
+            let _ = dict.set_item("duration", v);
+        }
+        if !self.album.is_empty() {
+            let album: Vec<_> = self.album.iter().map(|a| a.to_py_dict(py)).collect();
+            let _ = dict.set_item("album", album);
+        }
+        if !self.musician.is_empty() {
+            let _ = dict.set_item("musician", self.musician.clone());
+        }
+        if !self.song.is_empty() {
+            let song: Vec<_> = self.song.iter().map(|s| s.to_py_dict(py)).collect();
+            let _ = dict.set_item("song", song);
+        }
+        if let Some(ref v) = self.release_date {
+            let _ = dict.set_item("release_date", v);
+        }
+        if !self.creator.is_empty() {
+            let _ = dict.set_item("creator", self.creator.clone());
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl OgMusicRef {
+    /// Convert OgMusicRef to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        let _ = dict.set_item("url", &self.url);
+        if let Some(v) = self.disc {
+            let _ = dict.set_item("disc", v);
+        }
+        if let Some(v) = self.track {
+            let _ = dict.set_item("track", v);
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl OgVideoMetadata {
+    /// Convert OgVideoMetadata to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        if !self.actor.is_empty() {
+            let actor: Vec<_> = self.actor.iter().map(|a| a.to_py_dict(py)).collect();
+            let _ = dict.set_item("actor", actor);
+        }
+        if !self.director.is_empty() {
+            let _ = dict.set_item("director", self.director.clone());
+        }
+        if !self.writer.is_empty() {
+            let _ = dict.set_item("writer", self.writer.clone());
+        }
+        if let Some(v) = self.duration {
+            let _ = dict.set_item("duration", v);
+        }
+        if let Some(ref v) = self.release_date {
+            let _ = dict.set_item("release_date", v);
+        }
+        if !self.tag.is_empty() {
+            let _ = dict.set_item("tag", self.tag.clone());
+        }
+        if let Some(ref v) = self.series {
+            let _ = dict.set_item("series", v);
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl OgActor {
+    /// Convert OgActor to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        let _ = dict.set_item("url", &self.url);
+        if let Some(ref v) = self.role {
+            let _ = dict.set_item("role", v);
+        }
+        dict.unbind()
+    }
+}
+
+#[cfg(feature = "python")]
+impl OgRestrictions {
+    /// Convert OgRestrictions to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+        if let Some(ref v) = self.age {
+            let _ = dict.set_item("age", v);
+        }
+        if !self.country_allowed.is_empty() {
+            let _ = dict.set_item("country_allowed", self.country_allowed.clone());
+        }
+        if !self.country_disallowed.is_empty() {
+            let _ = dict.set_item("country_disallowed", self.country_disallowed.clone());
+        }
+        if !self.content.is_empty() {
+            let _ = dict.set_item("content", self.content.clone());
+        }
+        dict.unbind()
+    }
+}
+
 #[cfg(feature = "python")]
 impl TwitterCard {
     /// Convert TwitterCard to Python dictionary
@@ -475,6 +925,18 @@ impl TwitterCard {
             let _ = dict.set_item("player", player.to_py_dict(py));
         }
 
+        // Privacy
+        if let Some(v) = self.dnt {
+            let _ = dict.set_item("dnt", v);
+        }
+        if !self.widgets.is_empty() {
+            let widgets = PyDict::new_bound(py);
+            for (name, value) in &self.widgets {
+                let _ = widgets.set_item(name, value);
+            }
+            let _ = dict.set_item("widgets", widgets);
+        }
+
         dict.unbind()
     }
 }
@@ -536,3 +998,30 @@ impl TwitterPlayer {
         dict.unbind()
     }
 }
+
+#[cfg(feature = "python")]
+impl Pinterest {
+    /// Convert Pinterest tags to Python dictionary
+    pub fn to_py_dict(&self, py: Python) -> Py<PyDict> {
+        let dict = PyDict::new_bound(py);
+
+        if let Some(v) = self.rich_pin {
+            let _ = dict.set_item("rich_pin", v);
+        }
+        if self.nopin {
+            let _ = dict.set_item("nopin", true);
+        }
+        if let Some(ref v) = self.nopin_description {
+            let _ = dict.set_item("nopin_description", v);
+        }
+        if !self.properties.is_empty() {
+            let properties = PyDict::new_bound(py);
+            for (key, value) in &self.properties {
+                let _ = properties.set_item(key, value);
+            }
+            let _ = dict.set_item("properties", properties);
+        }
+
+        dict.unbind()
+    }
+}
@@ -0,0 +1,28 @@
+//! Types for the resolved social preview card
+
+use serde::{Deserialize, Serialize};
+
+/// One preview card resolved from Open Graph, Twitter Cards, meta tags and JSON-LD
+///
+/// Each field holds the first value found along the fallback order documented
+/// on [`social_preview`](crate::analysis::social_preview).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SocialPreview {
+    /// Card title
+    pub title: Option<String>,
+
+    /// Card description
+    pub description: Option<String>,
+
+    /// Image URL, either an http(s) URL or a `data:` URI
+    pub image: Option<String>,
+
+    /// Name of the site the page belongs to
+    pub site_name: Option<String>,
+
+    /// URL the card links to
+    pub url: Option<String>,
+
+    /// Twitter card type, `summary` when no `twitter:card` is declared
+    pub card_type: String,
+}
@@ -0,0 +1,47 @@
+//! Types for social profile aggregation across metadata formats
+
+use serde::{Deserialize, Serialize};
+
+/// Platform a profile URL belongs to, classified by host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SocialPlatform {
+    Twitter,
+    Facebook,
+    Instagram,
+    LinkedIn,
+    GitHub,
+    YouTube,
+    TikTok,
+    Pinterest,
+    Reddit,
+    Mastodon,
+    Bluesky,
+    Threads,
+    /// Any other site (homepage, Wikipedia, ...)
+    Other,
+}
+
+/// Where a profile URL was declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfileSource {
+    /// JSON-LD `sameAs` of an Organization or Person
+    JsonLdSameAs,
+    /// `<link rel="me">` / `<a rel="me">`
+    RelMe,
+    /// Microformats h-card `u-url`
+    HCard,
+}
+
+/// A social profile URL declared by the page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialProfile {
+    /// The profile URL as declared
+    pub url: String,
+
+    /// Platform inferred from the URL's host
+    pub platform: SocialPlatform,
+
+    /// The first source that declared this profile
+    pub source: ProfileSource,
+}
@@ -0,0 +1,19 @@
+//! Types for comparing the page theme color with the Web App Manifest
+
+use crate::types::meta::ThemeColor;
+use serde::{Deserialize, Serialize};
+
+/// The theme colors declared by the page and its manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ThemeColorReport {
+    /// Every `<meta name="theme-color">`, in document order
+    pub meta_colors: Vec<ThemeColor>,
+
+    /// The manifest's `theme_color`
+    pub manifest_color: Option<String>,
+
+    /// Whether both are declared and no meta color matches the manifest
+    ///
+    /// Always `false` when either side is missing, since there is nothing to compare.
+    pub mismatch: bool,
+}
@@ -0,0 +1,38 @@
+//! Compile test for building `#[non_exhaustive]` output types downstream
+//!
+//! Struct literals of these types don't compile outside the crate; this fails
+//! to build if one of the setters it relies on goes away.
+
+use meta_oxide::prelude::*;
+use meta_oxide::social::OpenGraph;
+use meta_oxide::HasData;
+use serde_json::json;
+
+#[test]
+fn meta_tags_from_builder() {
+    let meta = MetaTags::default()
+        .with_title("Builders")
+        .with_description("Built outside the crate")
+        .with_keywords(vec!["rust".to_string(), "metadata".to_string()])
+        .with_is_adult(false);
+
+    assert!(meta.has_data());
+    assert_eq!(meta.title.as_deref(), Some("Builders"));
+    assert_eq!(meta.keywords.as_ref().map(Vec::len), Some(2));
+}
+
+#[test]
+fn extracted_data_from_builder() {
+    let product = JsonLdObject::default()
+        .with_context("https://schema.org")
+        .with_type("Product")
+        .with_property("name", "Widget");
+    let data = ExtractedData::default()
+        .with_meta(MetaTags::default().with_title("Widget"))
+        .with_opengraph(OpenGraph::default().with_type("product"))
+        .with_jsonld(vec![product]);
+
+    assert_eq!(data.jsonld[0].type_, Some(json!("Product")));
+    assert_eq!(data.jsonld[0].properties["name"], json!("Widget"));
+    assert_eq!(data.opengraph.r#type.as_deref(), Some("product"));
+}
@@ -0,0 +1,36 @@
+//! Golden snapshot tests for `extract_all_json`
+//!
+//! Each page under `tests/fixtures/golden/` is extracted and compared with the
+//! committed snapshot in `tests/snapshots/`. After an intentional output change,
+//! review and accept the new snapshots with `cargo insta review` (or rerun with
+//! `INSTA_UPDATE=always`).
+
+use meta_oxide::extract_all_json;
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/golden/{}.html", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn assert_golden(name: &str, base_url: &str) {
+    let html = fixture(name);
+    let json = extract_all_json(&html, Some(base_url)).unwrap();
+    // Output must not depend on hash seeds or run order
+    assert_eq!(json, extract_all_json(&html, Some(base_url)).unwrap());
+    insta::assert_snapshot!(name, json);
+}
+
+#[test]
+fn golden_news_article() {
+    assert_golden("news_article", "https://gazette.example/news/2024/05/bike-lanes");
+}
+
+#[test]
+fn golden_product() {
+    assert_golden("product", "https://shop.example/p/trail-runner-3");
+}
+
+#[test]
+fn golden_indieweb_post() {
+    assert_golden("indieweb_post", "https://ada.example/2024/05/14/release");
+}
@@ -0,0 +1,61 @@
+//! Compile test for the pure Rust API
+//!
+//! Touches only items that exist without the `python` feature, so
+//! `cargo test --no-default-features` fails if any of them is accidentally
+//! gated behind PyO3.
+
+use meta_oxide::extractors::{jsonld, meta, microdata, microformats, rdfa, social};
+use meta_oxide::meta::MetaTags;
+use meta_oxide::social::OpenGraph;
+use meta_oxide::{analysis, extract_all, serialize, ExtractedData};
+
+const PAGE: &str = r#"
+    <html lang="en">
+    <head>
+        <title>Embedding</title>
+        <meta name="description" content="No Python required">
+        <meta property="og:title" content="Embedding OG">
+        <link rel="canonical" href="https://example.com/embed">
+        <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Article", "headline": "Embedding"}
+        </script>
+    </head>
+    <body>
+        <div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Ada</span></div>
+        <div class="h-card"><span class="p-name">Grace</span></div>
+        <div vocab="https://schema.org/" typeof="Event"><span property="name">Launch</span></div>
+    </body>
+    </html>
+"#;
+
+#[test]
+fn extract_all_without_python() {
+    let data: ExtractedData = extract_all(PAGE, Some("https://example.com/")).unwrap();
+    assert_eq!(data.meta.title.as_deref(), Some("Embedding"));
+    assert!(!data.jsonld.is_empty());
+    assert!(data.microformats.contains_key("h-card"));
+
+    let json = serde_json::to_string(&data).unwrap();
+    let back: ExtractedData = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.meta.description, data.meta.description);
+
+    let report = analysis::canonical_report(&data);
+    assert!(report.link_canonical.is_some());
+}
+
+#[test]
+fn per_format_extractors_without_python() {
+    let tags: MetaTags = meta::extract(PAGE, None).unwrap();
+    assert_eq!(tags.description.as_deref(), Some("No Python required"));
+
+    let og: OpenGraph = social::opengraph::extract(PAGE, None).unwrap();
+    assert_eq!(og.title.as_deref(), Some("Embedding OG"));
+
+    assert_eq!(jsonld::extract(PAGE, None).unwrap().len(), 1);
+    assert_eq!(microdata::extract(PAGE, None).unwrap().len(), 1);
+    assert_eq!(microformats::hcard::extract(PAGE, None).unwrap().len(), 1);
+    assert_eq!(rdfa::extract(PAGE, None).unwrap().len(), 1);
+
+    let ntriples = serialize::extract_ntriples(PAGE, None).unwrap();
+    assert!(ntriples.contains("<https://schema.org/name>"));
+}
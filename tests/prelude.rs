@@ -0,0 +1,32 @@
+//! Compile test for `meta_oxide::prelude`
+//!
+//! Fails to build if an item is dropped from the prelude or a glob import of
+//! it becomes ambiguous.
+
+use meta_oxide::prelude::*;
+
+fn extract(html: &str) -> Result<ExtractedData> {
+    extract_all_with_options(html, Some("https://example.com/"), &ExtractOptions::default())
+}
+
+#[test]
+fn prelude_covers_common_api() {
+    let html = r#"
+        <title>Prelude</title>
+        <script type="application/ld+json">{"@type": "Article"}</script>
+        <div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Ada</span></div>
+        <div vocab="https://schema.org/" typeof="Event"><span property="name">Launch</span></div>
+    "#;
+    let data = extract(html).unwrap();
+    assert_eq!(data, extract_all(html, Some("https://example.com/")).unwrap());
+
+    let meta: &MetaTags = &data.meta;
+    let jsonld: &[JsonLdObject] = &data.jsonld;
+    let microdata: &[MicrodataItem] = &data.microdata;
+    let rdfa: &[RdfaItem] = &data.rdfa;
+    assert_eq!(meta.title.as_deref(), Some("Prelude"));
+    assert_eq!((jsonld.len(), microdata.len(), rdfa.len()), (1, 1, 1));
+
+    let error: MicroformatError = MicroformatError::ExtractionFailed("boom".to_string());
+    assert!(error.to_string().contains("boom"));
+}